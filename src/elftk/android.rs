@@ -0,0 +1,251 @@
+use constants::*;
+use error::*;
+use format::*;
+use reader::*;
+use types::*;
+
+/// Dynamic relocations packed in the Android `APS2` format (`SHT_ANDROID_REL` or
+/// `SHT_ANDROID_RELA`).
+///
+/// The packed data is a sequence of SLEB128 values: the number of relocations and the initial
+/// offset followed by groups of relocations which share their offset delta, `r_info` or addend.
+pub struct AndroidPackedRelocationsRef<'a> {
+    pub symbol_table: SymbolTableRef<'a>,
+    /// The section the relocations apply to (`sh_info`), if any.
+    pub target: Option<SectionHeaderRef<'a>>,
+    /// `true` for `SHT_ANDROID_RELA`, `false` for `SHT_ANDROID_REL`.
+    pub explicit_addends: bool,
+    data: ElfSliceRef<'a, u8, u8>,
+}
+
+/// Relocations unpacked from an [AndroidPackedRelocationsRef](struct.AndroidPackedRelocationsRef.html).
+pub struct AndroidUnpackedRelocations<'a> {
+    symbol_table: SymbolTableRef<'a>,
+    target: Option<SectionHeaderRef<'a>>,
+    entries: UnpackedEntries,
+}
+
+enum UnpackedEntries {
+    Rel(ElfT<Vec<Elf32_Rel>, Vec<Elf64_Rel>>),
+    Rela(ElfT<Vec<Elf32_Rela>, Vec<Elf64_Rela>>),
+}
+
+struct Unpacked {
+    offset: Elf64_Addr,
+    info: Elf64_Xword,
+    addend: Elf64_Sxword,
+}
+
+fn read_sleb128(data: &[u8], position: &mut usize) -> Result<i64> {
+    let mut value = 0i64;
+    let mut shift = 0;
+    loop {
+        let b = *data.get(*position)
+            .ok_or(Error::Msg { msg: "truncated Android packed relocations" })?;
+        *position += 1;
+        if shift < 64 {
+            value |= i64::from(b & 0x7f) << shift;
+        }
+        shift += 7;
+        if b & 0x80 == 0 {
+            if shift < 64 && b & 0x40 != 0 {
+                value |= -1 << shift;
+            }
+            return Ok(value);
+        }
+    }
+}
+
+impl<'a> AndroidPackedRelocationsRef<'a> {
+    pub(super) fn new(symbol_table: SymbolTableRef<'a>, target: Option<SectionHeaderRef<'a>>,
+                      explicit_addends: bool, data: ElfSliceRef<'a, u8, u8>) -> Self
+    {
+        AndroidPackedRelocationsRef { symbol_table, target, explicit_addends, data }
+    }
+
+    /// Decodes the packed relocations.
+    pub fn unpack(&self) -> Result<AndroidUnpackedRelocations<'a>> {
+        let data = self.data.apply(|&s| s, |&s| s);
+        if !data.starts_with(b"APS2") {
+            return Err(Error::Msg { msg: "Android packed relocations without APS2 header" });
+        }
+        let mut position = 4;
+        let mut next = || read_sleb128(data, &mut position);
+        let count = next()? as usize;
+        let mut offset = next()? as Elf64_Addr;
+        let mut info = 0;
+        let mut addend = 0i64;
+        let mut relocations = Vec::with_capacity(count.min(data.len()));
+        while relocations.len() < count {
+            let group_size = next()? as usize;
+            let group_flags = next()? as Elf64_Xword;
+            let grouped_by_info = group_flags & RELOCATION_GROUPED_BY_INFO_FLAG != 0;
+            let grouped_by_offset_delta = group_flags & RELOCATION_GROUPED_BY_OFFSET_DELTA_FLAG != 0;
+            let grouped_by_addend = group_flags & RELOCATION_GROUPED_BY_ADDEND_FLAG != 0;
+            let has_addend = group_flags & RELOCATION_GROUP_HAS_ADDEND_FLAG != 0;
+            if has_addend && !self.explicit_addends {
+                return Err(Error::Msg { msg: "Android packed Rel relocations with addends" });
+            }
+            if group_size == 0 || group_size > count - relocations.len() {
+                return Err(Error::Msg { msg: "invalid Android packed relocation group size" });
+            }
+            let offset_delta = if grouped_by_offset_delta { next()? } else { 0 };
+            if grouped_by_info {
+                info = next()? as Elf64_Xword;
+            }
+            if !has_addend {
+                addend = 0;
+            } else if grouped_by_addend {
+                addend = addend.wrapping_add(next()?);
+            }
+            for _ in 0..group_size {
+                let delta = if grouped_by_offset_delta { offset_delta } else { next()? };
+                offset = offset.wrapping_add(delta as Elf64_Addr);
+                if !grouped_by_info {
+                    info = next()? as Elf64_Xword;
+                }
+                if has_addend && !grouped_by_addend {
+                    addend = addend.wrapping_add(next()?);
+                }
+                relocations.push(Unpacked { offset, info, addend });
+            }
+        }
+
+        // Store the entries in the byte order of the file so that the usual accessors apply.
+        let little_endian = match self.data {
+            ElfT::Elf32LE(_) | ElfT::Elf64LE(_) => true,
+            ElfT::Elf32BE(_) | ElfT::Elf64BE(_) => false,
+        };
+        let swap32 = |x: u32| if little_endian { x.to_le() } else { x.to_be() };
+        let swap64 = |x: u64| if little_endian { x.to_le() } else { x.to_be() };
+        let format = self.data.construct_from(());
+        let entries = if self.explicit_addends {
+            UnpackedEntries::Rela(format.map(
+                |()| relocations.iter().map(|r| Elf32_Rela {
+                    r_offset: swap32(r.offset as u32),
+                    r_info: swap32(r.info as u32),
+                    r_addend: swap32(r.addend as u32) as Elf32_Sword,
+                }).collect(),
+                |()| relocations.iter().map(|r| Elf64_Rela {
+                    r_offset: swap64(r.offset),
+                    r_info: swap64(r.info),
+                    r_addend: swap64(r.addend as u64) as Elf64_Sxword,
+                }).collect()))
+        } else {
+            UnpackedEntries::Rel(format.map(
+                |()| relocations.iter().map(|r| Elf32_Rel {
+                    r_offset: swap32(r.offset as u32),
+                    r_info: swap32(r.info as u32),
+                }).collect(),
+                |()| relocations.iter().map(|r| Elf64_Rel {
+                    r_offset: swap64(r.offset),
+                    r_info: swap64(r.info),
+                }).collect()))
+        };
+        Ok(AndroidUnpackedRelocations {
+            symbol_table: self.symbol_table,
+            target: self.target,
+            entries,
+        })
+    }
+}
+
+fn as_slices<'a, T32, T64>(entries: &'a ElfT<Vec<T32>, Vec<T64>>) -> ElfSliceRef<'a, T32, T64> {
+    match *entries {
+        ElfT::Elf32LE(ref v) => ElfT::Elf32LE(&v[..]),
+        ElfT::Elf32BE(ref v) => ElfT::Elf32BE(&v[..]),
+        ElfT::Elf64LE(ref v) => ElfT::Elf64LE(&v[..]),
+        ElfT::Elf64BE(ref v) => ElfT::Elf64BE(&v[..]),
+    }
+}
+
+impl<'a> AndroidUnpackedRelocations<'a> {
+    pub fn len(&self) -> usize {
+        match self.entries {
+            UnpackedEntries::Rel(ref entries)  => entries.apply(Vec::len, Vec::len),
+            UnpackedEntries::Rela(ref entries) => entries.apply(Vec::len, Vec::len),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the entry at `index` with its symbol resolved.
+    pub fn get(&self, index: usize) -> Result<RelocationRef<'a>> {
+        let machine = self.symbol_table.machine;
+        let (offset, info, addend) = match self.entries {
+            UnpackedEntries::Rel(ref entries) => {
+                let entry = as_slices(entries).get(index)?;
+                (entry.r_offset(), entry.info(machine), None)
+            },
+            UnpackedEntries::Rela(ref entries) => {
+                let entry = as_slices(entries).get(index)?;
+                (entry.r_offset(), entry.info(machine), Some(entry.r_addend()))
+            },
+        };
+        Ok(RelocationRef {
+            offset,
+            info,
+            addend,
+            symbol: self.symbol_table.get(info.symbol_index as usize)?,
+        })
+    }
+
+    /// Returns the unpacked relocations as a
+    /// [RelocationTable](enum.SectionDataRef.html#variant.RelocationTable) or an
+    /// [ExplicitRelocationTable](enum.SectionDataRef.html#variant.ExplicitRelocationTable), like
+    /// those of unpacked `SHT_REL` and `SHT_RELA` sections.
+    pub fn data<'b>(&'b self) -> SectionDataRef<'b> where
+        'a: 'b,
+    {
+        match self.entries {
+            UnpackedEntries::Rel(ref entries) => SectionDataRef::RelocationTable(RelTableRef {
+                symbol_table: self.symbol_table,
+                target: self.target,
+                entries: as_slices(entries),
+            }),
+            UnpackedEntries::Rela(ref entries) => SectionDataRef::ExplicitRelocationTable(RelaTableRef {
+                symbol_table: self.symbol_table,
+                target: self.target,
+                entries: as_slices(entries),
+            }),
+        }
+    }
+}
+
+/// The contents of an Android [NT_ANDROID_TYPE_IDENT](constant.NT_ANDROID_TYPE_IDENT.html) note
+/// (`.note.android.ident`).
+#[derive(Debug, Clone, Copy)]
+pub struct AndroidIdent<'a> {
+    /// The minimum Android API level.
+    pub api_level: Elf_Word,
+    /// The NDK version (such as `r25c`), recorded by NDK r14 and later.
+    pub ndk_version: Option<&'a [u8]>,
+    /// The NDK build number, recorded by NDK r14 and later.
+    pub ndk_build_number: Option<&'a [u8]>,
+}
+
+impl<'a> AndroidIdent<'a> {
+    /// Parses the descriptor of an `NT_ANDROID_TYPE_IDENT` note.
+    pub fn parse(desc: &'a [u8], little_endian: bool) -> Option<AndroidIdent<'a>> {
+        if desc.len() < 4 {
+            return None;
+        }
+        let api_level = [desc[0], desc[1], desc[2], desc[3]];
+        let api_level = if little_endian {
+            Elf_Word::from_le_bytes(api_level)
+        } else {
+            Elf_Word::from_be_bytes(api_level)
+        };
+        // The NDK version and build number are null-padded 64-byte strings.
+        let string = |start: usize| desc.get(start..start+64).map(|s| {
+            s.iter().position(|&b| b == 0).map_or(s, |len| &s[..len])
+        });
+        Some(AndroidIdent {
+            api_level,
+            ndk_version: string(4),
+            ndk_build_number: string(68),
+        })
+    }
+}
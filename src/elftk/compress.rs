@@ -0,0 +1,170 @@
+use std::io::Read;
+
+use miniz_oxide::deflate::{self, CompressionLevel as ZlibLevel};
+use miniz_oxide::inflate;
+use ruzstd::decoding::StreamingDecoder;
+use ruzstd::encoding::{self, CompressionLevel as ZstdLevel};
+
+use constants::*;
+use edit::*;
+use error::*;
+use types::*;
+
+/// The algorithm used to compress an [SHF_COMPRESSED](constant.SHF_COMPRESSED.html) section.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compression {
+    /// zlib ([ELFCOMPRESS_ZLIB](constant.ELFCOMPRESS_ZLIB.html)), which all tools supporting
+    /// compressed sections understand.
+    Zlib,
+    /// Zstandard ([ELFCOMPRESS_ZSTD](constant.ELFCOMPRESS_ZSTD.html)).
+    Zstd,
+}
+
+impl Compression {
+    /// Returns the `ch_type` of the compression header of sections compressed with the algorithm.
+    pub fn ch_type(self) -> Elf_Word {
+        match self {
+            Compression::Zlib => ELFCOMPRESS_ZLIB,
+            Compression::Zstd => ELFCOMPRESS_ZSTD,
+        }
+    }
+}
+
+// The magic number and the size of the header of the sections compressed the GNU way, whose names
+// start with `.zdebug` rather than `.debug`.
+const ZDEBUG_MAGIC: &[u8] = b"ZLIB";
+const ZDEBUG_HEADER_SIZE: usize = 12;
+
+fn decompress(ch_type: Elf_Word, data: &[u8], size: usize) -> Result<Vec<u8>> {
+    let decompressed = match ch_type {
+        ELFCOMPRESS_ZLIB => inflate::decompress_to_vec_zlib_with_limit(data, size)
+            .map_err(|_| Error::Msg { msg: "invalid zlib compressed data" })?,
+        ELFCOMPRESS_ZSTD => {
+            // ch_size comes from the file, so let the vector grow with the decompressed data rather
+            // than reserving it up front, and stop one byte past it to catch oversized contents.
+            let mut decompressed = Vec::new();
+            let invalid = Error::Msg { msg: "invalid zstd compressed data" };
+            let decoder = StreamingDecoder::new(data).map_err(|_| invalid)?;
+            decoder.take(size as u64 + 1).read_to_end(&mut decompressed).map_err(|_| invalid)?;
+            decompressed
+        },
+        _ => return Err(Error::InvalidHeaderField {
+            header: "compression",
+            field: "ch_type",
+            value: ch_type.into(),
+        }),
+    };
+    if decompressed.len() != size {
+        return Err(Error::Msg { msg: "decompressed size does not match the compression header" });
+    }
+    Ok(decompressed)
+}
+
+impl ElfFile {
+    /// Compresses section `index` with `compression`, like `objcopy --compress-debug-sections`.
+    /// The compressed contents follow a compression header (`Elf32_Chdr` or `Elf64_Chdr`) which
+    /// holds the original size and alignment, and the section gets the flag
+    /// [SHF_COMPRESSED](constant.SHF_COMPRESSED.html) and the alignment of the header.
+    ///
+    /// Returns `false` and leaves the section unchanged if it is already compressed, has no
+    /// contents, or would not get smaller. Allocated sections cannot be compressed.
+    pub fn compress_section(&mut self, index: Elf_Word, compression: Compression) -> Result<bool> {
+        let format = self.format();
+        let length = self.sections.len();
+        let section = self.sections.get_mut(index as usize)
+            .ok_or(Error::IndexOutOfBounds { index: index as usize, length })?;
+        let header = &section.header;
+        if header.sh_flags & SHF_ALLOC != 0 {
+            return Err(Error::Msg { msg: "allocated sections cannot be compressed" });
+        }
+        if header.sh_flags & SHF_COMPRESSED != 0 || header.sh_type == SHT_NOBITS || header.sh_type == SHT_NULL {
+            return Ok(false);
+        }
+        let compressed = match compression {
+            Compression::Zlib => deflate::compress_to_vec_zlib(&section.data, ZlibLevel::DefaultLevel as u8),
+            Compression::Zstd => encoding::compress_to_vec(&section.data[..], ZstdLevel::Fastest),
+        };
+        let (chdr_size, alignment) = if format.is_64bit() { (24, 8) } else { (12, 4) };
+        if chdr_size + compressed.len() >= section.data.len() {
+            return Ok(false);
+        }
+
+        let mut data = vec![0; chdr_size];
+        write_uint(format, &mut data, 0, 4, compression.ch_type().into());
+        if format.is_64bit() {
+            write_uint(format, &mut data, 8, 8, section.data.len() as u64);
+            write_uint(format, &mut data, 16, 8, header.sh_addralign);
+        } else {
+            write_uint(format, &mut data, 4, 4, section.data.len() as u64);
+            write_uint(format, &mut data, 8, 4, header.sh_addralign);
+        }
+        data.extend_from_slice(&compressed);
+        section.data = data;
+        section.header.sh_flags |= SHF_COMPRESSED;
+        section.header.sh_addralign = alignment;
+        section.realign();
+        Ok(true)
+    }
+
+    /// Decompresses section `index` if it is compressed, restoring its original contents and
+    /// alignment. Sections compressed the older GNU way, named `.zdebug*` rather than `.debug*`,
+    /// are decompressed and renamed too.
+    ///
+    /// Returns `false` if the section is not compressed.
+    pub fn decompress_section(&mut self, index: Elf_Word) -> Result<bool> {
+        let format = self.format();
+        let length = self.sections.len();
+        let section = self.sections.get_mut(index as usize)
+            .ok_or(Error::IndexOutOfBounds { index: index as usize, length })?;
+        if section.header.sh_flags & SHF_COMPRESSED != 0 {
+            let field = |offset, size| read_uint(format, &section.data, offset, size)
+                .ok_or(Error::Msg { msg: "compressed section too small for its header" });
+            let (ch_type, size, alignment, chdr_size) = if format.is_64bit() {
+                (field(0, 4)?, field(8, 8)?, field(16, 8)?, 24)
+            } else {
+                (field(0, 4)?, field(4, 4)?, field(8, 4)?, 12)
+            };
+            section.data = decompress(ch_type as Elf_Word, &section.data[chdr_size..], size as usize)?;
+            section.header.sh_flags &= !SHF_COMPRESSED;
+            section.header.sh_addralign = alignment;
+            section.realign();
+            return Ok(true);
+        }
+
+        if !section.name.starts_with(b".zdebug") || !section.data.starts_with(ZDEBUG_MAGIC) ||
+           section.data.len() < ZDEBUG_HEADER_SIZE
+        {
+            return Ok(false);
+        }
+        let size = section.data[ZDEBUG_MAGIC.len()..ZDEBUG_HEADER_SIZE].iter()
+            .fold(0, |size, &b| (size << 8) | u64::from(b));
+        section.data = decompress(ELFCOMPRESS_ZLIB, &section.data[ZDEBUG_HEADER_SIZE..], size as usize)?;
+        let name = [b".debug", &section.name[b".zdebug".len()..]].concat();
+        self.rename_section(index, &name)?;
+        Ok(true)
+    }
+
+    /// Compresses the `.debug_*` sections with `compression`, like
+    /// `objcopy --compress-debug-sections`, and [compacts](#method.compact) the file. See
+    /// [compress_section](#method.compress_section).
+    pub fn compress_debug_sections(&mut self, compression: Compression) -> Result<()> {
+        for index in 1..self.sections.len() {
+            let section = &self.sections[index];
+            if section.name.starts_with(b".debug_") && section.header.sh_flags & SHF_ALLOC == 0 {
+                self.compress_section(index as Elf_Word, compression)?;
+            }
+        }
+        self.compact();
+        Ok(())
+    }
+
+    /// Decompresses all compressed sections, like `objcopy --decompress-debug-sections`, and
+    /// [compacts](#method.compact) the file. See [decompress_section](#method.decompress_section).
+    pub fn decompress_sections(&mut self) -> Result<()> {
+        for index in 1..self.sections.len() {
+            self.decompress_section(index as Elf_Word)?;
+        }
+        self.compact();
+        Ok(())
+    }
+}
@@ -34,7 +34,7 @@ macro_rules! declare_constants {
 
 macro_rules! match_constant_lhs {
     ($id:ident) => { $id };
-    ($lo:ident, $hi:ident) => { $lo ... $hi };
+    ($lo:ident, $hi:ident) => { $lo ..= $hi };
 }
 
 macro_rules! match_constant_rhs {
@@ -68,9 +68,36 @@ macro_rules! constants {
     }
 }
 
+macro_rules! relocation_size {
+    (WORD, $word_size:expr)  => { $word_size };
+    (WORD2, $word_size:expr) => { 2 * $word_size };
+    ($size:expr, $word_size:expr) => { $size };
+}
+
 macro_rules! relocations {
-    ($name:ident, { $( $(#[$attr:meta])* $id:ident = $val:expr ),+ }) => {
+    ($name:ident, $describe:ident, {
+        $( $(#[$attr:meta])* $id:ident = ($val:expr, $size:tt, $overflow:ident, $formula:ident, $flags:expr) ),+
+    }) => {
         constants! { $name, Elf_Word, { $( $(#[$attr])* $id = ($val, stringify!($id)) ),* } }
+
+        #[allow(unused_variables)]
+        fn $describe(x: Elf_Word, word_size: u8) -> Option<RelocationDescription> {
+            match x {
+                $( $id => Some(RelocationDescription {
+                    relocation_type: $id,
+                    name:            stringify!($id),
+                    size:            relocation_size!($size, word_size),
+                    overflow:        RelocationOverflow::$overflow,
+                    formula:         RelocationFormula::$formula,
+                    pc_relative:     has_flag($flags, PC),
+                    got:             has_flag($flags, GOT),
+                    plt:             has_flag($flags, PLT),
+                    tls:             has_flag($flags, TLS),
+                    dynamic:         has_flag($flags, DYNAMIC),
+                }), )*
+                _ => None,
+            }
+        }
     }
 }
 
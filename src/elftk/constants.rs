@@ -1,4 +1,4 @@
-use types::{Elf_Half, Elf_Word, Elf_Xword};
+use types::{Elf_Half, Elf_Sxword, Elf_Word, Elf_Xword};
 
 // Elf header constants.
 // e_ident
@@ -290,7 +290,10 @@ constants!(machine_name, Elf_Half, {
     EM_FT32          = (222, "FTDI Chip FT32 high performance 32-bit RISC architecture"),
     EM_MOXIE         = (223, "Moxie processor family"),
     EM_AMDGPU        = (224, "AMD GPU architecture"),
-    EM_RISCV         = (243, "RISC-V")
+    EM_RISCV         = (243, "RISC-V"),
+    EM_BPF           = (247, "Linux BPF"),
+    EM_CSKY          = (252, "C-SKY"),
+    EM_LOONGARCH     = (258, "LoongArch")
 });
 /// Cyan Technology eCOG1X family
 ///
@@ -335,6 +338,14 @@ constants!(section_type_name, Elf_Word, {
     SHT_PREINIT_ARRAY = (16, "PREINIT_ARRAY"),
     SHT_GROUP         = (17, "GROUP"),
     SHT_SYMTAB_SHNDX  = (18, "SYMTAB_SHNDX"),
+    SHT_RELR          = (19, "RELR"),
+    SHT_ANDROID_REL   = (0x6000_0001, "ANDROID_REL"),
+    SHT_ANDROID_RELA  = (0x6000_0002, "ANDROID_RELA"),
+    SHT_ANDROID_RELR  = (0x6fff_ff00, "ANDROID_RELR"),
+    SHT_GNU_HASH      = (0x6fff_fff6, "GNU_HASH"),
+    SHT_GNU_VERDEF    = (0x6fff_fffd, "VERDEF"),
+    SHT_GNU_VERNEED   = (0x6fff_fffe, "VERNEED"),
+    SHT_GNU_VERSYM    = (0x6fff_ffff, "VERSYM"),
     SHT_LOOS, SHT_HIOS = (0x6000_0000, 0x6fff_ffff, "OS"),
     SHT_LOPROC, SHT_HIPROC = (0x7000_0000, 0x7fff_ffff, "PROC"),
     SHT_LOUSER, SHT_HIUSER = (0x8000_0000, 0xffff_ffff, "USER")
@@ -358,6 +369,7 @@ pub const SHF_EXCLUDED:         Elf_Xword = 0x8000_0000;
 
 // ch_type
 pub const ELFCOMPRESS_ZLIB:   Elf_Word = 1;
+pub const ELFCOMPRESS_ZSTD:   Elf_Word = 2;
 pub const ELFCOMPRESS_LOOS:   Elf_Word = 0x6000_0000;
 pub const ELFCOMPRESS_HIOS:   Elf_Word = 0x6fff_ffff;
 pub const ELFCOMPRESS_LOPROC: Elf_Word = 0x7000_0000;
@@ -412,6 +424,10 @@ constants!(segment_type_name, Elf_Word, {
     PT_SHLIB   = (5, "SHLIB"),
     PT_PHDR    = (6, "PHDR"),
     PT_TLS     = (7, "TLS"),
+    PT_GNU_EH_FRAME = (0x6474_e550, "GNU_EH_FRAME"),
+    PT_GNU_STACK    = (0x6474_e551, "GNU_STACK"),
+    PT_GNU_RELRO    = (0x6474_e552, "GNU_RELRO"),
+    PT_GNU_PROPERTY = (0x6474_e553, "GNU_PROPERTY"),
     PT_LOOS, PT_HIOS = (0x6000_0000, 0x6fff_ffff, "OS"),
     PT_LOPROC, PT_HIPROC = (0x7000_0000, 0x7fff_ffff, "PROC")
 });
@@ -422,3 +438,278 @@ pub const PF_W:        Elf_Word = 2;
 pub const PF_R:        Elf_Word = 4;
 pub const PF_MASKOS:   Elf_Word = 0x0ff0_0000;
 pub const PF_MASKPROC: Elf_Word = 0xf000_0000;
+
+// Dynamic section d_tag
+constants!(dynamic_tag_name, Elf_Sxword, {
+    DT_NULL            = (0,  "NULL"),
+    DT_NEEDED          = (1,  "NEEDED"),
+    DT_PLTRELSZ        = (2,  "PLTRELSZ"),
+    DT_PLTGOT          = (3,  "PLTGOT"),
+    DT_HASH            = (4,  "HASH"),
+    DT_STRTAB          = (5,  "STRTAB"),
+    DT_SYMTAB          = (6,  "SYMTAB"),
+    DT_RELA            = (7,  "RELA"),
+    DT_RELASZ          = (8,  "RELASZ"),
+    DT_RELAENT         = (9,  "RELAENT"),
+    DT_STRSZ           = (10, "STRSZ"),
+    DT_SYMENT          = (11, "SYMENT"),
+    DT_INIT            = (12, "INIT"),
+    DT_FINI            = (13, "FINI"),
+    DT_SONAME          = (14, "SONAME"),
+    DT_RPATH           = (15, "RPATH"),
+    DT_SYMBOLIC        = (16, "SYMBOLIC"),
+    DT_REL             = (17, "REL"),
+    DT_RELSZ           = (18, "RELSZ"),
+    DT_RELENT          = (19, "RELENT"),
+    DT_PLTREL          = (20, "PLTREL"),
+    DT_DEBUG           = (21, "DEBUG"),
+    DT_TEXTREL         = (22, "TEXTREL"),
+    DT_JMPREL          = (23, "JMPREL"),
+    DT_BIND_NOW        = (24, "BIND_NOW"),
+    DT_INIT_ARRAY      = (25, "INIT_ARRAY"),
+    DT_FINI_ARRAY      = (26, "FINI_ARRAY"),
+    DT_INIT_ARRAYSZ    = (27, "INIT_ARRAYSZ"),
+    DT_FINI_ARRAYSZ    = (28, "FINI_ARRAYSZ"),
+    DT_RUNPATH         = (29, "RUNPATH"),
+    DT_FLAGS           = (30, "FLAGS"),
+    DT_PREINIT_ARRAY   = (32, "PREINIT_ARRAY"),
+    DT_PREINIT_ARRAYSZ = (33, "PREINIT_ARRAYSZ"),
+    DT_SYMTAB_SHNDX    = (34, "SYMTAB_SHNDX"),
+    DT_RELRSZ          = (35, "RELRSZ"),
+    DT_RELR            = (36, "RELR"),
+    DT_RELRENT         = (37, "RELRENT"),
+    DT_ANDROID_REL     = (0x6000_000f, "ANDROID_REL"),
+    DT_ANDROID_RELSZ   = (0x6000_0010, "ANDROID_RELSZ"),
+    DT_ANDROID_RELA    = (0x6000_0011, "ANDROID_RELA"),
+    DT_ANDROID_RELASZ  = (0x6000_0012, "ANDROID_RELASZ"),
+    DT_ANDROID_RELR    = (0x6fff_e000, "ANDROID_RELR"),
+    DT_ANDROID_RELRSZ  = (0x6fff_e001, "ANDROID_RELRSZ"),
+    DT_ANDROID_RELRENT = (0x6fff_e003, "ANDROID_RELRENT"),
+    DT_LOOS, DT_HIOS   = (0x6000_000d, 0x6fff_f000, "OS"),
+    DT_GNU_HASH        = (0x6fff_fef5, "GNU_HASH"),
+    DT_VERSYM          = (0x6fff_fff0, "VERSYM"),
+    DT_RELACOUNT       = (0x6fff_fff9, "RELACOUNT"),
+    DT_RELCOUNT        = (0x6fff_fffa, "RELCOUNT"),
+    DT_FLAGS_1         = (0x6fff_fffb, "FLAGS_1"),
+    DT_VERDEF          = (0x6fff_fffc, "VERDEF"),
+    DT_VERDEFNUM       = (0x6fff_fffd, "VERDEFNUM"),
+    DT_VERNEED         = (0x6fff_fffe, "VERNEED"),
+    DT_VERNEEDNUM      = (0x6fff_ffff, "VERNEEDNUM"),
+    DT_LOPROC, DT_HIPROC = (0x7000_0000, 0x7fff_ffff, "PROC")
+});
+
+// Symbol version indexes in the version symbol section (.gnu.version)
+pub const VER_NDX_LOCAL:  Elf_Half = 0;
+pub const VER_NDX_GLOBAL: Elf_Half = 1;
+pub const VERSYM_HIDDEN:  Elf_Half = 0x8000;
+
+// Note types for notes with the name "GNU"
+pub const NT_GNU_ABI_TAG:         Elf_Word = 1;
+pub const NT_GNU_HWCAP:           Elf_Word = 2;
+pub const NT_GNU_BUILD_ID:        Elf_Word = 3;
+pub const NT_GNU_GOLD_VERSION:    Elf_Word = 4;
+pub const NT_GNU_PROPERTY_TYPE_0: Elf_Word = 5;
+
+// Note types for notes with the name "Android"
+pub const NT_ANDROID_TYPE_IDENT:  Elf_Word = 1;
+pub const NT_ANDROID_TYPE_KUSER:  Elf_Word = 3;
+pub const NT_ANDROID_TYPE_MEMTAG: Elf_Word = 4;
+
+// Android packed relocation (APS2) group flags
+pub const RELOCATION_GROUPED_BY_INFO_FLAG:         Elf_Xword = 1;
+pub const RELOCATION_GROUPED_BY_OFFSET_DELTA_FLAG: Elf_Xword = 2;
+pub const RELOCATION_GROUPED_BY_ADDEND_FLAG:       Elf_Xword = 4;
+pub const RELOCATION_GROUP_HAS_ADDEND_FLAG:        Elf_Xword = 8;
+
+// ARM e_flags
+pub const EF_ARM_RELEXEC:          Elf_Word = 0x01;
+pub const EF_ARM_HASENTRY:         Elf_Word = 0x02;
+pub const EF_ARM_INTERWORK:        Elf_Word = 0x04;
+pub const EF_ARM_APCS_26:          Elf_Word = 0x08;
+pub const EF_ARM_APCS_FLOAT:       Elf_Word = 0x10;
+pub const EF_ARM_PIC:              Elf_Word = 0x20;
+pub const EF_ARM_ALIGN8:           Elf_Word = 0x40;
+pub const EF_ARM_NEW_ABI:          Elf_Word = 0x80;
+pub const EF_ARM_OLD_ABI:          Elf_Word = 0x100;
+pub const EF_ARM_SOFT_FLOAT:       Elf_Word = 0x200;
+pub const EF_ARM_VFP_FLOAT:        Elf_Word = 0x400;
+pub const EF_ARM_MAVERICK_FLOAT:   Elf_Word = 0x800;
+pub const EF_ARM_ABI_FLOAT_SOFT:   Elf_Word = 0x200;
+pub const EF_ARM_ABI_FLOAT_HARD:   Elf_Word = 0x400;
+pub const EF_ARM_LE8:              Elf_Word = 0x0040_0000;
+pub const EF_ARM_BE8:              Elf_Word = 0x0080_0000;
+pub const EF_ARM_EABIMASK:         Elf_Word = 0xff00_0000;
+pub const EF_ARM_EABI_UNKNOWN:     Elf_Word = 0x0000_0000;
+pub const EF_ARM_EABI_VER1:        Elf_Word = 0x0100_0000;
+pub const EF_ARM_EABI_VER2:        Elf_Word = 0x0200_0000;
+pub const EF_ARM_EABI_VER3:        Elf_Word = 0x0300_0000;
+pub const EF_ARM_EABI_VER4:        Elf_Word = 0x0400_0000;
+pub const EF_ARM_EABI_VER5:        Elf_Word = 0x0500_0000;
+
+// ARM symbol types
+/// Thumb function (obsolete; Thumb functions are now STT_FUNC with bit 0 of the value set).
+pub const STT_ARM_TFUNC: u8 = STT_LOPROC;
+
+// RISC-V e_flags
+pub const EF_RISCV_RVC:              Elf_Word = 0x0001;
+pub const EF_RISCV_FLOAT_ABI:        Elf_Word = 0x0006;
+pub const EF_RISCV_FLOAT_ABI_SOFT:   Elf_Word = 0x0000;
+pub const EF_RISCV_FLOAT_ABI_SINGLE: Elf_Word = 0x0002;
+pub const EF_RISCV_FLOAT_ABI_DOUBLE: Elf_Word = 0x0004;
+pub const EF_RISCV_FLOAT_ABI_QUAD:   Elf_Word = 0x0006;
+pub const EF_RISCV_RVE:              Elf_Word = 0x0008;
+pub const EF_RISCV_TSO:              Elf_Word = 0x0010;
+
+// PowerPC e_flags
+pub const EF_PPC_EMB:             Elf_Word = 0x8000_0000;
+pub const EF_PPC_RELOCATABLE:     Elf_Word = 0x0001_0000;
+pub const EF_PPC_RELOCATABLE_LIB: Elf_Word = 0x0000_8000;
+
+// PowerPC 64-bit e_flags
+/// ABI version mask: 0 for unspecified or not using any ABI-specific features, 1 for ELFv1
+/// (function descriptors), 2 for ELFv2 (local entry points).
+pub const EF_PPC64_ABI: Elf_Word = 0x3;
+
+// PowerPC 64-bit st_other
+pub const STO_PPC64_LOCAL_BIT:  u8 = 5;
+pub const STO_PPC64_LOCAL_MASK: u8 = 0xe0;
+
+// MIPS e_flags
+pub const EF_MIPS_NOREORDER:     Elf_Word = 0x0000_0001;
+pub const EF_MIPS_PIC:           Elf_Word = 0x0000_0002;
+pub const EF_MIPS_CPIC:          Elf_Word = 0x0000_0004;
+pub const EF_MIPS_XGOT:          Elf_Word = 0x0000_0008;
+pub const EF_MIPS_UCODE:         Elf_Word = 0x0000_0010;
+/// N32 ABI.
+pub const EF_MIPS_ABI2:          Elf_Word = 0x0000_0020;
+pub const EF_MIPS_OPTIONS_FIRST: Elf_Word = 0x0000_0080;
+pub const EF_MIPS_32BITMODE:     Elf_Word = 0x0000_0100;
+pub const EF_MIPS_FP64:          Elf_Word = 0x0000_0200;
+pub const EF_MIPS_NAN2008:       Elf_Word = 0x0000_0400;
+
+pub const EF_MIPS_ABI:        Elf_Word = 0x0000_f000;
+pub const EF_MIPS_ABI_O32:    Elf_Word = 0x0000_1000;
+pub const EF_MIPS_ABI_O64:    Elf_Word = 0x0000_2000;
+pub const EF_MIPS_ABI_EABI32: Elf_Word = 0x0000_3000;
+pub const EF_MIPS_ABI_EABI64: Elf_Word = 0x0000_4000;
+
+pub const EF_MIPS_MACH:         Elf_Word = 0x00ff_0000;
+pub const EF_MIPS_MACH_3900:    Elf_Word = 0x0081_0000;
+pub const EF_MIPS_MACH_4010:    Elf_Word = 0x0082_0000;
+pub const EF_MIPS_MACH_4100:    Elf_Word = 0x0083_0000;
+pub const EF_MIPS_MACH_4650:    Elf_Word = 0x0085_0000;
+pub const EF_MIPS_MACH_4120:    Elf_Word = 0x0087_0000;
+pub const EF_MIPS_MACH_4111:    Elf_Word = 0x0088_0000;
+pub const EF_MIPS_MACH_SB1:     Elf_Word = 0x008a_0000;
+pub const EF_MIPS_MACH_OCTEON:  Elf_Word = 0x008b_0000;
+pub const EF_MIPS_MACH_XLR:     Elf_Word = 0x008c_0000;
+pub const EF_MIPS_MACH_OCTEON2: Elf_Word = 0x008d_0000;
+pub const EF_MIPS_MACH_OCTEON3: Elf_Word = 0x008e_0000;
+pub const EF_MIPS_MACH_5400:    Elf_Word = 0x0091_0000;
+pub const EF_MIPS_MACH_5900:    Elf_Word = 0x0092_0000;
+pub const EF_MIPS_MACH_5500:    Elf_Word = 0x0098_0000;
+pub const EF_MIPS_MACH_9000:    Elf_Word = 0x0099_0000;
+pub const EF_MIPS_MACH_LS2E:    Elf_Word = 0x00a0_0000;
+pub const EF_MIPS_MACH_LS2F:    Elf_Word = 0x00a1_0000;
+pub const EF_MIPS_MACH_GS464:   Elf_Word = 0x00a2_0000;
+pub const EF_MIPS_MACH_GS464E:  Elf_Word = 0x00a3_0000;
+pub const EF_MIPS_MACH_GS264E:  Elf_Word = 0x00a4_0000;
+
+pub const EF_MIPS_ARCH_ASE:       Elf_Word = 0x0f00_0000;
+pub const EF_MIPS_ARCH_ASE_MDMX:  Elf_Word = 0x0800_0000;
+pub const EF_MIPS_ARCH_ASE_M16:   Elf_Word = 0x0400_0000;
+pub const EF_MIPS_MICROMIPS:      Elf_Word = 0x0200_0000;
+
+pub const EF_MIPS_ARCH:      Elf_Word = 0xf000_0000;
+pub const EF_MIPS_ARCH_1:    Elf_Word = 0x0000_0000;
+pub const EF_MIPS_ARCH_2:    Elf_Word = 0x1000_0000;
+pub const EF_MIPS_ARCH_3:    Elf_Word = 0x2000_0000;
+pub const EF_MIPS_ARCH_4:    Elf_Word = 0x3000_0000;
+pub const EF_MIPS_ARCH_5:    Elf_Word = 0x4000_0000;
+pub const EF_MIPS_ARCH_32:   Elf_Word = 0x5000_0000;
+pub const EF_MIPS_ARCH_64:   Elf_Word = 0x6000_0000;
+pub const EF_MIPS_ARCH_32R2: Elf_Word = 0x7000_0000;
+pub const EF_MIPS_ARCH_64R2: Elf_Word = 0x8000_0000;
+pub const EF_MIPS_ARCH_32R6: Elf_Word = 0x9000_0000;
+pub const EF_MIPS_ARCH_64R6: Elf_Word = 0xa000_0000;
+
+// MIPS section types
+pub const SHT_MIPS_REGINFO:  Elf_Word = 0x7000_0006;
+pub const SHT_MIPS_OPTIONS:  Elf_Word = 0x7000_000d;
+pub const SHT_MIPS_ABIFLAGS: Elf_Word = 0x7000_002a;
+
+// MIPS64 special symbols (r_ssym)
+pub const RSS_UNDEF: u8 = 0;
+pub const RSS_GP:    u8 = 1;
+pub const RSS_GP0:   u8 = 2;
+pub const RSS_LOC:   u8 = 3;
+
+// MIPS .MIPS.abiflags register sizes
+constants!(mips_abiflags_reg_size_name, u8, {
+    AFL_REG_NONE = (0, "0"),
+    AFL_REG_32   = (1, "32"),
+    AFL_REG_64   = (2, "64"),
+    AFL_REG_128  = (3, "128")
+});
+
+// MIPS .MIPS.abiflags floating-point ABIs
+constants!(mips_fp_abi_name, u8, {
+    VAL_GNU_MIPS_ABI_FP_ANY    = (0, "Hard or soft float"),
+    VAL_GNU_MIPS_ABI_FP_DOUBLE = (1, "Hard float (double precision)"),
+    VAL_GNU_MIPS_ABI_FP_SINGLE = (2, "Hard float (single precision)"),
+    VAL_GNU_MIPS_ABI_FP_SOFT   = (3, "Soft float"),
+    VAL_GNU_MIPS_ABI_FP_OLD_64 = (4, "Hard float (MIPS32r2 64-bit FPU 12 callee-saved)"),
+    VAL_GNU_MIPS_ABI_FP_XX     = (5, "Hard float (32-bit CPU, Any FPU)"),
+    VAL_GNU_MIPS_ABI_FP_64     = (6, "Hard float (32-bit CPU, 64-bit FPU)"),
+    VAL_GNU_MIPS_ABI_FP_64A    = (7, "Hard float compat (32-bit CPU, 64-bit FPU)")
+});
+
+// MIPS .MIPS.abiflags processor-specific extensions
+constants!(mips_isa_ext_name, Elf_Word, {
+    AFL_EXT_NONE        = (0,  "None"),
+    AFL_EXT_XLR         = (1,  "RMI Xlr instruction"),
+    AFL_EXT_OCTEON2     = (2,  "Cavium Networks Octeon2"),
+    AFL_EXT_OCTEONP     = (3,  "Cavium Networks OcteonP"),
+    AFL_EXT_LOONGSON_3A = (4,  "Loongson 3A"),
+    AFL_EXT_OCTEON      = (5,  "Cavium Networks Octeon"),
+    AFL_EXT_5900        = (6,  "Toshiba R5900"),
+    AFL_EXT_4650        = (7,  "MIPS R4650"),
+    AFL_EXT_4010        = (8,  "LSI R4010"),
+    AFL_EXT_4100        = (9,  "NEC VR4100"),
+    AFL_EXT_3900        = (10, "Toshiba R3900"),
+    AFL_EXT_10000       = (11, "MIPS R10000"),
+    AFL_EXT_SB1         = (12, "Broadcom SB-1"),
+    AFL_EXT_4111        = (13, "NEC VR4111/VR4181"),
+    AFL_EXT_4120        = (14, "NEC VR4120"),
+    AFL_EXT_5400        = (15, "NEC VR5400"),
+    AFL_EXT_5500        = (16, "NEC VR5500"),
+    AFL_EXT_LOONGSON_2E = (17, "ST Microelectronics Loongson 2E"),
+    AFL_EXT_LOONGSON_2F = (18, "ST Microelectronics Loongson 2F"),
+    AFL_EXT_OCTEON3     = (19, "Cavium Networks Octeon3")
+});
+
+// MIPS .MIPS.abiflags application-specific extensions
+pub const AFL_ASE_DSP:           Elf_Word = 0x0000_0001;
+pub const AFL_ASE_DSPR2:         Elf_Word = 0x0000_0002;
+pub const AFL_ASE_EVA:           Elf_Word = 0x0000_0004;
+pub const AFL_ASE_MCU:           Elf_Word = 0x0000_0008;
+pub const AFL_ASE_MDMX:          Elf_Word = 0x0000_0010;
+pub const AFL_ASE_MIPS3D:        Elf_Word = 0x0000_0020;
+pub const AFL_ASE_MT:            Elf_Word = 0x0000_0040;
+pub const AFL_ASE_SMARTMIPS:     Elf_Word = 0x0000_0080;
+pub const AFL_ASE_VIRT:          Elf_Word = 0x0000_0100;
+pub const AFL_ASE_MSA:           Elf_Word = 0x0000_0200;
+pub const AFL_ASE_MIPS16:        Elf_Word = 0x0000_0400;
+pub const AFL_ASE_MICROMIPS:     Elf_Word = 0x0000_0800;
+pub const AFL_ASE_XPA:           Elf_Word = 0x0000_1000;
+pub const AFL_ASE_DSPR3:         Elf_Word = 0x0000_2000;
+pub const AFL_ASE_MIPS16E2:      Elf_Word = 0x0000_4000;
+pub const AFL_ASE_CRC:           Elf_Word = 0x0000_8000;
+pub const AFL_ASE_GINV:          Elf_Word = 0x0002_0000;
+pub const AFL_ASE_LOONGSON_MMI:  Elf_Word = 0x0004_0000;
+pub const AFL_ASE_LOONGSON_CAM:  Elf_Word = 0x0008_0000;
+pub const AFL_ASE_LOONGSON_EXT:  Elf_Word = 0x0010_0000;
+pub const AFL_ASE_LOONGSON_EXT2: Elf_Word = 0x0020_0000;
+
+// MIPS .MIPS.abiflags flags1
+pub const AFL_FLAGS1_ODDSPREG: Elf_Word = 0x1;
@@ -0,0 +1,285 @@
+use std::collections::HashMap;
+
+use constants::*;
+use edit::*;
+use error::*;
+use reader::*;
+use types::*;
+use writer::*;
+
+/// The section flags which [ElfFile::set_section_flags](struct.ElfFile.html#method.set_section_flags)
+/// sets. The other flags describe how a section relates to other sections, such as
+/// [SHF_GROUP](constant.SHF_GROUP.html), or how its contents are stored, such as
+/// [SHF_COMPRESSED](constant.SHF_COMPRESSED.html).
+pub const SETTABLE_SECTION_FLAGS: Elf_Xword = SHF_WRITE | SHF_ALLOC | SHF_EXECINSTR | SHF_MERGE |
+    SHF_STRINGS | SHF_TLS | SHF_EXCLUDED;
+
+/// Returns `true` if `name` matches the shell wildcard pattern `pattern`, in which `*` matches
+/// any string, `?` any character, and `[...]` any character in a set, such as `[a-z_]`, or not in
+/// a set, such as `[!0-9]`. A backslash matches the following character literally.
+///
+/// # Examples
+///
+/// ```
+/// use elftk::wildcard_match;
+///
+/// assert!(wildcard_match(b".debug_*", b".debug_info"));
+/// assert!(wildcard_match(b".text.[!u]*", b".text.main"));
+/// assert!(!wildcard_match(b".text.[!u]*", b".text.unlikely"));
+/// ```
+pub fn wildcard_match(pattern: &[u8], name: &[u8]) -> bool {
+    match pattern.split_first() {
+        None                => name.is_empty(),
+        Some((b'*', rest))  => (0..=name.len()).any(|skip| wildcard_match(rest, &name[skip..])),
+        Some((b'?', rest))  => !name.is_empty() && wildcard_match(rest, &name[1..]),
+        Some((b'[', rest))  => match (name.split_first(), bracket_match(rest, name.first().cloned())) {
+            (Some((_, name)), Some((matched, rest))) => matched && wildcard_match(rest, name),
+            (None, Some(_))                          => false,
+            (_, None)                                => literal_match(b'[', rest, name),
+        },
+        Some((b'\\', rest)) if !rest.is_empty() => literal_match(rest[0], &rest[1..], name),
+        Some((&c, rest))    => literal_match(c, rest, name),
+    }
+}
+
+fn literal_match(c: u8, rest: &[u8], name: &[u8]) -> bool {
+    name.first() == Some(&c) && wildcard_match(rest, &name[1..])
+}
+
+// Matches `c` against the set of the bracket expression starting with `set`, just after the `[`.
+// Returns whether it matches and the rest of the pattern, or `None` if the bracket is not closed.
+fn bracket_match(set: &[u8], c: Option<u8>) -> Option<(bool, &[u8])> {
+    let (negated, set) = match set.first() {
+        Some(b'!') | Some(b'^') => (true, &set[1..]),
+        _                       => (false, set),
+    };
+    // A `]` first in the set is part of it.
+    let end = set.iter().skip(1).position(|&b| b == b']')? + 1;
+    let c = match c {
+        Some(c) => c,
+        None    => return Some((false, &set[end+1..])),
+    };
+    let items = &set[..end];
+    let mut matched = false;
+    let mut i = 0;
+    while i < items.len() {
+        if i + 2 < items.len() && items[i+1] == b'-' {
+            matched |= items[i] <= c && c <= items[i+2];
+            i += 3;
+        } else {
+            matched |= items[i] == c;
+            i += 1;
+        }
+    }
+    Some((matched != negated, &set[end+1..]))
+}
+
+/// Parses a symbol map file as read by `objcopy --redefine-syms`, with one pair of an old and a new
+/// symbol name per line, separated by whitespace. Text after a `#` is a comment.
+pub fn parse_symbol_map(text: &[u8]) -> Result<HashMap<Vec<u8>, Vec<u8>>> {
+    let mut map = HashMap::new();
+    for line in text.split(|&b| b == b'\n') {
+        let line = line.split(|&b| b == b'#').next().unwrap_or(&[]);
+        let mut names = line.split(u8::is_ascii_whitespace).filter(|name| !name.is_empty());
+        match (names.next(), names.next(), names.next()) {
+            (None, ..)                    => {},
+            (Some(old), Some(new), None) => { map.insert(old.to_vec(), new.to_vec()); },
+            _ => return Err(Error::Msg { msg: "invalid symbol map line" }),
+        }
+    }
+    Ok(map)
+}
+
+/// Parses section flags named as in `objcopy --set-section-flags`, a comma-separated list such as
+/// `alloc,readonly,code`. Allocated sections are writable unless `readonly` is given. The names of
+/// flags without an ELF equivalent, such as `contents` and `load`, are accepted and ignored.
+///
+/// # Examples
+///
+/// ```
+/// use elftk::*;
+///
+/// assert_eq!(parse_section_flags("alloc,code").unwrap(), SHF_ALLOC | SHF_WRITE | SHF_EXECINSTR);
+/// assert_eq!(parse_section_flags("alloc,readonly").unwrap(), SHF_ALLOC);
+/// ```
+pub fn parse_section_flags(text: &str) -> Result<Elf_Xword> {
+    let mut flags = 0;
+    let mut readonly = false;
+    for name in text.split(',').map(str::trim) {
+        match name {
+            "alloc"    => flags |= SHF_ALLOC,
+            "readonly" => readonly = true,
+            "code"     => flags |= SHF_EXECINSTR,
+            "merge"    => flags |= SHF_MERGE,
+            "strings"  => flags |= SHF_STRINGS,
+            "tls"      => flags |= SHF_TLS,
+            "exclude"  => flags |= SHF_EXCLUDED,
+            "contents" | "load" | "data" | "rom" | "debug" | "share" => {},
+            _ => return Err(Error::Msg { msg: "unknown section flag" }),
+        }
+    }
+    if flags & SHF_ALLOC != 0 && !readonly {
+        flags |= SHF_WRITE;
+    }
+    Ok(flags)
+}
+
+fn is_defined(symbol: &OutputSymbol) -> bool {
+    match symbol.section {
+        SectionIndex::Normal(index) => index != Elf_Word::from(SHN_UNDEF),
+        SectionIndex::Reserved(_)   => true,
+    }
+}
+
+impl ElfFile {
+    /// Renames section `index` to `name`, together with the relocation sections for it which are
+    /// named after it, such as `.rela.text` for `.text`.
+    pub fn rename_section(&mut self, index: Elf_Word, name: &[u8]) -> Result<()> {
+        let old = self.sections.get(index as usize)
+            .ok_or(Error::IndexOutOfBounds { index: index as usize, length: self.sections.len() })?
+            .name.clone();
+        for section in &mut self.sections {
+            let prefix: &[u8] = match section.header.sh_type {
+                SHT_REL  => b".rel",
+                SHT_RELA => b".rela",
+                _        => continue,
+            };
+            if section.header.sh_info == index && section.name == [prefix, &old[..]].concat() {
+                section.name = [prefix, name].concat();
+            }
+        }
+        self.sections[index as usize].name = name.to_vec();
+        Ok(())
+    }
+
+    /// Adds a section named `name` holding `data`, like `objcopy --add-section`, and returns its
+    /// index. Sections whose names start with `.note` are [SHT_NOTE](constant.SHT_NOTE.html)
+    /// sections, and others [SHT_PROGBITS](constant.SHT_PROGBITS.html) sections without flags.
+    pub fn add_section_from(&mut self, name: &[u8], data: Vec<u8>) -> Elf_Word {
+        let sh_type = if name.starts_with(b".note") { SHT_NOTE } else { SHT_PROGBITS };
+        self.add_section(FileSection::new(name, sh_type, 0, data))
+    }
+
+    /// Removes the sections whose names match one of the [wildcard patterns](fn.wildcard_match.html)
+    /// `patterns`, like `objcopy --remove-section`, and the sections which depend on them. See
+    /// [remove_sections](#method.remove_sections).
+    pub fn remove_sections_matching(&mut self, patterns: &[&[u8]]) -> Result<Vec<Option<Elf_Word>>> {
+        self.remove_sections(|_, section| patterns.iter().any(|pattern| wildcard_match(pattern, &section.name)))
+    }
+
+    /// Sets the [settable flags](constant.SETTABLE_SECTION_FLAGS.html) of section `index` to
+    /// those in `flags`, keeping its other flags.
+    pub fn set_section_flags(&mut self, index: Elf_Word, flags: Elf_Xword) -> Result<()> {
+        let length = self.sections.len();
+        let header = &mut self.sections.get_mut(index as usize)
+            .ok_or(Error::IndexOutOfBounds { index: index as usize, length })?
+            .header;
+        header.sh_flags = (header.sh_flags & !SETTABLE_SECTION_FLAGS) | (flags & SETTABLE_SECTION_FLAGS);
+        Ok(())
+    }
+
+    /// Sets [SHF_EXCLUDED](constant.SHF_EXCLUDED.html) on section `index` if `exclude` is `true`,
+    /// and clears it otherwise. The linker leaves excluded sections out of its output.
+    pub fn set_exclude(&mut self, index: Elf_Word, exclude: bool) -> Result<()> {
+        let length = self.sections.len();
+        let header = &mut self.sections.get_mut(index as usize)
+            .ok_or(Error::IndexOutOfBounds { index: index as usize, length })?
+            .header;
+        if exclude {
+            header.sh_flags |= SHF_EXCLUDED;
+        } else {
+            header.sh_flags &= !SHF_EXCLUDED;
+        }
+        Ok(())
+    }
+
+    /// Sets the alignment of section `index`, a power of two or zero. The section is moved when
+    /// the file is written if its offset in the file does not have the new alignment.
+    pub fn set_section_alignment(&mut self, index: Elf_Word, alignment: Elf64_Xword) -> Result<()> {
+        if alignment != 0 && !alignment.is_power_of_two() {
+            return Err(Error::InvalidHeaderField { header: "section", field: "sh_addralign", value: alignment });
+        }
+        let length = self.sections.len();
+        let section = self.sections.get_mut(index as usize)
+            .ok_or(Error::IndexOutOfBounds { index: index as usize, length })?;
+        section.header.sh_addralign = alignment;
+        section.realign();
+        Ok(())
+    }
+
+    /// Renames the symbols of the symbol tables whose names are keys of `map` to the
+    /// corresponding values, like `objcopy --redefine-syms`. See
+    /// [parse_symbol_map](fn.parse_symbol_map.html).
+    pub fn rename_symbols(&mut self, map: &HashMap<Vec<u8>, Vec<u8>>) -> Result<()> {
+        if map.is_empty() {
+            return Ok(());
+        }
+        self.edit_symbol_tables(|symbol| {
+            if let Some(name) = map.get(&symbol.name) {
+                symbol.name = name.clone();
+            }
+        })
+    }
+
+    /// Makes the defined global and weak symbols whose names match one of the
+    /// [wildcard patterns](fn.wildcard_match.html) `patterns` local, like
+    /// `objcopy --localize-symbol`.
+    pub fn localize_symbols(&mut self, patterns: &[&[u8]]) -> Result<()> {
+        self.rebind_symbols(patterns, |symbol| match symbol.binding() {
+            STB_GLOBAL | STB_WEAK if is_defined(symbol) => Some(STB_LOCAL),
+            _                                           => None,
+        })
+    }
+
+    /// Makes the defined local symbols whose names match one of the
+    /// [wildcard patterns](fn.wildcard_match.html) `patterns` global, like
+    /// `objcopy --globalize-symbol`. Section and file symbols stay local.
+    pub fn globalize_symbols(&mut self, patterns: &[&[u8]]) -> Result<()> {
+        self.rebind_symbols(patterns, |symbol| match (symbol.binding(), symbol.symbol_type()) {
+            (_, STT_SECTION) | (_, STT_FILE)     => None,
+            (STB_LOCAL, _) if is_defined(symbol) => Some(STB_GLOBAL),
+            _                                    => None,
+        })
+    }
+
+    /// Makes the global symbols whose names match one of the
+    /// [wildcard patterns](fn.wildcard_match.html) `patterns` weak, like
+    /// `objcopy --weaken-symbol`. Undefined symbols are weakened too, so that the references to
+    /// them resolve to 0 instead of failing to link if they are not defined elsewhere.
+    pub fn weaken_symbols(&mut self, patterns: &[&[u8]]) -> Result<()> {
+        self.rebind_symbols(patterns, |symbol| match symbol.binding() {
+            STB_GLOBAL => Some(STB_WEAK),
+            _          => None,
+        })
+    }
+
+    // Gives the symbols whose names match one of `patterns` the binding returned by `rebind`, if
+    // any.
+    fn rebind_symbols<F>(&mut self, patterns: &[&[u8]], rebind: F) -> Result<()> where
+        F: Fn(&OutputSymbol) -> Option<u8>
+    {
+        if patterns.is_empty() {
+            return Ok(());
+        }
+        self.edit_symbol_tables(|symbol| {
+            if !patterns.iter().any(|pattern| wildcard_match(pattern, &symbol.name)) {
+                return;
+            }
+            if let Some(binding) = rebind(symbol) {
+                symbol.info = (binding << 4) | symbol.symbol_type();
+            }
+        })
+    }
+
+    // Calls `edit` with each symbol of the symbol tables (SHT_SYMTAB) but the null symbols.
+    fn edit_symbol_tables<F>(&mut self, mut edit: F) -> Result<()> where
+        F: FnMut(&mut OutputSymbol)
+    {
+        for index in 0..self.sections.len() {
+            if self.sections[index].header.sh_type == SHT_SYMTAB {
+                self.edit_symbols(index as Elf_Word, |_, symbol| edit(symbol))?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,353 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use constants::*;
+use edit::*;
+use error::*;
+use reader::*;
+use types::*;
+
+/// The directory searched for separate debug files by default.
+pub const DEFAULT_DEBUG_DIRECTORY: &str = "/usr/lib/debug";
+
+const fn crc32_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut k = 0;
+        while k < 8 {
+            crc = if crc & 1 != 0 { 0xedb8_8320 ^ (crc >> 1) } else { crc >> 1 };
+            k += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+}
+
+const CRC32_TABLE: [u32; 256] = crc32_table();
+
+/// Returns the CRC-32 of `data` as used by the `.gnu_debuglink` section.
+///
+/// This is the same CRC-32 used by zlib and gzip.
+pub fn crc32(data: &[u8]) -> u32 {
+    !data.iter().fold(!0u32, |crc, &b| {
+        CRC32_TABLE[((crc ^ u32::from(b)) & 0xff) as usize] ^ (crc >> 8)
+    })
+}
+
+/// The contents of a `.gnu_debuglink` section.
+#[derive(Debug, Clone, Copy)]
+pub struct DebugLink<'a> {
+    /// The file name of the separate debug file, without any directory components.
+    pub file_name: &'a [u8],
+    /// The [crc32](fn.crc32.html) of the separate debug file.
+    pub crc: u32,
+}
+
+/// The contents of a `.gnu_debugaltlink` section, as produced by `dwz`.
+#[derive(Debug, Clone, Copy)]
+pub struct DebugAltLink<'a> {
+    /// The path of the supplementary debug file, either absolute or relative to the directory
+    /// containing the file with this section.
+    pub file_name: &'a [u8],
+    /// The build ID of the supplementary debug file.
+    pub build_id: &'a [u8],
+}
+
+// Returns the bytes up to the first null byte and the remaining bytes after it.
+fn split_string(data: &[u8]) -> Option<(&[u8], &[u8])> {
+    data.iter()
+        .position(|&b| b == 0)
+        .map(|len| (&data[..len], &data[len+1..]))
+}
+
+impl<'a> Reader<'a> {
+    /// Returns the contents of the `.gnu_debuglink` section, if any.
+    pub fn debug_link(&self) -> Option<DebugLink<'a>> {
+        let data = match self.section_by_name(b".gnu_debuglink")?.data {
+            SectionDataRef::Uninterpreted(data) => data,
+            _                                   => return None,
+        };
+        let (file_name, _) = split_string(data)?;
+        // The CRC follows the file name, padded to a 4-byte boundary.
+        let offset = (file_name.len() + 1 + 3) & !3;
+        let crc_bytes = data.get(offset..offset+4)?;
+        let crc = [crc_bytes[0], crc_bytes[1], crc_bytes[2], crc_bytes[3]];
+        let crc = if self.little_endian() { u32::from_le_bytes(crc) } else { u32::from_be_bytes(crc) };
+        Some(DebugLink { file_name, crc })
+    }
+
+    /// Returns the contents of the `.gnu_debugaltlink` section, if any.
+    pub fn debug_alt_link(&self) -> Option<DebugAltLink<'a>> {
+        let data = match self.section_by_name(b".gnu_debugaltlink")?.data {
+            SectionDataRef::Uninterpreted(data) => data,
+            _                                   => return None,
+        };
+        let (file_name, build_id) = split_string(data)?;
+        Some(DebugAltLink { file_name, build_id })
+    }
+}
+
+impl ElfFile {
+    /// Sets the `.gnu_debuglink` section, adding it if necessary, to refer to the separate debug
+    /// file `file_name` whose [crc32](fn.crc32.html) is `crc`, and returns its index.
+    ///
+    /// `file_name` should not contain any directory components.
+    pub fn set_debug_link(&mut self, file_name: &[u8], crc: u32) -> Elf_Word {
+        let mut data = file_name.to_vec();
+        data.push(0);
+        data.resize((data.len() + 3) & !3, 0);
+        if self.format().is_little_endian() {
+            data.extend_from_slice(&crc.to_le_bytes());
+        } else {
+            data.extend_from_slice(&crc.to_be_bytes());
+        }
+        match self.section_index(b".gnu_debuglink") {
+            Some(index) => {
+                self.sections[index as usize].data = data;
+                index
+            },
+            None => {
+                let mut section = FileSection::new(b".gnu_debuglink", SHT_PROGBITS, 0, data);
+                section.header.sh_addralign = 4;
+                self.add_section(section)
+            },
+        }
+    }
+}
+
+/// A separate debug file read into memory.
+#[derive(Debug, Clone)]
+pub struct DebugFile {
+    path: PathBuf,
+    data: Vec<u8>,
+}
+
+impl DebugFile {
+    /// Returns the path from which the debug file was read.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the contents of the debug file.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Returns a new `Reader` for the debug file.
+    pub fn reader(&self) -> Result<Reader<'_>> {
+        Reader::new(&self.data)
+    }
+
+    fn build_id(&self) -> Option<&[u8]> {
+        self.reader().ok().and_then(|reader| reader.build_id())
+    }
+}
+
+/// The separate debug files associated with an object file.
+#[derive(Debug, Clone, Default)]
+pub struct SeparateDebugInfo {
+    /// The debug file found via the build ID or `.gnu_debuglink`.
+    pub debug: Option<DebugFile>,
+    /// The supplementary debug file found via `.gnu_debugaltlink`.
+    pub alt: Option<DebugFile>,
+}
+
+impl SeparateDebugInfo {
+    /// Returns a combined view of `main` and its separate debug files.
+    pub fn view<'a>(&'a self, main: Reader<'a>) -> Result<DebugView<'a>> {
+        Ok(DebugView {
+            main,
+            debug: self.debug.as_ref().map(DebugFile::reader).transpose()?,
+            alt: self.alt.as_ref().map(DebugFile::reader).transpose()?,
+        })
+    }
+}
+
+/// Locates separate debug files the way GDB does.
+///
+/// A debug file is looked up by build ID as `ROOT/.build-id/xx/yyyy.debug` under each root
+/// directory, where `xx` is the first byte of the build ID in hex and `yyyy` is the rest. Failing
+/// that, the file named by `.gnu_debuglink` is looked up in the object file's directory, its
+/// `.debug` subdirectory, and the object file's directory under each root directory; a candidate
+/// is only accepted if its CRC matches.
+#[derive(Debug, Clone)]
+pub struct DebugLocator {
+    roots: Vec<PathBuf>,
+}
+
+impl Default for DebugLocator {
+    fn default() -> Self {
+        DebugLocator::new()
+    }
+}
+
+impl DebugLocator {
+    /// Returns a new `DebugLocator` that searches
+    /// [DEFAULT_DEBUG_DIRECTORY](constant.DEFAULT_DEBUG_DIRECTORY.html).
+    pub fn new() -> Self {
+        DebugLocator::with_roots(&[DEFAULT_DEBUG_DIRECTORY])
+    }
+
+    /// Returns a new `DebugLocator` that searches `roots`, in order.
+    pub fn with_roots<P: AsRef<Path>>(roots: &[P]) -> Self {
+        DebugLocator {
+            roots: roots.iter().map(|root| root.as_ref().to_path_buf()).collect(),
+        }
+    }
+
+    /// Adds a root directory to search after the existing ones.
+    pub fn add_root<P: AsRef<Path>>(&mut self, root: P) {
+        self.roots.push(root.as_ref().to_path_buf());
+    }
+
+    /// Returns the root directories that are searched.
+    pub fn roots(&self) -> &[PathBuf] {
+        &self.roots
+    }
+
+    /// Locates the separate debug files for `reader`, which was read from `path`.
+    ///
+    /// Candidates which cannot be read or whose build ID or CRC does not match are skipped.
+    pub fn locate(&self, reader: &Reader, path: &Path) -> SeparateDebugInfo {
+        let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        let debug = reader.build_id()
+            .and_then(|build_id| self.find_by_build_id(build_id))
+            .filter(|file| file.path != path)
+            .or_else(|| reader.debug_link().and_then(|link| self.find_by_debug_link(&link, &path)));
+
+        // The .gnu_debugaltlink section is in the debug file if there is one; otherwise, it may
+        // be in the object file itself.
+        let alt = match debug {
+            Some(ref file) => file.reader().ok()
+                .and_then(|debug_reader| debug_reader.debug_alt_link())
+                .and_then(|link| self.find_by_debug_alt_link(&link, &file.path)),
+            None => reader.debug_alt_link()
+                .and_then(|link| self.find_by_debug_alt_link(&link, &path)),
+        };
+        SeparateDebugInfo { debug, alt }
+    }
+
+    /// Returns the debug file with the given build ID from the first root directory that contains
+    /// one.
+    pub fn find_by_build_id(&self, build_id: &[u8]) -> Option<DebugFile> {
+        let (first, rest) = build_id.split_first()?;
+        let mut file_name: String = rest.iter().map(|b| format!("{:02x}", b)).collect();
+        file_name += ".debug";
+        let relative = Path::new(".build-id").join(format!("{:02x}", first)).join(file_name);
+        self.roots.iter()
+            .filter_map(|root| read_file(&root.join(&relative)))
+            .find(|file| file.build_id() == Some(build_id))
+    }
+
+    /// Returns the debug file named by `link` for the object file at `path`.
+    pub fn find_by_debug_link(&self, link: &DebugLink, path: &Path) -> Option<DebugFile> {
+        let file_name = Path::new(bytes_to_os_str(link.file_name)?);
+        let dir = path.parent().unwrap_or_else(|| Path::new(""));
+        let mut candidates = vec![dir.join(file_name), dir.join(".debug").join(file_name)];
+        let relative_dir = dir.strip_prefix("/").unwrap_or(dir);
+        candidates.extend(self.roots.iter().map(|root| root.join(relative_dir).join(file_name)));
+        candidates.into_iter()
+            .filter(|candidate| candidate != path)
+            .filter_map(|candidate| read_file(&candidate))
+            .find(|file| crc32(&file.data) == link.crc)
+    }
+
+    /// Returns the supplementary debug file named by `link` in the file at `path`.
+    ///
+    /// If the named file does not exist or has the wrong build ID, it is looked up by build ID.
+    pub fn find_by_debug_alt_link(&self, link: &DebugAltLink, path: &Path) -> Option<DebugFile> {
+        let dir = path.parent().unwrap_or_else(|| Path::new(""));
+        bytes_to_os_str(link.file_name)
+            .and_then(|file_name| read_file(&dir.join(file_name)))
+            .filter(|file| file.build_id() == Some(link.build_id))
+            .or_else(|| self.find_by_build_id(link.build_id))
+    }
+}
+
+#[cfg(unix)]
+fn bytes_to_os_str(bytes: &[u8]) -> Option<&::std::ffi::OsStr> {
+    use std::os::unix::ffi::OsStrExt;
+    Some(::std::ffi::OsStr::from_bytes(bytes))
+}
+
+#[cfg(not(unix))]
+fn bytes_to_os_str(bytes: &[u8]) -> Option<&::std::ffi::OsStr> {
+    ::std::str::from_utf8(bytes).ok().map(AsRef::as_ref)
+}
+
+fn read_file(path: &Path) -> Option<DebugFile> {
+    let path = path.canonicalize().ok()?;
+    let data = fs::read(&path).ok()?;
+    Reader::new(&data).ok()?;
+    Some(DebugFile { path, data })
+}
+
+/// A combined view of an object file and its separate debug files.
+///
+/// Symbol and section queries are answered by the object file when possible and fall through to
+/// the debug file otherwise.
+pub struct DebugView<'a> {
+    main: Reader<'a>,
+    debug: Option<Reader<'a>>,
+    alt: Option<Reader<'a>>,
+}
+
+impl<'a> DebugView<'a> {
+    /// Returns the `Reader` for the object file.
+    pub fn main(&self) -> &Reader<'a> {
+        &self.main
+    }
+
+    /// Returns the `Reader` for the debug file, if any.
+    pub fn debug(&self) -> Option<&Reader<'a>> {
+        self.debug.as_ref()
+    }
+
+    /// Returns the `Reader` for the supplementary debug file, if any.
+    pub fn alt(&self) -> Option<&Reader<'a>> {
+        self.alt.as_ref()
+    }
+
+    /// Returns the symbol table of the object file or, if it has been stripped, of the debug file.
+    pub fn symtab(&self) -> Result<Option<SectionRef<'a>>> {
+        if let Some(symtab) = self.main.symtab()? {
+            return Ok(Some(symtab));
+        }
+        match self.debug {
+            Some(ref debug) => debug.symtab(),
+            None            => Ok(None),
+        }
+    }
+
+    /// Returns the first section named `name` which has data in the file, searching the object
+    /// file followed by the debug file.
+    ///
+    /// Debug files produced by `objcopy --only-keep-debug` contain `SHT_NOBITS` headers for the
+    /// allocated sections, so those fall through to the object file and the `.debug_*` sections
+    /// fall through to the debug file.
+    pub fn section_by_name(&self, name: &[u8]) -> Option<SectionRef<'a>> {
+        let has_data = |section: &SectionRef<'a>| !matches!(section.data, SectionDataRef::NoBits);
+        self.main.section_by_name(name)
+            .filter(&has_data)
+            .or_else(|| self.debug.as_ref()
+                     .and_then(|debug| debug.section_by_name(name))
+                     .filter(&has_data))
+    }
+
+    /// Returns the section named `name` in the supplementary debug file, if any.
+    ///
+    /// Debug information entries in the object or debug file refer to the supplementary file's
+    /// `.debug_info` and `.debug_str` sections using the `DW_FORM_GNU_ref_alt` and
+    /// `DW_FORM_GNU_strp_alt` forms.
+    pub fn alt_section_by_name(&self, name: &[u8]) -> Option<SectionRef<'a>> {
+        self.alt.as_ref().and_then(|alt| alt.section_by_name(name))
+    }
+
+    /// Returns the build ID of the object file.
+    pub fn build_id(&self) -> Option<&'a [u8]> {
+        self.main.build_id()
+    }
+}
@@ -0,0 +1,1275 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::{self, Write};
+
+use serde::de::{self, Deserialize, Deserializer, Unexpected, Visitor};
+
+use constants::*;
+use edit::*;
+use error::*;
+use format::*;
+use image::*;
+use reader::*;
+use relocations::*;
+use types::*;
+use writer::*;
+
+/// A constant, such as a section type, written as its name if it has one and as a number
+/// otherwise. Names are those returned by the `*_name` functions, such as
+/// [section_type_name](fn.section_type_name.html).
+///
+/// YAML reads an unquoted `NULL` as null, which is accepted as the name `NULL`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum Constant {
+    Number(u64),
+    Name(String),
+}
+
+impl<'de> Deserialize<'de> for Constant {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> ::std::result::Result<Self, D::Error> {
+        struct ConstantVisitor;
+
+        impl<'de> Visitor<'de> for ConstantVisitor {
+            type Value = Constant;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a number or the name of a constant")
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> ::std::result::Result<Constant, E> {
+                Ok(Constant::Number(value))
+            }
+
+            fn visit_i64<E: de::Error>(self, value: i64) -> ::std::result::Result<Constant, E> {
+                if value < 0 {
+                    return Err(E::invalid_value(Unexpected::Signed(value), &self));
+                }
+                Ok(Constant::Number(value as u64))
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> ::std::result::Result<Constant, E> {
+                Ok(Constant::Name(value.to_string()))
+            }
+
+            fn visit_unit<E: de::Error>(self) -> ::std::result::Result<Constant, E> {
+                Ok(Constant::Name("NULL".to_string()))
+            }
+        }
+
+        deserializer.deserialize_any(ConstantVisitor)
+    }
+}
+
+/// A reference to a section or a symbol, by index or by name. Names are only written for
+/// sections and symbols whose names are unique.
+///
+/// Symbols refer to the special section indexes [SHN_ABS](constant.SHN_ABS.html) and
+/// [SHN_COMMON](constant.SHN_COMMON.html) by the names `ABS` and `COMMON`, and to the other
+/// reserved indexes by number.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Reference {
+    Index(u64),
+    Name(String),
+}
+
+/// A textual description of an object file, which can be serialized, for example as YAML or
+/// JSON, and [built](#method.build) back into an object file.
+///
+/// The section contents which the library understands are decoded: string tables, symbol tables,
+/// relocations, notes, dynamic tables, and section groups. Other contents are written as
+/// hexadecimal bytes. The section string table, the contents of `SHT_SYMTAB_SHNDX` sections, and
+/// the offsets of sections and segments are not described: they are generated by the
+/// [ElfBuilder](struct.ElfBuilder.html). Sections are numbered as in the built file, in which the
+/// section string table comes last.
+///
+/// # Examples
+///
+/// ```
+/// extern crate elftk;
+///
+/// use elftk::*;
+///
+/// # fn main() {
+/// let text = "
+/// header:
+///   class: ELF64
+///   data: LE
+///   type: REL
+///   machine: 62
+/// sections:
+///   - name: .text
+///     type: PROGBITS
+///     flags: AX
+///     content: c3
+///   - name: .symtab
+///     type: SYMTAB
+///     link: .strtab
+///     alignment: 8
+///     symbols:
+///       - name: main
+///         type: FUNC
+///         binding: GLOBAL
+///         section: .text
+///         size: 1
+///   - name: .strtab
+///     type: STRTAB
+/// ";
+/// let description = ElfDescription::from_yaml(text).unwrap();
+/// let data = description.build().unwrap();
+/// let reader = Reader::new(&data).unwrap();
+/// assert_eq!(reader.elf_header().e_machine(), EM_X86_64);
+/// assert_eq!(reader.describe().unwrap().sections[1].symbols.as_ref().unwrap()[0].name, "main");
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ElfDescription {
+    pub header: HeaderDescription,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sections: Vec<SectionDescription>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub segments: Vec<SegmentDescription>,
+}
+
+/// The ELF header. The class is `ELF32` or `ELF64` and the data encoding `LE` or `BE`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct HeaderDescription {
+    pub class: Constant,
+    pub data: Constant,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub os_abi: u8,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub abi_version: u8,
+    #[serde(rename = "type")]
+    pub e_type: Constant,
+    pub machine: Constant,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub entry: Elf64_Addr,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub flags: Elf_Word,
+}
+
+/// A section. Its contents are given by at most one of `content`, `size`, `strings`, `symbols`,
+/// `relocations`, `notes`, `dynamic`, and `group`; a section without any is empty, except that a
+/// string table starts with an empty string.
+///
+/// Flags are written as letters, as by `readelf`: `W` (write), `A` (alloc), `X` (execute),
+/// `M` (merge), `S` (strings), `I` (info link), `L` (link order), `O` (OS nonconforming),
+/// `G` (group), `T` (TLS), `C` (compressed), and `E` (exclude). Flags without a letter are
+/// written as a number.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SectionDescription {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub sh_type: Constant,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub flags: Option<Constant>,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub address: Elf64_Addr,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub link: Option<Reference>,
+    /// The section the section applies to, or the raw value of `sh_info` for section types which
+    /// do not refer to a section. Symbol tables default to the index of their first non-local
+    /// symbol.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub info: Option<Reference>,
+    #[serde(default = "one", skip_serializing_if = "is_one")]
+    pub alignment: Elf64_Xword,
+    /// The entry size, if it is not the size of the entries of the section type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entsize: Option<Elf64_Xword>,
+    /// The contents as hexadecimal bytes. Whitespace between bytes is ignored.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// The size of an `SHT_NOBITS` section.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<Elf64_Xword>,
+    /// The strings of a string table, each followed by a null byte.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub strings: Option<Vec<String>>,
+    /// The symbols of a symbol table, except the null symbol 0. Their names are added to the
+    /// linked string table if it does not contain them.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub symbols: Option<Vec<SymbolDescription>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relocations: Option<Vec<RelocationEntryDescription>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notes: Option<Vec<NoteDescription>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dynamic: Option<Vec<DynamicEntryDescription>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<GroupDescription>,
+}
+
+/// A symbol. An absent section means [SHN_UNDEF](constant.SHN_UNDEF.html); `other` holds the bits
+/// of `st_other` besides the visibility.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SymbolDescription {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub name: String,
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub symbol_type: Option<Constant>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub binding: Option<Constant>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<Constant>,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub other: u8,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub section: Option<Reference>,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub value: Elf64_Addr,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub size: Elf64_Xword,
+}
+
+/// A relocation. The type is named if the machine's relocation types are known, except for the
+/// composite types of MIPS64. Only `SHT_RELA` relocations have an addend.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RelocationEntryDescription {
+    pub offset: Elf64_Addr,
+    #[serde(rename = "type")]
+    pub relocation_type: Constant,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<Reference>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub addend: Option<Elf64_Sxword>,
+}
+
+/// A note. A note without a name has `namesz` 0; the descriptor is written as hexadecimal bytes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NoteDescription {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "type")]
+    pub note_type: Elf_Word,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub desc: String,
+}
+
+/// An entry of a dynamic table. The value of an entry whose tag refers to the linked string
+/// table, such as [DT_NEEDED](constant.DT_NEEDED.html), is written as a `string`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DynamicEntryDescription {
+    pub tag: Constant,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<Elf64_Xword>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub string: Option<String>,
+}
+
+/// The contents of an `SHT_GROUP` section.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GroupDescription {
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub flags: Elf_Word,
+    pub members: Vec<Reference>,
+}
+
+/// A segment, which covers its sections. The addresses default to the address of the section
+/// with the lowest index and the sizes to those covering the sections, and are only written if
+/// they differ. Flags are written as the letters `R`, `W`, and `X`, or as a number if there are
+/// other flags.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SegmentDescription {
+    #[serde(rename = "type")]
+    pub p_type: Constant,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub flags: Option<Constant>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vaddr: Option<Elf64_Addr>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub paddr: Option<Elf64_Addr>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filesz: Option<Elf64_Xword>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memsz: Option<Elf64_Xword>,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub align: Elf64_Xword,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sections: Vec<Reference>,
+}
+
+fn is_zero<T: Default + PartialEq>(x: &T) -> bool {
+    *x == T::default()
+}
+
+fn one() -> Elf64_Xword {
+    1
+}
+
+fn is_one(x: &Elf64_Xword) -> bool {
+    *x == 1
+}
+
+const SECTION_FLAG_LETTERS: &[(char, Elf_Xword)] = &[
+    ('W', SHF_WRITE),
+    ('A', SHF_ALLOC),
+    ('X', SHF_EXECINSTR),
+    ('M', SHF_MERGE),
+    ('S', SHF_STRINGS),
+    ('I', SHF_INFO_LINK),
+    ('L', SHF_LINK_ORDER),
+    ('O', SHF_OS_NONCONFORMING),
+    ('G', SHF_GROUP),
+    ('T', SHF_TLS),
+    ('C', SHF_COMPRESSED),
+    ('E', SHF_EXCLUDED),
+];
+
+const SEGMENT_FLAG_LETTERS: &[(char, Elf_Xword)] = &[
+    ('R', PF_R as Elf_Xword),
+    ('W', PF_W as Elf_Xword),
+    ('X', PF_X as Elf_Xword),
+];
+
+fn describe_flags(flags: Elf_Xword, letters: &[(char, Elf_Xword)]) -> Option<Constant> {
+    if flags == 0 {
+        return None;
+    }
+    let known = letters.iter().fold(0, |known, &(_, flag)| known | flag);
+    if flags & !known != 0 {
+        return Some(Constant::Number(flags));
+    }
+    Some(Constant::Name(letters.iter()
+        .filter(|&&(_, flag)| flags & flag != 0)
+        .map(|&(letter, _)| letter)
+        .collect()))
+}
+
+fn parse_flags(flags: &Option<Constant>, letters: &[(char, Elf_Xword)], msg: &'static str) -> Result<Elf_Xword> {
+    match *flags {
+        None                        => Ok(0),
+        Some(Constant::Number(n))   => Ok(n),
+        Some(Constant::Name(ref s)) => s.chars().try_fold(0, |flags, c| {
+            letters.iter()
+                .find(|&&(letter, _)| letter == c)
+                .map(|&(_, flag)| flags | flag)
+                .ok_or(Error::Msg { msg })
+        }),
+    }
+}
+
+fn to_hex(data: &[u8]) -> String {
+    let mut text = String::with_capacity(2 * data.len());
+    for b in data {
+        let _ = write!(text, "{:02x}", b);
+    }
+    text
+}
+
+fn from_hex(text: &str) -> Result<Vec<u8>> {
+    let digits: String = text.split_whitespace().collect();
+    parse_hex(&digits).ok_or(Error::Msg { msg: "invalid hexadecimal bytes" })
+}
+
+// Returns the strings of a string table, or `None` if it does not end with a null byte or holds
+// a string which is not valid UTF-8.
+fn table_strings(data: &[u8]) -> Option<Vec<String>> {
+    match data.split_last() {
+        None             => Some(Vec::new()),
+        Some((&0, rest)) => rest.split(|&b| b == 0)
+            .map(|s| String::from_utf8(s.to_vec()).ok())
+            .collect(),
+        Some(_)          => None,
+    }
+}
+
+// Converts a kind of constant between its values and the names returned by its `*_name`
+// function. Only names of a single value are used, which excludes the names of ranges.
+struct ConstantNames {
+    names: HashMap<u64, &'static str>,
+    values: HashMap<&'static str, u64>,
+}
+
+impl ConstantNames {
+    fn new<I: Iterator<Item=(u64, &'static str)>>(constants: I) -> Self {
+        let mut values = HashMap::new();
+        let mut ambiguous = HashSet::new();
+        for (value, name) in constants {
+            if name == "<unknown>" || name == "OS" || name == "PROC" || name == "USER" {
+                continue;
+            }
+            if *values.entry(name).or_insert(value) != value {
+                ambiguous.insert(name);
+            }
+        }
+        values.retain(|name, _| !ambiguous.contains(name));
+        let names = values.iter().map(|(&name, &value)| (value, name)).collect();
+        ConstantNames { names, values }
+    }
+
+    fn describe(&self, value: u64) -> Constant {
+        match self.names.get(&value) {
+            Some(name) => Constant::Name(name.to_string()),
+            None       => Constant::Number(value),
+        }
+    }
+
+    fn value(&self, constant: &Constant, msg: &'static str) -> Result<u64> {
+        match *constant {
+            Constant::Number(n)       => Ok(n),
+            Constant::Name(ref name) => self.values.get(name.as_str()).cloned().ok_or(Error::Msg { msg }),
+        }
+    }
+}
+
+// The names of the constants in the description of an object file.
+struct ConstantTables {
+    file_type: ConstantNames,
+    machine: ConstantNames,
+    section_type: ConstantNames,
+    segment_type: ConstantNames,
+    binding: ConstantNames,
+    symbol_type: ConstantNames,
+    visibility: ConstantNames,
+    dynamic_tag: ConstantNames,
+    relocation_type: ConstantNames,
+}
+
+impl ConstantTables {
+    fn new(format: ElfFormat, machine: Elf_Half) -> Self {
+        let class = if format.is_64bit() { ELFCLASS64 } else { ELFCLASS32 };
+        // The types of MIPS64 relocations combine up to three relocation types.
+        let relocation_types = if machine == EM_MIPS && format.is_64bit() { 0..0 } else { 0..0x500 };
+        let section_types = (0..0x20).chain(vec![SHT_ANDROID_REL, SHT_ANDROID_RELA, SHT_ANDROID_RELR]);
+        let segment_types = (0..0x10).chain(PT_GNU_EH_FRAME..=PT_GNU_PROPERTY);
+        let dynamic_tags = (0..0x40).chain(DT_ANDROID_REL..=DT_ANDROID_RELASZ)
+            .chain(DT_ANDROID_RELR..=DT_ANDROID_RELRENT)
+            .chain(Some(DT_GNU_HASH))
+            .chain(DT_VERSYM..=DT_VERNEEDNUM);
+        ConstantTables {
+            file_type: ConstantNames::new((0..0x10).map(|t| (u64::from(t), type_name(t)))),
+            machine: ConstantNames::new((0..0x200).map(|m| (u64::from(m), machine_name(m)))),
+            section_type: ConstantNames::new(section_types.map(|t| (u64::from(t), section_type_name(t)))),
+            segment_type: ConstantNames::new(segment_types.map(|t| (u64::from(t), segment_type_name(t)))),
+            binding: ConstantNames::new((0..0x10).map(|b| (u64::from(b), symbol_binding_name(b)))),
+            symbol_type: ConstantNames::new((0..0x10).map(|t| (u64::from(t), symbol_type_name(t)))),
+            visibility: ConstantNames::new((0..4).map(|v| (u64::from(v), symbol_visibility_name(v)))),
+            dynamic_tag: ConstantNames::new(dynamic_tags.map(|t| (t as u64, dynamic_tag_name(t)))),
+            relocation_type: ConstantNames::new(relocation_types
+                .map(|t| (u64::from(t), relocation_name(machine, class, t)))),
+        }
+    }
+}
+
+fn class_names() -> ConstantNames {
+    ConstantNames::new((0..3).map(|c| (u64::from(c), class_name(c))))
+}
+
+fn data_names() -> ConstantNames {
+    ConstantNames::new((0..3).map(|d| (u64::from(d), data_name(d))))
+}
+
+// The size of the entries of sections of type `sh_type`, or 0 if they do not hold a table.
+fn default_entsize(format: ElfFormat, sh_type: Elf_Word) -> Elf64_Xword {
+    let word = if format.is_64bit() { 8 } else { 4 };
+    match sh_type {
+        SHT_SYMTAB | SHT_DYNSYM => if format.is_64bit() { 24 } else { 16 },
+        SHT_REL                 => 2 * word,
+        SHT_RELA                => 3 * word,
+        SHT_DYNAMIC             => 2 * word,
+        SHT_RELR                => word,
+        SHT_HASH | SHT_GROUP | SHT_SYMTAB_SHNDX => 4,
+        _                       => 0,
+    }
+}
+
+// Returns whether `sh_info` refers to a section.
+fn info_is_section(sh_type: Elf_Word, sh_flags: Elf64_Xword) -> bool {
+    sh_type == SHT_REL || sh_type == SHT_RELA || sh_flags & SHF_INFO_LINK != 0
+}
+
+// Returns the index of the first non-local symbol, the default `sh_info` of a symbol table.
+fn first_non_local(symbols: &[SymbolDescription], tables: &ConstantTables) -> Result<Elf_Word> {
+    for (i, symbol) in symbols.iter().enumerate() {
+        let binding = match symbol.binding {
+            Some(ref binding) => tables.binding.value(binding, "unknown symbol binding name")?,
+            None              => u64::from(STB_LOCAL),
+        };
+        if binding != u64::from(STB_LOCAL) {
+            return Ok(i as Elf_Word + 1);
+        }
+    }
+    Ok(symbols.len() as Elf_Word + 1)
+}
+
+fn encode_notes(format: ElfFormat, alignment: Elf64_Xword, notes: &[NoteDescription]) -> Result<Vec<u8>> {
+    let align = if alignment == 8 { 8 } else { 4 };
+    let mut encoder = Encoder::new(format);
+    for note in notes {
+        let desc = from_hex(&note.desc)?;
+        let namesz = note.name.as_ref().map_or(0, |name| name.len() + 1);
+        encoder.word(namesz as Elf_Word);
+        encoder.word(desc.len() as Elf_Word);
+        encoder.word(note.note_type);
+        if let Some(ref name) = note.name {
+            encoder.data.extend_from_slice(name.as_bytes());
+            encoder.u8(0);
+        }
+        let end = align_up(encoder.data.len() as Elf64_Off, align);
+        encoder.pad_to(end);
+        encoder.data.extend_from_slice(&desc);
+        let end = align_up(encoder.data.len() as Elf64_Off, align);
+        encoder.pad_to(end);
+    }
+    Ok(encoder.data)
+}
+
+// A string table being built, which finds strings at the end of other strings.
+struct StringTableData {
+    data: Vec<u8>,
+    offsets: HashMap<String, Elf_Word>,
+}
+
+impl StringTableData {
+    fn new(strings: &[String]) -> Self {
+        let mut table = StringTableData { data: Vec::new(), offsets: HashMap::new() };
+        for string in strings {
+            table.push(string);
+        }
+        table
+    }
+
+    fn push(&mut self, string: &str) -> Elf_Word {
+        let offset = self.data.len() as Elf_Word;
+        self.data.extend_from_slice(string.as_bytes());
+        self.data.push(0);
+        for (i, _) in string.char_indices().chain(Some((string.len(), ' '))) {
+            self.offsets.entry(string[i..].to_string()).or_insert(offset + i as Elf_Word);
+        }
+        offset
+    }
+
+    fn offset(&mut self, string: &str) -> Elf_Word {
+        match self.offsets.get(string) {
+            Some(&offset) => offset,
+            None          => self.push(string),
+        }
+    }
+}
+
+// Maps the references to sections in a description to section indexes.
+struct SectionIndexes<'d> {
+    names: HashMap<&'d str, Elf_Word>,
+}
+
+impl<'d> SectionIndexes<'d> {
+    fn new(sections: &'d [SectionDescription]) -> Self {
+        let mut names = HashMap::new();
+        for (i, section) in sections.iter().enumerate() {
+            names.entry(section.name.as_str()).or_insert(i as Elf_Word + 1);
+        }
+        SectionIndexes { names }
+    }
+
+    fn resolve(&self, reference: &Reference) -> Result<Elf_Word> {
+        match *reference {
+            Reference::Index(index) if index <= u64::from(Elf_Word::MAX) => Ok(index as Elf_Word),
+            Reference::Index(index) => Err(Error::IndexOutOfBounds { index: index as usize, length: self.names.len() }),
+            Reference::Name(ref name) => self.names.get(name.as_str()).cloned()
+                .ok_or(Error::Msg { msg: "reference to an unknown section name" }),
+        }
+    }
+
+    fn resolve_optional(&self, reference: &Option<Reference>) -> Result<Elf_Word> {
+        reference.as_ref().map_or(Ok(0), |reference| self.resolve(reference))
+    }
+}
+
+// Returns whether the allocated section with header `shdr` lies in the segment with program
+// header `phdr`. A `.tbss` section only lies in the TLS segment.
+fn segment_contains(phdr: &Elf64_Phdr, shdr: &Elf64_Shdr) -> bool {
+    if shdr.sh_flags & SHF_ALLOC == 0 {
+        return false;
+    }
+    if shdr.sh_type == SHT_NOBITS {
+        if shdr.sh_flags & SHF_TLS != 0 && phdr.p_type != PT_TLS {
+            return false;
+        }
+        let end = phdr.p_vaddr.saturating_add(phdr.p_memsz);
+        return phdr.p_vaddr <= shdr.sh_addr && shdr.sh_addr.saturating_add(shdr.sh_size) <= end &&
+            (shdr.sh_size > 0 || shdr.sh_addr < end);
+    }
+    let end = phdr.p_offset.saturating_add(phdr.p_filesz);
+    phdr.p_offset <= shdr.sh_offset && shdr.sh_offset.saturating_add(shdr.sh_size) <= end &&
+        (shdr.sh_size > 0 || shdr.sh_offset < end) &&
+        shdr.sh_addr.wrapping_sub(phdr.p_vaddr) == shdr.sh_offset - phdr.p_offset
+}
+
+// The sections of an object file which is being described.
+struct SectionContext<'r, 'a: 'r> {
+    reader: &'r Reader<'a>,
+    headers: Vec<SectionHeaderRef<'a>>,
+    names: Vec<String>,
+    name_counts: HashMap<String, usize>,
+    shstrndx: Option<Elf_Word>,
+}
+
+impl<'r, 'a> SectionContext<'r, 'a> {
+    fn new(reader: &'r Reader<'a>) -> Self {
+        let headers: Vec<_> = reader.section_headers().into_iter().collect();
+        let shstrndx = reader.section_string_table_index()
+            .filter(|&index| index != 0 && (index as usize) < headers.len());
+        let names: Vec<String> = headers.iter()
+            .map(|&shdr| String::from_utf8_lossy(reader.section_name(shdr)).into_owned())
+            .collect();
+        let mut name_counts = HashMap::new();
+        for (index, name) in names.iter().enumerate().skip(1) {
+            if Some(index as Elf_Word) != shstrndx {
+                *name_counts.entry(name.clone()).or_insert(0) += 1;
+            }
+        }
+        SectionContext { reader, headers, names, name_counts, shstrndx }
+    }
+
+    fn is_described(&self, index: usize) -> bool {
+        index != 0 && Some(index as Elf_Word) != self.shstrndx
+    }
+
+    // Returns the index of section `index` in the built file, which has the section string table
+    // last.
+    fn output_index(&self, index: Elf_Word) -> Result<Elf_Word> {
+        match self.shstrndx {
+            Some(shstrndx) if index == shstrndx => Err(Error::Msg {
+                msg: "references to the section string table cannot be described",
+            }),
+            Some(shstrndx) if index > shstrndx && (index as usize) < self.headers.len() => Ok(index - 1),
+            _ => Ok(index),
+        }
+    }
+
+    fn reference(&self, index: Elf_Word) -> Result<Reference> {
+        let output_index = self.output_index(index)?;
+        match self.names.get(index as usize) {
+            Some(name) if index != 0 && self.name_counts.get(name) == Some(&1) && is_plain_name(name) =>
+                Ok(Reference::Name(name.clone())),
+            _ => Ok(Reference::Index(output_index.into())),
+        }
+    }
+
+    fn uninterpreted_data(&self, shdr: SectionHeaderRef<'a>) -> &'a [u8] {
+        match self.reader.uninterpreted_section_data(shdr) {
+            SectionDataRef::Uninterpreted(data) => data,
+            _                                   => &[],
+        }
+    }
+}
+
+// Returns whether `name` can be written as a reference without being mistaken for an index or a
+// special section.
+fn is_plain_name(name: &str) -> bool {
+    !name.is_empty() && name != "ABS" && name != "COMMON" && name.parse::<u64>().is_err()
+}
+
+impl<'a> Reader<'a> {
+    /// Returns a textual [description](struct.ElfDescription.html) of the object file, from which
+    /// an equivalent object file can be [built](struct.ElfDescription.html#method.build).
+    ///
+    /// Fails if a section other than the section string table refers to it.
+    pub fn describe(&self) -> Result<ElfDescription> {
+        let format = self.format();
+        let ehdr = self.elf_header().decode();
+        let tables = ConstantTables::new(format, ehdr.e_machine);
+        let context = SectionContext::new(self);
+
+        // String tables are described as strings if they can be, and the symbol tables linked to
+        // them as symbols.
+        let mut string_tables = HashMap::new();
+        for (index, &shdr) in context.headers.iter().enumerate() {
+            if shdr.sh_type() == SHT_STRTAB && context.is_described(index) {
+                if let Some(strings) = table_strings(context.uninterpreted_data(shdr)) {
+                    string_tables.insert(index as Elf_Word, strings);
+                }
+            }
+        }
+        let mut symbol_tables = HashMap::new();
+        for (index, &shdr) in context.headers.iter().enumerate() {
+            let sh_type = shdr.sh_type();
+            if (sh_type == SHT_SYMTAB || sh_type == SHT_DYNSYM) && context.is_described(index) &&
+               string_tables.contains_key(&shdr.sh_link()) &&
+               shdr.sh_entsize() == default_entsize(format, sh_type)
+            {
+                if let Some(symbols) = self.describe_symbols(shdr, &context, &tables) {
+                    symbol_tables.insert(index as Elf_Word, symbols);
+                }
+            }
+        }
+
+        let mut sections = Vec::new();
+        for (index, &shdr) in context.headers.iter().enumerate() {
+            if context.is_described(index) {
+                sections.push(self.describe_section(index as Elf_Word, shdr, &context, &tables,
+                                                    &string_tables, &symbol_tables)?);
+            }
+        }
+
+        let phdrs_size = Elf64_Xword::from(self.elf_header().e_phentsize()) * self.program_headers().len() as Elf64_Xword;
+        let mut segments = Vec::new();
+        for phdr in self.program_headers() {
+            let phdr = phdr.decode();
+            let mut members = Vec::new();
+            let mut first_address = None;
+            // The sizes the builder computes from the sections, as in ElfBuilder::program_header.
+            let mut file_end = phdr.p_offset;
+            let mut memory_end = phdr.p_vaddr;
+            for (index, &shdr) in context.headers.iter().enumerate() {
+                let shdr = shdr.decode();
+                if context.is_described(index) && segment_contains(&phdr, &shdr) {
+                    members.push(context.reference(index as Elf_Word)?);
+                    first_address = first_address.or(Some(shdr.sh_addr));
+                    if shdr.sh_type != SHT_NOBITS && shdr.sh_type != SHT_NULL {
+                        file_end = file_end.max(shdr.sh_offset + shdr.sh_size);
+                    }
+                    memory_end = memory_end.max(shdr.sh_addr + shdr.sh_size);
+                }
+            }
+            let filesz = match first_address {
+                Some(_)                     => file_end - phdr.p_offset,
+                None if phdr.p_type == PT_PHDR => phdrs_size,
+                None                        => 0,
+            };
+            let memsz = phdr.p_filesz.max(memory_end - phdr.p_vaddr);
+            segments.push(SegmentDescription {
+                p_type: tables.segment_type.describe(phdr.p_type.into()),
+                flags: describe_flags(phdr.p_flags.into(), SEGMENT_FLAG_LETTERS),
+                vaddr: if first_address == Some(phdr.p_vaddr) { None } else { Some(phdr.p_vaddr) },
+                paddr: if phdr.p_paddr == phdr.p_vaddr { None } else { Some(phdr.p_paddr) },
+                filesz: if phdr.p_filesz == filesz { None } else { Some(phdr.p_filesz) },
+                memsz: if phdr.p_memsz == memsz { None } else { Some(phdr.p_memsz) },
+                align: phdr.p_align,
+                sections: members,
+            });
+        }
+
+        Ok(ElfDescription {
+            header: HeaderDescription {
+                class: class_names().describe(ehdr.e_ident[EI_CLASS].into()),
+                data: data_names().describe(ehdr.e_ident[EI_DATA].into()),
+                os_abi: ehdr.e_ident[EI_OSABI],
+                abi_version: ehdr.e_ident[EI_ABIVERSION],
+                e_type: tables.file_type.describe(ehdr.e_type.into()),
+                machine: tables.machine.describe(ehdr.e_machine.into()),
+                entry: ehdr.e_entry,
+                flags: ehdr.e_flags,
+            },
+            sections,
+            segments,
+        })
+    }
+
+    // Describes the symbols of a symbol table, or returns `None` if they cannot be described
+    // exactly, for example because the null symbol is not null.
+    fn describe_symbols(&self, shdr: SectionHeaderRef<'a>, context: &SectionContext<'_, 'a>,
+                        tables: &ConstantTables) -> Option<Vec<SymbolDescription>>
+    {
+        let symtab = match self.get_section(shdr).ok()?.data {
+            SectionDataRef::SymbolTable(symtab) => symtab,
+            _                                   => return None,
+        };
+        let mut symbols = Vec::with_capacity(symtab.len());
+        for i in 0..symtab.len() {
+            let symbol = symtab.get(i).ok()?;
+            let name = String::from_utf8(symbol.symbol_name?.to_vec()).ok()?;
+            let section = match symbol.section {
+                SectionIndex::Normal(0)         => None,
+                SectionIndex::Normal(index)     => Some(context.reference(index).ok()?),
+                SectionIndex::Reserved(SHN_ABS)    => Some(Reference::Name("ABS".to_string())),
+                SectionIndex::Reserved(SHN_COMMON) => Some(Reference::Name("COMMON".to_string())),
+                SectionIndex::Reserved(index)   => Some(Reference::Index(index.into())),
+            };
+            let description = SymbolDescription {
+                name,
+                symbol_type: Some(symbol.symbol_type()).filter(|&t| t != STT_NOTYPE)
+                    .map(|t| tables.symbol_type.describe(t.into())),
+                binding: Some(symbol.binding()).filter(|&b| b != STB_LOCAL)
+                    .map(|b| tables.binding.describe(b.into())),
+                visibility: Some(symbol.visibility()).filter(|&v| v != STV_DEFAULT)
+                    .map(|v| tables.visibility.describe(v.into())),
+                other: symbol.other & !3,
+                section,
+                value: symbol.value,
+                size: symbol.size,
+            };
+            if i == 0 {
+                if description != SymbolDescription::null() {
+                    return None;
+                }
+                continue;
+            }
+            symbols.push(description);
+        }
+        Some(symbols)
+    }
+
+    fn describe_section(&self, index: Elf_Word, shdr: SectionHeaderRef<'a>, context: &SectionContext<'_, 'a>,
+                        tables: &ConstantTables, string_tables: &HashMap<Elf_Word, Vec<String>>,
+                        symbol_tables: &HashMap<Elf_Word, Vec<SymbolDescription>>)
+        -> Result<SectionDescription>
+    {
+        let format = self.format();
+        let header = shdr.decode();
+        let mut section = SectionDescription {
+            name: context.names[index as usize].clone(),
+            sh_type: tables.section_type.describe(header.sh_type.into()),
+            flags: describe_flags(header.sh_flags, SECTION_FLAG_LETTERS),
+            address: header.sh_addr,
+            link: if header.sh_link == 0 { None } else { Some(context.reference(header.sh_link)?) },
+            info: None,
+            alignment: header.sh_addralign,
+            entsize: Some(header.sh_entsize).filter(|&size| size != default_entsize(format, header.sh_type)),
+            content: None,
+            size: None,
+            strings: None,
+            symbols: None,
+            relocations: None,
+            notes: None,
+            dynamic: None,
+            group: None,
+        };
+        if header.sh_info != 0 {
+            section.info = Some(if info_is_section(header.sh_type, header.sh_flags) {
+                context.reference(header.sh_info)?
+            } else {
+                Reference::Index(header.sh_info.into())
+            });
+        }
+        let data = context.uninterpreted_data(shdr);
+        let entries_described = section.entsize.is_none();
+
+        match header.sh_type {
+            SHT_NULL => {},
+            SHT_NOBITS => section.size = Some(header.sh_size),
+            SHT_STRTAB if string_tables.contains_key(&index) =>
+                section.strings = string_tables.get(&index).cloned(),
+            SHT_SYMTAB | SHT_DYNSYM if symbol_tables.contains_key(&index) => {
+                let symbols = &symbol_tables[&index];
+                if first_non_local(symbols, tables)? == header.sh_info {
+                    section.info = None;
+                }
+                section.symbols = Some(symbols.clone());
+            },
+            SHT_SYMTAB_SHNDX if symbol_tables.contains_key(&header.sh_link) => {},
+            SHT_REL | SHT_RELA if entries_described => {
+                match self.describe_relocations(header, data, context, tables, symbol_tables) {
+                    Some(relocations) => section.relocations = Some(relocations),
+                    None              => section.content = Some(to_hex(data)),
+                }
+            },
+            SHT_NOTE => {
+                let notes = match self.get_section(shdr).map(|section| section.data) {
+                    Ok(SectionDataRef::NoteTable(notes)) => notes.iter()
+                        .map(|note| NoteDescription {
+                            name: note.name.map(|name| String::from_utf8_lossy(name).into_owned()),
+                            note_type: note.note_type as Elf_Word,
+                            desc: note.desc.map_or_else(String::new, to_hex),
+                        })
+                        .collect(),
+                    _ => Vec::new(),
+                };
+                // Notes are only described if they encode back to the same bytes.
+                if encode_notes(format, header.sh_addralign, &notes).ok().as_ref().map(|d| &d[..]) == Some(data) {
+                    section.notes = Some(notes);
+                } else {
+                    section.content = Some(to_hex(data));
+                }
+            },
+            SHT_DYNAMIC if entries_described => {
+                match self.get_section(shdr).map(|section| section.data) {
+                    Ok(SectionDataRef::DynamicTable(table)) => {
+                        let strings = string_tables.get(&header.sh_link)
+                            .and(context.headers.get(header.sh_link as usize))
+                            .map(|&strtab| StringTableRef::new(context.uninterpreted_data(strtab)));
+                        let mut entries = Vec::new();
+                        for entry in table.entries {
+                            let (tag, value) = (entry.d_tag(), entry.d_val());
+                            let string = match tag {
+                                DT_NEEDED | DT_SONAME | DT_RPATH | DT_RUNPATH => strings
+                                    .and_then(|strings| strings.get_string(value as Elf_Word))
+                                    .filter(|_| value <= u64::from(Elf_Word::MAX))
+                                    .and_then(|string| String::from_utf8(string.to_vec()).ok()),
+                                _ => None,
+                            };
+                            entries.push(DynamicEntryDescription {
+                                tag: tables.dynamic_tag.describe(tag as u64),
+                                value: if string.is_some() || value == 0 { None } else { Some(value) },
+                                string,
+                            });
+                        }
+                        section.dynamic = Some(entries);
+                    },
+                    _ => section.content = Some(to_hex(data)),
+                }
+            },
+            SHT_GROUP if data.len().is_multiple_of(4) && !data.is_empty() => {
+                let words: Vec<Elf_Word> = data.chunks(4)
+                    .map(|chunk| {
+                        let bytes = [chunk[0], chunk[1], chunk[2], chunk[3]];
+                        if format.is_little_endian() { Elf_Word::from_le_bytes(bytes) } else { Elf_Word::from_be_bytes(bytes) }
+                    })
+                    .collect();
+                let mut members = Vec::new();
+                for &member in &words[1..] {
+                    members.push(context.reference(member)?);
+                }
+                section.group = Some(GroupDescription { flags: words[0], members });
+            },
+            _ => section.content = Some(to_hex(data)),
+        }
+        Ok(section)
+    }
+
+    // Describes the relocations of a relocation section, or returns `None` if its symbol table
+    // cannot be read.
+    fn describe_relocations(&self, header: Elf64_Shdr, data: &'a [u8], context: &SectionContext<'_, 'a>,
+                            tables: &ConstantTables,
+                            symbol_tables: &HashMap<Elf_Word, Vec<SymbolDescription>>)
+        -> Option<Vec<RelocationEntryDescription>>
+    {
+        let format = self.format();
+        let (shift, mask) = symbol_index_field(format, self.elf_header().e_machine());
+        let mut entries: Vec<(Elf64_Addr, Elf64_Xword, Option<Elf64_Sxword>)> = Vec::new();
+        let raw = format.construct_from(data);
+        if header.sh_type == SHT_RELA {
+            for entry in RelaTableEntriesRef::try_from(raw).ok()? {
+                entries.push((entry.r_offset(), entry.r_info(), Some(entry.r_addend())));
+            }
+        } else {
+            for entry in RelTableEntriesRef::try_from(raw).ok()? {
+                entries.push((entry.r_offset(), entry.r_info(), None));
+            }
+        }
+
+        // Symbols are referred to by name if it is unique.
+        let mut name_counts = HashMap::new();
+        let symbols = symbol_tables.get(&header.sh_link).filter(|_| context.is_described(header.sh_link as usize));
+        for symbol in symbols.into_iter().flatten() {
+            *name_counts.entry(symbol.name.as_str()).or_insert(0) += 1;
+        }
+
+        let mut relocations = Vec::with_capacity(entries.len());
+        for (offset, info, addend) in entries {
+            let symbol_index = (info >> shift) & mask;
+            let relocation_type = if shift == 0 { info >> 32 } else { info & ((1 << shift) - 1) };
+            let symbol = match symbols.and_then(|symbols| symbols.get((symbol_index as usize).wrapping_sub(1))) {
+                _ if symbol_index == 0 => None,
+                Some(symbol) if name_counts.get(symbol.name.as_str()) == Some(&1) && is_plain_name(&symbol.name) =>
+                    Some(Reference::Name(symbol.name.clone())),
+                _ => Some(Reference::Index(symbol_index)),
+            };
+            relocations.push(RelocationEntryDescription {
+                offset,
+                relocation_type: tables.relocation_type.describe(relocation_type),
+                symbol,
+                addend,
+            });
+        }
+        Some(relocations)
+    }
+}
+
+impl SymbolDescription {
+    // The null symbol 0 of a symbol table.
+    fn null() -> Self {
+        SymbolDescription {
+            name: String::new(),
+            symbol_type: None,
+            binding: None,
+            visibility: None,
+            other: 0,
+            section: None,
+            value: 0,
+            size: 0,
+        }
+    }
+}
+
+fn encode_relocations(encoder: &mut Encoder, format: ElfFormat, machine: Elf_Half, sh_type: Elf_Word,
+                      relocations: &[RelocationEntryDescription],
+                      symbol_names: Option<&HashMap<&str, Elf_Word>>, tables: &ConstantTables)
+    -> Result<()>
+{
+    let (shift, mask) = symbol_index_field(format, machine);
+    let type_limit = if shift == 0 { 1 << 32 } else { 1 << shift };
+    for relocation in relocations {
+        let symbol = match relocation.symbol {
+            None                           => 0,
+            Some(Reference::Index(index)) => index,
+            Some(Reference::Name(ref name)) => symbol_names.and_then(|names| names.get(name.as_str()))
+                .cloned()
+                .ok_or(Error::Msg { msg: "reference to an unknown symbol name" })?
+                .into(),
+        };
+        let relocation_type = tables.relocation_type.value(&relocation.relocation_type,
+                                                           "unknown relocation type name")?;
+        if symbol > mask {
+            return Err(Error::InvalidHeaderField { header: "relocation", field: "r_info", value: symbol });
+        }
+        if relocation_type >= type_limit {
+            return Err(Error::InvalidHeaderField { header: "relocation", field: "r_info", value: relocation_type });
+        }
+        let info = if shift == 0 { (relocation_type << 32) | symbol } else { (symbol << shift) | relocation_type };
+        encoder.class_word("relocation", "r_offset", relocation.offset)?;
+        encoder.class_word("relocation", "r_info", info)?;
+        match (sh_type, relocation.addend) {
+            (SHT_RELA, addend) => {
+                let addend = addend.unwrap_or(0);
+                if encoder.is_64bit() {
+                    encoder.xword(addend as Elf_Xword);
+                } else if addend >= Elf_Sxword::from(Elf_Sword::MIN) && addend <= Elf_Sxword::from(Elf_Sword::MAX) {
+                    encoder.word(addend as Elf_Word);
+                } else {
+                    return Err(Error::InvalidHeaderField { header: "relocation", field: "r_addend", value: addend as u64 });
+                }
+            },
+            (SHT_REL, None)    => {},
+            (SHT_REL, Some(_)) => return Err(Error::Msg { msg: "relocation with an addend in a REL section" }),
+            _ => return Err(Error::InvalidSectionType { expected: SHT_RELA, actual: sh_type }),
+        }
+    }
+    Ok(())
+}
+
+impl ElfDescription {
+    /// Parses a YAML description, such as one written by `elftk-obj2yaml`.
+    pub fn from_yaml(text: &str) -> ::std::result::Result<Self, serde_yaml::Error> {
+        serde_yaml::from_str(text)
+    }
+
+    /// Builds the described object file with an [ElfBuilder](struct.ElfBuilder.html).
+    pub fn build(&self) -> Result<Vec<u8>> {
+        let class = class_names().value(&self.header.class, "unknown class name")?;
+        let data = data_names().value(&self.header.data, "unknown data encoding name")?;
+        let format = match (class as u8, data as u8) {
+            _ if class > 0xff || data > 0xff  => return Err(Error::Msg { msg: "invalid class or data encoding" }),
+            (ELFCLASS32, ELFDATA2LSB) => ElfT::Elf32LE(()),
+            (ELFCLASS32, ELFDATA2MSB) => ElfT::Elf32BE(()),
+            (ELFCLASS64, ELFDATA2LSB) => ElfT::Elf64LE(()),
+            (ELFCLASS64, ELFDATA2MSB) => ElfT::Elf64BE(()),
+            (ELFCLASS32, _) | (ELFCLASS64, _) =>
+                return Err(Error::InvalidHeaderField { header: "ELF", field: "EI_DATA", value: data }),
+            _ => return Err(Error::InvalidHeaderField { header: "ELF", field: "EI_CLASS", value: class }),
+        };
+        let machine = self.header.machine_value()?;
+        let tables = ConstantTables::new(format, machine);
+        let e_type = tables.file_type.value(&self.header.e_type, "unknown file type name")?;
+        if e_type > u64::from(Elf_Half::MAX) {
+            return Err(Error::InvalidHeaderField { header: "ELF", field: "e_type", value: e_type });
+        }
+        let mut builder = ElfBuilder::new(format, FileHeader {
+            os_abi: self.header.os_abi,
+            abi_version: self.header.abi_version,
+            e_type: e_type as Elf_Half,
+            e_machine: machine,
+            e_entry: self.header.entry,
+            e_flags: self.header.flags,
+        });
+
+        let indexes = SectionIndexes::new(&self.sections);
+        let mut section_types = Vec::with_capacity(self.sections.len());
+        for section in &self.sections {
+            let sh_type = tables.section_type.value(&section.sh_type, "unknown section type name")?;
+            if sh_type > u64::from(Elf_Word::MAX) {
+                return Err(Error::InvalidHeaderField { header: "section", field: "sh_type", value: sh_type });
+            }
+            section_types.push(sh_type as Elf_Word);
+        }
+
+        // String tables come first since symbol tables and dynamic tables add strings to them.
+        let empty = [String::new()];
+        let mut string_tables = HashMap::new();
+        for (i, section) in self.sections.iter().enumerate() {
+            if section_types[i] == SHT_STRTAB && section.content.is_none() {
+                let strings = section.strings.as_ref().map_or(&empty[..], |strings| &strings[..]);
+                string_tables.insert(i as Elf_Word + 1, StringTableData::new(strings));
+            }
+        }
+        let mut contents: Vec<Option<Vec<u8>>> = vec![None; self.sections.len()];
+        let mut infos = vec![None; self.sections.len()];
+
+        // Symbol tables, and the section indexes of their symbols which need SHN_XINDEX.
+        let mut symbol_names = HashMap::new();
+        let mut section_indexes = HashMap::new();
+        for (i, section) in self.sections.iter().enumerate() {
+            let symbols = match section.symbols {
+                Some(ref symbols) => symbols,
+                None              => continue,
+            };
+            let index = i as Elf_Word + 1;
+            let link = indexes.resolve_optional(&section.link)?;
+            let strings = string_tables.get_mut(&link).ok_or(Error::InvalidLinkedSection { linked: link })?;
+            let mut encoder = Encoder::new(format);
+            let mut shndx = vec![0; symbols.len() + 1];
+            encoder.sym(&Elf64_Sym { st_name: 0, st_info: 0, st_other: 0, st_shndx: 0, st_value: 0, st_size: 0 })?;
+            let mut names = HashMap::new();
+            for (j, symbol) in symbols.iter().enumerate() {
+                let st_shndx = match symbol.section {
+                    None => SHN_UNDEF,
+                    Some(Reference::Name(ref name)) if name == "ABS"    => SHN_ABS,
+                    Some(Reference::Name(ref name)) if name == "COMMON" => SHN_COMMON,
+                    Some(Reference::Index(reserved)) if reserved >= u64::from(SHN_LORESERVE) &&
+                                                        reserved <= u64::from(SHN_HIRESERVE) => reserved as Elf_Half,
+                    Some(ref reference) => match indexes.resolve(reference)? {
+                        index if index >= Elf_Word::from(SHN_LORESERVE) => {
+                            shndx[j + 1] = index;
+                            SHN_XINDEX
+                        },
+                        index => index as Elf_Half,
+                    },
+                };
+                let symbol_type = symbol.symbol_type.as_ref()
+                    .map_or(Ok(0), |t| tables.symbol_type.value(t, "unknown symbol type name"))?;
+                let binding = symbol.binding.as_ref()
+                    .map_or(Ok(0), |b| tables.binding.value(b, "unknown symbol binding name"))?;
+                let visibility = symbol.visibility.as_ref()
+                    .map_or(Ok(0), |v| tables.visibility.value(v, "unknown symbol visibility name"))?;
+                if symbol_type > 0xf || binding > 0xf {
+                    return Err(Error::InvalidHeaderField {
+                        header: "symbol",
+                        field: "st_info",
+                        value: (binding << 4) | symbol_type,
+                    });
+                }
+                if visibility > 3 {
+                    return Err(Error::InvalidHeaderField { header: "symbol", field: "st_other", value: visibility });
+                }
+                encoder.sym(&Elf64_Sym {
+                    st_name: if symbol.name.is_empty() { 0 } else { strings.offset(&symbol.name) },
+                    st_info: ((binding << 4) | symbol_type) as u8,
+                    st_other: symbol.other | visibility as u8,
+                    st_shndx,
+                    st_value: symbol.value,
+                    st_size: symbol.size,
+                })?;
+                names.entry(symbol.name.as_str()).or_insert(j as Elf_Word + 1);
+            }
+            contents[i] = Some(encoder.data);
+            infos[i] = Some(first_non_local(symbols, &tables)?);
+            symbol_names.insert(index, names);
+            section_indexes.insert(index, shndx);
+        }
+
+        for (i, section) in self.sections.iter().enumerate() {
+            let sh_type = section_types[i];
+            let mut encoder = Encoder::new(format);
+            if let Some(ref content) = section.content {
+                encoder.data = from_hex(content)?;
+            } else if let Some(ref relocations) = section.relocations {
+                let link = indexes.resolve_optional(&section.link)?;
+                let names = symbol_names.get(&link);
+                encode_relocations(&mut encoder, format, machine, sh_type, relocations, names, &tables)?;
+            } else if let Some(ref notes) = section.notes {
+                encoder.data = encode_notes(format, section.alignment, notes)?;
+            } else if let Some(ref entries) = section.dynamic {
+                let link = indexes.resolve_optional(&section.link)?;
+                for entry in entries {
+                    let tag = tables.dynamic_tag.value(&entry.tag, "unknown dynamic tag name")?;
+                    let d_val = match (entry.value, entry.string.as_ref()) {
+                        (Some(_), Some(_)) => return Err(Error::Msg { msg: "dynamic entry with a value and a string" }),
+                        (_, Some(string)) => string_tables.get_mut(&link)
+                            .ok_or(Error::InvalidLinkedSection { linked: link })?
+                            .offset(string).into(),
+                        (value, None) => value.unwrap_or(0),
+                    };
+                    encoder.dyn_entry(&Elf64_Dyn { d_tag: tag as Elf_Sxword, d_val })?;
+                }
+            } else if let Some(ref group) = section.group {
+                encoder.word(group.flags);
+                for member in &group.members {
+                    encoder.word(indexes.resolve(member)?);
+                }
+            } else if sh_type == SHT_SYMTAB_SHNDX {
+                let link = indexes.resolve_optional(&section.link)?;
+                for &index in section_indexes.get(&link).map_or(&[][..], |indexes| &indexes[..]) {
+                    encoder.word(index);
+                }
+            } else if contents[i].is_some() || string_tables.contains_key(&(i as Elf_Word + 1)) {
+                continue;
+            }
+            contents[i] = Some(encoder.data);
+        }
+
+        // Symbol tables with symbols in sections with extended indexes need a SHT_SYMTAB_SHNDX
+        // section.
+        for (&index, shndx) in &section_indexes {
+            let linked = self.sections.iter().enumerate()
+                .any(|(i, section)| section_types[i] == SHT_SYMTAB_SHNDX &&
+                     section.content.is_none() && indexes.resolve_optional(&section.link).ok() == Some(index));
+            if !linked && shndx.iter().any(|&index| index != 0) {
+                return Err(Error::Msg { msg: "symbols in sections with extended indexes need a SYMTAB_SHNDX section" });
+            }
+        }
+        for (index, strings) in string_tables {
+            contents[index as usize - 1] = Some(strings.data);
+        }
+
+        for (i, section) in self.sections.iter().enumerate() {
+            let sh_type = section_types[i];
+            let sh_info = match section.info {
+                Some(ref info) if info_is_section(sh_type, 0) => indexes.resolve(info)?,
+                Some(Reference::Index(info)) if info <= u64::from(Elf_Word::MAX) => info as Elf_Word,
+                Some(ref info) => indexes.resolve(info)?,
+                None           => infos[i].unwrap_or(0),
+            };
+            let data = contents[i].take().unwrap_or_default();
+            if sh_type == SHT_NOBITS && !data.is_empty() {
+                return Err(Error::Msg { msg: "NOBITS section with contents" });
+            }
+            builder.add_section(OutputSection {
+                name: section.name.as_bytes().to_vec(),
+                sh_type,
+                sh_flags: parse_flags(&section.flags, SECTION_FLAG_LETTERS, "unknown section flag")?,
+                sh_addr: section.address,
+                sh_link: indexes.resolve_optional(&section.link)?,
+                sh_info,
+                sh_addralign: section.alignment,
+                sh_entsize: section.entsize.unwrap_or_else(|| default_entsize(format, sh_type)),
+                data,
+                size: section.size.unwrap_or(0),
+            });
+        }
+
+        for segment in &self.segments {
+            let p_type = tables.segment_type.value(&segment.p_type, "unknown segment type name")?;
+            let p_flags = parse_flags(&segment.flags, SEGMENT_FLAG_LETTERS, "unknown segment flag")?;
+            if p_type > u64::from(Elf_Word::MAX) || p_flags > u64::from(Elf_Word::MAX) {
+                return Err(Error::Msg { msg: "segment type or flags too large" });
+            }
+            let mut sections = Vec::with_capacity(segment.sections.len());
+            for reference in &segment.sections {
+                sections.push(indexes.resolve(reference)?);
+            }
+            builder.add_segment(OutputSegment {
+                p_type: p_type as Elf_Word,
+                p_flags: p_flags as Elf_Word,
+                p_vaddr: segment.vaddr,
+                p_paddr: segment.paddr,
+                p_filesz: segment.filesz,
+                p_memsz: segment.memsz,
+                p_align: segment.align,
+                sections,
+            });
+        }
+        builder.build()
+    }
+}
+
+impl HeaderDescription {
+    fn machine_value(&self) -> Result<Elf_Half> {
+        let machines = ConstantNames::new((0..0x200).map(|m| (u64::from(m), machine_name(m))));
+        let machine = machines.value(&self.machine, "unknown machine name")?;
+        if machine > u64::from(Elf_Half::MAX) {
+            return Err(Error::InvalidHeaderField { header: "ELF", field: "e_machine", value: machine });
+        }
+        Ok(machine as Elf_Half)
+    }
+}
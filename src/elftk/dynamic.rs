@@ -0,0 +1,240 @@
+use android::*;
+use constants::*;
+use error::*;
+use reader::*;
+use types::*;
+
+/// A relocation table located through the dynamic table rather than the section headers.
+pub struct DynamicRelocationTableRef<'a> {
+    /// The dynamic tag holding the address of the table, such as [DT_RELA](constant.DT_RELA.html)
+    /// or [DT_JMPREL](constant.DT_JMPREL.html).
+    pub tag: Elf_Sxword,
+    pub address: Elf64_Addr,
+    /// The file offset of the table.
+    pub offset: Elf64_Off,
+    pub size: Elf64_Xword,
+    /// A [RelocationTable](enum.SectionDataRef.html#variant.RelocationTable),
+    /// [ExplicitRelocationTable](enum.SectionDataRef.html#variant.ExplicitRelocationTable),
+    /// [RelativeRelocationTable](enum.SectionDataRef.html#variant.RelativeRelocationTable), or
+    /// [AndroidPackedRelocationTable](enum.SectionDataRef.html#variant.AndroidPackedRelocationTable),
+    /// as for the corresponding section.
+    pub data: SectionDataRef<'a>,
+}
+
+// The dynamic tags of the relocation tables, their sizes, and the format of their entries, in the
+// order readelf -D shows them. The format of DT_JMPREL tables is given by DT_PLTREL.
+const DYNAMIC_RELOCATION_TAGS: [(Elf_Sxword, Elf_Sxword, Elf_Sxword); 7] = [
+    (DT_REL,            DT_RELSZ,            DT_REL),
+    (DT_RELA,           DT_RELASZ,           DT_RELA),
+    (DT_RELR,           DT_RELRSZ,           DT_RELR),
+    (DT_ANDROID_REL,    DT_ANDROID_RELSZ,    DT_ANDROID_REL),
+    (DT_ANDROID_RELA,   DT_ANDROID_RELASZ,   DT_ANDROID_RELA),
+    (DT_ANDROID_RELR,   DT_ANDROID_RELRSZ,   DT_RELR),
+    (DT_JMPREL,         DT_PLTRELSZ,         DT_PLTREL),
+];
+
+impl<'a> Reader<'a> {
+    fn words_at(&self, address: Elf64_Addr, count: Elf64_Xword) -> Result<ElfWordsRef<'a>> {
+        let data = self.virtual_address_data(address, 4 * count)
+            .ok_or(Error::NotContainedInFile { what: "address", which: address })?;
+        ElfWordsRef::try_from(self.elf_header().construct_from(data))
+    }
+
+    // Returns the number of dynamic symbols from the DT_HASH or DT_GNU_HASH table, if any.
+    fn dynamic_symbol_count(&self, dynamic: &DynamicTableRef<'a>) -> Result<Option<Elf64_Xword>> {
+        if let Some(hash) = dynamic.get(DT_HASH) {
+            // nbucket, nchain; there is a chain entry for every symbol.
+            return Ok(Some(self.words_at(hash, 2)?.get(1)?.value().into()));
+        }
+        let hash = match dynamic.get(DT_GNU_HASH) {
+            Some(hash) => hash,
+            None       => return Ok(None),
+        };
+        // nbuckets, symoffset, bloom_size, bloom_shift, followed by the bloom filter of machine
+        // words, the buckets, and the hash chains for the symbols from symoffset on. The last
+        // entry of a chain has its lowest bit set.
+        let header = self.words_at(hash, 4)?;
+        let nbuckets = Elf64_Xword::from(header.get(0)?.value());
+        let symoffset = header.get(1)?.value();
+        let bloom_size = Elf64_Xword::from(header.get(2)?.value());
+        let word_size = if self.is_64bit() { 8 } else { 4 };
+        let buckets = bloom_size.checked_mul(word_size)
+            .and_then(|bloom| hash.checked_add(16)?.checked_add(bloom))
+            .ok_or(Error::NotContainedInFile { what: "address", which: hash })?;
+        let mut last = 0;
+        for bucket in self.words_at(buckets, nbuckets)? {
+            last = last.max(bucket.value());
+        }
+        if last < symoffset {
+            return Ok(Some(symoffset.into()));
+        }
+        let chains = buckets.checked_add(4 * nbuckets)
+            .ok_or(Error::NotContainedInFile { what: "address", which: buckets })?;
+        loop {
+            let chain = chains.checked_add(4 * Elf64_Xword::from(last - symoffset))
+                .ok_or(Error::NotContainedInFile { what: "address", which: chains })?;
+            if self.words_at(chain, 1)?.get(0)?.value() & 1 != 0 {
+                break;
+            }
+            last += 1;
+        }
+        Ok(Some(Elf64_Xword::from(last) + 1))
+    }
+
+    /// Returns the dynamic symbol table located through [DT_SYMTAB](constant.DT_SYMTAB.html) and
+    /// [DT_STRTAB](constant.DT_STRTAB.html), if any.
+    ///
+    /// The number of symbols is taken from the [DT_HASH](constant.DT_HASH.html) or
+    /// [DT_GNU_HASH](constant.DT_GNU_HASH.html) table. Without either, the symbol table extends
+    /// to the end of its segment.
+    pub fn dynamic_symbol_table(&self) -> Result<Option<SymbolTableRef<'a>>> {
+        let dynamic = match self.dynamic_table()? {
+            Some(dynamic) => dynamic,
+            None          => return Ok(None),
+        };
+        let (symtab, strtab, strsz) = match (dynamic.get(DT_SYMTAB), dynamic.get(DT_STRTAB), dynamic.get(DT_STRSZ)) {
+            (Some(symtab), Some(strtab), Some(strsz)) => (symtab, strtab, strsz),
+            _                                         => return Ok(None),
+        };
+        let symbol_names = self.virtual_address_data(strtab, strsz)
+            .ok_or(Error::NotContainedInFile { what: "address", which: strtab })?;
+        let entry_size = if self.is_64bit() { 24 } else { 16 };
+        let size = match self.dynamic_symbol_count(&dynamic)? {
+            Some(count) => count * entry_size,
+            None => {
+                let phdr = self.program_headers().into_iter()
+                    .find(|phdr| {
+                        phdr.p_type() == PT_LOAD && symtab >= phdr.p_vaddr() &&
+                            symtab - phdr.p_vaddr() <= phdr.p_filesz()
+                    })
+                    .ok_or(Error::NotContainedInFile { what: "address", which: symtab })?;
+                let end = phdr.p_vaddr().checked_add(phdr.p_filesz())
+                    .ok_or(Error::NotContainedInFile { what: "address", which: symtab })?;
+                (end - symtab) / entry_size * entry_size
+            },
+        };
+        let data = self.virtual_address_data(symtab, size)
+            .ok_or(Error::NotContainedInFile { what: "address", which: symtab })?;
+        let entries = SymbolTableEntriesRef::try_from(self.elf_header().construct_from(data))?;
+        Ok(Some(self.symbol_table_from(StringTableRef::new(symbol_names), entries, None)))
+    }
+
+    /// Returns the relocation tables located through the dynamic table
+    /// ([DT_REL](constant.DT_REL.html), [DT_RELA](constant.DT_RELA.html),
+    /// [DT_RELR](constant.DT_RELR.html), their Android equivalents, and
+    /// [DT_JMPREL](constant.DT_JMPREL.html)), whose symbols are resolved against the
+    /// [dynamic symbol table](#method.dynamic_symbol_table).
+    ///
+    /// Unlike [sections_matching](#method.sections_matching), this works for object files whose
+    /// section headers have been stripped.
+    ///
+    /// As the dynamic linker does, a DT_REL or DT_RELA table which overlaps the DT_JMPREL one is
+    /// trimmed so that the PLT relocations are only returned once, and a DT_JMPREL table whose
+    /// [DT_PLTREL](constant.DT_PLTREL.html) is missing or invalid is skipped.
+    pub fn dynamic_relocations(&self) -> Result<Vec<DynamicRelocationTableRef<'a>>> {
+        let dynamic = match self.dynamic_table()? {
+            Some(dynamic) => dynamic,
+            None          => return Ok(Vec::new()),
+        };
+        let symbol_table = self.dynamic_symbol_table()?;
+        let symbol_table = || symbol_table.ok_or(Error::Msg { msg: "dynamic relocations without DT_SYMTAB" });
+        let plt_relocations = match (dynamic.get(DT_JMPREL), dynamic.get(DT_PLTRELSZ)) {
+            (Some(address), Some(size)) => Some((address, size)),
+            _                           => None,
+        };
+        let mut tables = Vec::new();
+        for &(tag, size_tag, format_tag) in &DYNAMIC_RELOCATION_TAGS {
+            let (mut address, mut size) = match (dynamic.get(tag), dynamic.get(size_tag)) {
+                (Some(address), Some(size)) => (address, size),
+                _                           => continue,
+            };
+            if let Some((plt_address, plt_size)) = plt_relocations.filter(|_| tag == DT_REL || tag == DT_RELA) {
+                let (start, end) = trim_range(address, address.saturating_add(size),
+                                              plt_address, plt_address.saturating_add(plt_size));
+                address = start;
+                size = end - start;
+                if size == 0 {
+                    continue;
+                }
+            }
+            let format = if format_tag == DT_PLTREL {
+                match dynamic.get(DT_PLTREL).map(|format| format as Elf_Sxword) {
+                    Some(DT_REL)  => DT_REL,
+                    Some(DT_RELA) => DT_RELA,
+                    _             => continue,
+                }
+            } else {
+                format_tag
+            };
+            let offset = self.virtual_address_offset(address, size)
+                .ok_or(Error::NotContainedInFile { what: "address", which: address })?;
+            let data = self.virtual_address_data(address, size)
+                .ok_or(Error::NotContainedInFile { what: "address", which: address })?;
+            let raw = self.elf_header().construct_from(data);
+            let data = match format {
+                DT_REL => SectionDataRef::RelocationTable(RelTableRef {
+                    symbol_table: symbol_table()?,
+                    target: None,
+                    entries: RelTableEntriesRef::try_from(raw)?,
+                }),
+                DT_RELA => SectionDataRef::ExplicitRelocationTable(RelaTableRef {
+                    symbol_table: symbol_table()?,
+                    target: None,
+                    entries: RelaTableEntriesRef::try_from(raw)?,
+                }),
+                DT_RELR => SectionDataRef::RelativeRelocationTable(RelrTableRef {
+                    entries: MachineWordsRef::try_from(raw)?,
+                }),
+                DT_ANDROID_REL | DT_ANDROID_RELA => SectionDataRef::AndroidPackedRelocationTable(
+                    AndroidPackedRelocationsRef::new(symbol_table()?, None, format == DT_ANDROID_RELA, raw)),
+                _ => unreachable!("not a relocation table format: {}", format),
+            };
+            tables.push(DynamicRelocationTableRef { tag, address, offset, size, data });
+        }
+        Ok(tables)
+    }
+
+    // Returns the relocation tables which the dynamic linker applies: those located through the
+    // dynamic table or, for static executables, which have IRELATIVE relocations in .rela.iplt but
+    // no dynamic table, the allocated relocation sections.
+    pub(super) fn loaded_relocations(&self) -> Result<Vec<SectionDataRef<'a>>> {
+        let tables: Vec<_> = self.dynamic_relocations()?.into_iter().map(|table| table.data).collect();
+        if !tables.is_empty() {
+            return Ok(tables);
+        }
+        Ok(self.sections_matching(|shdr| {
+            shdr.sh_flags() & SHF_ALLOC != 0 && matches!(shdr.sh_type(), SHT_REL | SHT_RELA | SHT_RELR)
+        }).map(|section| section.data).collect())
+    }
+}
+
+// Removes the range [other_start, other_end) from [start, end) where it overlaps its beginning or
+// its end, and returns what remains. An overlap in the middle leaves the range unchanged.
+fn trim_range(start: Elf64_Addr, end: Elf64_Addr, other_start: Elf64_Addr, other_end: Elf64_Addr)
+              -> (Elf64_Addr, Elf64_Addr) {
+    if other_end <= start || other_start >= end {
+        (start, end)
+    } else if other_start <= start {
+        (other_end.min(end), end)
+    } else if other_end >= end {
+        (start, other_start)
+    } else {
+        (start, end)
+    }
+}
+
+// Returns the relocations of a table returned by loaded_relocations with their symbols resolved,
+// unpacking Android packed relocations. Packed relative relocations have no symbols and no type,
+// so callers which need them match RelativeRelocationTable themselves.
+pub(super) fn flatten_relocations<'a>(data: &SectionDataRef<'a>) -> Result<Vec<RelocationRef<'a>>> {
+    match *data {
+        SectionDataRef::AndroidPackedRelocationTable(ref packed) => {
+            let unpacked = packed.unpack()?;
+            (0..unpacked.len()).map(|index| unpacked.get(index)).collect()
+        },
+        ref data => match data.relocation_table() {
+            Some(table) => table.iter().collect(),
+            None        => Ok(Vec::new()),
+        },
+    }
+}
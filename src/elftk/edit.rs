@@ -0,0 +1,795 @@
+use std::collections::{HashMap, HashSet};
+
+use constants::*;
+use error::*;
+use format::*;
+use reader::*;
+use types::*;
+use writer::*;
+
+/// A section of an [ElfFile](struct.ElfFile.html).
+#[derive(Debug, Clone)]
+pub struct FileSection {
+    pub name: Vec<u8>,
+    /// The section header. `sh_name`, `sh_offset`, and, except for `SHT_NOBITS` sections,
+    /// `sh_size` are set when the file is written.
+    pub header: Elf64_Shdr,
+    /// The contents of the section, which are empty for `SHT_NULL` and `SHT_NOBITS` sections.
+    pub data: Vec<u8>,
+    // The offset and size of the section in the original file.
+    original: Option<(Elf64_Off, Elf64_Xword)>,
+}
+
+impl FileSection {
+    /// Returns a new section with contents `data` and no alignment.
+    pub fn new(name: &[u8], sh_type: Elf_Word, sh_flags: Elf64_Xword, data: Vec<u8>) -> Self {
+        FileSection {
+            name: name.to_vec(),
+            header: Elf64_Shdr {
+                sh_name: 0,
+                sh_type,
+                sh_flags,
+                sh_addr: 0,
+                sh_offset: 0,
+                sh_size: data.len() as Elf64_Xword,
+                sh_link: 0,
+                sh_info: 0,
+                sh_addralign: 1,
+                sh_entsize: 0,
+            },
+            data,
+            original: None,
+        }
+    }
+
+    /// Returns the size of the section (`sh_size`).
+    pub fn size(&self) -> Elf64_Xword {
+        if occupies_file(&self.header) {
+            self.data.len() as Elf64_Xword
+        } else {
+            self.header.sh_size
+        }
+    }
+
+    // Moves the section when the file is written if its place in the file does not have its
+    // alignment.
+    pub(super) fn realign(&mut self) {
+        let alignment = self.header.sh_addralign;
+        if alignment > 1 && self.original.is_some_and(|(offset, _)| offset % alignment != 0) {
+            self.original = None;
+        }
+    }
+}
+
+// Returns the section index in `sh_info`, if it holds one.
+fn info_link(shdr: &Elf64_Shdr) -> Option<Elf_Word> {
+    if shdr.sh_type == SHT_REL || shdr.sh_type == SHT_RELA || shdr.sh_flags & SHF_INFO_LINK != 0 {
+        Some(shdr.sh_info)
+    } else {
+        None
+    }
+}
+
+fn occupies_file(shdr: &Elf64_Shdr) -> bool {
+    shdr.sh_type != SHT_NOBITS && shdr.sh_type != SHT_NULL
+}
+
+// Appends data to the end of the file and returns its offset.
+fn append(out: &mut Vec<u8>, data: &[u8], alignment: Elf64_Xword) -> Elf64_Off {
+    let offset = align_up(out.len() as Elf64_Off, alignment);
+    out.resize(offset as usize, 0);
+    out.extend_from_slice(data);
+    offset
+}
+
+// Returns the null-terminated string at `offset` in a string table.
+pub(super) fn string_at(strings: &[u8], offset: usize) -> Option<&[u8]> {
+    let s = strings.get(offset..)?;
+    s.iter().position(|&b| b == 0).map(|len| &s[..len])
+}
+
+// Returns the offsets of the strings in a string table.
+fn string_offsets(strings: &[u8]) -> HashMap<Vec<u8>, Elf_Word> {
+    let mut offsets = HashMap::new();
+    let mut start = 0;
+    for (index, &b) in strings.iter().enumerate() {
+        if b == 0 {
+            offsets.entry(strings[start..index].to_vec()).or_insert(start as Elf_Word);
+            start = index + 1;
+        }
+    }
+    offsets
+}
+
+// Returns the offset of `string` in a string table with offsets `offsets`, appending it if
+// necessary.
+fn add_string(strings: &mut Vec<u8>, offsets: &mut HashMap<Vec<u8>, Elf_Word>, string: &[u8]) -> Elf_Word {
+    *offsets.entry(string.to_vec()).or_insert_with(|| {
+        let offset = strings.len() as Elf_Word;
+        strings.extend_from_slice(string);
+        strings.push(0);
+        offset
+    })
+}
+
+// Reads the unsigned integer of `size` bytes at `offset` in the byte order of `format`.
+pub(super) fn read_uint(format: ElfFormat, data: &[u8], offset: usize, size: usize) -> Option<u64> {
+    let bytes = data.get(offset..offset+size)?;
+    Some(if format.is_little_endian() {
+        bytes.iter().rev().fold(0, |x, &b| (x << 8) | u64::from(b))
+    } else {
+        bytes.iter().fold(0, |x, &b| (x << 8) | u64::from(b))
+    })
+}
+
+// Writes the unsigned integer `value` of `size` bytes at `offset` in the byte order of `format`.
+pub(super) fn write_uint(format: ElfFormat, data: &mut [u8], offset: usize, size: usize, value: u64) {
+    for i in 0..size {
+        let shift = if format.is_little_endian() { i } else { size - 1 - i };
+        data[offset + i] = (value >> (8 * shift)) as u8;
+    }
+}
+
+// Returns the shift and mask of the symbol index in the `r_info` field of a relocation. MIPS64
+// stores the symbol index first, which is the low 32 bits in little endian files.
+pub(super) fn symbol_index_field(format: ElfFormat, machine: Elf_Half) -> (u32, Elf64_Xword) {
+    match format {
+        ElfT::Elf64LE(()) if machine == EM_MIPS => (0, 0xffff_ffff),
+        _ if format.is_64bit()                   => (32, 0xffff_ffff),
+        _                                        => (8, 0xff_ffff),
+    }
+}
+
+/// An editable object file which is written back with its original layout.
+///
+/// The header, segments, and sections are decoded from a [Reader](struct.Reader.html) and can be
+/// changed freely. When the file is [written](#method.write), sections which still fit in their
+/// original place in the file are written there and the others are moved to the end of the file,
+/// as are the program and section header tables if they grow. All other bytes of the original
+/// file are kept, so writing an unmodified `ElfFile` reproduces the original file exactly.
+///
+/// Moving a section only changes its place in the file: the segments containing allocated
+/// sections which grow have to be updated separately.
+#[derive(Debug, Clone)]
+pub struct ElfFile {
+    format: ElfFormat,
+    /// The ELF header. `e_phoff`, `e_phnum`, `e_shoff`, `e_shnum`, and `e_shstrndx` are set when
+    /// the file is written.
+    pub header: Elf64_Ehdr,
+    pub segments: Vec<Elf64_Phdr>,
+    /// The sections, including the null section 0 if the file has section headers.
+    pub sections: Vec<FileSection>,
+    /// The index of the section string table, or 0 if there is none.
+    pub shstrndx: Elf_Word,
+    image: Vec<u8>,
+    phnum: usize,
+    shnum: usize,
+}
+
+impl ElfFile {
+    /// Returns an editable copy of the object file read by `reader`.
+    pub fn new(reader: &Reader) -> Result<Self> {
+        let data = reader.data();
+        let mut sections = Vec::new();
+        for shdr in reader.section_headers() {
+            let header = shdr.decode();
+            let (contents, original) = if occupies_file(&header) {
+                let offset = header.sh_offset as usize;
+                (data[offset..offset+header.sh_size as usize].to_vec(), Some((header.sh_offset, header.sh_size)))
+            } else {
+                (Vec::new(), None)
+            };
+            sections.push(FileSection {
+                name: reader.section_name(shdr).to_vec(),
+                header,
+                data: contents,
+                original,
+            });
+        }
+        let mut segments = Vec::new();
+        for phdr in reader.program_headers() {
+            segments.push(phdr.decode());
+        }
+        Ok(ElfFile {
+            format: reader.format(),
+            header: reader.elf_header().decode(),
+            phnum: segments.len(),
+            segments,
+            shnum: sections.len(),
+            sections,
+            shstrndx: reader.section_string_table_index().unwrap_or(0),
+            image: data.to_vec(),
+        })
+    }
+
+    pub fn format(&self) -> ElfFormat {
+        self.format
+    }
+
+    /// Returns the index of the first section named `name`, if any.
+    pub fn section_index(&self, name: &[u8]) -> Option<Elf_Word> {
+        self.sections.iter()
+            .position(|section| section.name == name)
+            .map(|index| index as Elf_Word)
+    }
+
+    /// Adds a section after the existing sections and returns its index.
+    pub fn add_section(&mut self, section: FileSection) -> Elf_Word {
+        if self.sections.is_empty() {
+            let mut null = FileSection::new(b"", SHT_NULL, 0, Vec::new());
+            null.header.sh_addralign = 0;
+            self.sections.push(null);
+        }
+        self.sections.push(section);
+        (self.sections.len() - 1) as Elf_Word
+    }
+
+    /// Reserves `size` zero bytes at the end of the file, aligned to `alignment`, and returns their
+    /// offset.
+    ///
+    /// The space can hold sections or the program header table placed there with
+    /// [place_section](#method.place_section) and
+    /// [place_program_headers](#method.place_program_headers).
+    pub fn reserve(&mut self, size: Elf64_Xword, alignment: Elf64_Xword) -> Elf64_Off {
+        let offset = align_up(self.image.len() as Elf64_Off, alignment);
+        self.image.resize((offset + size) as usize, 0);
+        offset
+    }
+
+    /// Places section `index` at `offset` in the file, where it is written as long as it fits in
+    /// `size` bytes.
+    pub fn place_section(&mut self, index: Elf_Word, offset: Elf64_Off, size: Elf64_Xword) -> Result<()> {
+        let length = self.sections.len();
+        let section = self.sections.get_mut(index as usize)
+            .ok_or(Error::IndexOutOfBounds { index: index as usize, length })?;
+        section.original = Some((offset, size));
+        Ok(())
+    }
+
+    /// Places the program header table at `offset` in the file, where it is written as long as it
+    /// has at most `count` entries.
+    pub fn place_program_headers(&mut self, offset: Elf64_Off, count: usize) {
+        self.header.e_phoff = offset;
+        self.phnum = count;
+    }
+
+    fn section(&self, index: Elf_Word) -> Result<&FileSection> {
+        self.sections.get(index as usize)
+            .ok_or(Error::IndexOutOfBounds { index: index as usize, length: self.sections.len() })
+    }
+
+    // Returns the index of the SHT_SYMTAB_SHNDX section of symbol table `symtab`, if any.
+    fn symbol_section_indexes(&self, symtab: Elf_Word) -> Option<usize> {
+        self.sections.iter().position(|section| {
+            section.header.sh_type == SHT_SYMTAB_SHNDX && section.header.sh_link == symtab
+        })
+    }
+
+    /// Returns the symbols of symbol table `index`, starting with the null symbol, so that the
+    /// position of each symbol is its index.
+    pub fn symbols(&self, index: Elf_Word) -> Result<Vec<OutputSymbol>> {
+        let section = self.section(index)?;
+        if section.header.sh_type != SHT_SYMTAB && section.header.sh_type != SHT_DYNSYM {
+            return Err(Error::InvalidSectionType { expected: SHT_SYMTAB, actual: section.header.sh_type });
+        }
+        let names = &self.section(section.header.sh_link)?.data;
+        let shndx = match self.symbol_section_indexes(index) {
+            Some(shndx) => Some(ElfWordsRef::try_from(self.format.construct_from(&self.sections[shndx].data[..]))?),
+            None        => None,
+        };
+        let entries = SymbolTableEntriesRef::try_from(self.format.construct_from(&section.data[..]))?;
+        let mut symbols = Vec::with_capacity(entries.len());
+        for (position, entry) in entries.iter().enumerate() {
+            let section = match entry.st_shndx() {
+                SHN_XINDEX => match shndx {
+                    Some(shndx) => SectionIndex::Normal(shndx.get(position)?.value()),
+                    None        => return Err(Error::Msg { msg: "No associated SYMTAB_SHNDX section for symbol" }),
+                },
+                index if index >= SHN_LORESERVE => SectionIndex::Reserved(index),
+                index                           => SectionIndex::Normal(index.into()),
+            };
+            let name = string_at(names, entry.st_name() as usize).unwrap_or(&[]);
+            symbols.push(OutputSymbol {
+                name: name.to_vec(),
+                section,
+                value: entry.st_value(),
+                size: entry.st_size(),
+                info: entry.st_info(),
+                other: entry.st_other(),
+            });
+        }
+        Ok(symbols)
+    }
+
+    /// Replaces the contents of symbol table `index` and of its string table with `table`, adding
+    /// an `SHT_SYMTAB_SHNDX` section if the table needs one.
+    ///
+    /// If the string table is also used by other sections, the symbol names are added to it
+    /// rather than replacing it. The relocations and section groups
+    /// using the symbols are not changed; see [renumber_symbols](#method.renumber_symbols).
+    pub fn set_symbols(&mut self, index: Elf_Word, table: SymbolTable) -> Result<()> {
+        let strtab = self.section(index)?.header.sh_link;
+        if strtab == 0 || self.section(strtab)?.header.sh_type != SHT_STRTAB {
+            return Err(Error::InvalidLinkedSection { linked: strtab });
+        }
+        let shared = strtab == self.shstrndx ||
+            self.sections.iter().enumerate().any(|(other, section)| {
+                other != index as usize && section.header.sh_link == strtab &&
+                    section.header.sh_type != SHT_SYMTAB_SHNDX
+            });
+        let entry_size = if self.format.is_64bit() { 24 } else { 16 };
+        let count = table.symbols.len() / entry_size;
+        let SymbolTable { mut symbols, strings: symbol_names, section_indexes, first_non_local, .. } = table;
+        if shared {
+            // Keep the other strings of a shared string table and add the symbol names to it.
+            let strings = &mut self.sections[strtab as usize].data;
+            let mut offsets = string_offsets(strings);
+            for offset in (0..count).map(|entry| entry * entry_size) {
+                let name = read_uint(self.format, &symbols, offset, 4)
+                    .and_then(|name| string_at(&symbol_names, name as usize))
+                    .unwrap_or(&[]);
+                let name = add_string(strings, &mut offsets, name);
+                write_uint(self.format, &mut symbols, offset, 4, name.into());
+            }
+        } else {
+            self.sections[strtab as usize].data = symbol_names;
+        }
+        let section = &mut self.sections[index as usize];
+        section.data = symbols;
+        section.header.sh_info = first_non_local;
+        match (self.symbol_section_indexes(index), section_indexes) {
+            (Some(shndx), Some(data)) => self.sections[shndx].data = data,
+            (Some(shndx), None)       => self.sections[shndx].data = vec![0; count * 4],
+            (None, Some(data))        => {
+                let mut section = FileSection::new(b".symtab_shndx", SHT_SYMTAB_SHNDX, 0, data);
+                section.header.sh_link = index;
+                section.header.sh_addralign = 4;
+                section.header.sh_entsize = 4;
+                self.add_section(section);
+            },
+            (None, None) => {},
+        }
+        Ok(())
+    }
+
+    // Returns the sections and offsets of the r_info fields of the relocations using symbol table
+    // `symtab`, skipping the sections in `ignored`.
+    fn relocation_infos(&self, symtab: Elf_Word, ignored: &[bool]) -> Vec<(usize, usize)> {
+        let word_size = if self.format.is_64bit() { 8 } else { 4 };
+        let mut infos = Vec::new();
+        for (index, section) in self.sections.iter().enumerate() {
+            let header = &section.header;
+            if ignored.get(index) == Some(&true) || header.sh_link != symtab ||
+               (header.sh_type != SHT_REL && header.sh_type != SHT_RELA)
+            {
+                continue;
+            }
+            let entry_size = match header.sh_entsize {
+                0 if header.sh_type == SHT_REL => 2 * word_size,
+                0                              => 3 * word_size,
+                size                           => size as usize,
+            };
+            for offset in (0..section.data.len() / entry_size).map(|entry| entry * entry_size + word_size) {
+                infos.push((index, offset));
+            }
+        }
+        infos
+    }
+
+    /// Returns the indexes of the symbols of symbol table `index` which are used by relocations
+    /// or as section group signatures.
+    pub fn referenced_symbols(&self, index: Elf_Word) -> HashSet<Elf_Word> {
+        let word_size = if self.format.is_64bit() { 8 } else { 4 };
+        let (shift, mask) = symbol_index_field(self.format, self.header.e_machine);
+        let mut referenced = HashSet::new();
+        for (section, offset) in self.relocation_infos(index, &[]) {
+            if let Some(info) = read_uint(self.format, &self.sections[section].data, offset, word_size) {
+                referenced.insert(((info >> shift) & mask) as Elf_Word);
+            }
+        }
+        for section in &self.sections {
+            if section.header.sh_type == SHT_GROUP && section.header.sh_link == index {
+                referenced.insert(section.header.sh_info);
+            }
+        }
+        referenced
+    }
+
+    /// Changes the symbol indexes used by the relocations and section groups which use symbol
+    /// table `index`: symbol `i` becomes symbol `indexes[i]`. It is an error for a used symbol to
+    /// have no new index.
+    pub fn renumber_symbols(&mut self, index: Elf_Word, indexes: &[Option<Elf_Word>]) -> Result<()> {
+        self.renumber_symbols_except(index, indexes, &[])
+    }
+
+    fn renumber_symbols_except(&mut self, symtab: Elf_Word, indexes: &[Option<Elf_Word>], ignored: &[bool]) -> Result<()> {
+        let format = self.format;
+        let word_size = if format.is_64bit() { 8 } else { 4 };
+        let (shift, mask) = symbol_index_field(format, self.header.e_machine);
+        let renumber = |symbol: Elf_Word| {
+            indexes.get(symbol as usize).cloned().and_then(|index| index)
+                .ok_or(Error::UnresolvedSymbol { symbol })
+        };
+        for (section, offset) in self.relocation_infos(symtab, ignored) {
+            let data = &mut self.sections[section].data;
+            if let Some(info) = read_uint(format, data, offset, word_size) {
+                let symbol = renumber(((info >> shift) & mask) as Elf_Word)?;
+                let info = (info & !(mask << shift)) | (Elf64_Xword::from(symbol) << shift);
+                write_uint(format, data, offset, word_size, info);
+            }
+        }
+        for (index, section) in self.sections.iter_mut().enumerate() {
+            if ignored.get(index) != Some(&true) && section.header.sh_type == SHT_GROUP &&
+               section.header.sh_link == symtab
+            {
+                section.header.sh_info = renumber(section.header.sh_info)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Keeps only the symbols of symbol table `index` for which `keep` returns `true`, given their
+    /// index, and renumbers the relocations and section groups using them. It is an error to
+    /// remove a used symbol.
+    pub fn retain_symbols<F>(&mut self, index: Elf_Word, keep: F) -> Result<()> where
+        F: FnMut(Elf_Word, &OutputSymbol) -> bool
+    {
+        self.retain_symbols_except(index, keep, &[])
+    }
+
+    /// Calls `edit` with the index of each symbol of symbol table `index` but the null symbol to
+    /// change it, such as to rename it or change its binding. Local symbols are moved before the
+    /// other symbols as needed, and the relocations and section groups using moved symbols are
+    /// renumbered.
+    pub fn edit_symbols<F>(&mut self, index: Elf_Word, mut edit: F) -> Result<()> where
+        F: FnMut(Elf_Word, &mut OutputSymbol)
+    {
+        self.rebuild_symbols_except(index, |index, symbol| { edit(index, symbol); true }, &[])
+    }
+
+    fn retain_symbols_except<F>(&mut self, symtab: Elf_Word, mut keep: F, ignored: &[bool]) -> Result<()> where
+        F: FnMut(Elf_Word, &OutputSymbol) -> bool
+    {
+        self.rebuild_symbols_except(symtab, |index, symbol| keep(index, symbol), ignored)
+    }
+
+    // Rebuilds symbol table `symtab` from the symbols for which `edit` returns `true` after
+    // changing them.
+    fn rebuild_symbols_except<F>(&mut self, symtab: Elf_Word, mut edit: F, ignored: &[bool]) -> Result<()> where
+        F: FnMut(Elf_Word, &mut OutputSymbol) -> bool
+    {
+        let symbols = self.symbols(symtab)?;
+        let mut builder = SymbolTableBuilder::new();
+        let mut positions = vec![None; symbols.len()];
+        for (index, mut symbol) in symbols.into_iter().enumerate().skip(1) {
+            if edit(index as Elf_Word, &mut symbol) {
+                positions[index] = Some(builder.add(symbol));
+            }
+        }
+        let table = builder.build(self.format)?;
+        let mut indexes: Vec<_> = positions.iter().map(|position| position.and_then(|position| table.index(position))).collect();
+        if let Some(null) = indexes.first_mut() {
+            *null = Some(0);
+        }
+        self.set_symbols(symtab, table)?;
+        self.renumber_symbols_except(symtab, &indexes, ignored)
+    }
+
+    /// Removes the sections for which `remove` returns `true`, given their index, and returns the
+    /// new index of each original section.
+    ///
+    /// Relocation sections for removed sections or using removed symbol tables are removed too,
+    /// as are the `SHT_SYMTAB_SHNDX`, hash table and symbol version sections of removed symbol
+    /// tables and the [SHF_LINK_ORDER](constant.SHF_LINK_ORDER.html) sections of removed
+    /// sections. The symbols defined in removed sections are removed from the symbol table
+    /// (`SHT_SYMTAB`). Section links, section group members, and the section indexes of symbols
+    /// are renumbered. Other sections linked to a removed section make the
+    /// [linked section invalid](enum.Error.html#variant.InvalidLinkedSection), and nothing is
+    /// removed.
+    ///
+    /// The bytes of the removed sections stay in the file until it is
+    /// [compacted](#method.compact).
+    pub fn remove_sections<F>(&mut self, mut remove: F) -> Result<Vec<Option<Elf_Word>>> where
+        F: FnMut(Elf_Word, &FileSection) -> bool
+    {
+        let mut removed: Vec<bool> = self.sections.iter().enumerate()
+            .map(|(index, section)| index != 0 && remove(index as Elf_Word, section))
+            .collect();
+        loop {
+            let mut changed = false;
+            for index in 0..self.sections.len() {
+                let header = &self.sections[index].header;
+                let linked = |link: Elf_Word| removed.get(link as usize) == Some(&true);
+                let data = &self.sections[index].data;
+                let dependent = match header.sh_type {
+                    _ if header.sh_flags & SHF_LINK_ORDER != 0 => linked(header.sh_link),
+                    SHT_REL | SHT_RELA => linked(header.sh_info) || linked(header.sh_link),
+                    SHT_SYMTAB_SHNDX | SHT_HASH | SHT_GNU_HASH | SHT_GNU_VERSYM => linked(header.sh_link),
+                    // Groups whose members are all removed
+                    SHT_GROUP => data.len() > 4 && (4..data.len() - 3).step_by(4).all(|offset| {
+                        linked(read_uint(self.format, data, offset, 4).unwrap_or(0) as Elf_Word)
+                    }),
+                    _ => false,
+                };
+                if dependent && !removed[index] {
+                    removed[index] = true;
+                    changed = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+        if removed.get(self.shstrndx as usize) == Some(&true) {
+            return Err(Error::Msg { msg: "cannot remove the section string table" });
+        }
+        // The sections which stay must not be linked to removed ones.
+        for (index, section) in self.sections.iter().enumerate() {
+            let header = &section.header;
+            for link in Some(header.sh_link).into_iter().chain(info_link(header)) {
+                if !removed[index] && removed.get(link as usize) == Some(&true) {
+                    return Err(Error::InvalidLinkedSection { linked: link });
+                }
+            }
+        }
+
+        // Remove the symbols defined in removed sections.
+        let in_removed = |removed: &[bool], symbol: &OutputSymbol| match symbol.section {
+            SectionIndex::Normal(index) => removed.get(index as usize) == Some(&true),
+            SectionIndex::Reserved(_)   => false,
+        };
+        for symtab in 0..self.sections.len() {
+            if removed[symtab] || self.sections[symtab].header.sh_type != SHT_SYMTAB {
+                continue;
+            }
+            if self.symbols(symtab as Elf_Word)?.iter().any(|symbol| in_removed(&removed, symbol)) {
+                let ignored = removed.clone();
+                self.retain_symbols_except(symtab as Elf_Word, |_, symbol| !in_removed(&ignored, symbol), &ignored)?;
+                removed.resize(self.sections.len(), false);
+            }
+        }
+
+        let mut indexes = Vec::with_capacity(removed.len());
+        let mut next = 0;
+        for &removed in &removed {
+            indexes.push(if removed { None } else { next += 1; Some(next - 1) });
+        }
+        let renumber = |index: Elf_Word| match indexes.get(index as usize) {
+            Some(&Some(index)) => Ok(index),
+            Some(&None)        => Err(Error::InvalidLinkedSection { linked: index }),
+            None               => Ok(index),
+        };
+
+        // Renumber the section indexes of symbols and the members of section groups.
+        let format = self.format;
+        let (entry_size, shndx_offset) = if format.is_64bit() { (24, 6) } else { (16, 14) };
+        for index in 0..self.sections.len() {
+            if removed[index] {
+                continue;
+            }
+            match self.sections[index].header.sh_type {
+                SHT_SYMTAB | SHT_DYNSYM => {
+                    let shndx = self.symbol_section_indexes(index as Elf_Word);
+                    let mut extended = shndx.map(|shndx| self.sections[shndx].data.clone());
+                    let data = &mut self.sections[index].data;
+                    for entry in 0..data.len() / entry_size {
+                        let offset = entry * entry_size + shndx_offset;
+                        match read_uint(format, data, offset, 2).map(|shndx| shndx as Elf_Half) {
+                            Some(SHN_XINDEX) => if let Some(ref mut extended) = extended {
+                                if let Some(section) = read_uint(format, extended, entry * 4, 4) {
+                                    let section = renumber(section as Elf_Word)?;
+                                    write_uint(format, extended, entry * 4, 4, section.into());
+                                }
+                            },
+                            Some(section) if section != SHN_UNDEF && section < SHN_LORESERVE => {
+                                let section = renumber(section.into())?;
+                                write_uint(format, data, offset, 2, section.into());
+                            },
+                            _ => {},
+                        }
+                    }
+                    if let (Some(shndx), Some(extended)) = (shndx, extended) {
+                        self.sections[shndx].data = extended;
+                    }
+                },
+                SHT_GROUP => {
+                    let data = &self.sections[index].data;
+                    let mut members = data.get(..4).unwrap_or(&[]).to_vec();
+                    for offset in (4..data.len().saturating_sub(3)).step_by(4) {
+                        let member = read_uint(format, data, offset, 4).unwrap_or(0) as Elf_Word;
+                        if removed.get(member as usize) != Some(&true) {
+                            let mut word = [0; 4];
+                            write_uint(format, &mut word, 0, 4, renumber(member)?.into());
+                            members.extend_from_slice(&word);
+                        }
+                    }
+                    self.sections[index].data = members;
+                },
+                _ => {},
+            }
+        }
+
+        for (index, section) in self.sections.iter_mut().enumerate() {
+            if removed[index] {
+                continue;
+            }
+            let header = &mut section.header;
+            header.sh_link = renumber(header.sh_link)?;
+            if let Some(info) = info_link(header) {
+                header.sh_info = renumber(info)?;
+            }
+        }
+        let mut index = 0;
+        self.sections.retain(|_| { index += 1; !removed[index - 1] });
+        self.shstrndx = renumber(self.shstrndx)?;
+        Ok(indexes)
+    }
+
+    /// Discards the bytes of the original file which are not part of a segment, a section, or
+    /// the ELF or program header table, such as the contents of removed sections.
+    ///
+    /// The sections and the section header table past the end of the segments are moved to the
+    /// end of the file when it is written, in index order, so the file shrinks.
+    pub fn compact(&mut self) {
+        let (ehsize, phentsize, shentsize) = header_sizes(self.format);
+        let mut ranges = vec![(0, Elf64_Off::from(ehsize))];
+        if self.phnum > 0 {
+            ranges.push((self.header.e_phoff, self.header.e_phoff + (self.phnum * usize::from(phentsize)) as Elf64_Off));
+        }
+        for segment in &self.segments {
+            ranges.push((segment.p_offset, segment.p_offset + segment.p_filesz));
+        }
+        let end = ranges.iter().map(|&(_, end)| end).max().unwrap_or(0);
+
+        for section in &mut self.sections {
+            match section.original {
+                Some((offset, size)) if offset + size <= end => ranges.push((offset, offset + size)),
+                _ => section.original = None,
+            }
+        }
+        if self.header.e_shoff + (self.shnum * usize::from(shentsize)) as Elf64_Off > end {
+            self.shnum = 0;
+        }
+
+        self.image.truncate(end as usize);
+        ranges.sort();
+        let mut start = 0;
+        for (offset, range_end) in ranges {
+            if offset > start {
+                for b in &mut self.image[start as usize..offset as usize] {
+                    *b = 0;
+                }
+            }
+            start = start.max(range_end);
+        }
+    }
+
+    // Returns the section name offsets and the section string table with any new names appended.
+    fn section_names(&self) -> Result<(Vec<Elf_Word>, Option<Vec<u8>>)> {
+        let mut shstrtab = self.sections.get(self.shstrndx as usize)
+            .filter(|_| self.shstrndx != 0)
+            .map(|section| section.data.clone());
+        let mut offsets = shstrtab.as_ref().map(|strings| string_offsets(strings)).unwrap_or_default();
+        let mut names = Vec::with_capacity(self.sections.len());
+        for section in &self.sections {
+            let strings = match shstrtab {
+                Some(ref mut strings) => strings,
+                None if section.name.is_empty() => {
+                    names.push(section.header.sh_name);
+                    continue;
+                },
+                None => return Err(Error::Msg { msg: "named section without a section string table" }),
+            };
+            let current = string_at(strings, section.header.sh_name as usize);
+            if current == Some(&section.name[..]) {
+                names.push(section.header.sh_name);
+                continue;
+            }
+            let offset = add_string(strings, &mut offsets, &section.name);
+            names.push(offset);
+        }
+        Ok((names, shstrtab))
+    }
+
+    /// Returns the contents of the object file.
+    pub fn write(&self) -> Result<Vec<u8>> {
+        let (sh_names, shstrtab) = self.section_names()?;
+        let (ehsize, phentsize, shentsize) = header_sizes(self.format);
+        let table_alignment = if self.format.is_64bit() { 8 } else { 4 };
+        let mut out = self.image.clone();
+
+        let mut headers = Vec::with_capacity(self.sections.len());
+        for (index, section) in self.sections.iter().enumerate() {
+            let mut header = section.header;
+            header.sh_name = sh_names[index];
+            if occupies_file(&header) {
+                let data = match shstrtab {
+                    Some(ref strings) if index == self.shstrndx as usize => strings,
+                    _                                                    => &section.data,
+                };
+                let size = data.len() as Elf64_Xword;
+                header.sh_size = size;
+                header.sh_offset = match section.original {
+                    Some((offset, original_size)) if size <= original_size => {
+                        let start = offset as usize;
+                        out[start..start+data.len()].copy_from_slice(data);
+                        for b in &mut out[start+data.len()..start+original_size as usize] {
+                            *b = 0;
+                        }
+                        offset
+                    },
+                    _ => append(&mut out, data, header.sh_addralign),
+                };
+            }
+            headers.push(header);
+        }
+
+        let mut ehdr = self.header;
+        ehdr.e_ehsize = ehsize;
+
+        // Program headers
+        if self.segments.len() >= usize::from(Elf_Half::MAX) {
+            return Err(Error::Msg { msg: "too many segments" });
+        }
+        let mut encoder = Encoder::new(self.format);
+        for phdr in &self.segments {
+            encoder.phdr(phdr)?;
+        }
+        if self.segments.len() <= self.phnum && self.phnum > 0 {
+            let start = ehdr.e_phoff as usize;
+            let end = start + self.phnum * usize::from(phentsize);
+            encoder.data.resize(end - start, 0);
+            out[start..end].copy_from_slice(&encoder.data);
+        } else if !self.segments.is_empty() {
+            ehdr.e_phoff = append(&mut out, &encoder.data, table_alignment);
+        }
+        if !self.segments.is_empty() {
+            ehdr.e_phentsize = phentsize;
+        }
+        ehdr.e_phnum = self.segments.len() as Elf_Half;
+
+        // Section headers, with the number of sections and the index of the section string table
+        // in section 0 if they do not fit in the ELF header.
+        let shnum = headers.len();
+        if let Some(null) = headers.first_mut() {
+            if shnum >= usize::from(SHN_LORESERVE) {
+                null.sh_size = shnum as Elf64_Xword;
+            } else if ehdr.e_shnum == 0 {
+                null.sh_size = 0;
+            }
+            if self.shstrndx >= Elf_Word::from(SHN_LORESERVE) {
+                null.sh_link = self.shstrndx;
+            } else if ehdr.e_shstrndx == SHN_XINDEX {
+                null.sh_link = 0;
+            }
+        } else if self.shstrndx != 0 {
+            return Err(Error::IndexOutOfBounds { index: self.shstrndx as usize, length: 0 });
+        }
+        ehdr.e_shnum = if shnum >= usize::from(SHN_LORESERVE) { 0 } else { shnum as Elf_Half };
+        ehdr.e_shstrndx = if self.shstrndx >= Elf_Word::from(SHN_LORESERVE) {
+            SHN_XINDEX
+        } else {
+            self.shstrndx as Elf_Half
+        };
+        let mut encoder = Encoder::new(self.format);
+        for header in &headers {
+            encoder.shdr(header)?;
+        }
+        if shnum <= self.shnum && self.shnum > 0 {
+            let start = ehdr.e_shoff as usize;
+            let end = start + self.shnum * usize::from(shentsize);
+            encoder.data.resize(end - start, 0);
+            out[start..end].copy_from_slice(&encoder.data);
+        } else if shnum > 0 {
+            ehdr.e_shoff = append(&mut out, &encoder.data, table_alignment);
+        }
+        if shnum > 0 {
+            ehdr.e_shentsize = shentsize;
+        }
+
+        let mut encoder = Encoder::new(self.format);
+        encoder.ehdr(&ehdr)?;
+        out[..encoder.data.len()].copy_from_slice(&encoder.data);
+        Ok(out)
+    }
+}
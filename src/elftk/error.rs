@@ -1,6 +1,8 @@
+#![allow(non_local_definitions)]
+
 use std::result;
 
-use types::Elf_Word;
+use types::{Elf_Half, Elf_Word};
 
 #[derive(Debug, Clone, Copy, Fail)]
 pub enum Error {
@@ -34,6 +36,15 @@ pub enum Error {
     #[fail(display = "invalid section type: expected={}, actual={}", expected, actual)]
     InvalidSectionType { expected: Elf_Word, actual: Elf_Word },
 
+    #[fail(display = "unsupported relocation: machine={}, type={}", machine, relocation_type)]
+    UnsupportedRelocation { machine: Elf_Half, relocation_type: Elf_Word },
+
+    #[fail(display = "relocation {} at offset {:#x} out of range", relocation, offset)]
+    RelocationOutOfRange { relocation: &'static str, offset: u64 },
+
+    #[fail(display = "unresolved symbol={}", symbol)]
+    UnresolvedSymbol { symbol: Elf_Word },
+
     #[fail(display = "{}", msg)]
     Msg { msg: &'static str },
 }
@@ -45,6 +45,18 @@ impl<T32, T64> ElfT<T32, T64> {
         self.apply(|_| false, |_| true)
     }
 
+    /// Returns `true` for little-endian types.
+    #[inline]
+    pub fn is_little_endian(&self) -> bool {
+        matches!(*self, ElfT::Elf32LE(..) | ElfT::Elf64LE(..))
+    }
+
+    /// Returns the format (32- or 64-bit, little- or big-endian) of `self`.
+    #[inline]
+    pub fn format(&self) -> ElfFormat {
+        self.construct_from(())
+    }
+
     /// Construct a new `ElfT` with the same format (32- or 64-bit, little- or bit-endian) with
     /// `data`.
     #[inline]
@@ -67,9 +79,9 @@ impl<T32, T64> ElfT<T32, T64> {
     {
         match *self {
             ElfT::Elf32LE(ref x) |
-            ElfT::Elf32BE(ref x) => f1(&x),
+            ElfT::Elf32BE(ref x) => f1(x),
             ElfT::Elf64LE(ref x) |
-            ElfT::Elf64BE(ref x) => f2(&x),
+            ElfT::Elf64BE(ref x) => f2(x),
         }
     }
 
@@ -91,6 +103,10 @@ impl<T32, T64> ElfT<T32, T64> {
     }
 }
 
+/// The format of an object file, 32- or 64-bit and little- or big-endian, such as
+/// `ElfT::Elf64LE(())`.
+pub type ElfFormat = ElfT<(), ()>;
+
 pub type ElfRef<'a, T32, T64> = ElfT<&'a T32, &'a T64>;
 
 impl<'a, T32, T64> ElfRef<'a, T32, T64> where
@@ -141,11 +157,11 @@ impl<'a, T32, T64> ElfSliceRef<'a, T32, T64> {
         if index >= self.len() {
             return Err(Error::IndexOutOfBounds { index, length: self.len() });
         }
-        Ok(self.clone().map(move |slice| &slice[index], move |slice| &slice[index]))
+        Ok(self.map(move |slice| &slice[index], move |slice| &slice[index]))
     }
 
     pub fn iter(&self) -> ElfIter<'a, T32, T64> {
-        self.clone().map(move |slice| slice.into_iter(), move |slice| slice.into_iter())
+        self.map(move |slice| slice.iter(), move |slice| slice.iter())
     }
 }
 
@@ -168,8 +184,8 @@ impl<'a, T32, T64> ElfSliceRef<'a, T32, T64> where
             return Err(Error::AlignmentError { alignment: e_align, address: addr });
         }
         let num = length / e_size;
-        Ok(raw.map(|s| unsafe { slice::from_raw_parts(s.as_ptr() as *const T32, num as usize) },
-                   |s| unsafe { slice::from_raw_parts(s.as_ptr() as *const T64, num as usize) }))
+        Ok(raw.map(|s| unsafe { slice::from_raw_parts(s.as_ptr() as *const T32, num) },
+                   |s| unsafe { slice::from_raw_parts(s.as_ptr() as *const T64, num) }))
     }
 }
 
@@ -178,7 +194,7 @@ impl<'a, T32, T64> iter::IntoIterator for ElfSliceRef<'a, T32, T64> {
     type IntoIter = ElfIter<'a, T32, T64>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.map(move |slice| slice.into_iter(), move |slice| slice.into_iter())
+        self.map(move |slice| slice.iter(), move |slice| slice.iter())
     }
 }
 
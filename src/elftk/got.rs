@@ -0,0 +1,82 @@
+use std::collections::{HashMap, HashSet};
+
+use constants::*;
+use dynamic::*;
+use error::*;
+use reader::*;
+use types::*;
+
+/// A slot of the global offset table (`.got` or `.got.plt`).
+#[derive(Debug, Clone, Copy)]
+pub struct GotSlot<'a> {
+    /// The virtual address of the slot.
+    pub address: Elf64_Addr,
+    /// The name of the section holding the slot.
+    pub section_name: &'a [u8],
+    /// The value of the slot in the file, before relocation. For lazily bound `JUMP_SLOT`s, this
+    /// is the address of the PLT code which calls the dynamic linker.
+    pub value: Elf64_Addr,
+    /// The dynamic relocation which fills in the slot, such as a `GLOB_DAT`, `JUMP_SLOT`,
+    /// `RELATIVE`, `TPOFF`, or `IRELATIVE` relocation, if any. If several relocations apply to the
+    /// slot, this is the one the dynamic linker applies last, which determines its value: a
+    /// [DT_JMPREL](constant.DT_JMPREL.html) relocation takes precedence over a `DT_REL` or
+    /// `DT_RELA` one, and a relocation over those before it in the same table.
+    pub relocation: Option<RelocationRef<'a>>,
+    /// `true` if a packed relative relocation (`SHT_RELR`) adjusts the slot by the load base.
+    pub relative: bool,
+    /// `true` if the slot is in the [PT_GNU_RELRO](constant.PT_GNU_RELRO.html) segment and so is
+    /// made read-only after relocation.
+    pub relro: bool,
+}
+
+impl<'a> Reader<'a> {
+    /// Returns the slots of the `.got` and `.got.plt` sections, sorted by address, with the
+    /// dynamic relocations which fill them in.
+    pub fn got_slots(&self) -> Result<Vec<GotSlot<'a>>> {
+        let mut relocations = HashMap::new();
+        let mut relative = HashSet::new();
+        for data in self.loaded_relocations()? {
+            if let SectionDataRef::RelativeRelocationTable(table) = data {
+                relative.extend(table.addresses());
+                continue;
+            }
+            // The tables come in the order the dynamic linker applies them, with DT_JMPREL last, so
+            // later relocations replace earlier ones for the same slot.
+            for relocation in flatten_relocations(&data)? {
+                relocations.insert(relocation.offset, relocation);
+            }
+        }
+        let relro = self.program_headers().into_iter()
+            .filter(|phdr| phdr.p_type() == PT_GNU_RELRO)
+            .map(|phdr| match phdr.p_vaddr().checked_add(phdr.p_memsz()) {
+                Some(end) => Ok((phdr.p_vaddr(), end)),
+                None      => Err(Error::NotContainedInFile { what: "address", which: phdr.p_vaddr() }),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let word_size = if self.is_64bit() { 8 } else { 4 };
+
+        let mut slots = Vec::new();
+        for section in self.sections_matching(|shdr| {
+            let name = self.section_name(*shdr);
+            name == b".got" || name == b".got.plt"
+        }) {
+            let words = match section.data {
+                SectionDataRef::Uninterpreted(data) => MachineWordsRef::try_from(section.shdr.construct_from(data))?,
+                _                                   => continue,
+            };
+            for (index, word) in words.into_iter().enumerate() {
+                let address = section.shdr.sh_addr() + index as Elf64_Addr * word_size;
+                slots.push(GotSlot {
+                    address,
+                    section_name: section.name.unwrap_or(&[]),
+                    value: word.get(),
+                    relocation: relocations.get(&address).cloned(),
+                    relative: relative.contains(&address),
+                    relro: relro.iter().any(|&(start, end)| start <= address && address < end),
+                });
+            }
+        }
+        slots.sort_by_key(|slot| slot.address);
+        Ok(slots)
+    }
+}
@@ -0,0 +1,481 @@
+use std::fmt::Write;
+
+use constants::*;
+use error::*;
+use format::*;
+use reader::*;
+use types::*;
+use writer::*;
+
+/// A contiguous block of a [LoadImage](struct.LoadImage.html).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoadBlock {
+    /// The load memory address of the block.
+    pub address: Elf64_Addr,
+    /// The contents of the block, starting at its address.
+    pub data: Vec<u8>,
+}
+
+impl LoadBlock {
+    /// Returns the address following the block, saturating at the end of the address space.
+    ///
+    /// The blocks of a [LoadImage](struct.LoadImage.html) never extend that far, as
+    /// [add](struct.LoadImage.html#method.add) rejects them.
+    pub fn end(&self) -> Elf64_Addr {
+        self.address.saturating_add(self.data.len() as Elf64_Addr)
+    }
+}
+
+/// The memory contents of an object file as loaded into memory, such as a firmware image to
+/// flash, in the formats of `objcopy -O`.
+///
+/// Addresses are load memory addresses: the physical addresses (`p_paddr`) of the
+/// [PT_LOAD](constant.PT_LOAD.html) segments, which differ from their virtual addresses for data
+/// copied from flash to RAM at startup. Uninitialized data such as `.bss` is not part of the
+/// image.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LoadImage {
+    /// The blocks of the image, sorted by address. Adjacent blocks are merged.
+    pub blocks: Vec<LoadBlock>,
+    /// The entry point address, which Intel HEX and S-record files record.
+    pub entry: Elf64_Addr,
+}
+
+/// The number of data bytes per Intel HEX or S-record record.
+const RECORD_SIZE: usize = 16;
+
+/// The number of data bytes per UF2 block.
+const UF2_PAYLOAD_SIZE: usize = 256;
+
+const UF2_MAGIC_START0: u32 = 0x0a32_4655;
+const UF2_MAGIC_START1: u32 = 0x9e5d_5157;
+const UF2_MAGIC_END: u32 = 0x0ab1_6f30;
+const UF2_FLAG_FAMILY_ID_PRESENT: u32 = 0x2000;
+
+// Parses hexadecimal digits, two per byte.
+pub(super) fn parse_hex(digits: &str) -> Option<Vec<u8>> {
+    if !digits.len().is_multiple_of(2) || !digits.is_ascii() {
+        return None;
+    }
+    (0..digits.len()).step_by(2)
+        .map(|i| u8::from_str_radix(&digits[i..i+2], 16).ok())
+        .collect()
+}
+
+// Returns the big-endian number in `bytes`.
+fn parse_be(bytes: &[u8]) -> Elf64_Addr {
+    bytes.iter().fold(0, |value, &b| (value << 8) | Elf64_Addr::from(b))
+}
+
+// Appends a record of `bytes`, as hexadecimal digits, followed by its checksum.
+fn push_record(out: &mut String, start: &str, bytes: &[u8], checksum: fn(u8) -> u8) {
+    out.push_str(start);
+    let mut sum = 0u8;
+    for &b in bytes {
+        sum = sum.wrapping_add(b);
+        let _ = write!(out, "{:02X}", b);
+    }
+    let _ = writeln!(out, "{:02X}", checksum(sum));
+}
+
+impl LoadImage {
+    /// Returns an image from `blocks`, which may be in any order.
+    pub fn new(blocks: Vec<LoadBlock>, entry: Elf64_Addr) -> Result<Self> {
+        let mut image = LoadImage { blocks: Vec::new(), entry };
+        for block in blocks {
+            image.add(block.address, &block.data)?;
+        }
+        Ok(image)
+    }
+
+    /// Adds `data` at `address`, replacing any data already at the same addresses.
+    ///
+    /// Returns an error if `data` extends to or past the end of the address space, where the
+    /// address following it cannot be represented.
+    pub fn add(&mut self, address: Elf64_Addr, data: &[u8]) -> Result<()> {
+        if data.is_empty() {
+            return Ok(());
+        }
+        let end = address.checked_add(data.len() as Elf64_Addr)
+            .ok_or(Error::Msg { msg: "data extends past the end of the address space" })?;
+        // The blocks which overlap or touch the new data are merged with it.
+        let first = self.blocks.iter().position(|block| block.end() >= address).unwrap_or(self.blocks.len());
+        let last = self.blocks.iter().rposition(|block| block.address <= end).map_or(first, |last| last + 1).max(first);
+        let start = self.blocks[first..last].first().map_or(address, |block| block.address.min(address));
+        let mut merged = LoadBlock { address: start, data: Vec::new() };
+        for block in &self.blocks[first..last] {
+            merged.data.resize((block.address - start) as usize, 0);
+            merged.data.extend_from_slice(&block.data);
+        }
+        let offset = (address - start) as usize;
+        if merged.data.len() < offset + data.len() {
+            merged.data.resize(offset + data.len(), 0);
+        }
+        merged.data[offset..offset+data.len()].copy_from_slice(data);
+        self.blocks.splice(first..last, Some(merged));
+        Ok(())
+    }
+
+    /// Returns the lowest address of the image, or 0 if it is empty.
+    pub fn start(&self) -> Elf64_Addr {
+        self.blocks.first().map_or(0, |block| block.address)
+    }
+
+    /// Returns the address following the highest address of the image, or 0 if it is empty.
+    pub fn end(&self) -> Elf64_Addr {
+        self.blocks.last().map_or(0, LoadBlock::end)
+    }
+
+    /// Returns the image as raw binary (`objcopy -O binary`), from its lowest to its highest
+    /// address, with the gaps between blocks filled with `gap_fill`.
+    pub fn to_binary(&self, gap_fill: u8) -> Vec<u8> {
+        let start = self.start();
+        let mut out = Vec::with_capacity((self.end() - start) as usize);
+        for block in &self.blocks {
+            out.resize((block.address - start) as usize, gap_fill);
+            out.extend_from_slice(&block.data);
+        }
+        out
+    }
+
+    /// Parses an Intel HEX file, such as one written by
+    /// [to_intel_hex](#method.to_intel_hex). Extended segment and extended linear address
+    /// records set the upper address bits of the data records following them, and start segment
+    /// and start linear address records set the entry point.
+    pub fn from_intel_hex(text: &str) -> Result<Self> {
+        let invalid = Error::Msg { msg: "invalid Intel HEX record" };
+        let mut image = LoadImage::default();
+        let mut base = 0;
+        for line in text.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            if !line.starts_with(':') {
+                return Err(invalid);
+            }
+            let bytes = parse_hex(&line[1..]).ok_or(invalid)?;
+            if bytes.len() < 5 || bytes.len() != usize::from(bytes[0]) + 5 {
+                return Err(invalid);
+            }
+            if bytes.iter().fold(0u8, |sum, &b| sum.wrapping_add(b)) != 0 {
+                return Err(Error::Msg { msg: "invalid Intel HEX checksum" });
+            }
+            let address = parse_be(&bytes[1..3]);
+            let data = &bytes[4..bytes.len()-1];
+            match (bytes[3], data.len()) {
+                (0, _) => image.add(base + address, data)?,
+                (1, _) => break,
+                (2, 2) => base = parse_be(data) << 4,
+                (3, 4) => image.entry = (parse_be(&data[..2]) << 4) + parse_be(&data[2..]),
+                (4, 2) => base = parse_be(data) << 16,
+                (5, 4) => image.entry = parse_be(data),
+                _      => return Err(invalid),
+            }
+        }
+        Ok(image)
+    }
+
+    /// Parses a Motorola S-record file, such as one written by [to_srec](#method.to_srec). The
+    /// data of S1, S2, and S3 records is added to the image and S7, S8, and S9 records set the
+    /// entry point. Header and count records are ignored.
+    pub fn from_srec(text: &str) -> Result<Self> {
+        let invalid = Error::Msg { msg: "invalid S-record" };
+        let mut image = LoadImage::default();
+        for line in text.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            if !line.starts_with('S') || line.len() < 2 {
+                return Err(invalid);
+            }
+            let record_type = line.as_bytes()[1];
+            let address_size = match record_type {
+                b'0' | b'1' | b'5' | b'9' => 2,
+                b'2' | b'6' | b'8'        => 3,
+                b'3' | b'7'               => 4,
+                _ => return Err(invalid),
+            };
+            let bytes = parse_hex(&line[2..]).ok_or(invalid)?;
+            if bytes.len() < address_size + 2 || bytes.len() != usize::from(bytes[0]) + 1 {
+                return Err(invalid);
+            }
+            if bytes.iter().fold(0u8, |sum, &b| sum.wrapping_add(b)) != 0xff {
+                return Err(Error::Msg { msg: "invalid S-record checksum" });
+            }
+            let address = parse_be(&bytes[1..1+address_size]);
+            match record_type {
+                b'1' | b'2' | b'3' => image.add(address, &bytes[1+address_size..bytes.len()-1])?,
+                b'7' | b'8' | b'9' => image.entry = address,
+                _ => (),
+            }
+        }
+        Ok(image)
+    }
+
+    /// Returns a relocatable object file (`objcopy -I ihex -O elf*`) with a section for each
+    /// block of the image, named `.sec1`, `.sec2`, and so on, at the address of the block.
+    /// `header` sets the fields of the ELF header other than `e_type` and `e_entry`.
+    pub fn to_relocatable(&self, format: ElfFormat, header: FileHeader) -> Result<Vec<u8>> {
+        if !format.is_64bit() && (self.end() > 1 << 32 || self.entry > Elf64_Addr::from(u32::MAX)) {
+            return Err(Error::Msg { msg: "address does not fit in 32 bits" });
+        }
+        let mut builder = ElfBuilder::new(format, FileHeader { e_type: ET_REL, e_entry: self.entry, ..header });
+        for (index, block) in self.blocks.iter().enumerate() {
+            let name = format!(".sec{}", index + 1);
+            let mut section = OutputSection::new(name.as_bytes(), SHT_PROGBITS, SHF_ALLOC | SHF_WRITE,
+                                                 block.data.clone());
+            section.sh_addr = block.address;
+            builder.add_section(section);
+        }
+        builder.build()
+    }
+
+    /// Returns the image in Intel HEX format (`objcopy -O ihex`), using extended linear address
+    /// records for addresses above 64 KiB and a start linear address record for a nonzero entry
+    /// point.
+    pub fn to_intel_hex(&self) -> Result<String> {
+        if self.end() > 1 << 32 || self.entry > Elf64_Addr::from(u32::MAX) {
+            return Err(Error::Msg { msg: "address does not fit in 32 bits" });
+        }
+        let checksum = |sum: u8| sum.wrapping_neg();
+        let mut out = String::new();
+        let mut upper = 0;
+        for block in &self.blocks {
+            let mut address = block.address;
+            let mut data = &block.data[..];
+            while !data.is_empty() {
+                if address >> 16 != upper {
+                    upper = address >> 16;
+                    push_record(&mut out, ":", &[2, 0, 0, 4, (upper >> 8) as u8, upper as u8], checksum);
+                }
+                // Records do not cross 64 KiB boundaries.
+                let size = data.len().min(RECORD_SIZE).min((0x1_0000 - (address & 0xffff)) as usize);
+                let mut record = vec![size as u8, (address >> 8) as u8, address as u8, 0];
+                record.extend_from_slice(&data[..size]);
+                push_record(&mut out, ":", &record, checksum);
+                address += size as Elf64_Addr;
+                data = &data[size..];
+            }
+        }
+        if self.entry != 0 {
+            let mut record = vec![4, 0, 0, 5];
+            record.extend_from_slice(&(self.entry as u32).to_be_bytes());
+            push_record(&mut out, ":", &record, checksum);
+        }
+        push_record(&mut out, ":", &[0, 0, 0, 1], checksum);
+        Ok(out)
+    }
+
+    /// Returns the image in Motorola S-record format (`objcopy -O srec`). The data records are
+    /// S1, S2, or S3 records, with 16-, 24-, or 32-bit addresses, whichever are wide enough for
+    /// all the addresses of the image.
+    pub fn to_srec(&self) -> Result<String> {
+        let highest = self.end().saturating_sub(1).max(self.entry);
+        let address_size = match highest {
+            0..=0xffff               => 2,
+            0x1_0000..=0xff_ffff     => 3,
+            0x100_0000..=0xffff_ffff => 4,
+            _ => return Err(Error::Msg { msg: "address does not fit in 32 bits" }),
+        };
+        let checksum = |sum: u8| !sum;
+        let record = |out: &mut String, record_type: u8, address: Elf64_Addr, data: &[u8]| {
+            let mut bytes = vec![(address_size + data.len() + 1) as u8];
+            bytes.extend_from_slice(&address.to_be_bytes()[8 - address_size..]);
+            bytes.extend_from_slice(data);
+            push_record(out, &format!("S{}", record_type), &bytes, checksum);
+        };
+        let mut out = String::new();
+        push_record(&mut out, "S0", &[3, 0, 0], checksum);
+        let mut count = 0;
+        for block in &self.blocks {
+            for (index, chunk) in block.data.chunks(RECORD_SIZE).enumerate() {
+                record(&mut out, address_size as u8 - 1, block.address + (index * RECORD_SIZE) as Elf64_Addr, chunk);
+                count += 1;
+            }
+        }
+        // The count record holds the number of data records, in 16 or 24 bits.
+        if count <= 0xffff {
+            push_record(&mut out, "S5", &[3, (count >> 8) as u8, count as u8], checksum);
+        } else if count <= 0xff_ffff {
+            push_record(&mut out, "S6", &[4, (count >> 16) as u8, (count >> 8) as u8, count as u8], checksum);
+        }
+        record(&mut out, 11 - address_size as u8, self.entry, &[]);
+        Ok(out)
+    }
+
+    /// Returns the image in the UF2 format used by USB mass storage bootloaders, in blocks of 256
+    /// bytes at 256-byte aligned addresses. Bytes of a block outside the image are filled with
+    /// `gap_fill`.
+    ///
+    /// `family_id` identifies the microcontroller family the image is for.
+    pub fn to_uf2(&self, family_id: Option<u32>, gap_fill: u8) -> Result<Vec<u8>> {
+        if self.end() > 1 << 32 {
+            return Err(Error::Msg { msg: "address does not fit in 32 bits" });
+        }
+        let size = UF2_PAYLOAD_SIZE as Elf64_Addr;
+        let mut pages: Vec<Elf64_Addr> = Vec::new();
+        for block in &self.blocks {
+            let mut page = block.address & !(size - 1);
+            while page < block.end() {
+                if pages.last() != Some(&page) {
+                    pages.push(page);
+                }
+                page += size;
+            }
+        }
+
+        let mut out = Vec::with_capacity(pages.len() * 512);
+        for (number, &page) in pages.iter().enumerate() {
+            let mut payload = vec![gap_fill; UF2_PAYLOAD_SIZE];
+            for block in &self.blocks {
+                let start = block.address.max(page);
+                let end = block.end().min(page + size);
+                if start < end {
+                    payload[(start - page) as usize..(end - page) as usize]
+                        .copy_from_slice(&block.data[(start - block.address) as usize..(end - block.address) as usize]);
+                }
+            }
+            let words = [
+                UF2_MAGIC_START0,
+                UF2_MAGIC_START1,
+                if family_id.is_some() { UF2_FLAG_FAMILY_ID_PRESENT } else { 0 },
+                page as u32,
+                UF2_PAYLOAD_SIZE as u32,
+                number as u32,
+                pages.len() as u32,
+                family_id.unwrap_or(0),
+            ];
+            for word in &words {
+                out.extend_from_slice(&word.to_le_bytes());
+            }
+            out.extend_from_slice(&payload);
+            out.resize(out.len() + 476 - UF2_PAYLOAD_SIZE, 0);
+            out.extend_from_slice(&UF2_MAGIC_END.to_le_bytes());
+        }
+        Ok(out)
+    }
+}
+
+/// A relocatable object file embedding the contents of a file, such as an asset to link into
+/// firmware, like those created by `objcopy -I binary`.
+///
+/// The contents are placed in a single section and the symbols `_binary_<name>_start` and
+/// `_binary_<name>_end` are defined at its start and end, and the absolute symbol
+/// `_binary_<name>_size` to its size.
+///
+/// # Examples
+///
+/// ```
+/// use elftk::*;
+///
+/// let object = BinaryObject::new(b"assets/logo.png");
+/// let data = object.build(ElfFormat::Elf32LE(()), FileHeader {
+///     e_machine: EM_ARM,
+///     ..FileHeader::default()
+/// }, b"\x89PNG").unwrap();
+/// let reader = Reader::new(&data).unwrap();
+/// assert_eq!(reader.elf_header().e_type(), ET_REL);
+/// assert!(reader.section_by_name(b".data").is_some());
+/// ```
+#[derive(Debug, Clone)]
+pub struct BinaryObject {
+    /// The name of the section holding the contents, `.data` by default.
+    pub section_name: Vec<u8>,
+    /// The flags of the section, [SHF_ALLOC](constant.SHF_ALLOC.html) and
+    /// [SHF_WRITE](constant.SHF_WRITE.html) by default.
+    pub sh_flags: Elf64_Xword,
+    /// The alignment of the section, 1 by default.
+    pub sh_addralign: Elf64_Xword,
+    /// The `<name>` part of the symbol names.
+    pub symbol_name: Vec<u8>,
+}
+
+impl BinaryObject {
+    /// Returns an object file whose symbol names are derived from `file_name`, the name of the
+    /// embedded file, by replacing all characters other than ASCII letters and digits with
+    /// underscores, as `objcopy` does.
+    pub fn new(file_name: &[u8]) -> Self {
+        BinaryObject {
+            section_name: b".data".to_vec(),
+            sh_flags: SHF_ALLOC | SHF_WRITE,
+            sh_addralign: 1,
+            symbol_name: file_name.iter()
+                .map(|&c| if c.is_ascii_alphanumeric() { c } else { b'_' })
+                .collect(),
+        }
+    }
+
+    /// Returns the object file embedding `data`. `header` sets the fields of the ELF header other
+    /// than `e_type`, such as the machine.
+    pub fn build(&self, format: ElfFormat, header: FileHeader, data: &[u8]) -> Result<Vec<u8>> {
+        if self.sh_addralign != 0 && !self.sh_addralign.is_power_of_two() {
+            return Err(Error::InvalidHeaderField {
+                header: "section",
+                field: "sh_addralign",
+                value: self.sh_addralign,
+            });
+        }
+        let mut builder = ElfBuilder::new(format, FileHeader { e_type: ET_REL, ..header });
+        let mut section = OutputSection::new(&self.section_name, SHT_PROGBITS, self.sh_flags, data.to_vec());
+        section.sh_addralign = self.sh_addralign;
+        let index = builder.add_section(section);
+
+        let size = data.len() as Elf64_Xword;
+        let symbol = |suffix: &[u8], section, value| {
+            let name = [b"_binary_", &self.symbol_name[..], suffix].concat();
+            OutputSymbol::new(&name, STB_GLOBAL, STT_NOTYPE, section, value, 0)
+        };
+        let mut symbols = SymbolTableBuilder::new();
+        symbols.add(symbol(b"_start", SectionIndex::Normal(index), 0));
+        symbols.add(symbol(b"_end", SectionIndex::Normal(index), size));
+        symbols.add(symbol(b"_size", SectionIndex::Reserved(SHN_ABS), size));
+        builder.add_symbol_table(&symbols)?;
+        builder.build()
+    }
+}
+
+impl<'a> Reader<'a> {
+    /// Returns the contents of the [PT_LOAD](constant.PT_LOAD.html) segments at their physical
+    /// addresses, or, if there are none, of the allocated sections at their addresses.
+    ///
+    /// Only the allocated sections in a segment are included if the object file has section
+    /// headers, which leaves out the ELF and program headers loaded with the first segment of
+    /// most executables.
+    pub fn load_image(&self) -> Result<LoadImage> {
+        let mut image = LoadImage { blocks: Vec::new(), entry: self.elf_header().e_entry() };
+        let loaded = |shdr: &SectionHeaderRef<'a>| {
+            shdr.sh_flags() & SHF_ALLOC != 0 && shdr.sh_type() != SHT_NOBITS && shdr.sh_size() > 0
+        };
+        let mut segments = false;
+        for phdr in self.program_headers() {
+            if phdr.p_type() != PT_LOAD || phdr.p_filesz() == 0 {
+                continue;
+            }
+            segments = true;
+            let data = match self.segment_data(phdr) {
+                Some(data) => data,
+                None       => continue,
+            };
+            if self.num_sections() == 0 {
+                image.add(phdr.p_paddr(), data)?;
+                continue;
+            }
+            // Reader::new checked that the segment is contained in the file.
+            let (start, end) = (phdr.p_offset(), phdr.p_offset() + phdr.p_filesz());
+            for shdr in self.section_headers() {
+                let contained = shdr.sh_offset().checked_add(shdr.sh_size())
+                    .is_some_and(|shdr_end| start <= shdr.sh_offset() && shdr_end <= end);
+                if loaded(&shdr) && contained {
+                    let offset = (shdr.sh_offset() - start) as usize;
+                    let address = phdr.p_paddr().checked_add(offset as Elf64_Addr)
+                        .ok_or(Error::Msg { msg: "data extends past the end of the address space" })?;
+                    image.add(address, &data[offset..offset+shdr.sh_size() as usize])?;
+                }
+            }
+        }
+        if !segments {
+            for section in self.sections_matching(|shdr| loaded(shdr)) {
+                let offset = section.shdr.sh_offset() as usize;
+                let size = section.shdr.sh_size() as usize;
+                let data = self.data().get(offset..offset+size)
+                    .ok_or(Error::NotContainedInFile { what: "section", which: offset as u64 })?;
+                image.add(section.shdr.sh_addr(), data)?;
+            }
+        }
+        Ok(image)
+    }
+}
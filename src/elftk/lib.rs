@@ -1,20 +1,56 @@
 extern crate failure;
 #[macro_use]
 extern crate failure_derive;
+extern crate miniz_oxide;
+extern crate ruzstd;
+extern crate serde;
+#[macro_use]
+extern crate serde_derive;
+extern crate serde_yaml;
 
 #[macro_use]
 mod constant_macros;
+mod android;
+mod compress;
 mod constants;
+mod copy;
+mod debuglink;
+mod description;
+mod dynamic;
+mod edit;
 mod error;
 mod format;
+mod got;
+mod image;
+mod patch;
+mod plt;
+mod relocate;
 mod relocations;
+mod strip;
+mod symbolize;
 mod types;
+mod writer;
 mod reader;
 
 // Re-export these.
+pub use self::android::*;
+pub use self::compress::*;
 pub use self::constants::*;
+pub use self::copy::*;
+pub use self::debuglink::*;
+pub use self::description::*;
+pub use self::dynamic::*;
+pub use self::edit::*;
 pub use self::error::*;
 pub use self::format::*;
+pub use self::got::*;
+pub use self::image::*;
+pub use self::patch::*;
+pub use self::plt::*;
+pub use self::relocate::*;
 pub use self::relocations::*;
+pub use self::strip::*;
+pub use self::symbolize::*;
 pub use self::types::*;
+pub use self::writer::*;
 pub use self::reader::*;
@@ -0,0 +1,477 @@
+use std::mem;
+
+use constants::*;
+use edit::*;
+use error::*;
+use format::*;
+use reader::*;
+use types::*;
+use writer::*;
+
+// A version need (Elf_Verneed) of the version needs section (.gnu.version_r), which names a
+// DT_NEEDED library, with the (vna_hash, vna_flags, vna_other, vna_name) of each of its
+// Elf_Vernaux entries.
+#[derive(Debug, Clone)]
+struct VersionNeed {
+    version: Elf_Half,
+    file: Elf_Word,
+    versions: Vec<(Elf_Word, Elf_Half, Elf_Half, Elf_Word)>,
+}
+
+// The size of Elf32_Verneed, Elf64_Verneed, Elf32_Vernaux, and Elf64_Vernaux.
+const VERNEED_SIZE: usize = 16;
+
+// Returns the version needs in `data`, which holds `count` of them.
+fn version_needs(format: ElfFormat, data: &[u8], count: Elf64_Xword) -> Vec<VersionNeed> {
+    let field = |offset: usize, size| read_uint(format, data, offset, size);
+    let mut needs = Vec::new();
+    let mut offset = 0;
+    for _ in 0..count {
+        let (version, aux_count, file, aux, next) = match (field(offset, 2), field(offset + 2, 2),
+                                                           field(offset + 4, 4), field(offset + 8, 4),
+                                                           field(offset + 12, 4)) {
+            (Some(version), Some(aux_count), Some(file), Some(aux), Some(next)) => (version, aux_count, file, aux, next),
+            _ => break,
+        };
+        let mut versions = Vec::new();
+        let mut aux_offset = offset + aux as usize;
+        for _ in 0..aux_count {
+            let fields = (field(aux_offset, 4), field(aux_offset + 4, 2), field(aux_offset + 6, 2),
+                          field(aux_offset + 8, 4), field(aux_offset + 12, 4));
+            match fields {
+                (Some(hash), Some(flags), Some(other), Some(name), Some(aux_next)) => {
+                    versions.push((hash as Elf_Word, flags as Elf_Half, other as Elf_Half, name as Elf_Word));
+                    aux_offset += aux_next as usize;
+                },
+                _ => break,
+            }
+        }
+        needs.push(VersionNeed { version: version as Elf_Half, file: file as Elf_Word, versions });
+        if next == 0 {
+            break;
+        }
+        offset += next as usize;
+    }
+    needs
+}
+
+/// Edits the program interpreter and the dynamic table of an executable or shared object, like
+/// `patchelf`.
+///
+/// Strings are appended to a copy of the dynamic string table (`.dynstr`). When the file is
+/// [written](#method.write), the dynamic string table, the dynamic table, and the interpreter
+/// (`.interp`) are updated in place if they still fit. Otherwise they are moved, along with the
+/// program header table, to a new [PT_LOAD](constant.PT_LOAD.html) segment at the end of the file
+/// whose addresses follow those of the existing segments.
+///
+/// The object file must have section headers.
+#[derive(Debug, Clone)]
+pub struct ElfPatcher {
+    file: ElfFile,
+    dynamic_index: Elf_Word,
+    dynstr_index: Elf_Word,
+    interp_index: Option<Elf_Word>,
+    verneed_index: Option<Elf_Word>,
+    versym_index: Option<Elf_Word>,
+    interpreter: Option<Vec<u8>>,
+    entries: Vec<(Elf64_Sxword, Elf64_Xword)>,
+    dynstr: Vec<u8>,
+    version_needs: Vec<VersionNeed>,
+    // The version indexes of the version needs of removed DT_NEEDED libraries.
+    removed_versions: Vec<Elf_Half>,
+}
+
+impl ElfPatcher {
+    pub fn new(reader: &Reader) -> Result<Self> {
+        let file = ElfFile::new(reader)?;
+        let dynamic = reader.dynamic()?
+            .ok_or(Error::Msg { msg: "no dynamic section" })?;
+        let dynamic_index = reader.section_index(dynamic.shdr)
+            .ok_or(Error::Msg { msg: "no dynamic section" })?;
+        let dynstr_index = dynamic.shdr.sh_link();
+        let dynstr = file.sections.get(dynstr_index as usize)
+            .filter(|section| section.header.sh_type == SHT_STRTAB)
+            .ok_or(Error::InvalidLinkedSection { linked: dynstr_index })?
+            .data.clone();
+        let entries: Vec<_> = match dynamic.data {
+            SectionDataRef::DynamicTable(table) => {
+                let mut entries = Vec::new();
+                for entry in table.iter() {
+                    entries.push((entry.d_tag(), entry.d_val()));
+                }
+                entries
+            },
+            _ => Vec::new(),
+        };
+
+        // The interpreter is the section at the offset of the PT_INTERP segment.
+        let interp_index = reader.program_headers().into_iter()
+            .find(|phdr| phdr.p_type() == PT_INTERP)
+            .and_then(|phdr| file.sections.iter().position(|section| {
+                section.header.sh_type == SHT_PROGBITS && section.header.sh_offset == phdr.p_offset()
+            }));
+
+        // The version needs, which name DT_NEEDED libraries, and the version symbol section which
+        // refers to them by index.
+        let allocated_at = |tag| {
+            let address = entries.iter().find(|&&(t, _)| t == tag).map(|&(_, address)| address)?;
+            file.sections.iter().position(|section| {
+                section.header.sh_flags & SHF_ALLOC != 0 && section.header.sh_addr == address &&
+                    section.header.sh_type != SHT_NOBITS
+            })
+        };
+        let verneed_index = allocated_at(DT_VERNEED);
+        let versym_index = allocated_at(DT_VERSYM);
+        let version_needs = match verneed_index {
+            Some(index) => {
+                let count = entries.iter().find(|&&(tag, _)| tag == DT_VERNEEDNUM).map_or(0, |&(_, count)| count);
+                version_needs(file.format(), &file.sections[index].data, count)
+            },
+            None => Vec::new(),
+        };
+
+        Ok(ElfPatcher {
+            interpreter: interp_index.map(|index| {
+                let data = &file.sections[index].data;
+                data.iter().position(|&b| b == 0).map_or(&data[..], |len| &data[..len]).to_vec()
+            }),
+            file,
+            dynamic_index,
+            dynstr_index,
+            interp_index: interp_index.map(|index| index as Elf_Word),
+            verneed_index: verneed_index.map(|index| index as Elf_Word),
+            versym_index: versym_index.map(|index| index as Elf_Word),
+            entries,
+            dynstr,
+            version_needs,
+            removed_versions: Vec::new(),
+        })
+    }
+
+    // Returns the offset of `string` in the dynamic string table, appending it if necessary.
+    fn add_string(&mut self, string: &[u8]) -> Elf64_Xword {
+        let mut target = string.to_vec();
+        target.push(0);
+        if let Some(offset) = self.dynstr.windows(target.len()).position(|window| window == &target[..]) {
+            return offset as Elf64_Xword;
+        }
+        let offset = self.dynstr.len();
+        self.dynstr.extend_from_slice(&target);
+        offset as Elf64_Xword
+    }
+
+    fn strings(&self, tag: Elf64_Sxword) -> Vec<&[u8]> {
+        self.entries.iter()
+            .filter(|&&(t, _)| t == tag)
+            .filter_map(|&(_, offset)| string_at(&self.dynstr, offset as usize))
+            .collect()
+    }
+
+    // Sets the string of the first entry with tag `tag` or adds an entry before the DT_NULL
+    // entry.
+    fn set_string(&mut self, tag: Elf64_Sxword, string: &[u8]) {
+        let offset = self.add_string(string);
+        match self.entries.iter_mut().find(|entry| entry.0 == tag) {
+            Some(entry) => entry.1 = offset,
+            None        => self.entries.push((tag, offset)),
+        }
+    }
+
+    /// Returns the program interpreter (`PT_INTERP`), if any.
+    pub fn interpreter(&self) -> Option<&[u8]> {
+        self.interpreter.as_ref().map(|interpreter| &interpreter[..])
+    }
+
+    /// Sets the program interpreter of an object file which has one.
+    pub fn set_interpreter(&mut self, interpreter: &[u8]) -> Result<()> {
+        if self.interp_index.is_none() {
+            return Err(Error::Msg { msg: "no program interpreter" });
+        }
+        self.interpreter = Some(interpreter.to_vec());
+        Ok(())
+    }
+
+    /// Returns the libraries named by `DT_NEEDED` entries, in order.
+    pub fn needed(&self) -> Vec<&[u8]> {
+        self.strings(DT_NEEDED)
+    }
+
+    /// Adds a `DT_NEEDED` entry for `library` before the existing ones, unless there is one
+    /// already.
+    pub fn add_needed(&mut self, library: &[u8]) {
+        if self.needed().contains(&library) {
+            return;
+        }
+        let offset = self.add_string(library);
+        self.entries.insert(0, (DT_NEEDED, offset));
+    }
+
+    /// Removes the `DT_NEEDED` entries for `library` and returns `true` if there were any.
+    ///
+    /// The version needs (`.gnu.version_r`) of `library` are removed as well, and the dynamic
+    /// symbols which referred to their versions become unversioned.
+    pub fn remove_needed(&mut self, library: &[u8]) -> bool {
+        let dynstr = &self.dynstr;
+        let is_library = |offset: Elf64_Xword| string_at(dynstr, offset as usize) == Some(library);
+        let before = self.entries.len();
+        self.entries.retain(|&(tag, offset)| tag != DT_NEEDED || !is_library(offset));
+        if before == self.entries.len() {
+            return false;
+        }
+
+        let (removed, kept): (Vec<_>, Vec<_>) = self.version_needs.drain(..)
+            .partition(|need| is_library(need.file.into()));
+        self.version_needs = kept;
+        if !removed.is_empty() {
+            self.removed_versions.extend(removed.iter().flat_map(|need| need.versions.iter().map(|version| version.2)));
+            let count = self.version_needs.len() as Elf64_Xword;
+            if count == 0 {
+                // The dynamic linker follows DT_VERNEED regardless of DT_VERNEEDNUM.
+                self.entries.retain(|&(tag, _)| tag != DT_VERNEED && tag != DT_VERNEEDNUM);
+            }
+            for entry in self.entries.iter_mut().filter(|entry| entry.0 == DT_VERNEEDNUM) {
+                entry.1 = count;
+            }
+        }
+        true
+    }
+
+    /// Replaces the `DT_NEEDED` entries for `old` with `new`, as well as the version needs
+    /// (`.gnu.version_r`) of `old`. Returns `true` if there were any entries for `old`.
+    pub fn replace_needed(&mut self, old: &[u8], new: &[u8]) -> bool {
+        let matches = |dynstr: &[u8], offset: Elf64_Xword| string_at(dynstr, offset as usize) == Some(old);
+        if !self.entries.iter().any(|&(tag, offset)| tag == DT_NEEDED && matches(&self.dynstr, offset)) {
+            return false;
+        }
+        let offset = self.add_string(new);
+        for entry in &mut self.entries {
+            if entry.0 == DT_NEEDED && matches(&self.dynstr, entry.1) {
+                entry.1 = offset;
+            }
+        }
+        for need in &mut self.version_needs {
+            if matches(&self.dynstr, need.file.into()) {
+                need.file = offset as Elf_Word;
+            }
+        }
+        true
+    }
+
+    /// Returns the `DT_SONAME` of a shared object, if any.
+    pub fn soname(&self) -> Option<&[u8]> {
+        self.strings(DT_SONAME).first().cloned()
+    }
+
+    /// Sets the `DT_SONAME`, adding the entry if there is none.
+    pub fn set_soname(&mut self, soname: &[u8]) {
+        self.set_string(DT_SONAME, soname);
+    }
+
+    /// Returns the `DT_RPATH`, if any.
+    pub fn rpath(&self) -> Option<&[u8]> {
+        self.strings(DT_RPATH).first().cloned()
+    }
+
+    /// Returns the `DT_RUNPATH`, if any.
+    pub fn runpath(&self) -> Option<&[u8]> {
+        self.strings(DT_RUNPATH).first().cloned()
+    }
+
+    /// Sets the `DT_RPATH` and removes any `DT_RUNPATH`, which would take precedence.
+    pub fn set_rpath(&mut self, rpath: &[u8]) {
+        self.entries.retain(|&(tag, _)| tag != DT_RUNPATH);
+        self.set_string(DT_RPATH, rpath);
+    }
+
+    /// Sets the `DT_RUNPATH` and removes any `DT_RPATH`.
+    pub fn set_runpath(&mut self, runpath: &[u8]) {
+        self.entries.retain(|&(tag, _)| tag != DT_RPATH);
+        self.set_string(DT_RUNPATH, runpath);
+    }
+
+    /// Removes the `DT_RPATH` and `DT_RUNPATH` entries.
+    pub fn remove_rpath(&mut self) {
+        self.entries.retain(|&(tag, _)| tag != DT_RPATH && tag != DT_RUNPATH);
+    }
+
+    fn encode_dynamic(&self, entries: &[(Elf64_Sxword, Elf64_Xword)], count: usize) -> Result<Vec<u8>> {
+        let mut encoder = Encoder::new(self.file.format());
+        for &(d_tag, d_val) in entries {
+            encoder.dyn_entry(&Elf64_Dyn { d_tag, d_val })?;
+        }
+        for _ in entries.len()..count {
+            encoder.dyn_entry(&Elf64_Dyn { d_tag: DT_NULL, d_val: 0 })?;
+        }
+        Ok(encoder.data)
+    }
+
+    // Lays out the version needs contiguously, each followed by its versions, in `size` bytes.
+    fn encode_version_needs(&self, size: usize) -> Vec<u8> {
+        let format = self.file.format();
+        let mut data = vec![0; size];
+        let mut offset = 0;
+        for (index, need) in self.version_needs.iter().enumerate() {
+            let length = VERNEED_SIZE * (need.versions.len() + 1);
+            if offset + length > size {
+                break;
+            }
+            let next = if index + 1 == self.version_needs.len() { 0 } else { length };
+            write_uint(format, &mut data, offset, 2, need.version.into());
+            write_uint(format, &mut data, offset + 2, 2, need.versions.len() as u64);
+            write_uint(format, &mut data, offset + 4, 4, need.file.into());
+            write_uint(format, &mut data, offset + 8, 4, if need.versions.is_empty() { 0 } else { VERNEED_SIZE as u64 });
+            write_uint(format, &mut data, offset + 12, 4, next as u64);
+            for (position, &(hash, flags, other, name)) in need.versions.iter().enumerate() {
+                let aux = offset + VERNEED_SIZE * (position + 1);
+                let next = if position + 1 == need.versions.len() { 0 } else { VERNEED_SIZE };
+                write_uint(format, &mut data, aux, 4, hash.into());
+                write_uint(format, &mut data, aux + 4, 2, flags.into());
+                write_uint(format, &mut data, aux + 6, 2, other.into());
+                write_uint(format, &mut data, aux + 8, 4, name.into());
+                write_uint(format, &mut data, aux + 12, 4, next as u64);
+            }
+            offset += length;
+        }
+        data
+    }
+
+    /// Returns the contents of the patched object file.
+    pub fn write(&self) -> Result<Vec<u8>> {
+        let mut file = self.file.clone();
+        let is_64bit = file.format().is_64bit();
+        let (_, phentsize, _) = header_sizes(file.format());
+        let dyn_size = if is_64bit { mem::size_of::<Elf64_Dyn>() } else { mem::size_of::<Elf32_Dyn>() };
+
+        let dynamic = &file.sections[self.dynamic_index as usize];
+        let dynamic_capacity = dynamic.data.len() / dyn_size;
+        let move_dynamic = self.entries.len() + 1 > dynamic_capacity;
+        let dynamic_count = if move_dynamic { self.entries.len() + 1 } else { dynamic_capacity };
+        let move_dynstr = self.dynstr.len() > file.sections[self.dynstr_index as usize].data.len();
+        let interp = self.interpreter.as_ref().map(|interpreter| {
+            let mut data = interpreter.clone();
+            data.push(0);
+            data
+        });
+        let move_interp = match (self.interp_index, interp.as_ref()) {
+            (Some(index), Some(data)) => data.len() > file.sections[index as usize].data.len(),
+            _                         => false,
+        };
+
+        // Lay out the new segment: the program headers followed by the moved sections.
+        let mut addresses = Vec::new();
+        if move_dynamic || move_dynstr || move_interp {
+            let alignment = file.segments.iter()
+                .filter(|phdr| phdr.p_type == PT_LOAD)
+                .map(|phdr| phdr.p_align)
+                .max()
+                .unwrap_or(0x1000)
+                .max(1);
+            let address = file.segments.iter()
+                .filter(|phdr| phdr.p_type == PT_LOAD)
+                .map(|phdr| phdr.p_vaddr + phdr.p_memsz)
+                .max()
+                .unwrap_or(0);
+            let address = align_up(address, alignment);
+            let phnum = file.segments.len() + 1;
+            let mut size = (phnum * usize::from(phentsize)) as Elf64_Xword;
+            let mut moved = Vec::new();
+            if move_interp {
+                moved.push((self.interp_index.unwrap_or(0), interp.as_ref().map_or(0, Vec::len), 1));
+            }
+            if move_dynstr {
+                moved.push((self.dynstr_index, self.dynstr.len(), 1));
+            }
+            if move_dynamic {
+                moved.push((self.dynamic_index, dynamic_count * dyn_size, if is_64bit { 8 } else { 4 }));
+            }
+            for &(index, length, section_alignment) in &moved {
+                size = align_up(size, section_alignment);
+                addresses.push((index, size, length as Elf64_Xword));
+                size += length as Elf64_Xword;
+            }
+            let offset = file.reserve(size, alignment);
+            file.place_program_headers(offset, phnum);
+            for &mut (index, ref mut start, length) in &mut addresses {
+                file.place_section(index, offset + *start, length)?;
+                let section = &mut file.sections[index as usize];
+                section.header.sh_addr = address + *start;
+                section.header.sh_offset = offset + *start;
+                *start += address;
+            }
+
+            let mut flags = PF_R;
+            if move_dynamic {
+                flags |= PF_W;
+            }
+            let load = Elf64_Phdr {
+                p_type: PT_LOAD,
+                p_flags: flags,
+                p_offset: offset,
+                p_vaddr: address,
+                p_paddr: address,
+                p_filesz: size,
+                p_memsz: size,
+                p_align: alignment,
+            };
+            let position = file.segments.iter().rposition(|phdr| phdr.p_type == PT_LOAD).map_or(0, |index| index + 1);
+            file.segments.insert(position, load);
+            for phdr in &mut file.segments {
+                if phdr.p_type == PT_PHDR {
+                    phdr.p_offset = offset;
+                    phdr.p_vaddr = address;
+                    phdr.p_paddr = address;
+                    phdr.p_filesz = (phnum * usize::from(phentsize)) as Elf64_Xword;
+                    phdr.p_memsz = phdr.p_filesz;
+                }
+            }
+        }
+
+        // Update the sections and the segments which hold them.
+        let mut entries = self.entries.clone();
+        for entry in &mut entries {
+            match entry.0 {
+                DT_STRTAB => entry.1 = file.sections[self.dynstr_index as usize].header.sh_addr,
+                DT_STRSZ  => entry.1 = self.dynstr.len() as Elf64_Xword,
+                _         => {},
+            }
+        }
+        file.sections[self.dynstr_index as usize].data = self.dynstr.clone();
+        file.sections[self.dynamic_index as usize].data = self.encode_dynamic(&entries, dynamic_count)?;
+        if let (Some(index), Some(data)) = (self.interp_index, interp) {
+            file.sections[index as usize].data = data;
+        }
+        if let Some(index) = self.verneed_index {
+            let section = &mut file.sections[index as usize];
+            section.data = self.encode_version_needs(section.data.len());
+            section.header.sh_info = self.version_needs.len() as Elf_Word;
+        }
+        if let Some(index) = self.versym_index.filter(|_| !self.removed_versions.is_empty()) {
+            let format = file.format();
+            let data = &mut file.sections[index as usize].data;
+            for offset in (0..data.len() / 2).map(|entry| 2 * entry) {
+                let version = read_uint(format, data, offset, 2).unwrap_or(0) as Elf_Half;
+                if self.removed_versions.contains(&(version & !VERSYM_HIDDEN)) {
+                    write_uint(format, data, offset, 2, VER_NDX_GLOBAL.into());
+                }
+            }
+        }
+        let moved = |index: Option<Elf_Word>| index.is_some_and(|index| addresses.iter().any(|entry| entry.0 == index));
+        for (segment_type, index) in [(PT_DYNAMIC, Some(self.dynamic_index)), (PT_INTERP, self.interp_index)] {
+            let section = match index {
+                Some(index) => &file.sections[index as usize],
+                None        => continue,
+            };
+            let (address, offset, size) = (section.header.sh_addr, section.header.sh_offset, section.size());
+            for phdr in file.segments.iter_mut().filter(|phdr| phdr.p_type == segment_type) {
+                if moved(index) {
+                    phdr.p_offset = offset;
+                    phdr.p_vaddr = address;
+                    phdr.p_paddr = address;
+                }
+                phdr.p_filesz = size;
+                phdr.p_memsz = size;
+            }
+        }
+        file.write()
+    }
+}
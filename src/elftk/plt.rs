@@ -0,0 +1,170 @@
+use std::collections::HashMap;
+
+use constants::*;
+use dynamic::*;
+use error::*;
+use reader::*;
+use relocations::*;
+use types::*;
+
+/// A stub in a procedure linkage table (`.plt`, `.plt.sec`, `.plt.got`, or `.iplt`), which jumps
+/// through a GOT slot filled in by a dynamic relocation.
+///
+/// `objdump -d` labels these stubs with synthetic `name@plt` symbols; see [name](#method.name).
+#[derive(Debug, Clone, Copy)]
+pub struct PltEntry<'a> {
+    pub address: Elf64_Addr,
+    pub size: Elf64_Xword,
+    /// The address of the GOT slot through which the stub jumps.
+    pub got_address: Elf64_Addr,
+    /// The `JUMP_SLOT`, `GLOB_DAT`, or `IRELATIVE` relocation which fills in the GOT slot.
+    pub relocation: RelocationRef<'a>,
+}
+
+impl<'a> PltEntry<'a> {
+    /// Returns the name of the synthetic symbol for the stub: `name@plt`, or `*ABS*+0xaddend@plt`
+    /// for `IRELATIVE` relocations, which have no symbol.
+    pub fn name(&self) -> Vec<u8> {
+        let mut name = match self.relocation.symbol.name() {
+            Some(name) if !name.is_empty() => name.to_vec(),
+            _ => format!("*ABS*+{:#x}", self.relocation.addend.unwrap_or(0)).into_bytes(),
+        };
+        name.extend_from_slice(b"@plt");
+        name
+    }
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    data.get(offset..offset+4).map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+}
+
+const ENDBR64: [u8; 4] = [0xf3, 0x0f, 0x1e, 0xfa];
+const ENDBR32: [u8; 4] = [0xf3, 0x0f, 0x1e, 0xfb];
+const AARCH64_BTI_C: u32 = 0xd503_245f;
+
+// Returns the GOT slot that the x86 PLT entry jumps through. The jump is preceded by an optional
+// endbr (IBT) and an optional bnd prefix (MPX).
+fn x86_got_slot(entry: &[u8], address: Elf64_Addr, x86_64: bool, got: Elf64_Addr) -> Option<Elf64_Addr> {
+    let mut start = 0;
+    if entry.starts_with(if x86_64 { &ENDBR64 } else { &ENDBR32 }) {
+        start += 4;
+    }
+    if entry.get(start) == Some(&0xf2) {
+        start += 1;
+    }
+    let disp = read_u32(entry, start + 2)?;
+    match (entry.get(start)?, entry.get(start+1)?) {
+        // jmp *disp(%rip)
+        (0xff, 0x25) if x86_64 => {
+            let next = address + start as Elf64_Addr + 6;
+            Some(next.wrapping_add(disp as i32 as Elf64_Addr))
+        },
+        // jmp *addr
+        (0xff, 0x25) => Some(disp.into()),
+        // jmp *disp(%ebx), where %ebx holds the address of the GOT
+        (0xff, 0xa3) if !x86_64 => Some((got as u32).wrapping_add(disp).into()),
+        _ => None,
+    }
+}
+
+// Returns the (address, GOT slot) pairs of the AArch64 PLT entries, which load the slot with
+//     adrp x16, slot
+//     ldr  x17, [x16, #:lo12:slot]
+// optionally preceded by bti c.
+fn aarch64_got_slots(data: &[u8], address: Elf64_Addr) -> Vec<(Elf64_Addr, Elf64_Addr)> {
+    let mut slots = Vec::new();
+    for offset in (0..data.len().saturating_sub(7)).step_by(4) {
+        let (adrp, ldr) = match (read_u32(data, offset), read_u32(data, offset + 4)) {
+            (Some(adrp), Some(ldr)) => (adrp, ldr),
+            _                       => break,
+        };
+        if adrp & 0x9f00_001f != 0x9000_0010 || ldr & 0xffc0_03ff != 0xf940_0211 {
+            continue;
+        }
+        let pc = address + offset as Elf64_Addr;
+        let immlo = Elf64_Addr::from((adrp >> 29) & 3);
+        let immhi = Elf64_Addr::from((adrp >> 5) & 0x7ffff);
+        let page_offset = ((((immhi << 2) | immlo) << 12) as i64) << 31 >> 31;
+        let page = (pc & !0xfff).wrapping_add(page_offset as Elf64_Addr);
+        let slot = page + Elf64_Addr::from((ldr >> 10) & 0xfff) * 8;
+        let start = if offset >= 4 && read_u32(data, offset - 4) == Some(AARCH64_BTI_C) { pc - 4 } else { pc };
+        slots.push((start, slot));
+    }
+    slots
+}
+
+impl<'a> Reader<'a> {
+    // Returns the relocations which fill in GOT slots used by PLT entries, by slot address.
+    fn got_slot_relocations(&self) -> Result<HashMap<Elf64_Addr, RelocationRef<'a>>> {
+        let machine = self.elf_header().e_machine();
+        let is_slot = |relocation_type| match machine {
+            EM_386     => matches!(relocation_type, R_386_JUMP_SLOT | R_386_GLOB_DAT | R_386_IRELATIVE),
+            EM_X86_64  => matches!(relocation_type, R_X86_64_JUMP_SLOT | R_X86_64_GLOB_DAT | R_X86_64_IRELATIVE),
+            EM_AARCH64 => matches!(relocation_type, R_AARCH64_JUMP_SLOT | R_AARCH64_GLOB_DAT | R_AARCH64_IRELATIVE),
+            _          => false,
+        };
+        let mut slots = HashMap::new();
+        for data in self.loaded_relocations()? {
+            for relocation in flatten_relocations(&data)? {
+                if is_slot(relocation.relocation_type()) {
+                    slots.insert(relocation.offset, relocation);
+                }
+            }
+        }
+        Ok(slots)
+    }
+
+    /// Returns the PLT entries of an x86-64, i386, or AArch64 executable or shared object, sorted
+    /// by address.
+    ///
+    /// Entries are paired with the relocations of the GOT slots they jump through, which handles
+    /// lazy (`.plt`), IBT or BTI (`.plt.sec`, `bti c`), and non-lazy (`.plt.got`) layouts alike.
+    /// Other machines have no entries.
+    pub fn plt_entries(&self) -> Result<Vec<PltEntry<'a>>> {
+        let machine = self.elf_header().e_machine();
+        if !matches!(machine, EM_386 | EM_X86_64 | EM_AARCH64) {
+            return Ok(Vec::new());
+        }
+        let slots = self.got_slot_relocations()?;
+        let got = match self.dynamic_table()? {
+            Some(dynamic) => dynamic.get(DT_PLTGOT).unwrap_or(0),
+            None          => 0,
+        };
+        let mut entries = Vec::new();
+        let plt_sections = [&b".plt"[..], b".plt.sec", b".plt.got", b".iplt"];
+        for section in self.sections_matching(|shdr| plt_sections.contains(&self.section_name(*shdr))) {
+            let data = match section.data {
+                SectionDataRef::Uninterpreted(data) => data,
+                _                                   => continue,
+            };
+            let address = section.shdr.sh_addr();
+            let mut stubs = Vec::new();
+            if machine == EM_AARCH64 {
+                let found = aarch64_got_slots(data, address);
+                for (index, &(start, slot)) in found.iter().enumerate() {
+                    let end = found.get(index + 1).map_or(address + data.len() as Elf64_Addr, |next| next.0);
+                    stubs.push((start, end - start, slot));
+                }
+            } else {
+                // The i386 .plt has sh_entsize 4, although its entries are 16 bytes.
+                let entry_size = match section.shdr.sh_entsize() {
+                    size if size >= 8 => size as usize,
+                    _                 => 16,
+                };
+                for (index, entry) in data.chunks(entry_size).enumerate() {
+                    let start = address + (index * entry_size) as Elf64_Addr;
+                    if let Some(slot) = x86_got_slot(entry, start, machine == EM_X86_64, got) {
+                        stubs.push((start, entry.len() as Elf64_Xword, slot));
+                    }
+                }
+            }
+            for (address, size, got_address) in stubs {
+                if let Some(&relocation) = slots.get(&got_address) {
+                    entries.push(PltEntry { address, size, got_address, relocation });
+                }
+            }
+        }
+        entries.sort_by_key(|entry| entry.address);
+        Ok(entries)
+    }
+}
@@ -1,7 +1,10 @@
+use std::collections::HashMap;
 use std::mem;
 use std::iter;
 
+use android::*;
 use constants::*;
+use relocations::*;
 use error::*;
 use format::*;
 use types::*;
@@ -48,6 +51,26 @@ impl<'a> ElfHeaderRef<'a> {
     field_impl!(e_shentsize, Elf32_Half, Elf64_Half);
     field_impl!(e_shnum,     Elf32_Half, Elf64_Half);
     field_impl!(e_shstrndx,  Elf32_Half, Elf64_Half);
+
+    /// Returns the header as an `Elf64_Ehdr` in native byte order.
+    pub fn decode(&self) -> Elf64_Ehdr {
+        Elf64_Ehdr {
+            e_ident:     self.e_ident(),
+            e_type:      self.e_type(),
+            e_machine:   self.e_machine(),
+            e_version:   self.e_version(),
+            e_entry:     self.e_entry(),
+            e_phoff:     self.e_phoff(),
+            e_shoff:     self.e_shoff(),
+            e_flags:     self.e_flags(),
+            e_ehsize:    self.e_ehsize(),
+            e_phentsize: self.e_phentsize(),
+            e_phnum:     self.e_phnum(),
+            e_shentsize: self.e_shentsize(),
+            e_shnum:     self.e_shnum(),
+            e_shstrndx:  self.e_shstrndx(),
+        }
+    }
 }
 
 pub type ProgramHeaderRef<'a> = ElfRef<'a, Elf32_Phdr, Elf64_Phdr>;
@@ -62,6 +85,20 @@ impl<'a> ProgramHeaderRef<'a> {
     field_impl!(p_memsz,  Elf32_Word, Elf64_Xword);
     field_impl!(p_flags,  Elf32_Word, Elf64_Word);
     field_impl!(p_align,  Elf32_Word, Elf64_Xword);
+
+    /// Returns the header as an `Elf64_Phdr` in native byte order.
+    pub fn decode(&self) -> Elf64_Phdr {
+        Elf64_Phdr {
+            p_type:   self.p_type(),
+            p_flags:  self.p_flags(),
+            p_offset: self.p_offset(),
+            p_vaddr:  self.p_vaddr(),
+            p_paddr:  self.p_paddr(),
+            p_filesz: self.p_filesz(),
+            p_memsz:  self.p_memsz(),
+            p_align:  self.p_align(),
+        }
+    }
 }
 
 pub type SectionHeaderRef<'a> = ElfRef<'a, Elf32_Shdr, Elf64_Shdr>;
@@ -78,6 +115,22 @@ impl<'a> SectionHeaderRef<'a> {
     field_impl!(sh_info,      Elf32_Word, Elf64_Word);
     field_impl!(sh_addralign, Elf32_Word, Elf64_Xword);
     field_impl!(sh_entsize,   Elf32_Word, Elf64_Xword);
+
+    /// Returns the header as an `Elf64_Shdr` in native byte order.
+    pub fn decode(&self) -> Elf64_Shdr {
+        Elf64_Shdr {
+            sh_name:      self.sh_name(),
+            sh_type:      self.sh_type(),
+            sh_flags:     self.sh_flags(),
+            sh_addr:      self.sh_addr(),
+            sh_offset:    self.sh_offset(),
+            sh_size:      self.sh_size(),
+            sh_link:      self.sh_link(),
+            sh_info:      self.sh_info(),
+            sh_addralign: self.sh_addralign(),
+            sh_entsize:   self.sh_entsize(),
+        }
+    }
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -201,7 +254,7 @@ impl<'a> Reader<'a> {
         let array_in_bounds = move |offset: u64, size: u64, num: u64| -> bool {
             size.checked_mul(num)
                 .and_then(|total_size| offset.checked_add(total_size))
-                .map_or(false, move |end| end <= len)
+                .is_some_and(move |end| end <= len)
         };
 
         // Check the program headers, if any.
@@ -309,6 +362,10 @@ impl<'a> Reader<'a> {
                         }
                         reader.dynsym_index = index;
                     },
+                    // Files with more than one dynamic table are accepted; the first one is used.
+                    SHT_DYNAMIC if reader.dynamic_index == Elf_Word::from(SHN_UNDEF) => {
+                        reader.dynamic_index = index;
+                    },
                     _ => {},
                 }
             }
@@ -326,6 +383,16 @@ impl<'a> Reader<'a> {
         self.data[EI_CLASS] == ELFCLASS64
     }
 
+    /// Returns the bytes of the object file.
+    pub fn data(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Returns the format of the object file.
+    pub fn format(&self) -> ElfFormat {
+        self.ehdr.format()
+    }
+
     /// Returns a reference to the ELF header.
     pub fn elf_header(&self) -> ElfHeaderRef<'a> {
         self.ehdr
@@ -352,6 +419,25 @@ impl<'a> Reader<'a> {
         Some(&self.data[offset..offset+size])
     }
 
+    /// Returns the file offset of the `size` bytes at virtual address `address`, if they are
+    /// contained in the file image of a [PT_LOAD](constant.PT_LOAD.html) segment.
+    pub fn virtual_address_offset(&self, address: Elf64_Addr, size: Elf64_Xword) -> Option<Elf64_Off> {
+        self.program_headers().into_iter()
+            .filter(|phdr| phdr.p_type() == PT_LOAD)
+            .find(|phdr| {
+                address >= phdr.p_vaddr() && address - phdr.p_vaddr() <= phdr.p_filesz() &&
+                    size <= phdr.p_filesz() - (address - phdr.p_vaddr())
+            })
+            .map(|phdr| phdr.p_offset() + (address - phdr.p_vaddr()))
+    }
+
+    /// Returns the `size` bytes at virtual address `address`, if they are contained in the file
+    /// image of a [PT_LOAD](constant.PT_LOAD.html) segment.
+    pub fn virtual_address_data(&self, address: Elf64_Addr, size: Elf64_Xword) -> Option<&'a [u8]> {
+        let offset = self.virtual_address_offset(address, size)? as usize;
+        self.data.get(offset..offset+size as usize)
+    }
+
     // The ELF file MUST have sections if this file is called.
     fn section_0(&self) -> SectionHeaderRef<'a> {
         let shoff = self.ehdr.e_shoff() as usize;
@@ -439,6 +525,149 @@ impl<'a> Reader<'a> {
         }
     }
 
+    /// Returns the first section named `name`, if any.
+    pub fn section_by_name(&self, name: &[u8]) -> Option<SectionRef<'a>> {
+        self.sections().find(|section| section.name == Some(name))
+    }
+
+    /// Returns the index of the section header, which must be one of the section headers of this
+    /// file.
+    pub fn section_index(&self, shdr: SectionHeaderRef<'a>) -> Option<Elf_Word> {
+        let address = |shdr: &SectionHeaderRef<'a>| {
+            shdr.apply(|&h| h as *const Elf32_Shdr as usize, |&h| h as *const Elf64_Shdr as usize)
+        };
+        self.section_headers().into_iter()
+            .position(|other| address(&other) == address(&shdr))
+            .map(|index| index as Elf_Word)
+    }
+
+    /// Returns the relocation tables of the `SHT_REL` and `SHT_RELA` sections that apply to
+    /// `section`.
+    ///
+    /// Android packed relocations only hold dynamic relocations, which are found through
+    /// [dynamic_relocations](#method.dynamic_relocations).
+    pub fn relocations_for<'b>(&'b self, section: &SectionRef<'a>) -> impl Iterator<Item=Result<RelocationTableRef<'a>>> + 'b where
+        'a: 'b,
+    {
+        let index = self.section_index(section.shdr).filter(|&index| index != 0);
+        self.section_headers().into_iter()
+            .filter(move |shdr| Some(shdr.sh_info()) == index && matches!(shdr.sh_type(), SHT_REL | SHT_RELA))
+            .map(move |shdr| {
+                let data = self.section_data(shdr)?;
+                data.relocation_table().ok_or(Error::InvalidSectionType {
+                    expected: SHT_RELA,
+                    actual: shdr.sh_type(),
+                })
+            })
+    }
+
+    /// Returns the notes contained in the segment corresponding to the program header, if it is a
+    /// [PT_NOTE](constant.PT_NOTE.html) segment.
+    pub fn segment_notes(&self, phdr: ProgramHeaderRef<'a>) -> Result<Option<NoteTableRef<'a>>> {
+        if phdr.p_type() != PT_NOTE {
+            return Ok(None);
+        }
+        let data = self.segment_data(phdr).unwrap_or(&[][..]);
+        Ok(Some(NoteTableRef::try_from(phdr.construct_from(data), phdr.p_align())?))
+    }
+
+    /// Returns the build ID from the [NT_GNU_BUILD_ID](constant.NT_GNU_BUILD_ID.html) note, if
+    /// any.
+    ///
+    /// Note sections are searched first, followed by [PT_NOTE](constant.PT_NOTE.html) segments
+    /// for object files without section headers.
+    pub fn build_id(&self) -> Option<&'a [u8]> {
+        let is_build_id = |note: &NoteRef<'a>| {
+            note.note_type == Elf_Xword::from(NT_GNU_BUILD_ID) && note.name == Some(&b"GNU"[..])
+        };
+        for section in self.sections_matching(|shdr| shdr.sh_type() == SHT_NOTE) {
+            if let SectionDataRef::NoteTable(notes) = section.data {
+                if let Some(note) = notes.into_iter().find(&is_build_id) {
+                    return note.desc;
+                }
+            }
+        }
+        for phdr in self.program_headers() {
+            if let Ok(Some(notes)) = self.segment_notes(phdr) {
+                if let Some(note) = notes.into_iter().find(&is_build_id) {
+                    return note.desc;
+                }
+            }
+        }
+        None
+    }
+
+    /// Returns the contents of the Android [NT_ANDROID_TYPE_IDENT](constant.NT_ANDROID_TYPE_IDENT.html)
+    /// note (`.note.android.ident`), if any.
+    pub fn android_ident(&self) -> Option<AndroidIdent<'a>> {
+        let is_android_ident = |note: &NoteRef<'a>| {
+            note.note_type == Elf_Xword::from(NT_ANDROID_TYPE_IDENT) && note.name == Some(&b"Android"[..])
+        };
+        for section in self.sections_matching(|shdr| shdr.sh_type() == SHT_NOTE) {
+            if let SectionDataRef::NoteTable(notes) = section.data {
+                if let Some(note) = notes.into_iter().find(&is_android_ident) {
+                    return AndroidIdent::parse(note.desc?, self.little_endian());
+                }
+            }
+        }
+        for phdr in self.program_headers() {
+            if let Ok(Some(notes)) = self.segment_notes(phdr) {
+                if let Some(note) = notes.into_iter().find(&is_android_ident) {
+                    return AndroidIdent::parse(note.desc?, self.little_endian());
+                }
+            }
+        }
+        None
+    }
+
+    /// Returns the address of the code for the function `symbol`.
+    ///
+    /// For ELFv1 PowerPC 64-bit object files, function symbols refer to function descriptors in
+    /// the `.opd` section whose first doubleword holds the address of the code. In relocatable
+    /// files, that doubleword is filled in by a relocation in `.rela.opd`. For other object
+    /// files, this is the symbol's [address](struct.SymbolRef.html#method.address).
+    pub fn function_address(&self, symbol: &SymbolRef<'a>) -> Result<Elf64_Addr> {
+        let address = symbol.address();
+        if self.ehdr.e_machine() != EM_PPC64 || self.ehdr.e_flags() & EF_PPC64_ABI == 2 {
+            return Ok(address);
+        }
+        let opd = match self.section_by_name(b".opd") {
+            Some(opd) => opd,
+            None      => return Ok(address),
+        };
+        let opd_index = match symbol.section {
+            SectionIndex::Normal(index) => index,
+            SectionIndex::Reserved(_)   => return Ok(address),
+        };
+        if Some(opd_index) != self.section_index(opd.shdr) {
+            return Ok(address);
+        }
+        // In relocatable files, the symbol's value is an offset into .opd.
+        let offset = if self.ehdr.e_type() == ET_REL { address } else { address.wrapping_sub(opd.shdr.sh_addr()) };
+        if self.ehdr.e_type() == ET_REL {
+            for section in self.sections_matching(|shdr| shdr.sh_type() == SHT_RELA && shdr.sh_info() == opd_index) {
+                if let SectionDataRef::ExplicitRelocationTable(table) = section.data {
+                    if let Some(entry) = table.entries.iter().find(|entry| {
+                        entry.r_offset() == offset && entry.info(EM_PPC64).relocation_type() == R_PPC64_ADDR64
+                    }) {
+                        let target = table.symbol_table.get(entry.info(EM_PPC64).symbol_index as usize)?;
+                        return Ok(target.value.wrapping_add(entry.r_addend() as Elf64_Addr));
+                    }
+                }
+            }
+            return Ok(address);
+        }
+        let data = match opd.data {
+            SectionDataRef::Uninterpreted(data) => data,
+            _                                   => return Ok(address),
+        };
+        let words = MachineWordsRef::try_from(opd.shdr.construct_from(data))?;
+        if offset % 8 != 0 {
+            return Err(Error::AlignmentError { alignment: 8, address: offset as usize });
+        }
+        Ok(words.get((offset / 8) as usize)?.get())
+    }
+
     pub fn uninterpreted_section_data(&self, shdr: SectionHeaderRef<'a>) -> SectionDataRef<'a> {
         match shdr.sh_type() {
             SHT_NULL | SHT_NOBITS => SectionDataRef::NoBits,
@@ -472,6 +701,44 @@ impl<'a> Reader<'a> {
         }
     }
 
+    // Relocation sections of executables and shared objects may apply to several sections, in
+    // which case sh_info is 0.
+    fn relocation_target(&self, shdr: SectionHeaderRef<'a>) -> Option<SectionHeaderRef<'a>> {
+        match shdr.sh_info() {
+            0     => None,
+            index => self.section_headers().get(index as usize).ok(),
+        }
+    }
+
+    // Returns the entries of the SHT_SYMTAB_SHNDX section associated with the symbol table, if any.
+    fn symbol_section_indexes(&self, symtab: SectionHeaderRef<'a>) -> Result<Option<ElfWordsRef<'a>>> {
+        let index = match self.section_index(symtab) {
+            Some(index) => index,
+            None        => return Ok(None),
+        };
+        let shndx = self.section_headers().into_iter()
+            .find(|shdr| shdr.sh_type() == SHT_SYMTAB_SHNDX && shdr.sh_link() == index);
+        match shndx.map(|shdr| (shdr, self.uninterpreted_section_data(shdr))) {
+            Some((shdr, SectionDataRef::Uninterpreted(data))) =>
+                Ok(Some(ElfWordsRef::try_from(shdr.construct_from(data))?)),
+            _ => Ok(None),
+        }
+    }
+
+    pub(super) fn symbol_table_from(&self, symbol_names: StringTableRef<'a>,
+                                    entries: SymbolTableEntriesRef<'a>,
+                                    shndx: Option<ElfWordsRef<'a>>) -> SymbolTableRef<'a>
+    {
+        SymbolTableRef {
+            symbol_names,
+            entries,
+            shndx,
+            machine: self.ehdr.e_machine(),
+            section_headers: self.section_headers(),
+            section_names: self.section_string_table().ok().and_then(|strtab| strtab),
+        }
+    }
+
     fn section_data(&self, shdr: SectionHeaderRef<'a>) -> Result<SectionDataRef<'a>> {
         let data = if let SectionDataRef::Uninterpreted(data) = self.uninterpreted_section_data(shdr) {
             data
@@ -484,25 +751,36 @@ impl<'a> Reader<'a> {
             SHT_SYMTAB | SHT_DYNSYM => {
                 let symbol_names = self.linked_string_table(shdr.sh_link())?;
                 let entries = SymbolTableEntriesRef::try_from(shdr.construct_from(data))?;
-                let shndx = None; // TODO: 
-                SectionDataRef::SymbolTable(SymbolTableRef {
-                    symbol_names,
-                    entries,
-                    shndx,
-                })
+                let shndx = self.symbol_section_indexes(shdr)?;
+                SectionDataRef::SymbolTable(self.symbol_table_from(symbol_names, entries, shndx))
             },
             SHT_REL => SectionDataRef::RelocationTable(RelTableRef {
                 symbol_table: self.linked_symbol_table(shdr.sh_link())?,
+                target: self.relocation_target(shdr),
                 entries: RelTableEntriesRef::try_from(shdr.construct_from(data))?,
             }),
             SHT_RELA => SectionDataRef::ExplicitRelocationTable(RelaTableRef {
                 symbol_table: self.linked_symbol_table(shdr.sh_link())?,
+                target: self.relocation_target(shdr),
                 entries: RelaTableEntriesRef::try_from(shdr.construct_from(data))?,
             }),
-            SHT_NOTE => SectionDataRef::NoteTable(NoteTableRef {
-                words: ElfSliceRef::try_from(shdr.construct_from(data))?,
-                data
+            SHT_RELR | SHT_ANDROID_RELR => SectionDataRef::RelativeRelocationTable(RelrTableRef {
+                entries: MachineWordsRef::try_from(shdr.construct_from(data))?,
+            }),
+            SHT_ANDROID_REL | SHT_ANDROID_RELA =>
+                SectionDataRef::AndroidPackedRelocationTable(AndroidPackedRelocationsRef::new(
+                    self.linked_symbol_table(shdr.sh_link())?,
+                    self.relocation_target(shdr),
+                    shdr.sh_type() == SHT_ANDROID_RELA,
+                    shdr.construct_from(data),
+                )),
+            SHT_DYNAMIC => SectionDataRef::DynamicTable(DynamicTableRef {
+                entries: DynamicEntriesRef::try_from(shdr.construct_from(data))?,
             }),
+            SHT_NOTE => SectionDataRef::NoteTable(NoteTableRef::try_from(shdr.construct_from(data),
+                                                                         shdr.sh_addralign())?),
+            SHT_MIPS_ABIFLAGS if self.ehdr.e_machine() == EM_MIPS =>
+                SectionDataRef::MipsAbiFlags(MipsAbiFlagsRef::try_from(shdr.construct_from(data))?),
             // TODO: Fill in the rest.
             _ => SectionDataRef::Uninterpreted(data),
         })
@@ -543,13 +821,41 @@ impl<'a> Reader<'a> {
         let shdr = self.section_headers().get(self.dynsym_index as usize)?;
         Ok(Some(self.get_section(shdr)?))
     }
+
+    pub fn dynamic(&self) -> Result<Option<SectionRef<'a>>> {
+        if self.dynamic_index == Elf_Word::from(SHN_UNDEF) {
+            return Ok(None);
+        }
+        let shdr = self.section_headers().get(self.dynamic_index as usize)?;
+        Ok(Some(self.get_section(shdr)?))
+    }
+
+    /// Returns the dynamic table from the [PT_DYNAMIC](constant.PT_DYNAMIC.html) segment or, for
+    /// object files without program headers, the [SHT_DYNAMIC](constant.SHT_DYNAMIC.html)
+    /// section.
+    pub fn dynamic_table(&self) -> Result<Option<DynamicTableRef<'a>>> {
+        if let Some(phdr) = self.program_headers().into_iter().find(|phdr| phdr.p_type() == PT_DYNAMIC) {
+            let data = self.segment_data(phdr).unwrap_or(&[][..]);
+            let entries = DynamicEntriesRef::try_from(phdr.construct_from(data))?;
+            return Ok(Some(DynamicTableRef { entries }));
+        }
+        match self.dynamic()? {
+            Some(SectionRef { data: SectionDataRef::DynamicTable(table), .. }) => Ok(Some(table)),
+            _                                                               => Ok(None),
+        }
+    }
 }
 
+#[derive(Clone, Copy)]
 pub struct StringTableRef<'a> {
     data: &'a [u8],
 }
 
 impl<'a> StringTableRef<'a> {
+    pub(super) fn new(data: &'a [u8]) -> Self {
+        StringTableRef { data }
+    }
+
     pub fn get_string<'b>(&'b self, index: Elf_Word) -> Option<&'a [u8]> where
         'a: 'b,
     {
@@ -579,13 +885,17 @@ pub enum SectionIndex {
     Reserved(Elf_Half),
 }
 
+#[derive(Debug, Clone, Copy)]
 pub struct SymbolRef<'a> {
     pub symbol_name: Option<&'a [u8]>,
+    /// The name of the section, for [STT_SECTION](constant.STT_SECTION.html) symbols.
+    pub section_name: Option<&'a [u8]>,
     pub section: SectionIndex,
     pub value: Elf64_Addr,
     pub size: Elf64_Xword,
     pub info: u8,
     pub other: u8,
+    machine: Elf_Half,
 }
 
 impl<'a> SymbolRef<'a> {
@@ -594,12 +904,61 @@ impl<'a> SymbolRef<'a> {
     }
 
     pub fn symbol_type(&self) -> u8 {
-        (self.info & 0xf)
+        self.info & 0xf
     }
 
     pub fn visibility(&self) -> u8 {
         self.other & 3
     }
+
+    /// Returns the name of the symbol, or the name of its section for
+    /// [STT_SECTION](constant.STT_SECTION.html) symbols, which are usually unnamed.
+    pub fn name(&self) -> Option<&'a [u8]> {
+        if self.symbol_type() == STT_SECTION {
+            self.section_name
+        } else {
+            self.symbol_name
+        }
+    }
+
+    /// Returns `true` if the symbol is an ARM function containing Thumb code.
+    ///
+    /// Bit 0 of the value of such a symbol is set and is not part of its address.
+    pub fn is_thumb(&self) -> bool {
+        self.machine == EM_ARM &&
+            match self.symbol_type() {
+                STT_FUNC      => self.value & 1 != 0,
+                STT_ARM_TFUNC => true,
+                _             => false,
+            }
+    }
+
+    /// Returns the offset of the local entry point from the global entry point of an ELFv2
+    /// PowerPC 64-bit function, encoded in `other`, or 0 if there is no separate local entry
+    /// point.
+    pub fn ppc64_local_entry_offset(&self) -> Elf64_Addr {
+        if self.machine != EM_PPC64 {
+            return 0;
+        }
+        let encoded = (self.other & STO_PPC64_LOCAL_MASK) >> STO_PPC64_LOCAL_BIT;
+        // Values 0 and 1 mean the local and global entry points coincide; values 2 through 6
+        // encode offsets of 4 through 64 bytes. Value 7 is reserved.
+        match encoded {
+            2..=6 => 1 << encoded,
+            _     => 0,
+        }
+    }
+
+    /// Returns the address of the symbol.
+    ///
+    /// This is the same as `value` except for [Thumb](#method.is_thumb) symbols.
+    pub fn address(&self) -> Elf64_Addr {
+        if self.is_thumb() {
+            self.value & !1
+        } else {
+            self.value
+        }
+    }
 }
 
 pub type ElfWordRef<'a> = ElfRef<'a, Elf32_Word, Elf64_Word>;
@@ -617,10 +976,14 @@ impl<'a> ElfWordRef<'a> {
 }
 
 
+#[derive(Clone, Copy)]
 pub struct SymbolTableRef<'a> {
     symbol_names: StringTableRef<'a>,
     entries: SymbolTableEntriesRef<'a>,
-    shndx: Option<ElfSliceRef<'a, Elf_Word, Elf_Word>>,
+    shndx: Option<ElfWordsRef<'a>>,
+    pub(super) machine: Elf_Half,
+    section_headers: SectionHeadersRef<'a>,
+    section_names: Option<StringTableRef<'a>>,
 }
 
 impl<'a> SymbolTableRef<'a> {
@@ -650,13 +1013,22 @@ impl<'a> SymbolTableRef<'a> {
             section = SectionIndex::Reserved(shndx);
         }
         let symbol_name = self.symbol_names.get_string(entry.st_name());
+        let section_name = match section {
+            SectionIndex::Normal(index) if entry.st_info() & 0xf == STT_SECTION => {
+                let shdr = self.section_headers.get(index as usize).ok();
+                self.section_names.and_then(|names| names.get_string(shdr?.sh_name()))
+            },
+            _ => None,
+        };
         Ok(SymbolRef {
             symbol_name,
+            section_name,
             section,
             value: entry.st_value(),
             size: entry.st_size(),
             info: entry.st_info(),
             other: entry.st_other(),
+            machine: self.machine,
         })
     }
 }
@@ -670,15 +1042,26 @@ impl<'a> RelTableEntryRef<'a> {
     field_impl!(r_offset, Elf32_Addr, Elf64_Addr);
     field_impl!(r_info,   Elf32_Word, Elf64_Xword);
 
+    /// Returns the symbol index using the generic `r_info` layout.
+    ///
+    /// Use [info](#method.info) for machines with a different layout, such as MIPS64.
     pub fn symbol_index(&self) -> Elf_Word {
         let info = self.r_info();
         self.apply(|_| info >> 8, |_| info >> 32) as Elf_Word
     }
 
+    /// Returns the relocation type using the generic `r_info` layout.
+    ///
+    /// Use [info](#method.info) for machines with a different layout, such as MIPS64.
     pub fn relocation_type(&self) -> Elf_Word {
         let info = self.r_info();
         self.apply(|_| info & 0xff, |_| info & 0xffff_ffff) as Elf_Word
     }
+
+    /// Decodes `r_info` according to the layout used by `machine`.
+    pub fn info(&self, machine: Elf_Half) -> RelocationInfo {
+        RelocationInfo::decode(self.construct_from(()), machine, self.r_info())
+    }
 }
 
 impl<'a> RelaTableEntryRef<'a> {
@@ -686,27 +1069,377 @@ impl<'a> RelaTableEntryRef<'a> {
     field_impl!(r_info,   Elf32_Word,  Elf64_Xword);
     field_impl!(r_addend, Elf32_Sword, Elf64_Sxword);
 
+    /// Returns the symbol index using the generic `r_info` layout.
+    ///
+    /// Use [info](#method.info) for machines with a different layout, such as MIPS64.
     pub fn symbol_index(&self) -> Elf_Word {
         let info = self.r_info();
         self.apply(|_| info >> 8, |_| info >> 32) as Elf_Word
     }
 
+    /// Returns the relocation type using the generic `r_info` layout.
+    ///
+    /// Use [info](#method.info) for machines with a different layout, such as MIPS64.
     pub fn relocation_type(&self) -> Elf_Word {
         let info = self.r_info();
         self.apply(|_| info & 0xff, |_| info & 0xffff_ffff) as Elf_Word
     }
+
+    /// Decodes `r_info` according to the layout used by `machine`.
+    pub fn info(&self, machine: Elf_Half) -> RelocationInfo {
+        RelocationInfo::decode(self.construct_from(()), machine, self.r_info())
+    }
+}
+
+/// A decoded `r_info` field of a relocation entry.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RelocationInfo {
+    pub symbol_index: Elf_Word,
+    /// The special symbol (`RSS_*`) of a MIPS64 relocation, or 0.
+    pub special_symbol: u8,
+    /// The relocation types, applied in order. Only MIPS64 relocations can have more than one
+    /// type; unused types are 0 (`R_*_NONE`).
+    pub types: [Elf_Word; 3],
+    /// The signed 24-bit type-specific data of a SPARC64 relocation (such as the second addend
+    /// of [R_SPARC_OLO10](constant.R_SPARC_OLO10.html)), or 0.
+    pub type_data: Elf_Sword,
+}
+
+impl RelocationInfo {
+    fn decode(format: ElfT<(), ()>, machine: Elf_Half, info: Elf64_Xword) -> RelocationInfo {
+        // MIPS64 splits r_info into a 32-bit symbol index followed by the special symbol and the
+        // third, second and first relocation type, one byte each, regardless of endianness.
+        let bytes = match (machine, format) {
+            (EM_MIPS, ElfT::Elf64LE(())) => Some([info as u32, (info >> 32) as u32]),
+            (EM_MIPS, ElfT::Elf64BE(())) => Some([(info >> 32) as u32, (info as u32).swap_bytes()]),
+            _                            => None,
+        };
+        match bytes {
+            Some([symbol_index, rest]) => RelocationInfo {
+                symbol_index,
+                special_symbol: rest as u8,
+                types: [rest >> 24, (rest >> 16) & 0xff, (rest >> 8) & 0xff],
+                type_data: 0,
+            },
+            // SPARC64 stores the type in the low 8 bits of the 32-bit type field and
+            // type-specific data in the remaining 24 bits.
+            None if machine == EM_SPARCV9 && format.is_64bit() => RelocationInfo {
+                symbol_index: (info >> 32) as Elf_Word,
+                special_symbol: 0,
+                types: [(info & 0xff) as Elf_Word, 0, 0],
+                type_data: ((info as Elf_Word) as Elf_Sword) >> 8,
+            },
+            None => RelocationInfo {
+                symbol_index: format.apply(|_| info >> 8, |_| info >> 32) as Elf_Word,
+                special_symbol: 0,
+                types: [format.apply(|_| info & 0xff, |_| info & 0xffff_ffff) as Elf_Word, 0, 0],
+                type_data: 0,
+            },
+        }
+    }
+
+    /// Returns the first relocation type.
+    pub fn relocation_type(&self) -> Elf_Word {
+        self.types[0]
+    }
 }
 
+#[derive(Clone, Copy)]
 pub struct RelTableRef<'a> {
     pub symbol_table: SymbolTableRef<'a>,
+    /// The section the relocations apply to (`sh_info`), if any.
+    pub target: Option<SectionHeaderRef<'a>>,
     pub entries: RelTableEntriesRef<'a>
 }
 
+#[derive(Clone, Copy)]
 pub struct RelaTableRef<'a> {
     pub symbol_table: SymbolTableRef<'a>,
+    /// The section the relocations apply to (`sh_info`), if any.
+    pub target: Option<SectionHeaderRef<'a>>,
     pub entries: RelaTableEntriesRef<'a>,
 }
 
+/// A relocation entry with its symbol resolved.
+#[derive(Debug, Clone, Copy)]
+pub struct RelocationRef<'a> {
+    pub offset: Elf64_Addr,
+    pub info: RelocationInfo,
+    /// The explicit addend, or `None` for relocations with implicit addends (Rel).
+    pub addend: Option<Elf64_Sxword>,
+    pub symbol: SymbolRef<'a>,
+}
+
+impl<'a> RelocationRef<'a> {
+    /// Returns the first relocation type.
+    pub fn relocation_type(&self) -> Elf_Word {
+        self.info.relocation_type()
+    }
+}
+
+impl<'a> RelTableRef<'a> {
+    /// Returns the decoded `r_info` field of the entry at `index`.
+    pub fn info(&self, index: usize) -> Result<RelocationInfo> {
+        Ok(self.entries.get(index)?.info(self.symbol_table.machine))
+    }
+
+    /// Returns the entry at `index` with its symbol resolved.
+    pub fn get(&self, index: usize) -> Result<RelocationRef<'a>> {
+        let entry = self.entries.get(index)?;
+        let info = entry.info(self.symbol_table.machine);
+        Ok(RelocationRef {
+            offset: entry.r_offset(),
+            info,
+            addend: None,
+            symbol: self.symbol_table.get(info.symbol_index as usize)?,
+        })
+    }
+}
+
+impl<'a> RelaTableRef<'a> {
+    /// Returns the decoded `r_info` field of the entry at `index`.
+    pub fn info(&self, index: usize) -> Result<RelocationInfo> {
+        Ok(self.entries.get(index)?.info(self.symbol_table.machine))
+    }
+
+    /// Returns the entry at `index` with its symbol resolved.
+    pub fn get(&self, index: usize) -> Result<RelocationRef<'a>> {
+        let entry = self.entries.get(index)?;
+        let info = entry.info(self.symbol_table.machine);
+        Ok(RelocationRef {
+            offset: entry.r_offset(),
+            info,
+            addend: Some(entry.r_addend()),
+            symbol: self.symbol_table.get(info.symbol_index as usize)?,
+        })
+    }
+
+    /// Returns, for each relocation, the index of the RISC-V `%pcrel_hi` relocation it is paired
+    /// with, if it is a `%pcrel_lo` relocation.
+    ///
+    /// The symbol of an [R_RISCV_PCREL_LO12_I](constant.R_RISCV_PCREL_LO12_I.html),
+    /// [R_RISCV_PCREL_LO12_S](constant.R_RISCV_PCREL_LO12_S.html),
+    /// [R_RISCV_TLSDESC_LOAD_LO12](constant.R_RISCV_TLSDESC_LOAD_LO12.html), or
+    /// [R_RISCV_TLSDESC_ADD_LO12](constant.R_RISCV_TLSDESC_ADD_LO12.html) relocation labels the
+    /// `auipc` instruction whose relocation holds the actual target; the low 12 bits are computed
+    /// from that relocation.
+    pub fn riscv_pcrel_hi_indexes(&self) -> Result<Vec<Option<usize>>> {
+        let mut indexes = vec![None; self.entries.len()];
+        if self.symbol_table.machine != EM_RISCV {
+            return Ok(indexes);
+        }
+        let mut hi_indexes = HashMap::new();
+        for (index, hi) in self.entries.iter().enumerate() {
+            if matches!(hi.info(self.symbol_table.machine).relocation_type(),
+                        R_RISCV_PCREL_HI20 | R_RISCV_GOT_HI20 | R_RISCV_TLS_GOT_HI20 |
+                        R_RISCV_TLS_GD_HI20 | R_RISCV_TLSDESC_HI20) {
+                hi_indexes.entry(hi.r_offset()).or_insert(index);
+            }
+        }
+        for (index, entry) in self.entries.iter().enumerate() {
+            let info = entry.info(self.symbol_table.machine);
+            match info.relocation_type() {
+                R_RISCV_PCREL_LO12_I | R_RISCV_PCREL_LO12_S |
+                R_RISCV_TLSDESC_LOAD_LO12 | R_RISCV_TLSDESC_ADD_LO12 => {},
+                _ => continue,
+            }
+            let label = self.symbol_table.get(info.symbol_index as usize)?;
+            let address = label.value.wrapping_add(entry.r_addend() as Elf64_Addr);
+            indexes[index] = hi_indexes.get(&address).cloned();
+        }
+        Ok(indexes)
+    }
+}
+
+/// A relocation table with implicit (Rel) or explicit (Rela) addends.
+#[derive(Clone, Copy)]
+pub enum RelocationTableRef<'a> {
+    Rel(RelTableRef<'a>),
+    Rela(RelaTableRef<'a>),
+}
+
+impl<'a> RelocationTableRef<'a> {
+    pub fn len(&self) -> usize {
+        match *self {
+            RelocationTableRef::Rel(ref table)  => table.entries.len(),
+            RelocationTableRef::Rela(ref table) => table.entries.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the section the relocations apply to, if any.
+    pub fn target(&self) -> Option<SectionHeaderRef<'a>> {
+        match *self {
+            RelocationTableRef::Rel(ref table)  => table.target,
+            RelocationTableRef::Rela(ref table) => table.target,
+        }
+    }
+
+    pub fn symbol_table(&self) -> SymbolTableRef<'a> {
+        match *self {
+            RelocationTableRef::Rel(ref table)  => table.symbol_table,
+            RelocationTableRef::Rela(ref table) => table.symbol_table,
+        }
+    }
+
+    /// Returns the entry at `index` with its symbol resolved.
+    pub fn get(&self, index: usize) -> Result<RelocationRef<'a>> {
+        match *self {
+            RelocationTableRef::Rel(ref table)  => table.get(index),
+            RelocationTableRef::Rela(ref table) => table.get(index),
+        }
+    }
+
+    /// Returns an iterator over the entries with their symbols resolved.
+    pub fn iter(&self) -> RelocationIter<'a> {
+        RelocationIter { table: *self, index: 0 }
+    }
+}
+
+impl<'a> From<RelTableRef<'a>> for RelocationTableRef<'a> {
+    fn from(table: RelTableRef<'a>) -> Self {
+        RelocationTableRef::Rel(table)
+    }
+}
+
+impl<'a> From<RelaTableRef<'a>> for RelocationTableRef<'a> {
+    fn from(table: RelaTableRef<'a>) -> Self {
+        RelocationTableRef::Rela(table)
+    }
+}
+
+impl<'a> iter::IntoIterator for RelocationTableRef<'a> {
+    type Item = Result<RelocationRef<'a>>;
+    type IntoIter = RelocationIter<'a>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+pub struct RelocationIter<'a> {
+    table: RelocationTableRef<'a>,
+    index: usize,
+}
+
+impl<'a> Iterator for RelocationIter<'a> {
+    type Item = Result<RelocationRef<'a>>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.table.len() {
+            return None;
+        }
+        let relocation = self.table.get(self.index);
+        self.index += 1;
+        Some(relocation)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.table.len().saturating_sub(self.index);
+        (remaining, Some(remaining))
+    }
+}
+
+/// A table of packed relative relocations (`SHT_RELR`).
+///
+/// An even entry is the address of a machine word to relocate. An odd entry is a bitmap: bit `i`
+/// (for `i` ≥ 1) marks the word `i - 1` words after the last word relocated by the previous
+/// entry. Each relocated word is adjusted by the load base, like an `R_*_RELATIVE` relocation.
+pub struct RelrTableRef<'a> {
+    pub entries: MachineWordsRef<'a>,
+}
+
+impl<'a> RelrTableRef<'a> {
+    /// Returns an iterator over the addresses relocated by the table.
+    pub fn addresses(&self) -> RelrIter<'a> {
+        RelrIter {
+            entries: self.entries,
+            index: 0,
+            next_address: 0,
+            bitmap: 0,
+            bitmap_address: 0,
+        }
+    }
+}
+
+pub struct RelrIter<'a> {
+    entries: MachineWordsRef<'a>,
+    index: usize,
+    // The address following the last word covered by an entry.
+    next_address: Elf64_Addr,
+    // The bits of the current bitmap which haven't been returned yet, shifted so that bit 0
+    // corresponds to bitmap_address.
+    bitmap: Elf64_Xword,
+    bitmap_address: Elf64_Addr,
+}
+
+impl<'a> Iterator for RelrIter<'a> {
+    type Item = Elf64_Addr;
+    fn next(&mut self) -> Option<Self::Item> {
+        let word_size = if self.entries.is_64bit() { 8 } else { 4 };
+        loop {
+            if self.bitmap != 0 {
+                let bit = Elf64_Addr::from(self.bitmap.trailing_zeros());
+                self.bitmap &= self.bitmap - 1;
+                return Some(self.bitmap_address.wrapping_add(bit * word_size));
+            }
+            let entry = self.entries.get(self.index).ok()?.get();
+            self.index += 1;
+            if entry & 1 == 0 {
+                self.next_address = entry.wrapping_add(word_size);
+                return Some(entry);
+            }
+            let bits = 8 * word_size - 1;
+            self.bitmap = entry >> 1;
+            self.bitmap_address = self.next_address;
+            self.next_address = self.next_address.wrapping_add(bits * word_size);
+        }
+    }
+}
+
+pub type DynamicEntryRef<'a> = ElfRef<'a, Elf32_Dyn, Elf64_Dyn>;
+pub type DynamicEntriesRef<'a> = ElfSliceRef<'a, Elf32_Dyn, Elf64_Dyn>;
+
+impl<'a> DynamicEntryRef<'a> {
+    field_impl!(d_tag, Elf32_Sword, Elf64_Sxword);
+    field_impl!(d_val, Elf32_Word,  Elf64_Xword);
+}
+
+/// The dynamic table (`SHT_DYNAMIC` section or `PT_DYNAMIC` segment).
+#[derive(Clone, Copy)]
+pub struct DynamicTableRef<'a> {
+    pub entries: DynamicEntriesRef<'a>,
+}
+
+impl<'a> DynamicTableRef<'a> {
+    /// Returns an iterator over the entries preceding the terminating `DT_NULL` entry.
+    pub fn iter(&self) -> impl Iterator<Item=DynamicEntryRef<'a>> {
+        self.entries.into_iter().take_while(|entry| entry.d_tag() != DT_NULL)
+    }
+
+    /// Returns the value of the first entry with tag `tag`, if any.
+    pub fn get(&self, tag: Elf_Sxword) -> Option<Elf64_Xword> {
+        self.iter().find(|entry| entry.d_tag() == tag).map(|entry| entry.d_val())
+    }
+}
+
+pub type MipsAbiFlagsRef<'a> = ElfRef<'a, Elf_MIPS_ABIFlags_v0, Elf_MIPS_ABIFlags_v0>;
+
+impl<'a> MipsAbiFlagsRef<'a> {
+    field_impl!(version, Elf_Half, Elf_Half);
+    noswap_field_impl!(isa_level, u8);
+    noswap_field_impl!(isa_rev,   u8);
+    noswap_field_impl!(gpr_size,  u8);
+    noswap_field_impl!(cpr1_size, u8);
+    noswap_field_impl!(cpr2_size, u8);
+    noswap_field_impl!(fp_abi,    u8);
+    field_impl!(isa_ext, Elf_Word, Elf_Word);
+    field_impl!(ases,    Elf_Word, Elf_Word);
+    field_impl!(flags1,  Elf_Word, Elf_Word);
+    field_impl!(flags2,  Elf_Word, Elf_Word);
+}
+
 pub struct NoteRef<'a> {
     pub name: Option<&'a [u8]>,
     pub desc: Option<&'a [u8]>,
@@ -717,65 +1450,67 @@ pub type MachineWordRef<'a> = ElfRef<'a, Elf32_Word, Elf64_Xword>;
 pub type MachineWordsRef<'a> = ElfSliceRef<'a, Elf32_Word, Elf64_Xword>;
 
 pub struct NoteIter<'a> {
-    word_iter: ElfIter<'a, Elf32_Word, Elf64_Xword>,
+    words: ElfWordsRef<'a>,
     data: &'a [u8],
+    align: usize,
 }
 
 impl<'a> Iterator for NoteIter<'a> {
     type Item = NoteRef<'a>;
     fn next(&mut self) -> Option<Self::Item> {
-        let namesz = if let Some(namesz) = self.word_iter.next().map(|w| w.get()) {
-            namesz as usize
-        } else {
+        // The namesz, descsz, and type fields are 4-byte words in both 32-bit and 64-bit object
+        // files. The name and descriptor are padded to the note's alignment (4 or 8).
+        let namesz = self.words.get(0).ok()?.value() as usize;
+        let descsz = self.words.get(1).ok()?.value() as usize;
+        let note_type = Elf_Xword::from(self.words.get(2).ok()?.value());
+        let mask = self.align - 1;
+        let name_start = 12;
+        let desc_start = (name_start + namesz + mask) & !mask;
+        let next = (desc_start + descsz + mask) & !mask;
+        if self.data.len() < desc_start + descsz {
             return None;
-        };
-        let descsz = if let Some(descsz) = self.word_iter.next().map(|w| w.get()) {
-            descsz as usize
+        }
+        // If namesz or descsz is 0, then the corresponding note doesn't have the field. The name
+        // is a null-terminated string of bytes; the descriptor is arbitrary.
+        let name = if namesz == 0 {
+            None
         } else {
-            return None;
+            let name = &self.data[name_start .. name_start+namesz];
+            Some(name.split_last().filter(|&(&last, _)| last == 0).map_or(name, |(_, name)| name))
         };
-        let note_type = if let Some(nt) = self.word_iter.next().map(|w| w.get()) {
-            nt
+        let desc = if descsz == 0 {
+            None
         } else {
-            return None;
+            Some(&self.data[desc_start .. desc_start+descsz])
         };
-        let (size, mask) = if self.word_iter.is_64bit() { (8, 7) } else { (4, 3) };
-        let mut offset = 3*size;
-        let total_size = offset + ((namesz + mask) & !mask) + ((descsz + mask) & !mask);
-        if self.data.len() < total_size {
-            return None;
-        }
-        // If namesz or descsz is 0, then the corresponding note doesn't have the field.
-        // Otherwise, they're the length of a null-terminated string of bytes.
-        let name;
-        if namesz == 0 {
-            name = None;
-        } else {
-            name = Some(&self.data[offset .. offset+namesz-1]);
-            offset += (namesz + mask) & !mask;
-        }
-        let desc;
-        if descsz == 0 {
-            desc = None;
-        } else {
-            desc = Some(&self.data[offset .. offset+descsz-1]);
-            offset += (descsz + mask) & !mask;
-        }
-        self.data = &self.data[offset ..];
+        let next = next.min(self.data.len()) & !3;
+        self.data = &self.data[next ..];
+        self.words = self.words.map(|w| &w[next/4 ..], |w| &w[next/4 ..]);
         Some(NoteRef { name, desc, note_type })
     }
 }
 
 pub struct NoteTableRef<'a> {
-    words: ElfSliceRef<'a, Elf32_Word, Elf64_Xword>,
+    words: ElfWordsRef<'a>,
     data: &'a [u8],
+    align: usize,
 }
 
 impl<'a> NoteTableRef<'a> {
+    pub(super) fn try_from(raw: ElfSliceRef<'a, u8, u8>, align: Elf_Xword) -> Result<Self> {
+        let data = raw.apply(|&s| s, |&s| s);
+        Ok(NoteTableRef {
+            words: ElfWordsRef::try_from(raw)?,
+            data,
+            align: if align == 8 { 8 } else { 4 },
+        })
+    }
+
     pub fn iter(&self) -> NoteIter<'a> {
         NoteIter {
+            words: self.words,
             data: self.data,
-            word_iter: self.words.iter(),
+            align: self.align,
         }
     }
 }
@@ -784,10 +1519,7 @@ impl<'a> iter::IntoIterator for NoteTableRef<'a> {
     type Item = NoteRef<'a>;
     type IntoIter = NoteIter<'a>;
     fn into_iter(self) -> Self::IntoIter {
-        NoteIter {
-            data: self.data,
-            word_iter: self.words.into_iter(),
-        }
+        self.iter()
     }
 }
 
@@ -807,9 +1539,21 @@ pub enum SectionDataRef<'a> {
     /// Section holds a slice of Elf_Words.
     ElfWords(ElfWordsRef<'a>),
 
+    /// Section holds a table of packed relative relocations (Relr).
+    RelativeRelocationTable(RelrTableRef<'a>),
+
+    /// Section holds Android packed relocations (`SHT_ANDROID_REL` or `SHT_ANDROID_RELA`).
+    AndroidPackedRelocationTable(AndroidPackedRelocationsRef<'a>),
+
+    /// Section holds the dynamic table.
+    DynamicTable(DynamicTableRef<'a>),
+
     /// Section holds a notes table.
     NoteTable(NoteTableRef<'a>),
 
+    /// Section holds MIPS ABI flags (`.MIPS.abiflags`).
+    MipsAbiFlags(MipsAbiFlagsRef<'a>),
+
     /// Section holds some uninterpreted data.
     Uninterpreted(&'a [u8]),
 
@@ -817,6 +1561,19 @@ pub enum SectionDataRef<'a> {
     NoBits,
 }
 
+impl<'a> SectionDataRef<'a> {
+    /// Returns the relocation table, if the section holds a
+    /// [RelocationTable](#variant.RelocationTable) or an
+    /// [ExplicitRelocationTable](#variant.ExplicitRelocationTable).
+    pub fn relocation_table(&self) -> Option<RelocationTableRef<'a>> {
+        match *self {
+            SectionDataRef::RelocationTable(table)         => Some(table.into()),
+            SectionDataRef::ExplicitRelocationTable(table) => Some(table.into()),
+            _                                              => None,
+        }
+    }
+}
+
 pub struct SectionRef<'a> {
     pub shdr: SectionHeaderRef<'a>,
     pub name: Option<&'a [u8]>,
@@ -0,0 +1,512 @@
+use constants::*;
+use error::*;
+use reader::*;
+use relocations::*;
+use types::*;
+
+struct Relocation {
+    offset: Elf64_Addr,
+    info: RelocationInfo,
+    addend: Option<Elf64_Sxword>,
+}
+
+/// Returns the contents of `section` of an `ET_REL` file with `relocations` applied, as if the
+/// section were loaded at `load_address`. The relocation table of a section is found with
+/// [Reader::relocations_for](struct.Reader.html#method.relocations_for).
+///
+/// `resolve` is called for each symbol referenced by a relocation and returns the symbol's
+/// address, or `None` if the symbol cannot be resolved. The values of symbols in a relocatable
+/// file are offsets into the section given by their section index, so `resolve` usually adds the
+/// address chosen for that section.
+///
+/// x86-64, i386, AArch64 and RISC-V relocations are supported. PLT-relative relocations are
+/// resolved directly to the symbol and the TLS offsets used by debugging information are computed
+/// from the address returned by `resolve`. Relocations which need a GOT or a dynamic linker are
+/// [unsupported](enum.Error.html#variant.UnsupportedRelocation). A relocated value which does not
+/// fit its field according to the relocation's [overflow check](enum.RelocationOverflow.html) is
+/// [out of range](enum.Error.html#variant.RelocationOutOfRange). `relocations` must apply to
+/// `section`, or the [linked section is invalid](enum.Error.html#variant.InvalidLinkedSection).
+pub fn relocate_section<'a, F>(reader: &Reader<'a>,
+                               section: &SectionRef<'a>,
+                               relocations: RelocationTableRef<'a>,
+                               load_address: Elf64_Addr,
+                               mut resolve: F) -> Result<Vec<u8>> where
+    F: FnMut(&SymbolRef<'a>) -> Option<Elf64_Addr>,
+{
+    let ehdr = reader.elf_header();
+    if ehdr.e_type() != ET_REL {
+        return Err(Error::InvalidHeaderField {
+            header: "ELF",
+            field: "e_type",
+            value: ehdr.e_type().into(),
+        });
+    }
+    let machine = ehdr.e_machine();
+    match machine {
+        EM_386 | EM_X86_64 | EM_AARCH64 | EM_RISCV => {},
+        _ => return Err(Error::InvalidHeaderField {
+            header: "ELF",
+            field: "e_machine",
+            value: machine.into(),
+        }),
+    }
+    let section_index = reader.section_index(section.shdr);
+    let target_index = relocations.target().and_then(|target| reader.section_index(target));
+    if section_index.is_none() || target_index != section_index {
+        return Err(Error::InvalidLinkedSection { linked: target_index.unwrap_or(0) });
+    }
+    let class = if reader.is_64bit() { ELFCLASS64 } else { ELFCLASS32 };
+    let mut data = match reader.uninterpreted_section_data(section.shdr) {
+        SectionDataRef::Uninterpreted(data) => data.to_vec(),
+        _ => return Err(Error::InvalidSectionType {
+            expected: SHT_PROGBITS,
+            actual: section.shdr.sh_type(),
+        }),
+    };
+
+    let mut entries = Vec::new();
+    match relocations {
+        RelocationTableRef::Rel(table) => for entry in table.entries {
+            entries.push(Relocation {
+                offset: entry.r_offset(),
+                info: entry.info(machine),
+                addend: None,
+            });
+        },
+        RelocationTableRef::Rela(table) => for entry in table.entries {
+            entries.push(Relocation {
+                offset: entry.r_offset(),
+                info: entry.info(machine),
+                addend: Some(entry.r_addend()),
+            });
+        },
+    }
+
+    let symbol_table = relocations.symbol_table();
+    // The %pcrel_hi relocations paired with RISC-V %pcrel_lo relocations, which only have
+    // explicit addends.
+    let hi_indexes = match relocations {
+        RelocationTableRef::Rela(table) if machine == EM_RISCV => Some(table.riscv_pcrel_hi_indexes()?),
+        _ => None,
+    };
+    let mut target = Target {
+        data: &mut data,
+        little_endian: reader.little_endian(),
+        class,
+    };
+    for (index, relocation) in entries.iter().enumerate() {
+        let relocation_type = relocation.info.relocation_type();
+        let unsupported = Error::UnsupportedRelocation { machine, relocation_type };
+        let description = relocation_description(machine, class, relocation_type)
+            .ok_or(unsupported)?;
+        let place = load_address.wrapping_add(relocation.offset);
+        let (symbol, size) =
+            resolve_symbol(&symbol_table, relocation.info.symbol_index, &mut resolve)?;
+        let addend = match relocation.addend {
+            Some(addend) => addend,
+            // Only i386 uses SHT_REL sections in relocatable files; its relocations are all
+            // data relocations whose field holds the addend.
+            None if machine == EM_386 => target.read_signed(relocation.offset, description.size)?,
+            None => return Err(Error::Msg { msg: "implicit addends are only supported for i386" }),
+        };
+        let value = symbol.wrapping_add(addend as Elf64_Addr);
+
+        let value = match (machine, relocation_type) {
+            (EM_X86_64, R_X86_64_DTPOFF32) | (EM_X86_64, R_X86_64_DTPOFF64) |
+            (EM_386, R_386_TLS_LDO_32) |
+            (EM_AARCH64, R_AARCH64_TLS_DTPREL) |
+            (EM_RISCV, R_RISCV_TLS_DTPREL32) | (EM_RISCV, R_RISCV_TLS_DTPREL64) => value,
+            (EM_RISCV, _) if description.formula == RelocationFormula::Other => {
+                match riscv_arithmetic(&mut target, relocation.offset, relocation_type, value)? {
+                    Some(value) => value,
+                    None => return Err(unsupported),
+                }
+            },
+            (EM_RISCV, R_RISCV_PCREL_LO12_I) | (EM_RISCV, R_RISCV_PCREL_LO12_S) => {
+                // The low 12 bits come from the value of the paired %pcrel_hi relocation.
+                let hi_indexes = match hi_indexes {
+                    Some(ref hi_indexes) => hi_indexes,
+                    None => return Err(unsupported),
+                };
+                let hi = hi_indexes[index]
+                    .map(|hi| &entries[hi])
+                    .ok_or(Error::Msg { msg: "%pcrel_lo relocation without %pcrel_hi" })?;
+                if hi.info.relocation_type() != R_RISCV_PCREL_HI20 {
+                    return Err(unsupported);
+                }
+                let (hi_symbol, _) =
+                    resolve_symbol(&symbol_table, hi.info.symbol_index, &mut resolve)?;
+                hi_symbol.wrapping_add(hi.addend.unwrap_or(0) as Elf64_Addr)
+                    .wrapping_sub(load_address.wrapping_add(hi.offset))
+            },
+            _ if description.got || description.tls || description.dynamic =>
+                return Err(unsupported),
+            _ => match description.formula {
+                RelocationFormula::None => continue,
+                RelocationFormula::Symbol => symbol,
+                RelocationFormula::Absolute | RelocationFormula::Plt => value,
+                RelocationFormula::PcRelative | RelocationFormula::PltPcRelative =>
+                    value.wrapping_sub(place),
+                RelocationFormula::PagePcRelative => (value & !0xfff).wrapping_sub(place & !0xfff),
+                RelocationFormula::Size => size.wrapping_add(addend as Elf64_Addr),
+                _ => return Err(unsupported),
+            },
+        };
+        let value = target.truncate(value);
+
+        let encoded = match machine {
+            EM_AARCH64 => encode_aarch64(&mut target, relocation.offset, &description, value)?,
+            EM_RISCV   => encode_riscv(&mut target, relocation.offset, &description, value)?,
+            _          => false,
+        };
+        if !encoded {
+            let bits = 8 * u32::from(description.size);
+            check_range(&description, relocation.offset, value, bits)?;
+            target.write(relocation.offset, description.size, value)?;
+        }
+    }
+    Ok(data)
+}
+
+fn resolve_symbol<'a, F>(symbol_table: &SymbolTableRef<'a>,
+                         index: Elf_Word,
+                         resolve: &mut F) -> Result<(Elf64_Addr, Elf64_Xword)> where
+    F: FnMut(&SymbolRef<'a>) -> Option<Elf64_Addr>,
+{
+    if index == 0 {
+        return Ok((0, 0));
+    }
+    let symbol = symbol_table.get(index as usize)?;
+    let address = resolve(&symbol).ok_or(Error::UnresolvedSymbol { symbol: index })?;
+    Ok((address, symbol.size))
+}
+
+fn fits(value: u64, bits: u32, overflow: RelocationOverflow) -> bool {
+    if bits >= 64 {
+        return true;
+    }
+    let signed = value as i64;
+    let fits_signed = signed >= -(1i64 << (bits - 1)) && signed < 1i64 << (bits - 1);
+    let fits_unsigned = value >> bits == 0;
+    match overflow {
+        RelocationOverflow::Unchecked => true,
+        RelocationOverflow::Signed    => fits_signed,
+        RelocationOverflow::Unsigned  => fits_unsigned,
+        RelocationOverflow::Bitfield  => fits_signed || fits_unsigned,
+    }
+}
+
+fn check_range(description: &RelocationDescription, offset: Elf64_Addr, value: u64, bits: u32)
+    -> Result<()>
+{
+    if fits(value, bits, description.overflow) {
+        Ok(())
+    } else {
+        Err(Error::RelocationOutOfRange { relocation: description.name, offset })
+    }
+}
+
+// The bytes of the section being relocated.
+struct Target<'d> {
+    data: &'d mut [u8],
+    little_endian: bool,
+    class: u8,
+}
+
+impl<'d> Target<'d> {
+    fn field(&mut self, offset: Elf64_Addr, size: u8) -> Result<&mut [u8]> {
+        let length = self.data.len();
+        let start = offset as usize;
+        match start.checked_add(size.into()) {
+            Some(end) if offset <= length as u64 && end <= length => Ok(&mut self.data[start..end]),
+            _ => Err(Error::IndexOutOfBounds { index: start, length }),
+        }
+    }
+
+    fn read_with(&mut self, offset: Elf64_Addr, size: u8, little_endian: bool) -> Result<u64> {
+        let field = self.field(offset, size)?;
+        Ok(if little_endian {
+            field.iter().rev().fold(0, |value, &b| value << 8 | u64::from(b))
+        } else {
+            field.iter().fold(0, |value, &b| value << 8 | u64::from(b))
+        })
+    }
+
+    fn write_with(&mut self, offset: Elf64_Addr, size: u8, value: u64, little_endian: bool)
+        -> Result<()>
+    {
+        let field = self.field(offset, size)?;
+        let size = size as usize;
+        for (i, b) in field.iter_mut().enumerate() {
+            let shift = if little_endian { i } else { size - 1 - i };
+            *b = (value >> (8 * shift)) as u8;
+        }
+        Ok(())
+    }
+
+    fn read(&mut self, offset: Elf64_Addr, size: u8) -> Result<u64> {
+        let little_endian = self.little_endian;
+        self.read_with(offset, size, little_endian)
+    }
+
+    fn read_signed(&mut self, offset: Elf64_Addr, size: u8) -> Result<i64> {
+        let value = self.read(offset, size)?;
+        let shift = 64 - 8 * u32::from(size);
+        Ok(if shift >= 64 { 0 } else { (value << shift) as i64 >> shift })
+    }
+
+    fn write(&mut self, offset: Elf64_Addr, size: u8, value: u64) -> Result<()> {
+        let little_endian = self.little_endian;
+        self.write_with(offset, size, value, little_endian)
+    }
+
+    // AArch64 and RISC-V instructions are always little endian.
+    fn read_instruction(&mut self, offset: Elf64_Addr, size: u8) -> Result<u32> {
+        Ok(self.read_with(offset, size, true)? as u32)
+    }
+
+    fn write_instruction(&mut self, offset: Elf64_Addr, size: u8, instruction: u32) -> Result<()> {
+        self.write_with(offset, size, instruction.into(), true)
+    }
+
+    // Reduces a computed value to the address size of the file, sign extending 32-bit values so
+    // that signed overflow checks work for both classes.
+    fn truncate(&self, value: u64) -> u64 {
+        if self.class == ELFCLASS32 {
+            value as u32 as i32 as i64 as u64
+        } else {
+            value
+        }
+    }
+
+    fn read_uleb128(&mut self, offset: Elf64_Addr) -> Result<(u64, u8)> {
+        let mut value = 0;
+        let mut length = 0;
+        loop {
+            let b = self.read(offset + u64::from(length), 1)?;
+            if length < 10 {
+                value |= (b & 0x7f) << (7 * u32::from(length));
+            }
+            length += 1;
+            if b & 0x80 == 0 {
+                return Ok((value, length));
+            }
+        }
+    }
+
+    // Overwrites an existing ULEB128 value, keeping its length.
+    fn write_uleb128(&mut self, offset: Elf64_Addr, length: u8, value: u64) -> Result<()> {
+        for i in 0..length {
+            let shift = 7 * u32::from(i);
+            let b = if shift < 64 { (value >> shift) & 0x7f } else { 0 };
+            let more = if i + 1 < length { 0x80 } else { 0 };
+            self.write(offset + u64::from(i), 1, b | more)?;
+        }
+        Ok(())
+    }
+}
+
+// The instruction fields patched by AArch64 relocations.
+#[derive(Clone, Copy)]
+enum AArch64Field {
+    // B and BL
+    Imm26,
+    // B.cond, CBZ, CBNZ and LDR (literal)
+    Imm19,
+    // TBZ and TBNZ
+    Imm14,
+    // ADR and ADRP
+    Adr,
+    // ADD and scaled LDR/STR offsets
+    Imm12 { scale: u32 },
+    // MOVZ and MOVK
+    Movw,
+    // MOVZ or MOVN, depending on the sign of the value
+    MovnMovz,
+}
+
+// Encodes the AArch64 instruction relocations, returning false for data relocations.
+fn encode_aarch64(target: &mut Target, offset: Elf64_Addr, description: &RelocationDescription,
+                  value: u64) -> Result<bool>
+{
+    use self::AArch64Field::*;
+    // The field, the number of significant bits of the value and the shift applied to the value
+    // before it's encoded.
+    let (field, bits, shift) = match description.relocation_type {
+        R_AARCH64_CALL26 | R_AARCH64_JUMP26                => (Imm26, 28, 2),
+        R_AARCH64_CONDBR19 | R_AARCH64_LD_PREL_LO19        => (Imm19, 21, 2),
+        R_AARCH64_TSTBR14                                  => (Imm14, 16, 2),
+        R_AARCH64_ADR_PREL_LO21                            => (Adr, 21, 0),
+        R_AARCH64_ADR_PREL_PG_HI21 |
+        R_AARCH64_ADR_PREL_PG_HI21_NC                      => (Adr, 33, 12),
+        R_AARCH64_ADD_ABS_LO12_NC |
+        R_AARCH64_LDST8_ABS_LO12_NC                        => (Imm12 { scale: 0 }, 12, 0),
+        R_AARCH64_LDST16_ABS_LO12_NC                       => (Imm12 { scale: 1 }, 12, 0),
+        R_AARCH64_LDST32_ABS_LO12_NC                       => (Imm12 { scale: 2 }, 12, 0),
+        R_AARCH64_LDST64_ABS_LO12_NC                       => (Imm12 { scale: 3 }, 12, 0),
+        R_AARCH64_LDST128_ABS_LO12_NC                      => (Imm12 { scale: 4 }, 12, 0),
+        R_AARCH64_MOVW_UABS_G0 | R_AARCH64_MOVW_UABS_G0_NC => (Movw, 16, 0),
+        R_AARCH64_MOVW_UABS_G1 | R_AARCH64_MOVW_UABS_G1_NC => (Movw, 32, 16),
+        R_AARCH64_MOVW_UABS_G2 | R_AARCH64_MOVW_UABS_G2_NC => (Movw, 48, 32),
+        R_AARCH64_MOVW_UABS_G3                             => (Movw, 64, 48),
+        R_AARCH64_MOVW_PREL_G0_NC                          => (Movw, 17, 0),
+        R_AARCH64_MOVW_PREL_G1_NC                          => (Movw, 33, 16),
+        R_AARCH64_MOVW_PREL_G2_NC                          => (Movw, 49, 32),
+        R_AARCH64_MOVW_SABS_G0 | R_AARCH64_MOVW_PREL_G0    => (MovnMovz, 17, 0),
+        R_AARCH64_MOVW_SABS_G1 | R_AARCH64_MOVW_PREL_G1    => (MovnMovz, 33, 16),
+        R_AARCH64_MOVW_SABS_G2 | R_AARCH64_MOVW_PREL_G2    => (MovnMovz, 49, 32),
+        R_AARCH64_MOVW_PREL_G3                             => (MovnMovz, 64, 48),
+        R_AARCH64_ABS64 | R_AARCH64_ABS32 | R_AARCH64_ABS16 |
+        R_AARCH64_PREL64 | R_AARCH64_PREL32 | R_AARCH64_PREL16 |
+        R_AARCH64_PLT32 | R_AARCH64_TLS_DTPREL => return Ok(false),
+        relocation_type => return Err(Error::UnsupportedRelocation {
+            machine: EM_AARCH64,
+            relocation_type,
+        }),
+    };
+    check_range(description, offset, value, bits)?;
+    let insn = target.read_instruction(offset, 4)?;
+    let imm = ((value as i64) >> shift) as u32;
+    let movw = |insn: u32, imm: u32| (insn & 0xffe0_001f) | ((imm & 0xffff) << 5);
+    let insn = match field {
+        Imm26              => (insn & 0xfc00_0000) | (imm & 0x03ff_ffff),
+        Imm19              => (insn & 0xff00_001f) | ((imm & 0x7ffff) << 5),
+        Imm14              => (insn & 0xfff8_001f) | ((imm & 0x3fff) << 5),
+        Adr                => (insn & 0x9f00_001f) | ((imm & 3) << 29) | ((imm >> 2 & 0x7ffff) << 5),
+        Imm12 { scale }    => (insn & 0xffc0_03ff) | (((imm & 0xfff) >> scale) << 10),
+        Movw               => movw(insn, imm),
+        // MOVN writes the complement of its immediate.
+        MovnMovz if (value as i64) < 0 => movw(insn & !(1 << 30), !imm),
+        MovnMovz           => movw(insn | (1 << 30), imm),
+    };
+    target.write_instruction(offset, 4, insn)?;
+    Ok(true)
+}
+
+// Computes the RISC-V relocations which combine the value with the current contents of the field,
+// returning None for relocations which aren't arithmetic.
+fn riscv_arithmetic(target: &mut Target, offset: Elf64_Addr, relocation_type: Elf_Word, value: u64)
+    -> Result<Option<u64>>
+{
+    let (size, add) = match relocation_type {
+        R_RISCV_ADD8  => (1, true),
+        R_RISCV_ADD16 => (2, true),
+        R_RISCV_ADD32 => (4, true),
+        R_RISCV_ADD64 => (8, true),
+        R_RISCV_SUB6 | R_RISCV_SUB8 => (1, false),
+        R_RISCV_SUB16 => (2, false),
+        R_RISCV_SUB32 => (4, false),
+        R_RISCV_SUB64 => (8, false),
+        R_RISCV_SUB_ULEB128 => {
+            let (current, _) = target.read_uleb128(offset)?;
+            return Ok(Some(current.wrapping_sub(value)));
+        },
+        _ => return Ok(None),
+    };
+    let current = target.read(offset, size)?;
+    Ok(Some(if add { current.wrapping_add(value) } else { current.wrapping_sub(value) }))
+}
+
+// The RISC-V instruction formats patched by relocations.
+#[derive(Clone, Copy)]
+enum RiscVFormat {
+    // Conditional branches
+    B,
+    // JAL
+    J,
+    // AUIPC and LUI
+    U,
+    // Loads, JALR and immediate arithmetic
+    I,
+    // Stores
+    S,
+    // AUIPC followed by JALR
+    UPlusI,
+    // C.BEQZ and C.BNEZ
+    CB,
+    // C.J and C.JAL
+    CJ,
+}
+
+// Encodes the RISC-V instruction relocations and the data relocations with unusual fields,
+// returning false for the remaining data relocations.
+fn encode_riscv(target: &mut Target, offset: Elf64_Addr, description: &RelocationDescription,
+                value: u64) -> Result<bool>
+{
+    use self::RiscVFormat::*;
+    // The instruction format and the number of significant bits of the value.
+    let (format, bits) = match description.relocation_type {
+        R_RISCV_BRANCH                        => (B, 13),
+        R_RISCV_JAL                           => (J, 21),
+        R_RISCV_CALL | R_RISCV_CALL_PLT       => (UPlusI, 32),
+        R_RISCV_PCREL_HI20 | R_RISCV_HI20     => (U, 32),
+        R_RISCV_PCREL_LO12_I | R_RISCV_LO12_I => (I, 12),
+        R_RISCV_PCREL_LO12_S | R_RISCV_LO12_S => (S, 12),
+        R_RISCV_RVC_BRANCH                    => (CB, 9),
+        R_RISCV_RVC_JUMP                      => (CJ, 12),
+        R_RISCV_SET6 | R_RISCV_SUB6 => {
+            let current = target.read(offset, 1)?;
+            target.write(offset, 1, (current & 0xc0) | (value & 0x3f))?;
+            return Ok(true);
+        },
+        R_RISCV_SET_ULEB128 | R_RISCV_SUB_ULEB128 => {
+            let (_, length) = target.read_uleb128(offset)?;
+            target.write_uleb128(offset, length, value)?;
+            return Ok(true);
+        },
+        R_RISCV_32 | R_RISCV_64 | R_RISCV_32_PCREL | R_RISCV_PLT32 |
+        R_RISCV_ADD8 | R_RISCV_ADD16 | R_RISCV_ADD32 | R_RISCV_ADD64 |
+        R_RISCV_SUB8 | R_RISCV_SUB16 | R_RISCV_SUB32 | R_RISCV_SUB64 |
+        R_RISCV_SET8 | R_RISCV_SET16 | R_RISCV_SET32 |
+        R_RISCV_TLS_DTPREL32 | R_RISCV_TLS_DTPREL64 => return Ok(false),
+        relocation_type => return Err(Error::UnsupportedRelocation {
+            machine: EM_RISCV,
+            relocation_type,
+        }),
+    };
+    // The upper 20 bits are rounded up when bit 11 is set, so the rounded value must fit.
+    let checked = match format {
+        U | UPlusI => value.wrapping_add(0x800),
+        _          => value,
+    };
+    check_range(description, offset, checked, bits)?;
+    let imm = value as u32;
+    // The upper 20 bits are rounded so that adding the sign-extended lower 12 bits produces the
+    // value.
+    let hi20 = value.wrapping_add(0x800) as u32 & 0xffff_f000;
+    let i_type = |insn: u32, imm: u32| (insn & 0x000f_ffff) | ((imm & 0xfff) << 20);
+    let u_type = |insn: u32| (insn & 0xfff) | hi20;
+    match format {
+        B | J | U | I | S => {
+            let insn = target.read_instruction(offset, 4)?;
+            let insn = match format {
+                B => (insn & 0x01ff_f07f) | ((imm >> 12 & 1) << 31) | ((imm >> 5 & 0x3f) << 25) |
+                     ((imm >> 1 & 0xf) << 8) | ((imm >> 11 & 1) << 7),
+                J => (insn & 0x0000_0fff) | ((imm >> 20 & 1) << 31) | ((imm >> 1 & 0x3ff) << 21) |
+                     ((imm >> 11 & 1) << 20) | ((imm >> 12 & 0xff) << 12),
+                U => u_type(insn),
+                I => i_type(insn, imm),
+                _ => (insn & 0x01ff_f07f) | ((imm >> 5 & 0x7f) << 25) | ((imm & 0x1f) << 7),
+            };
+            target.write_instruction(offset, 4, insn)?;
+        },
+        UPlusI => {
+            let auipc = target.read_instruction(offset, 4)?;
+            let jalr = target.read_instruction(offset + 4, 4)?;
+            target.write_instruction(offset, 4, u_type(auipc))?;
+            target.write_instruction(offset + 4, 4, i_type(jalr, imm))?;
+        },
+        CB | CJ => {
+            let insn = target.read_instruction(offset, 2)?;
+            let insn = match format {
+                CB => (insn & 0xe383) | ((imm >> 8 & 1) << 12) | ((imm >> 3 & 3) << 10) |
+                      ((imm >> 6 & 3) << 5) | ((imm >> 1 & 3) << 3) | ((imm >> 5 & 1) << 2),
+                _  => (insn & 0xe003) | ((imm >> 11 & 1) << 12) | ((imm >> 4 & 1) << 11) |
+                      ((imm >> 8 & 3) << 9) | ((imm >> 10 & 1) << 8) | ((imm >> 6 & 1) << 7) |
+                      ((imm >> 7 & 1) << 6) | ((imm >> 1 & 7) << 3) | ((imm >> 5 & 1) << 2),
+            };
+            target.write_instruction(offset, 2, insn)?;
+        },
+    }
+    Ok(true)
+}
@@ -1,82 +1,1453 @@
-use types::Elf_Word;
+use constants::*;
+use types::{Elf_Half, Elf_Word};
+
+/// How a relocation checks that the computed value fits into the relocated field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelocationOverflow {
+    /// The value is truncated to the field without any check.
+    Unchecked,
+    /// The value must fit into the field as a signed integer.
+    Signed,
+    /// The value must fit into the field as an unsigned integer.
+    Unsigned,
+    /// The value must fit into the field as either a signed or an unsigned integer.
+    Bitfield,
+}
+
+/// The value computed by a relocation before it is encoded into the relocated field.
+///
+/// The names follow the usual psABI notation: `S` is the symbol value, `A` the addend, `P` the
+/// place being relocated, `B` the load base, `Z` the symbol size, `G` the offset of the symbol's
+/// GOT entry, `GOT` the address of the GOT and `L` the address of the symbol's PLT entry.
+/// Relocations whose value does not fit any of these (TLS models, instruction-specific tricks,
+/// pointer authentication, ...) are reported as [Other](#variant.Other).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelocationFormula {
+    /// No value is computed (`R_*_NONE`, relaxation markers, ...).
+    None,
+    /// `S`
+    Symbol,
+    /// `S + A`
+    Absolute,
+    /// `S + A - P`
+    PcRelative,
+    /// `Page(S + A) - Page(P)`
+    PagePcRelative,
+    /// `B + A`
+    BaseRelative,
+    /// `Z + A`
+    Size,
+    /// `GOT + G` (the address of the GOT entry)
+    GotEntry,
+    /// `GOT + G + A - P`
+    GotEntryPcRelative,
+    /// `Page(GOT + G) - Page(P)`
+    GotEntryPagePcRelative,
+    /// `G + A`
+    GotOffset,
+    /// `S + A - GOT`
+    GotRelative,
+    /// `GOT + A - P`
+    GotPcRelative,
+    /// `L + A`
+    Plt,
+    /// `L + A - P`
+    PltPcRelative,
+    /// The symbol's data is copied at load time.
+    Copy,
+    /// Any other computation.
+    Other,
+}
+
+/// Machine-readable description of a relocation type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RelocationDescription {
+    pub relocation_type: Elf_Word,
+    pub name: &'static str,
+    /// Width of the relocated field in bytes (0 for relocations that do not write anything or
+    /// write a variable-length value like ULEB128).
+    pub size: u8,
+    pub overflow: RelocationOverflow,
+    pub formula: RelocationFormula,
+    /// The value is relative to the place being relocated.
+    pub pc_relative: bool,
+    /// The relocation refers to the GOT or requires a GOT entry.
+    pub got: bool,
+    /// The relocation refers to the PLT or requires a PLT entry.
+    pub plt: bool,
+    /// The relocation refers to thread-local storage.
+    pub tls: bool,
+    /// The relocation is only meaningful in dynamic relocation tables.
+    pub dynamic: bool,
+}
+
+// Flags used by the relocation tables.
+const PC: u8      = 1 << 0;
+const GOT: u8     = 1 << 1;
+const PLT: u8     = 1 << 2;
+const TLS: u8     = 1 << 3;
+const DYNAMIC: u8 = 1 << 4;
+
+fn has_flag(flags: u8, flag: u8) -> bool {
+    flags & flag != 0
+}
+
+/// Returns the description of relocation type `relocation_type` for machine `machine` and class
+/// `class` ([ELFCLASS32](constant.ELFCLASS32.html) or [ELFCLASS64](constant.ELFCLASS64.html)),
+/// or `None` if the relocation type is unknown.
+///
+/// For MIPS64 and SPARC64 relocations, `relocation_type` must be a single type as returned by
+/// [RelocationInfo](struct.RelocationInfo.html), not the raw `r_info` bits.
+///
+/// # Examples
+///
+/// Relocations of the x32 ABI (`EM_X86_64` with `ELFCLASS32`) which fill in a machine word are
+/// 4 bytes wide:
+///
+/// ```
+/// use elftk::*;
+///
+/// let size = |class, relocation_type| relocation_description(EM_X86_64, class, relocation_type).unwrap().size;
+/// assert_eq!(size(ELFCLASS64, R_X86_64_GLOB_DAT), 8);
+/// assert_eq!(size(ELFCLASS32, R_X86_64_GLOB_DAT), 4);
+/// assert_eq!(size(ELFCLASS32, R_X86_64_RELATIVE), 4);
+/// assert_eq!(size(ELFCLASS32, R_X86_64_64), 8);
+/// ```
+pub fn relocation_description(machine: Elf_Half, class: u8, relocation_type: Elf_Word)
+    -> Option<RelocationDescription>
+{
+    let word_size = if class == ELFCLASS64 { 8 } else { 4 };
+    match (machine, class) {
+        (EM_386, _)               => i386_relocation_description(relocation_type, word_size),
+        (EM_X86_64, _)            => x86_64_relocation_description(relocation_type, word_size),
+        (EM_AARCH64, ELFCLASS32)  => match relocation_type {
+            R_AARCH64_NONE => aarch64_relocation_description(relocation_type, word_size),
+            _              => aarch64_ilp32_relocation_description(relocation_type, word_size),
+        },
+        (EM_AARCH64, _)           => aarch64_relocation_description(relocation_type, word_size),
+        (EM_ARM, _)               => arm_relocation_description(relocation_type, word_size),
+        (EM_RISCV, _)             => riscv_relocation_description(relocation_type, word_size),
+        (EM_MIPS, _)              => mips_relocation_description(relocation_type, word_size),
+        (EM_PPC, _)               => ppc_relocation_description(relocation_type, word_size),
+        (EM_PPC64, _)             => ppc64_relocation_description(relocation_type, word_size),
+        (EM_S390, _)              => s390_relocation_description(relocation_type, word_size),
+        (EM_SPARC, _) | (EM_SPARC32PLUS, _) |
+        (EM_SPARCV9, _)           => sparc_relocation_description(relocation_type, word_size),
+        (EM_LOONGARCH, _)         => loongarch_relocation_description(relocation_type, word_size),
+        _                         => None,
+    }
+}
+
+/// Returns the name of relocation type `relocation_type` for machine `machine` and class `class`
+/// ([ELFCLASS32](constant.ELFCLASS32.html) or [ELFCLASS64](constant.ELFCLASS64.html)).
+///
+/// For MIPS64 and SPARC64 relocations, `relocation_type` must be a single type as returned by
+/// [RelocationInfo](struct.RelocationInfo.html), not the raw `r_info` bits.
+pub fn relocation_name(machine: Elf_Half, class: u8, relocation_type: Elf_Word) -> &'static str {
+    relocation_description(machine, class, relocation_type).map_or("<unknown>", |d| d.name)
+}
 
 // i386
-relocations!(i386_relocation_name, {
-    R_386_NONE          = 0,  // none   none
-    R_386_32            = 1,  // word32 S + A
-    R_386_PC32          = 2,  // word32 S + A - P
-    R_386_GOT32         = 3,  // word32 G + A - GOT
-    R_386_PLT32         = 4,  // word32 L + A - P
-    R_386_COPY          = 5,  // none   none
-    R_386_GLOB_DAT      = 6,  // word32 S
-    R_386_JUMP_SLOT     = 7,  // word32 S
-    R_386_RELATIVE      = 8,  // word32 B + A
-    R_386_GOTOFF        = 9,  // word32 S + A - GOT
-    R_386_GOTPC         = 10, // word32 GOT + A - P
-    R_386_TLS_TPOFF     = 14, // word32
-    R_386_TLS_IE        = 15, // word32
-    R_386_TLS_GOTIE     = 16, // word32
-    R_386_TLS_LE        = 17, // word32
-    R_386_TLS_GD        = 18, // word32
-    R_386_TLS_LDM       = 19, // word32
-    R_386_16            = 20, // word16 S + A
-    R_386_PC16          = 21, // word16 S + A - P
-    R_386_8             = 22, // word8  S + A
-    R_386_PC8           = 23, // word8  S + A - P
-    R_386_TLS_GD_32     = 24, // word32
-    R_386_TLS_GD_PUSH   = 25, // word32
-    R_386_TLS_GD_CALL   = 26, // word32
-    R_386_TLS_GD_POP    = 27, // word32
-    R_386_TLS_LDM_32    = 28, // word32
-    R_386_TLS_LDM_PUSH  = 29, // word32
-    R_386_TLS_LDM_CALL  = 30, // word32
-    R_386_TLS_LDM_POP   = 31, // word32
-    R_386_TLS_LDO_32    = 32, // word32
-    R_386_TLS_IE_32     = 33, // word32
-    R_386_TLS_LE_32     = 34, // word32
-    R_386_TLS_DTPMOD32  = 35, // word32
-    R_386_TLS_DTPOFF32  = 36, // word32
-    R_386_TLS_TPOFF32   = 37, // word32
-    R_386_SIZE32        = 38, // word32 Z + A
-    R_386_TLS_GOTDESC   = 39, // word32
-    R_386_TLS_DESC_CALL = 40, // none   none
-    R_386_TLS_DESC      = 41, // word32
-    R_386_IRELATIVE     = 42  // word32 indirect (B + A)
+relocations!(i386_relocation_name, i386_relocation_description, {
+    R_386_NONE          = (0,  0, Unchecked, None,          0),              // none   none
+    R_386_32            = (1,  4, Bitfield,  Absolute,      0),              // word32 S + A
+    R_386_PC32          = (2,  4, Signed,    PcRelative,    PC),             // word32 S + A - P
+    R_386_GOT32         = (3,  4, Unchecked, GotOffset,     GOT),            // word32 G + A
+    R_386_PLT32         = (4,  4, Unchecked, PltPcRelative, PC | PLT),       // word32 L + A - P
+    R_386_COPY          = (5,  0, Unchecked, Copy,          DYNAMIC),        // none   none
+    R_386_GLOB_DAT      = (6,  4, Unchecked, Symbol,        DYNAMIC),        // word32 S
+    R_386_JUMP_SLOT     = (7,  4, Unchecked, Symbol,        DYNAMIC),        // word32 S
+    R_386_RELATIVE      = (8,  4, Unchecked, BaseRelative,  DYNAMIC),        // word32 B + A
+    R_386_GOTOFF        = (9,  4, Unchecked, GotRelative,   GOT),            // word32 S + A - GOT
+    R_386_GOTPC         = (10, 4, Unchecked, GotPcRelative, PC | GOT),       // word32 GOT + A - P
+    R_386_TLS_TPOFF     = (14, 4, Unchecked, Other,         TLS | DYNAMIC),  // word32
+    R_386_TLS_IE        = (15, 4, Unchecked, Other,         GOT | TLS),      // word32
+    R_386_TLS_GOTIE     = (16, 4, Unchecked, Other,         GOT | TLS),      // word32
+    R_386_TLS_LE        = (17, 4, Unchecked, Other,         TLS),            // word32
+    R_386_TLS_GD        = (18, 4, Unchecked, Other,         GOT | TLS),      // word32
+    R_386_TLS_LDM       = (19, 4, Unchecked, Other,         GOT | TLS),      // word32
+    R_386_16            = (20, 2, Bitfield,  Absolute,      0),              // word16 S + A
+    R_386_PC16          = (21, 2, Signed,    PcRelative,    PC),             // word16 S + A - P
+    R_386_8             = (22, 1, Bitfield,  Absolute,      0),              // word8  S + A
+    R_386_PC8           = (23, 1, Signed,    PcRelative,    PC),             // word8  S + A - P
+    R_386_TLS_GD_32     = (24, 4, Unchecked, Other,         GOT | TLS),      // word32
+    R_386_TLS_GD_PUSH   = (25, 4, Unchecked, Other,         TLS),            // word32
+    R_386_TLS_GD_CALL   = (26, 4, Unchecked, Other,         TLS),            // word32
+    R_386_TLS_GD_POP    = (27, 4, Unchecked, Other,         TLS),            // word32
+    R_386_TLS_LDM_32    = (28, 4, Unchecked, Other,         GOT | TLS),      // word32
+    R_386_TLS_LDM_PUSH  = (29, 4, Unchecked, Other,         TLS),            // word32
+    R_386_TLS_LDM_CALL  = (30, 4, Unchecked, Other,         TLS),            // word32
+    R_386_TLS_LDM_POP   = (31, 4, Unchecked, Other,         TLS),            // word32
+    R_386_TLS_LDO_32    = (32, 4, Unchecked, Other,         TLS),            // word32
+    R_386_TLS_IE_32     = (33, 4, Unchecked, Other,         GOT | TLS),      // word32
+    R_386_TLS_LE_32     = (34, 4, Unchecked, Other,         TLS),            // word32
+    R_386_TLS_DTPMOD32  = (35, 4, Unchecked, Other,         TLS | DYNAMIC),  // word32
+    R_386_TLS_DTPOFF32  = (36, 4, Unchecked, Other,         TLS),            // word32
+    R_386_TLS_TPOFF32   = (37, 4, Unchecked, Other,         TLS | DYNAMIC),  // word32
+    R_386_SIZE32        = (38, 4, Unchecked, Size,          0),              // word32 Z + A
+    R_386_TLS_GOTDESC   = (39, 4, Unchecked, Other,         GOT | TLS),      // word32
+    R_386_TLS_DESC_CALL = (40, 0, Unchecked, None,          TLS),            // none   none
+    R_386_TLS_DESC      = (41, 4, Unchecked, Other,         TLS | DYNAMIC),  // word32
+    R_386_IRELATIVE     = (42, 4, Unchecked, Other,         DYNAMIC),        // word32 indirect (B + A)
+    R_386_GOT32X        = (43, 4, Unchecked, GotOffset,     GOT)             // word32 G + A - GOT
 });
 
 // x86-64
-relocations!(x86_64_relocation_name, {
-    R_X86_64_NONE            = 0,  // none none
-    R_X86_64_64              = 1,  // word64 S + A
-    R_X86_64_PC32            = 2,  // word32 S + A - P
-    R_X86_64_GOT32           = 3,  // word32 G + A
-    R_X86_64_PLT32           = 4,  // word32 L + A - P
-    R_X86_64_COPY            = 5,  // none none
-    R_X86_64_GLOB_DAT        = 6,  // word64 S
-    R_X86_64_JUMP_SLOT       = 7,  // word64 S
-    R_X86_64_RELATIVE        = 8,  // word64 B + A
-    R_X86_64_GOTPCREL        = 9,  // word32 G + GOT + A - P
-    R_X86_64_32              = 10, // word32 S + A
-    R_X86_64_32S             = 11, // word32 S + A
-    R_X86_64_16              = 12, // word16 S + A
-    R_X86_64_PC16            = 13, // word16 S + A - P
-    R_X86_64_8               = 14, // word8 S + A
-    R_X86_64_PC8             = 15, // word8 S + A - P
-    R_X86_64_DTPMOD64        = 16, // word64
-    R_X86_64_DTPOFF64        = 17, // word64
-    R_X86_64_TPOFF64         = 18, // word64
-    R_X86_64_TLSGD           = 19, // word32
-    R_X86_64_TLSLD           = 20, // word32
-    R_X86_64_DTPOFF32        = 21, // word32
-    R_X86_64_GOTTPOFF        = 22, // word32
-    R_X86_64_TPOFF32         = 23, // word32
-    R_X86_64_PC64            = 24, // word64 S + A - P
-    R_X86_64_GOTOFF64        = 25, // word64 S + A - GOT
-    R_X86_64_GOTPC32         = 26, // word32 GOT + A - P
-    R_X86_64_SIZE32          = 32, // word32 Z + A
-    R_X86_64_SIZE64          = 33, // word64 Z + A
-    R_X86_64_GOTPC32_TLSDESC = 34, // word32
-    R_X86_64_TLSDESC_CALL    = 35, // none
-    R_X86_64_TLSDESC         = 36, // word64×2
-    R_X86_64_IRELATIVE       = 37  // word64 indirect (B + A)
+relocations!(x86_64_relocation_name, x86_64_relocation_description, {
+    R_X86_64_NONE                   = (0,  0,    Unchecked, None,               0),               // none none
+    R_X86_64_64                     = (1,  8,    Unchecked, Absolute,           0),               // word64 S + A
+    R_X86_64_PC32                   = (2,  4,    Signed,    PcRelative,         PC),              // word32 S + A - P
+    R_X86_64_GOT32                  = (3,  4,    Signed,    GotOffset,          GOT),             // word32 G + A
+    R_X86_64_PLT32                  = (4,  4,    Signed,    PltPcRelative,      PC | PLT),        // word32 L + A - P
+    R_X86_64_COPY                   = (5,  0,    Unchecked, Copy,               DYNAMIC),         // none none
+    R_X86_64_GLOB_DAT               = (6,  WORD, Unchecked, Symbol,             DYNAMIC),         // wordclass S
+    R_X86_64_JUMP_SLOT              = (7,  WORD, Unchecked, Symbol,             DYNAMIC),         // wordclass S
+    R_X86_64_RELATIVE               = (8,  WORD, Unchecked, BaseRelative,       DYNAMIC),         // wordclass B + A
+    R_X86_64_GOTPCREL               = (9,  4,    Signed,    GotEntryPcRelative, PC | GOT),        // word32 G + GOT + A - P
+    R_X86_64_32                     = (10, 4,    Unsigned,  Absolute,           0),               // word32 S + A
+    R_X86_64_32S                    = (11, 4,    Signed,    Absolute,           0),               // word32 S + A
+    R_X86_64_16                     = (12, 2,    Bitfield,  Absolute,           0),               // word16 S + A
+    R_X86_64_PC16                   = (13, 2,    Signed,    PcRelative,         PC),              // word16 S + A - P
+    R_X86_64_8                      = (14, 1,    Bitfield,  Absolute,           0),               // word8 S + A
+    R_X86_64_PC8                    = (15, 1,    Signed,    PcRelative,         PC),              // word8 S + A - P
+    R_X86_64_DTPMOD64               = (16, 8,    Unchecked, Other,              TLS | DYNAMIC),   // word64
+    R_X86_64_DTPOFF64               = (17, 8,    Unchecked, Other,              TLS | DYNAMIC),   // word64
+    R_X86_64_TPOFF64                = (18, 8,    Unchecked, Other,              TLS | DYNAMIC),   // word64
+    R_X86_64_TLSGD                  = (19, 4,    Signed,    Other,              PC | GOT | TLS),  // word32
+    R_X86_64_TLSLD                  = (20, 4,    Signed,    Other,              PC | GOT | TLS),  // word32
+    R_X86_64_DTPOFF32               = (21, 4,    Signed,    Other,              TLS),             // word32
+    R_X86_64_GOTTPOFF               = (22, 4,    Signed,    Other,              PC | GOT | TLS),  // word32
+    R_X86_64_TPOFF32                = (23, 4,    Signed,    Other,              TLS),             // word32
+    R_X86_64_PC64                   = (24, 8,    Unchecked, PcRelative,         PC),              // word64 S + A - P
+    R_X86_64_GOTOFF64               = (25, 8,    Unchecked, GotRelative,        GOT),             // word64 S + A - GOT
+    R_X86_64_GOTPC32                = (26, 4,    Signed,    GotPcRelative,      PC | GOT),        // word32 GOT + A - P
+    R_X86_64_GOT64                  = (27, 8,    Unchecked, GotOffset,          GOT),             // word64 G + A
+    R_X86_64_GOTPCREL64             = (28, 8,    Unchecked, GotEntryPcRelative, PC | GOT),        // word64 G + GOT - P + A
+    R_X86_64_GOTPC64                = (29, 8,    Unchecked, GotPcRelative,      PC | GOT),        // word64 GOT - P + A
+    R_X86_64_GOTPLT64               = (30, 8,    Unchecked, GotOffset,          GOT),             // word64 G + A
+    R_X86_64_PLTOFF64               = (31, 8,    Unchecked, Other,              GOT | PLT),       // word64 L - GOT + A
+    R_X86_64_SIZE32                 = (32, 4,    Unsigned,  Size,               0),               // word32 Z + A
+    R_X86_64_SIZE64                 = (33, 8,    Unchecked, Size,               0),               // word64 Z + A
+    R_X86_64_GOTPC32_TLSDESC        = (34, 4,    Signed,    Other,              PC | GOT | TLS),  // word32
+    R_X86_64_TLSDESC_CALL           = (35, 0,    Unchecked, None,               TLS),             // none
+    R_X86_64_TLSDESC                = (36, 16,   Unchecked, Other,              TLS | DYNAMIC),   // word64×2
+    R_X86_64_IRELATIVE              = (37, WORD, Unchecked, Other,              DYNAMIC),         // wordclass indirect (B + A)
+    R_X86_64_RELATIVE64             = (38, 8,    Unchecked, BaseRelative,       DYNAMIC),         // word64 B + A
+    R_X86_64_GOTPCRELX              = (41, 4,    Signed,    GotEntryPcRelative, PC | GOT),        // word32 G + GOT + A - P
+    R_X86_64_REX_GOTPCRELX          = (42, 4,    Signed,    GotEntryPcRelative, PC | GOT),        // word32 G + GOT + A - P
+    R_X86_64_CODE_4_GOTPCRELX       = (43, 4,    Signed,    GotEntryPcRelative, PC | GOT),        // word32 G + GOT + A - P
+    R_X86_64_CODE_4_GOTTPOFF        = (44, 4,    Signed,    Other,              PC | GOT | TLS),  // word32
+    R_X86_64_CODE_4_GOTPC32_TLSDESC = (45, 4,    Signed,    Other,              PC | GOT | TLS)   // word32
+});
+
+// AArch64 (LP64)
+relocations!(aarch64_relocation_name, aarch64_relocation_description, {
+    R_AARCH64_NONE                         = (0,      0,  Unchecked, None,                   0),               // none   none
+    R_AARCH64_NONE_WITHDRAWN               = (256,    0,  Unchecked, None,                   0),               // none   none
+
+    // Static data relocations
+    R_AARCH64_ABS64                        = (257,    8,  Unchecked, Absolute,               0),               // word64 S + A
+    R_AARCH64_ABS32                        = (258,    4,  Bitfield,  Absolute,               0),               // word32 S + A
+    R_AARCH64_ABS16                        = (259,    2,  Bitfield,  Absolute,               0),               // word16 S + A
+    R_AARCH64_PREL64                       = (260,    8,  Unchecked, PcRelative,             PC),              // word64 S + A - P
+    R_AARCH64_PREL32                       = (261,    4,  Bitfield,  PcRelative,             PC),              // word32 S + A - P
+    R_AARCH64_PREL16                       = (262,    2,  Bitfield,  PcRelative,             PC),              // word16 S + A - P
+
+    // Static AArch64 relocations
+    R_AARCH64_MOVW_UABS_G0                 = (263,    4,  Unsigned,  Absolute,               0),               // movw   S + A
+    R_AARCH64_MOVW_UABS_G0_NC              = (264,    4,  Unchecked, Absolute,               0),               // movk   S + A
+    R_AARCH64_MOVW_UABS_G1                 = (265,    4,  Unsigned,  Absolute,               0),               // movw   S + A
+    R_AARCH64_MOVW_UABS_G1_NC              = (266,    4,  Unchecked, Absolute,               0),               // movk   S + A
+    R_AARCH64_MOVW_UABS_G2                 = (267,    4,  Unsigned,  Absolute,               0),               // movw   S + A
+    R_AARCH64_MOVW_UABS_G2_NC              = (268,    4,  Unchecked, Absolute,               0),               // movk   S + A
+    R_AARCH64_MOVW_UABS_G3                 = (269,    4,  Unsigned,  Absolute,               0),               // movw   S + A
+    R_AARCH64_MOVW_SABS_G0                 = (270,    4,  Signed,    Absolute,               0),               // movw   S + A
+    R_AARCH64_MOVW_SABS_G1                 = (271,    4,  Signed,    Absolute,               0),               // movw   S + A
+    R_AARCH64_MOVW_SABS_G2                 = (272,    4,  Signed,    Absolute,               0),               // movw   S + A
+    R_AARCH64_LD_PREL_LO19                 = (273,    4,  Signed,    PcRelative,             PC),              // ld     S + A - P
+    R_AARCH64_ADR_PREL_LO21                = (274,    4,  Signed,    PcRelative,             PC),              // adr    S + A - P
+    R_AARCH64_ADR_PREL_PG_HI21             = (275,    4,  Signed,    PagePcRelative,         PC),              // adrp   Page(S + A) - Page(P)
+    R_AARCH64_ADR_PREL_PG_HI21_NC          = (276,    4,  Unchecked, PagePcRelative,         PC),              // adrp   Page(S + A) - Page(P)
+    R_AARCH64_ADD_ABS_LO12_NC              = (277,    4,  Unchecked, Absolute,               0),               // add    S + A
+    R_AARCH64_LDST8_ABS_LO12_NC            = (278,    4,  Unchecked, Absolute,               0),               // ldst8  S + A
+    R_AARCH64_TSTBR14                      = (279,    4,  Signed,    PcRelative,             PC),              // tbz    S + A - P
+    R_AARCH64_CONDBR19                     = (280,    4,  Signed,    PcRelative,             PC),              // b.cond S + A - P
+    R_AARCH64_JUMP26                       = (282,    4,  Signed,    PcRelative,             PC),              // b      S + A - P
+    R_AARCH64_CALL26                       = (283,    4,  Signed,    PcRelative,             PC),              // bl     S + A - P
+    R_AARCH64_LDST16_ABS_LO12_NC           = (284,    4,  Unchecked, Absolute,               0),               // ldst16 S + A
+    R_AARCH64_LDST32_ABS_LO12_NC           = (285,    4,  Unchecked, Absolute,               0),               // ldst32 S + A
+    R_AARCH64_LDST64_ABS_LO12_NC           = (286,    4,  Unchecked, Absolute,               0),               // ldst64 S + A
+    R_AARCH64_MOVW_PREL_G0                 = (287,    4,  Signed,    PcRelative,             PC),              // movw   S + A - P
+    R_AARCH64_MOVW_PREL_G0_NC              = (288,    4,  Unchecked, PcRelative,             PC),              // movk   S + A - P
+    R_AARCH64_MOVW_PREL_G1                 = (289,    4,  Signed,    PcRelative,             PC),              // movw   S + A - P
+    R_AARCH64_MOVW_PREL_G1_NC              = (290,    4,  Unchecked, PcRelative,             PC),              // movk   S + A - P
+    R_AARCH64_MOVW_PREL_G2                 = (291,    4,  Signed,    PcRelative,             PC),              // movw   S + A - P
+    R_AARCH64_MOVW_PREL_G2_NC              = (292,    4,  Unchecked, PcRelative,             PC),              // movk   S + A - P
+    R_AARCH64_MOVW_PREL_G3                 = (293,    4,  Signed,    PcRelative,             PC),              // movw   S + A - P
+    R_AARCH64_LDST128_ABS_LO12_NC          = (299,    4,  Unchecked, Absolute,               0),               // ldst128 S + A
+    R_AARCH64_MOVW_GOTOFF_G0               = (300,    4,  Signed,    GotOffset,              GOT),             // movw   G(GDAT(S + A)) - GOT
+    R_AARCH64_MOVW_GOTOFF_G0_NC            = (301,    4,  Unchecked, GotOffset,              GOT),             // movk   G(GDAT(S + A)) - GOT
+    R_AARCH64_MOVW_GOTOFF_G1               = (302,    4,  Signed,    GotOffset,              GOT),             // movw   G(GDAT(S + A)) - GOT
+    R_AARCH64_MOVW_GOTOFF_G1_NC            = (303,    4,  Unchecked, GotOffset,              GOT),             // movk   G(GDAT(S + A)) - GOT
+    R_AARCH64_MOVW_GOTOFF_G2               = (304,    4,  Signed,    GotOffset,              GOT),             // movw   G(GDAT(S + A)) - GOT
+    R_AARCH64_MOVW_GOTOFF_G2_NC            = (305,    4,  Unchecked, GotOffset,              GOT),             // movk   G(GDAT(S + A)) - GOT
+    R_AARCH64_MOVW_GOTOFF_G3               = (306,    4,  Signed,    GotOffset,              GOT),             // movw   G(GDAT(S + A)) - GOT
+    R_AARCH64_GOTREL64                     = (307,    8,  Unchecked, GotRelative,            GOT),             // word64 S + A - GOT
+    R_AARCH64_GOTREL32                     = (308,    4,  Signed,    GotRelative,            GOT),             // word32 S + A - GOT
+    R_AARCH64_GOT_LD_PREL19                = (309,    4,  Signed,    GotEntryPcRelative,     PC | GOT),        // ld     G(GDAT(S + A)) - P
+    R_AARCH64_LD64_GOTOFF_LO15             = (310,    4,  Unsigned,  GotOffset,              GOT),             // ld64   G(GDAT(S + A)) - GOT
+    R_AARCH64_ADR_GOT_PAGE                 = (311,    4,  Signed,    GotEntryPagePcRelative, PC | GOT),        // adrp   Page(G(GDAT(S + A))) - Page(P)
+    R_AARCH64_LD64_GOT_LO12_NC             = (312,    4,  Unchecked, GotEntry,               GOT),             // ld64   G(GDAT(S + A))
+    R_AARCH64_LD64_GOTPAGE_LO15            = (313,    4,  Unsigned,  Other,                  GOT),             // ld64   G(GDAT(S + A)) - Page(GOT)
+    R_AARCH64_PLT32                        = (314,    4,  Signed,    PcRelative,             PC | PLT),        // word32 S + A - P
+    R_AARCH64_GOTPCREL32                   = (315,    4,  Signed,    GotEntryPcRelative,     PC | GOT),        // word32 G(GDAT(S + A)) - P
+
+    // Thread-local storage relocations
+    R_AARCH64_TLSGD_ADR_PREL21             = (512,    4,  Signed,    Other,                  PC | GOT | TLS),  // adr    G(GTLSIDX(S, A)) - P
+    R_AARCH64_TLSGD_ADR_PAGE21             = (513,    4,  Signed,    Other,                  PC | GOT | TLS),  // adrp   Page(G(GTLSIDX(S, A))) - Page(P)
+    R_AARCH64_TLSGD_ADD_LO12_NC            = (514,    4,  Unchecked, Other,                  GOT | TLS),       // add    G(GTLSIDX(S, A))
+    R_AARCH64_TLSGD_MOVW_G1                = (515,    4,  Signed,    Other,                  GOT | TLS),       // movw   G(GTLSIDX(S, A)) - GOT
+    R_AARCH64_TLSGD_MOVW_G0_NC             = (516,    4,  Unchecked, Other,                  GOT | TLS),       // movk   G(GTLSIDX(S, A)) - GOT
+    R_AARCH64_TLSLD_ADR_PREL21             = (517,    4,  Signed,    Other,                  PC | GOT | TLS),  // adr    G(GLDM(S)) - P
+    R_AARCH64_TLSLD_ADR_PAGE21             = (518,    4,  Signed,    Other,                  PC | GOT | TLS),  // adrp   Page(G(GLDM(S))) - Page(P)
+    R_AARCH64_TLSLD_ADD_LO12_NC            = (519,    4,  Unchecked, Other,                  GOT | TLS),       // add    G(GLDM(S))
+    R_AARCH64_TLSLD_MOVW_G1                = (520,    4,  Signed,    Other,                  GOT | TLS),       // movw   G(GLDM(S)) - GOT
+    R_AARCH64_TLSLD_MOVW_G0_NC             = (521,    4,  Unchecked, Other,                  GOT | TLS),       // movk   G(GLDM(S)) - GOT
+    R_AARCH64_TLSLD_LD_PREL19              = (522,    4,  Signed,    Other,                  PC | GOT | TLS),  // ld     G(GLDM(S)) - P
+    R_AARCH64_TLSLD_MOVW_DTPREL_G2         = (523,    4,  Signed,    Other,                  TLS),             // movw   DTPREL(S + A)
+    R_AARCH64_TLSLD_MOVW_DTPREL_G1         = (524,    4,  Signed,    Other,                  TLS),             // movw   DTPREL(S + A)
+    R_AARCH64_TLSLD_MOVW_DTPREL_G1_NC      = (525,    4,  Unchecked, Other,                  TLS),             // movk   DTPREL(S + A)
+    R_AARCH64_TLSLD_MOVW_DTPREL_G0         = (526,    4,  Signed,    Other,                  TLS),             // movw   DTPREL(S + A)
+    R_AARCH64_TLSLD_MOVW_DTPREL_G0_NC      = (527,    4,  Unchecked, Other,                  TLS),             // movk   DTPREL(S + A)
+    R_AARCH64_TLSLD_ADD_DTPREL_HI12        = (528,    4,  Unsigned,  Other,                  TLS),             // add    DTPREL(S + A)
+    R_AARCH64_TLSLD_ADD_DTPREL_LO12        = (529,    4,  Unsigned,  Other,                  TLS),             // add    DTPREL(S + A)
+    R_AARCH64_TLSLD_ADD_DTPREL_LO12_NC     = (530,    4,  Unchecked, Other,                  TLS),             // add    DTPREL(S + A)
+    R_AARCH64_TLSLD_LDST8_DTPREL_LO12      = (531,    4,  Unsigned,  Other,                  TLS),             // ldst8  DTPREL(S + A)
+    R_AARCH64_TLSLD_LDST8_DTPREL_LO12_NC   = (532,    4,  Unchecked, Other,                  TLS),             // ldst8  DTPREL(S + A)
+    R_AARCH64_TLSLD_LDST16_DTPREL_LO12     = (533,    4,  Unsigned,  Other,                  TLS),             // ldst16 DTPREL(S + A)
+    R_AARCH64_TLSLD_LDST16_DTPREL_LO12_NC  = (534,    4,  Unchecked, Other,                  TLS),             // ldst16 DTPREL(S + A)
+    R_AARCH64_TLSLD_LDST32_DTPREL_LO12     = (535,    4,  Unsigned,  Other,                  TLS),             // ldst32 DTPREL(S + A)
+    R_AARCH64_TLSLD_LDST32_DTPREL_LO12_NC  = (536,    4,  Unchecked, Other,                  TLS),             // ldst32 DTPREL(S + A)
+    R_AARCH64_TLSLD_LDST64_DTPREL_LO12     = (537,    4,  Unsigned,  Other,                  TLS),             // ldst64 DTPREL(S + A)
+    R_AARCH64_TLSLD_LDST64_DTPREL_LO12_NC  = (538,    4,  Unchecked, Other,                  TLS),             // ldst64 DTPREL(S + A)
+    R_AARCH64_TLSIE_MOVW_GOTTPREL_G1       = (539,    4,  Signed,    Other,                  GOT | TLS),       // movw   G(GTPREL(S + A)) - GOT
+    R_AARCH64_TLSIE_MOVW_GOTTPREL_G0_NC    = (540,    4,  Unchecked, Other,                  GOT | TLS),       // movk   G(GTPREL(S + A)) - GOT
+    R_AARCH64_TLSIE_ADR_GOTTPREL_PAGE21    = (541,    4,  Signed,    Other,                  PC | GOT | TLS),  // adrp   Page(G(GTPREL(S + A))) - Page(P)
+    R_AARCH64_TLSIE_LD64_GOTTPREL_LO12_NC  = (542,    4,  Unchecked, Other,                  GOT | TLS),       // ld64   G(GTPREL(S + A))
+    R_AARCH64_TLSIE_LD_GOTTPREL_PREL19     = (543,    4,  Signed,    Other,                  PC | GOT | TLS),  // ld     G(GTPREL(S + A)) - P
+    R_AARCH64_TLSLE_MOVW_TPREL_G2          = (544,    4,  Signed,    Other,                  TLS),             // movw   TPREL(S + A)
+    R_AARCH64_TLSLE_MOVW_TPREL_G1          = (545,    4,  Signed,    Other,                  TLS),             // movw   TPREL(S + A)
+    R_AARCH64_TLSLE_MOVW_TPREL_G1_NC       = (546,    4,  Unchecked, Other,                  TLS),             // movk   TPREL(S + A)
+    R_AARCH64_TLSLE_MOVW_TPREL_G0          = (547,    4,  Signed,    Other,                  TLS),             // movw   TPREL(S + A)
+    R_AARCH64_TLSLE_MOVW_TPREL_G0_NC       = (548,    4,  Unchecked, Other,                  TLS),             // movk   TPREL(S + A)
+    R_AARCH64_TLSLE_ADD_TPREL_HI12         = (549,    4,  Unsigned,  Other,                  TLS),             // add    TPREL(S + A)
+    R_AARCH64_TLSLE_ADD_TPREL_LO12         = (550,    4,  Unsigned,  Other,                  TLS),             // add    TPREL(S + A)
+    R_AARCH64_TLSLE_ADD_TPREL_LO12_NC      = (551,    4,  Unchecked, Other,                  TLS),             // add    TPREL(S + A)
+    R_AARCH64_TLSLE_LDST8_TPREL_LO12       = (552,    4,  Unsigned,  Other,                  TLS),             // ldst8  TPREL(S + A)
+    R_AARCH64_TLSLE_LDST8_TPREL_LO12_NC    = (553,    4,  Unchecked, Other,                  TLS),             // ldst8  TPREL(S + A)
+    R_AARCH64_TLSLE_LDST16_TPREL_LO12      = (554,    4,  Unsigned,  Other,                  TLS),             // ldst16 TPREL(S + A)
+    R_AARCH64_TLSLE_LDST16_TPREL_LO12_NC   = (555,    4,  Unchecked, Other,                  TLS),             // ldst16 TPREL(S + A)
+    R_AARCH64_TLSLE_LDST32_TPREL_LO12      = (556,    4,  Unsigned,  Other,                  TLS),             // ldst32 TPREL(S + A)
+    R_AARCH64_TLSLE_LDST32_TPREL_LO12_NC   = (557,    4,  Unchecked, Other,                  TLS),             // ldst32 TPREL(S + A)
+    R_AARCH64_TLSLE_LDST64_TPREL_LO12      = (558,    4,  Unsigned,  Other,                  TLS),             // ldst64 TPREL(S + A)
+    R_AARCH64_TLSLE_LDST64_TPREL_LO12_NC   = (559,    4,  Unchecked, Other,                  TLS),             // ldst64 TPREL(S + A)
+    R_AARCH64_TLSDESC_LD_PREL19            = (560,    4,  Signed,    Other,                  PC | GOT | TLS),  // ld     G(GTLSDESC(S + A)) - P
+    R_AARCH64_TLSDESC_ADR_PREL21           = (561,    4,  Signed,    Other,                  PC | GOT | TLS),  // adr    G(GTLSDESC(S + A)) - P
+    R_AARCH64_TLSDESC_ADR_PAGE21           = (562,    4,  Signed,    Other,                  PC | GOT | TLS),  // adrp   Page(G(GTLSDESC(S + A))) - Page(P)
+    R_AARCH64_TLSDESC_LD64_LO12            = (563,    4,  Unchecked, Other,                  GOT | TLS),       // ld64   G(GTLSDESC(S + A))
+    R_AARCH64_TLSDESC_ADD_LO12             = (564,    4,  Unchecked, Other,                  GOT | TLS),       // add    G(GTLSDESC(S + A))
+    R_AARCH64_TLSDESC_OFF_G1               = (565,    4,  Signed,    Other,                  GOT | TLS),       // movw   G(GTLSDESC(S + A)) - GOT
+    R_AARCH64_TLSDESC_OFF_G0_NC            = (566,    4,  Unchecked, Other,                  GOT | TLS),       // movk   G(GTLSDESC(S + A)) - GOT
+    R_AARCH64_TLSDESC_LDR                  = (567,    0,  Unchecked, None,                   TLS),             // none   none
+    R_AARCH64_TLSDESC_ADD                  = (568,    0,  Unchecked, None,                   TLS),             // none   none
+    R_AARCH64_TLSDESC_CALL                 = (569,    0,  Unchecked, None,                   TLS),             // none   none
+    R_AARCH64_TLSLE_LDST128_TPREL_LO12     = (570,    4,  Unsigned,  Other,                  TLS),             // ldst128 TPREL(S + A)
+    R_AARCH64_TLSLE_LDST128_TPREL_LO12_NC  = (571,    4,  Unchecked, Other,                  TLS),             // ldst128 TPREL(S + A)
+    R_AARCH64_TLSLD_LDST128_DTPREL_LO12    = (572,    4,  Unsigned,  Other,                  TLS),             // ldst128 DTPREL(S + A)
+    R_AARCH64_TLSLD_LDST128_DTPREL_LO12_NC = (573,    4,  Unchecked, Other,                  TLS),             // ldst128 DTPREL(S + A)
+
+    // Pointer authentication (PAuth ABI) static relocations
+    R_AARCH64_AUTH_ABS64                   = (0x244,  8,  Unchecked, Other,                  0),               // word64 SIGN(S + A, SCHEMA)
+    R_AARCH64_AUTH_MOVW_GOTOFF_G0          = (0x8101, 4,  Signed,    Other,                  GOT),             // movw   G(ENCD(GDAT(S + A))) - GOT
+    R_AARCH64_AUTH_MOVW_GOTOFF_G0_NC       = (0x8102, 4,  Unchecked, Other,                  GOT),             // movk   G(ENCD(GDAT(S + A))) - GOT
+    R_AARCH64_AUTH_MOVW_GOTOFF_G1          = (0x8103, 4,  Signed,    Other,                  GOT),             // movw   G(ENCD(GDAT(S + A))) - GOT
+    R_AARCH64_AUTH_MOVW_GOTOFF_G1_NC       = (0x8104, 4,  Unchecked, Other,                  GOT),             // movk   G(ENCD(GDAT(S + A))) - GOT
+    R_AARCH64_AUTH_MOVW_GOTOFF_G2          = (0x8105, 4,  Signed,    Other,                  GOT),             // movw   G(ENCD(GDAT(S + A))) - GOT
+    R_AARCH64_AUTH_MOVW_GOTOFF_G2_NC       = (0x8106, 4,  Unchecked, Other,                  GOT),             // movk   G(ENCD(GDAT(S + A))) - GOT
+    R_AARCH64_AUTH_MOVW_GOTOFF_G3          = (0x8107, 4,  Signed,    Other,                  GOT),             // movw   G(ENCD(GDAT(S + A))) - GOT
+    R_AARCH64_AUTH_GOT_LD_PREL19           = (0x8108, 4,  Signed,    Other,                  PC | GOT),        // ld     G(ENCD(GDAT(S + A))) - P
+    R_AARCH64_AUTH_LD64_GOTOFF_LO15        = (0x8109, 4,  Unsigned,  Other,                  GOT),             // ld64   G(ENCD(GDAT(S + A))) - GOT
+    R_AARCH64_AUTH_ADR_GOT_PAGE            = (0x810a, 4,  Signed,    Other,                  PC | GOT),        // adrp   Page(G(ENCD(GDAT(S + A)))) - Page(P)
+    R_AARCH64_AUTH_LD64_GOT_LO12_NC        = (0x810b, 4,  Unchecked, Other,                  GOT),             // ld64   G(ENCD(GDAT(S + A)))
+    R_AARCH64_AUTH_LD64_GOTPAGE_LO15       = (0x810c, 4,  Unsigned,  Other,                  GOT),             // ld64   G(ENCD(GDAT(S + A))) - Page(GOT)
+    R_AARCH64_AUTH_GOT_ADD_LO12_NC         = (0x810d, 4,  Unchecked, Other,                  GOT),             // add    G(ENCD(GDAT(S + A)))
+    R_AARCH64_AUTH_GOT_ADR_PREL_LO21       = (0x810e, 4,  Signed,    Other,                  PC | GOT),        // adr    G(ENCD(GDAT(S + A))) - P
+    R_AARCH64_AUTH_TLSDESC_ADR_PAGE21      = (0x810f, 4,  Signed,    Other,                  PC | GOT | TLS),  // adrp   Page(G(ENCD(GTLSDESC(S + A)))) - Page(P)
+    R_AARCH64_AUTH_TLSDESC_LD64_LO12       = (0x8110, 4,  Unsigned,  Other,                  GOT | TLS),       // ld64   G(ENCD(GTLSDESC(S + A)))
+    R_AARCH64_AUTH_TLSDESC_ADD_LO12        = (0x8111, 4,  Unsigned,  Other,                  GOT | TLS),       // add    G(ENCD(GTLSDESC(S + A)))
+
+    // Dynamic relocations
+    R_AARCH64_COPY                         = (1024,   0,  Unchecked, Copy,                   DYNAMIC),         // none   none
+    R_AARCH64_GLOB_DAT                     = (1025,   8,  Unchecked, Absolute,               DYNAMIC),         // word64 S + A
+    R_AARCH64_JUMP_SLOT                    = (1026,   8,  Unchecked, Absolute,               DYNAMIC),         // word64 S + A
+    R_AARCH64_RELATIVE                     = (1027,   8,  Unchecked, BaseRelative,           DYNAMIC),         // word64 Delta(S) + A
+    R_AARCH64_TLS_DTPMOD                   = (1028,   8,  Unchecked, Other,                  TLS | DYNAMIC),   // word64 LDM(S)
+    R_AARCH64_TLS_DTPREL                   = (1029,   8,  Unchecked, Other,                  TLS | DYNAMIC),   // word64 DTPREL(S + A)
+    R_AARCH64_TLS_TPREL                    = (1030,   8,  Unchecked, Other,                  TLS | DYNAMIC),   // word64 TPREL(S + A)
+    R_AARCH64_TLSDESC                      = (1031,   16, Unchecked, Other,                  TLS | DYNAMIC),   // word64×2 TLSDESC(S + A)
+    R_AARCH64_IRELATIVE                    = (1032,   8,  Unchecked, Other,                  DYNAMIC),         // word64 Indirect(Delta(S) + A)
+
+    // Pointer authentication (PAuth ABI) dynamic relocations
+    R_AARCH64_AUTH_RELATIVE                = (0x411,  8,  Unchecked, Other,                  DYNAMIC),         // word64 SIGN(Delta(S) + A, SCHEMA)
+    R_AARCH64_AUTH_GLOB_DAT                = (0x412,  8,  Unchecked, Other,                  DYNAMIC),         // word64 SIGN(S + A, SCHEMA)
+    R_AARCH64_AUTH_TLSDESC                 = (0x413,  16, Unchecked, Other,                  TLS | DYNAMIC),   // word64×2 SIGN(TLSDESC(S + A), SCHEMA)
+    R_AARCH64_AUTH_IRELATIVE               = (0x414,  8,  Unchecked, Other,                  DYNAMIC)          // word64 SIGN(Indirect(Delta(S) + A), SCHEMA)
+});
+
+// AArch64 (ILP32)
+//
+// R_AARCH64_NONE is shared with the LP64 relocations.
+relocations!(aarch64_ilp32_relocation_name, aarch64_ilp32_relocation_description, {
+    // Static data relocations
+    R_AARCH64_P32_ABS32                        = (1,   4, Bitfield,  Absolute,               0),               // word32 S + A
+    R_AARCH64_P32_ABS16                        = (2,   2, Bitfield,  Absolute,               0),               // word16 S + A
+    R_AARCH64_P32_PREL32                       = (3,   4, Bitfield,  PcRelative,             PC),              // word32 S + A - P
+    R_AARCH64_P32_PREL16                       = (4,   2, Bitfield,  PcRelative,             PC),              // word16 S + A - P
+
+    // Static AArch64 relocations
+    R_AARCH64_P32_MOVW_UABS_G0                 = (5,   4, Unsigned,  Absolute,               0),               // movw   S + A
+    R_AARCH64_P32_MOVW_UABS_G0_NC              = (6,   4, Unchecked, Absolute,               0),               // movk   S + A
+    R_AARCH64_P32_MOVW_UABS_G1                 = (7,   4, Unsigned,  Absolute,               0),               // movw   S + A
+    R_AARCH64_P32_MOVW_SABS_G0                 = (8,   4, Signed,    Absolute,               0),               // movw   S + A
+    R_AARCH64_P32_LD_PREL_LO19                 = (9,   4, Signed,    PcRelative,             PC),              // ld     S + A - P
+    R_AARCH64_P32_ADR_PREL_LO21                = (10,  4, Signed,    PcRelative,             PC),              // adr    S + A - P
+    R_AARCH64_P32_ADR_PREL_PG_HI21             = (11,  4, Signed,    PagePcRelative,         PC),              // adrp   Page(S + A) - Page(P)
+    R_AARCH64_P32_ADD_ABS_LO12_NC              = (12,  4, Unchecked, Absolute,               0),               // add    S + A
+    R_AARCH64_P32_LDST8_ABS_LO12_NC            = (13,  4, Unchecked, Absolute,               0),               // ldst8  S + A
+    R_AARCH64_P32_LDST16_ABS_LO12_NC           = (14,  4, Unchecked, Absolute,               0),               // ldst16 S + A
+    R_AARCH64_P32_LDST32_ABS_LO12_NC           = (15,  4, Unchecked, Absolute,               0),               // ldst32 S + A
+    R_AARCH64_P32_LDST64_ABS_LO12_NC           = (16,  4, Unchecked, Absolute,               0),               // ldst64 S + A
+    R_AARCH64_P32_LDST128_ABS_LO12_NC          = (17,  4, Unchecked, Absolute,               0),               // ldst128 S + A
+    R_AARCH64_P32_TSTBR14                      = (18,  4, Signed,    PcRelative,             PC),              // tbz    S + A - P
+    R_AARCH64_P32_CONDBR19                     = (19,  4, Signed,    PcRelative,             PC),              // b.cond S + A - P
+    R_AARCH64_P32_JUMP26                       = (20,  4, Signed,    PcRelative,             PC),              // b      S + A - P
+    R_AARCH64_P32_CALL26                       = (21,  4, Signed,    PcRelative,             PC),              // bl     S + A - P
+    R_AARCH64_P32_MOVW_PREL_G0                 = (22,  4, Signed,    PcRelative,             PC),              // movw   S + A - P
+    R_AARCH64_P32_MOVW_PREL_G0_NC              = (23,  4, Unchecked, PcRelative,             PC),              // movk   S + A - P
+    R_AARCH64_P32_MOVW_PREL_G1                 = (24,  4, Signed,    PcRelative,             PC),              // movw   S + A - P
+    R_AARCH64_P32_GOT_LD_PREL19                = (25,  4, Signed,    GotEntryPcRelative,     PC | GOT),        // ld     G(GDAT(S + A)) - P
+    R_AARCH64_P32_ADR_GOT_PAGE                 = (26,  4, Signed,    GotEntryPagePcRelative, PC | GOT),        // adrp   Page(G(GDAT(S + A))) - Page(P)
+    R_AARCH64_P32_LD32_GOT_LO12_NC             = (27,  4, Unchecked, GotEntry,               GOT),             // ld32   G(GDAT(S + A))
+    R_AARCH64_P32_LD32_GOTPAGE_LO14            = (28,  4, Signed,    Other,                  GOT),             // ld32   G(GDAT(S + A)) - Page(GOT)
+    R_AARCH64_P32_PLT32                        = (29,  4, Signed,    PcRelative,             PC | PLT),        // word32 S + A - P
+
+    // Thread-local storage relocations
+    R_AARCH64_P32_TLSGD_ADR_PREL21             = (80,  4, Signed,    Other,                  PC | GOT | TLS),  // adr    G(GTLSIDX(S, A)) - P
+    R_AARCH64_P32_TLSGD_ADR_PAGE21             = (81,  4, Signed,    Other,                  PC | GOT | TLS),  // adrp   Page(G(GTLSIDX(S, A))) - Page(P)
+    R_AARCH64_P32_TLSGD_ADD_LO12_NC            = (82,  4, Unchecked, Other,                  GOT | TLS),       // add    G(GTLSIDX(S, A))
+    R_AARCH64_P32_TLSLD_ADR_PREL21             = (83,  4, Signed,    Other,                  PC | GOT | TLS),  // adr    G(GLDM(S)) - P
+    R_AARCH64_P32_TLSLD_ADR_PAGE21             = (84,  4, Signed,    Other,                  PC | GOT | TLS),  // adrp   Page(G(GLDM(S))) - Page(P)
+    R_AARCH64_P32_TLSLD_ADD_LO12_NC            = (85,  4, Unchecked, Other,                  GOT | TLS),       // add    G(GLDM(S))
+    R_AARCH64_P32_TLSLD_LD_PREL19              = (86,  4, Signed,    Other,                  PC | GOT | TLS),  // ld     G(GLDM(S)) - P
+    R_AARCH64_P32_TLSLD_MOVW_DTPREL_G1         = (87,  4, Signed,    Other,                  TLS),             // movw   DTPREL(S + A)
+    R_AARCH64_P32_TLSLD_MOVW_DTPREL_G0         = (88,  4, Signed,    Other,                  TLS),             // movw   DTPREL(S + A)
+    R_AARCH64_P32_TLSLD_MOVW_DTPREL_G0_NC      = (89,  4, Unchecked, Other,                  TLS),             // movk   DTPREL(S + A)
+    R_AARCH64_P32_TLSLD_ADD_DTPREL_HI12        = (90,  4, Unsigned,  Other,                  TLS),             // add    DTPREL(S + A)
+    R_AARCH64_P32_TLSLD_ADD_DTPREL_LO12        = (91,  4, Unsigned,  Other,                  TLS),             // add    DTPREL(S + A)
+    R_AARCH64_P32_TLSLD_ADD_DTPREL_LO12_NC     = (92,  4, Unchecked, Other,                  TLS),             // add    DTPREL(S + A)
+    R_AARCH64_P32_TLSLD_LDST8_DTPREL_LO12      = (93,  4, Unsigned,  Other,                  TLS),             // ldst8  DTPREL(S + A)
+    R_AARCH64_P32_TLSLD_LDST8_DTPREL_LO12_NC   = (94,  4, Unchecked, Other,                  TLS),             // ldst8  DTPREL(S + A)
+    R_AARCH64_P32_TLSLD_LDST16_DTPREL_LO12     = (95,  4, Unsigned,  Other,                  TLS),             // ldst16 DTPREL(S + A)
+    R_AARCH64_P32_TLSLD_LDST16_DTPREL_LO12_NC  = (96,  4, Unchecked, Other,                  TLS),             // ldst16 DTPREL(S + A)
+    R_AARCH64_P32_TLSLD_LDST32_DTPREL_LO12     = (97,  4, Unsigned,  Other,                  TLS),             // ldst32 DTPREL(S + A)
+    R_AARCH64_P32_TLSLD_LDST32_DTPREL_LO12_NC  = (98,  4, Unchecked, Other,                  TLS),             // ldst32 DTPREL(S + A)
+    R_AARCH64_P32_TLSLD_LDST64_DTPREL_LO12     = (99,  4, Unsigned,  Other,                  TLS),             // ldst64 DTPREL(S + A)
+    R_AARCH64_P32_TLSLD_LDST64_DTPREL_LO12_NC  = (100, 4, Unchecked, Other,                  TLS),             // ldst64 DTPREL(S + A)
+    R_AARCH64_P32_TLSLD_LDST128_DTPREL_LO12    = (101, 4, Unsigned,  Other,                  TLS),             // ldst128 DTPREL(S + A)
+    R_AARCH64_P32_TLSLD_LDST128_DTPREL_LO12_NC = (102, 4, Unchecked, Other,                  TLS),             // ldst128 DTPREL(S + A)
+    R_AARCH64_P32_TLSIE_ADR_GOTTPREL_PAGE21    = (103, 4, Signed,    Other,                  PC | GOT | TLS),  // adrp   Page(G(GTPREL(S + A))) - Page(P)
+    R_AARCH64_P32_TLSIE_LD32_GOTTPREL_LO12_NC  = (104, 4, Unchecked, Other,                  GOT | TLS),       // ld32   G(GTPREL(S + A))
+    R_AARCH64_P32_TLSIE_LD_GOTTPREL_PREL19     = (105, 4, Signed,    Other,                  PC | GOT | TLS),  // ld     G(GTPREL(S + A)) - P
+    R_AARCH64_P32_TLSLE_MOVW_TPREL_G1          = (106, 4, Signed,    Other,                  TLS),             // movw   TPREL(S + A)
+    R_AARCH64_P32_TLSLE_MOVW_TPREL_G0          = (107, 4, Signed,    Other,                  TLS),             // movw   TPREL(S + A)
+    R_AARCH64_P32_TLSLE_MOVW_TPREL_G0_NC       = (108, 4, Unchecked, Other,                  TLS),             // movk   TPREL(S + A)
+    R_AARCH64_P32_TLSLE_ADD_TPREL_HI12         = (109, 4, Unsigned,  Other,                  TLS),             // add    TPREL(S + A)
+    R_AARCH64_P32_TLSLE_ADD_TPREL_LO12         = (110, 4, Unsigned,  Other,                  TLS),             // add    TPREL(S + A)
+    R_AARCH64_P32_TLSLE_ADD_TPREL_LO12_NC      = (111, 4, Unchecked, Other,                  TLS),             // add    TPREL(S + A)
+    R_AARCH64_P32_TLSLE_LDST8_TPREL_LO12       = (112, 4, Unsigned,  Other,                  TLS),             // ldst8  TPREL(S + A)
+    R_AARCH64_P32_TLSLE_LDST8_TPREL_LO12_NC    = (113, 4, Unchecked, Other,                  TLS),             // ldst8  TPREL(S + A)
+    R_AARCH64_P32_TLSLE_LDST16_TPREL_LO12      = (114, 4, Unsigned,  Other,                  TLS),             // ldst16 TPREL(S + A)
+    R_AARCH64_P32_TLSLE_LDST16_TPREL_LO12_NC   = (115, 4, Unchecked, Other,                  TLS),             // ldst16 TPREL(S + A)
+    R_AARCH64_P32_TLSLE_LDST32_TPREL_LO12      = (116, 4, Unsigned,  Other,                  TLS),             // ldst32 TPREL(S + A)
+    R_AARCH64_P32_TLSLE_LDST32_TPREL_LO12_NC   = (117, 4, Unchecked, Other,                  TLS),             // ldst32 TPREL(S + A)
+    R_AARCH64_P32_TLSLE_LDST64_TPREL_LO12      = (118, 4, Unsigned,  Other,                  TLS),             // ldst64 TPREL(S + A)
+    R_AARCH64_P32_TLSLE_LDST64_TPREL_LO12_NC   = (119, 4, Unchecked, Other,                  TLS),             // ldst64 TPREL(S + A)
+    R_AARCH64_P32_TLSLE_LDST128_TPREL_LO12     = (120, 4, Unsigned,  Other,                  TLS),             // ldst128 TPREL(S + A)
+    R_AARCH64_P32_TLSLE_LDST128_TPREL_LO12_NC  = (121, 4, Unchecked, Other,                  TLS),             // ldst128 TPREL(S + A)
+    R_AARCH64_P32_TLSDESC_LD_PREL19            = (122, 4, Signed,    Other,                  PC | GOT | TLS),  // ld     G(GTLSDESC(S + A)) - P
+    R_AARCH64_P32_TLSDESC_ADR_PREL21           = (123, 4, Signed,    Other,                  PC | GOT | TLS),  // adr    G(GTLSDESC(S + A)) - P
+    R_AARCH64_P32_TLSDESC_ADR_PAGE21           = (124, 4, Signed,    Other,                  PC | GOT | TLS),  // adrp   Page(G(GTLSDESC(S + A))) - Page(P)
+    R_AARCH64_P32_TLSDESC_LD32_LO12            = (125, 4, Unsigned,  Other,                  GOT | TLS),       // ld32   G(GTLSDESC(S + A))
+    R_AARCH64_P32_TLSDESC_ADD_LO12             = (126, 4, Unchecked, Other,                  GOT | TLS),       // add    G(GTLSDESC(S + A))
+    R_AARCH64_P32_TLSDESC_CALL                 = (127, 0, Unchecked, None,                   TLS),             // none   none
+
+    // Dynamic relocations
+    R_AARCH64_P32_COPY                         = (180, 0, Unchecked, Copy,                   DYNAMIC),         // none   none
+    R_AARCH64_P32_GLOB_DAT                     = (181, 4, Signed,    Absolute,               DYNAMIC),         // word32 S + A
+    R_AARCH64_P32_JUMP_SLOT                    = (182, 4, Signed,    Absolute,               DYNAMIC),         // word32 S + A
+    R_AARCH64_P32_RELATIVE                     = (183, 4, Signed,    BaseRelative,           DYNAMIC),         // word32 Delta(S) + A
+    R_AARCH64_P32_TLS_DTPMOD                   = (184, 4, Signed,    Other,                  TLS | DYNAMIC),   // word32 LDM(S)
+    R_AARCH64_P32_TLS_DTPREL                   = (185, 4, Signed,    Other,                  TLS | DYNAMIC),   // word32 DTPREL(S + A)
+    R_AARCH64_P32_TLS_TPREL                    = (186, 4, Signed,    Other,                  TLS | DYNAMIC),   // word32 TPREL(S + A)
+    R_AARCH64_P32_TLSDESC                      = (187, 8, Unchecked, Other,                  TLS | DYNAMIC),   // word32×2 TLSDESC(S + A)
+    R_AARCH64_P32_IRELATIVE                    = (188, 4, Signed,    Other,                  DYNAMIC)          // word32 Indirect(Delta(S) + A)
+});
+
+// ARM (AArch32)
+relocations!(arm_relocation_name, arm_relocation_description, {
+    R_ARM_NONE               = (0,   0, Unchecked, None,               0),               // none   none
+    R_ARM_PC24               = (1,   4, Signed,    PcRelative,         PC),              // arm    ((S + A) | T) - P
+    R_ARM_ABS32              = (2,   4, Unchecked, Absolute,           0),               // word32 (S + A) | T
+    R_ARM_REL32              = (3,   4, Unchecked, PcRelative,         PC),              // word32 ((S + A) | T) - P
+    R_ARM_LDR_PC_G0          = (4,   4, Signed,    PcRelative,         PC),              // arm    S + A - P
+    R_ARM_ABS16              = (5,   2, Bitfield,  Absolute,           0),               // word16 S + A
+    R_ARM_ABS12              = (6,   4, Bitfield,  Absolute,           0),               // arm    S + A
+    R_ARM_THM_ABS5           = (7,   2, Bitfield,  Absolute,           0),               // thumb16S + A
+    R_ARM_ABS8               = (8,   1, Bitfield,  Absolute,           0),               // word8  S + A
+    R_ARM_SBREL32            = (9,   4, Unchecked, Other,              0),               // word32 ((S + A) | T) - B(S)
+    R_ARM_THM_CALL           = (10,  4, Signed,    PcRelative,         PC),              // thumb32((S + A) | T) - P
+    R_ARM_THM_PC8            = (11,  2, Signed,    PcRelative,         PC),              // thumb16S + A - Pa
+    R_ARM_BREL_ADJ           = (12,  4, Unchecked, Other,              0),               // word32 ΔB(S) + A
+    R_ARM_TLS_DESC           = (13,  4, Unchecked, Other,              TLS | DYNAMIC),   // word32
+    R_ARM_THM_SWI8           = (14,  0, Unchecked, Other,              0),               // obsolete
+    R_ARM_XPC25              = (15,  0, Unchecked, Other,              PC),              // obsolete
+    R_ARM_THM_XPC22          = (16,  0, Unchecked, Other,              PC),              // obsolete
+    R_ARM_TLS_DTPMOD32       = (17,  4, Unchecked, Other,              TLS | DYNAMIC),   // word32 Module[S]
+    R_ARM_TLS_DTPOFF32       = (18,  4, Unchecked, Other,              TLS),             // word32 S + A - TLS
+    R_ARM_TLS_TPOFF32        = (19,  4, Unchecked, Other,              TLS | DYNAMIC),   // word32 S + A - tp
+    R_ARM_COPY               = (20,  0, Unchecked, Copy,               DYNAMIC),         // none   none
+    R_ARM_GLOB_DAT           = (21,  4, Unchecked, Absolute,           DYNAMIC),         // word32 (S + A) | T
+    R_ARM_JUMP_SLOT          = (22,  4, Unchecked, Absolute,           DYNAMIC),         // word32 (S + A) | T
+    R_ARM_RELATIVE           = (23,  4, Unchecked, BaseRelative,       DYNAMIC),         // word32 B(S) + A
+    R_ARM_GOTOFF32           = (24,  4, Unchecked, GotRelative,        GOT),             // word32 ((S + A) | T) - GOT_ORG
+    R_ARM_BASE_PREL          = (25,  4, Unchecked, Other,              PC),              // word32 B(S) + A - P
+    R_ARM_GOT_BREL           = (26,  4, Unchecked, GotOffset,          GOT),             // word32 GOT(S) + A - GOT_ORG
+    R_ARM_PLT32              = (27,  4, Signed,    PcRelative,         PC | PLT),        // arm    ((S + A) | T) - P
+    R_ARM_CALL               = (28,  4, Signed,    PcRelative,         PC),              // arm    ((S + A) | T) - P
+    R_ARM_JUMP24             = (29,  4, Signed,    PcRelative,         PC),              // arm    ((S + A) | T) - P
+    R_ARM_THM_JUMP24         = (30,  4, Signed,    PcRelative,         PC),              // thumb32((S + A) | T) - P
+    R_ARM_BASE_ABS           = (31,  4, Unchecked, BaseRelative,       0),               // word32 B(S) + A
+    R_ARM_ALU_PCREL_7_0      = (32,  0, Unchecked, Other,              PC),              // obsolete
+    R_ARM_ALU_PCREL_15_8     = (33,  0, Unchecked, Other,              PC),              // obsolete
+    R_ARM_ALU_PCREL_23_15    = (34,  0, Unchecked, Other,              PC),              // obsolete
+    R_ARM_LDR_SBREL_11_0_NC  = (35,  4, Unchecked, Other,              0),               // arm    S + A - B(S)
+    R_ARM_ALU_SBREL_19_12_NC = (36,  4, Unchecked, Other,              0),               // arm    S + A - B(S)
+    R_ARM_ALU_SBREL_27_20_CK = (37,  4, Bitfield,  Other,              0),               // arm    S + A - B(S)
+    R_ARM_TARGET1            = (38,  4, Unchecked, Other,              PC),              // word32 (S + A) | T or ((S + A) | T) - P
+    R_ARM_SBREL31            = (39,  4, Unchecked, Other,              0),               // word32 ((S + A) | T) - B(S)
+    R_ARM_V4BX               = (40,  4, Unchecked, None,               0),               // arm    none
+    R_ARM_TARGET2            = (41,  4, Unchecked, Other,              0),               // word32 platform-specific
+    R_ARM_PREL31             = (42,  4, Signed,    PcRelative,         PC),              // word32 ((S + A) | T) - P
+    R_ARM_MOVW_ABS_NC        = (43,  4, Unchecked, Absolute,           0),               // arm    (S + A) | T
+    R_ARM_MOVT_ABS           = (44,  4, Unchecked, Absolute,           0),               // arm    S + A
+    R_ARM_MOVW_PREL_NC       = (45,  4, Unchecked, PcRelative,         PC),              // arm    ((S + A) | T) - P
+    R_ARM_MOVT_PREL          = (46,  4, Unchecked, PcRelative,         PC),              // arm    S + A - P
+    R_ARM_THM_MOVW_ABS_NC    = (47,  4, Unchecked, Absolute,           0),               // thumb32(S + A) | T
+    R_ARM_THM_MOVT_ABS       = (48,  4, Unchecked, Absolute,           0),               // thumb32S + A
+    R_ARM_THM_MOVW_PREL_NC   = (49,  4, Unchecked, PcRelative,         PC),              // thumb32((S + A) | T) - P
+    R_ARM_THM_MOVT_PREL      = (50,  4, Unchecked, PcRelative,         PC),              // thumb32S + A - P
+    R_ARM_THM_JUMP19         = (51,  4, Signed,    PcRelative,         PC),              // thumb32((S + A) | T) - P
+    R_ARM_THM_JUMP6          = (52,  2, Signed,    PcRelative,         PC),              // thumb16S + A - P
+    R_ARM_THM_ALU_PREL_11_0  = (53,  4, Signed,    PcRelative,         PC),              // thumb32((S + A) | T) - Pa
+    R_ARM_THM_PC12           = (54,  4, Signed,    PcRelative,         PC),              // thumb32S + A - Pa
+    R_ARM_ABS32_NOI          = (55,  4, Unchecked, Absolute,           0),               // word32 S + A
+    R_ARM_REL32_NOI          = (56,  4, Unchecked, PcRelative,         PC),              // word32 S + A - P
+    R_ARM_ALU_PC_G0_NC       = (57,  4, Unchecked, PcRelative,         PC),              // arm    ((S + A) | T) - P
+    R_ARM_ALU_PC_G0          = (58,  4, Signed,    PcRelative,         PC),              // arm    ((S + A) | T) - P
+    R_ARM_ALU_PC_G1_NC       = (59,  4, Unchecked, PcRelative,         PC),              // arm    ((S + A) | T) - P
+    R_ARM_ALU_PC_G1          = (60,  4, Signed,    PcRelative,         PC),              // arm    ((S + A) | T) - P
+    R_ARM_ALU_PC_G2          = (61,  4, Signed,    PcRelative,         PC),              // arm    ((S + A) | T) - P
+    R_ARM_LDR_PC_G1          = (62,  4, Signed,    PcRelative,         PC),              // arm    S + A - P
+    R_ARM_LDR_PC_G2          = (63,  4, Signed,    PcRelative,         PC),              // arm    S + A - P
+    R_ARM_LDRS_PC_G0         = (64,  4, Signed,    PcRelative,         PC),              // arm    S + A - P
+    R_ARM_LDRS_PC_G1         = (65,  4, Signed,    PcRelative,         PC),              // arm    S + A - P
+    R_ARM_LDRS_PC_G2         = (66,  4, Signed,    PcRelative,         PC),              // arm    S + A - P
+    R_ARM_LDC_PC_G0          = (67,  4, Signed,    PcRelative,         PC),              // arm    S + A - P
+    R_ARM_LDC_PC_G1          = (68,  4, Signed,    PcRelative,         PC),              // arm    S + A - P
+    R_ARM_LDC_PC_G2          = (69,  4, Signed,    PcRelative,         PC),              // arm    S + A - P
+    R_ARM_ALU_SB_G0_NC       = (70,  4, Unchecked, Other,              0),               // arm    ((S + A) | T) - B(S)
+    R_ARM_ALU_SB_G0          = (71,  4, Bitfield,  Other,              0),               // arm    ((S + A) | T) - B(S)
+    R_ARM_ALU_SB_G1_NC       = (72,  4, Unchecked, Other,              0),               // arm    ((S + A) | T) - B(S)
+    R_ARM_ALU_SB_G1          = (73,  4, Bitfield,  Other,              0),               // arm    ((S + A) | T) - B(S)
+    R_ARM_ALU_SB_G2          = (74,  4, Bitfield,  Other,              0),               // arm    ((S + A) | T) - B(S)
+    R_ARM_LDR_SB_G0          = (75,  4, Bitfield,  Other,              0),               // arm    S + A - B(S)
+    R_ARM_LDR_SB_G1          = (76,  4, Bitfield,  Other,              0),               // arm    S + A - B(S)
+    R_ARM_LDR_SB_G2          = (77,  4, Bitfield,  Other,              0),               // arm    S + A - B(S)
+    R_ARM_LDRS_SB_G0         = (78,  4, Bitfield,  Other,              0),               // arm    S + A - B(S)
+    R_ARM_LDRS_SB_G1         = (79,  4, Bitfield,  Other,              0),               // arm    S + A - B(S)
+    R_ARM_LDRS_SB_G2         = (80,  4, Bitfield,  Other,              0),               // arm    S + A - B(S)
+    R_ARM_LDC_SB_G0          = (81,  4, Bitfield,  Other,              0),               // arm    S + A - B(S)
+    R_ARM_LDC_SB_G1          = (82,  4, Bitfield,  Other,              0),               // arm    S + A - B(S)
+    R_ARM_LDC_SB_G2          = (83,  4, Bitfield,  Other,              0),               // arm    S + A - B(S)
+    R_ARM_MOVW_BREL_NC       = (84,  4, Unchecked, Other,              0),               // arm    ((S + A) | T) - B(S)
+    R_ARM_MOVT_BREL          = (85,  4, Unchecked, Other,              0),               // arm    S + A - B(S)
+    R_ARM_MOVW_BREL          = (86,  4, Unchecked, Other,              0),               // arm    ((S + A) | T) - B(S)
+    R_ARM_THM_MOVW_BREL_NC   = (87,  4, Unchecked, Other,              0),               // thumb32((S + A) | T) - B(S)
+    R_ARM_THM_MOVT_BREL      = (88,  4, Unchecked, Other,              0),               // thumb32S + A - B(S)
+    R_ARM_THM_MOVW_BREL      = (89,  4, Unchecked, Other,              0),               // thumb32((S + A) | T) - B(S)
+    R_ARM_TLS_GOTDESC        = (90,  4, Unchecked, Other,              GOT | TLS),       // word32
+    R_ARM_TLS_CALL           = (91,  4, Bitfield,  Other,              TLS),             // arm
+    R_ARM_TLS_DESCSEQ        = (92,  4, Bitfield,  Other,              TLS),             // arm
+    R_ARM_THM_TLS_CALL       = (93,  4, Bitfield,  Other,              TLS),             // thumb32
+    R_ARM_PLT32_ABS          = (94,  4, Unchecked, Plt,                PLT),             // word32 PLT(S) + A
+    R_ARM_GOT_ABS            = (95,  4, Unchecked, GotEntry,           GOT),             // word32 GOT(S) + A
+    R_ARM_GOT_PREL           = (96,  4, Unchecked, GotEntryPcRelative, PC | GOT),        // word32 GOT(S) + A - P
+    R_ARM_GOT_BREL12         = (97,  4, Bitfield,  GotOffset,          GOT),             // arm    GOT(S) + A - GOT_ORG
+    R_ARM_GOTOFF12           = (98,  4, Bitfield,  GotRelative,        GOT),             // arm    S + A - GOT_ORG
+    R_ARM_GOTRELAX           = (99,  0, Unchecked, Other,              GOT),             // misc
+    R_ARM_GNU_VTENTRY        = (100, 0, Unchecked, None,               0),               // data   none
+    R_ARM_GNU_VTINHERIT      = (101, 0, Unchecked, None,               0),               // data   none
+    R_ARM_THM_JUMP11         = (102, 2, Signed,    PcRelative,         PC),              // thumb16S + A - P
+    R_ARM_THM_JUMP8          = (103, 2, Signed,    PcRelative,         PC),              // thumb16S + A - P
+    R_ARM_TLS_GD32           = (104, 4, Unchecked, GotEntryPcRelative, PC | GOT | TLS),  // word32 GOT(S) + A - P
+    R_ARM_TLS_LDM32          = (105, 4, Unchecked, GotEntryPcRelative, PC | GOT | TLS),  // word32 GOT(S) + A - P
+    R_ARM_TLS_LDO32          = (106, 4, Unchecked, Other,              TLS),             // word32 S + A - TLS
+    R_ARM_TLS_IE32           = (107, 4, Unchecked, GotEntryPcRelative, PC | GOT | TLS),  // word32 GOT(S) + A - P
+    R_ARM_TLS_LE32           = (108, 4, Unchecked, Other,              TLS),             // word32 S + A - tp
+    R_ARM_TLS_LDO12          = (109, 4, Bitfield,  Other,              TLS),             // arm    S + A - TLS
+    R_ARM_TLS_LE12           = (110, 4, Bitfield,  Other,              TLS),             // arm    S + A - tp
+    R_ARM_TLS_IE12GP         = (111, 4, Bitfield,  GotOffset,          GOT | TLS),       // arm    GOT(S) + A - GOT_ORG
+    R_ARM_PRIVATE_0          = (112, 0, Unchecked, Other,              0),               // private
+    R_ARM_PRIVATE_1          = (113, 0, Unchecked, Other,              0),               // private
+    R_ARM_PRIVATE_2          = (114, 0, Unchecked, Other,              0),               // private
+    R_ARM_PRIVATE_3          = (115, 0, Unchecked, Other,              0),               // private
+    R_ARM_PRIVATE_4          = (116, 0, Unchecked, Other,              0),               // private
+    R_ARM_PRIVATE_5          = (117, 0, Unchecked, Other,              0),               // private
+    R_ARM_PRIVATE_6          = (118, 0, Unchecked, Other,              0),               // private
+    R_ARM_PRIVATE_7          = (119, 0, Unchecked, Other,              0),               // private
+    R_ARM_PRIVATE_8          = (120, 0, Unchecked, Other,              0),               // private
+    R_ARM_PRIVATE_9          = (121, 0, Unchecked, Other,              0),               // private
+    R_ARM_PRIVATE_10         = (122, 0, Unchecked, Other,              0),               // private
+    R_ARM_PRIVATE_11         = (123, 0, Unchecked, Other,              0),               // private
+    R_ARM_PRIVATE_12         = (124, 0, Unchecked, Other,              0),               // private
+    R_ARM_PRIVATE_13         = (125, 0, Unchecked, Other,              0),               // private
+    R_ARM_PRIVATE_14         = (126, 0, Unchecked, Other,              0),               // private
+    R_ARM_PRIVATE_15         = (127, 0, Unchecked, Other,              0),               // private
+    R_ARM_ME_TOO             = (128, 0, Unchecked, Other,              0),               // obsolete
+    R_ARM_THM_TLS_DESCSEQ16  = (129, 2, Bitfield,  Other,              TLS),             // thumb16
+    R_ARM_THM_TLS_DESCSEQ32  = (130, 4, Bitfield,  Other,              TLS),             // thumb32
+    R_ARM_THM_GOT_BREL12     = (131, 4, Bitfield,  GotOffset,          GOT),             // thumb32GOT(S) + A - GOT_ORG
+    R_ARM_THM_ALU_ABS_G0_NC  = (132, 2, Unchecked, Absolute,           0),               // thumb16(S + A) | T
+    R_ARM_THM_ALU_ABS_G1_NC  = (133, 2, Unchecked, Absolute,           0),               // thumb16S + A
+    R_ARM_THM_ALU_ABS_G2_NC  = (134, 2, Unchecked, Absolute,           0),               // thumb16S + A
+    R_ARM_THM_ALU_ABS_G3     = (135, 2, Bitfield,  Absolute,           0),               // thumb16S + A
+    R_ARM_THM_BF16           = (136, 4, Signed,    PcRelative,         PC),              // thumb32((S + A) | T) - P
+    R_ARM_THM_BF12           = (137, 4, Signed,    PcRelative,         PC),              // thumb32((S + A) | T) - P
+    R_ARM_THM_BF18           = (138, 4, Signed,    PcRelative,         PC),              // thumb32((S + A) | T) - P
+    R_ARM_IRELATIVE          = (160, 4, Unchecked, Other,              DYNAMIC),         // word32
+    R_ARM_GOTFUNCDESC        = (161, 4, Unchecked, Other,              GOT),             // word32 GOTFUNCDESC(S) - GOT_ORG
+    R_ARM_GOTOFFFUNCDESC     = (162, 4, Unchecked, Other,              GOT),             // word32 FUNCDESC(S) - GOT_ORG
+    R_ARM_FUNCDESC           = (163, 4, Unchecked, Other,              0),               // word32 FUNCDESC(S)
+    R_ARM_FUNCDESC_VALUE     = (164, 8, Unchecked, Other,              0),               // word32×2
+    R_ARM_TLS_GD32_FDPIC     = (165, 4, Unchecked, GotOffset,          GOT | TLS),       // word32 GOT(S) - GOT_ORG
+    R_ARM_TLS_LDM32_FDPIC    = (166, 4, Unchecked, GotOffset,          GOT | TLS),       // word32 GOT(S) - GOT_ORG
+    R_ARM_TLS_IE32_FDPIC     = (167, 4, Unchecked, GotOffset,          GOT | TLS),       // word32 GOT(S) - GOT_ORG
+    R_ARM_RXPC25             = (249, 0, Unchecked, Other,              PC),              // obsolete
+    R_ARM_RSBREL32           = (250, 0, Unchecked, Other,              0),               // obsolete
+    R_ARM_THM_RPC22          = (251, 0, Unchecked, Other,              PC),              // obsolete
+    R_ARM_RREL32             = (252, 0, Unchecked, Other,              0),               // obsolete
+    R_ARM_RABS32             = (253, 0, Unchecked, Other,              0),               // obsolete
+    R_ARM_RPC24              = (254, 0, Unchecked, Other,              PC),              // obsolete
+    R_ARM_RBASE              = (255, 0, Unchecked, Other,              0)                // obsolete
+});
+
+// RISC-V
+relocations!(riscv_relocation_name, riscv_relocation_description, {
+    R_RISCV_NONE              = (0,   0,     Unchecked, None,               0),               // none   none
+    R_RISCV_32                = (1,   4,     Unchecked, Absolute,           0),               // word32 S + A
+    R_RISCV_64                = (2,   8,     Unchecked, Absolute,           0),               // word64 S + A
+    R_RISCV_RELATIVE          = (3,   WORD,  Unchecked, BaseRelative,       DYNAMIC),         // wordclass B + A
+    R_RISCV_COPY              = (4,   0,     Unchecked, Copy,               DYNAMIC),         // none   none
+    R_RISCV_JUMP_SLOT         = (5,   WORD,  Unchecked, Symbol,             DYNAMIC),         // wordclass S
+    R_RISCV_TLS_DTPMOD32      = (6,   4,     Unchecked, Other,              TLS | DYNAMIC),   // word32 TLSMODULE
+    R_RISCV_TLS_DTPMOD64      = (7,   8,     Unchecked, Other,              TLS | DYNAMIC),   // word64 TLSMODULE
+    R_RISCV_TLS_DTPREL32      = (8,   4,     Unchecked, Other,              TLS),             // word32 S + A - TLS_DTV_OFFSET
+    R_RISCV_TLS_DTPREL64      = (9,   8,     Unchecked, Other,              TLS),             // word64 S + A - TLS_DTV_OFFSET
+    R_RISCV_TLS_TPREL32       = (10,  4,     Unchecked, Other,              TLS | DYNAMIC),   // word32 S + A + TLSOFFSET
+    R_RISCV_TLS_TPREL64       = (11,  8,     Unchecked, Other,              TLS | DYNAMIC),   // word64 S + A + TLSOFFSET
+    R_RISCV_TLSDESC           = (12,  WORD2, Unchecked, Other,              TLS | DYNAMIC),   // wordclass×2 TLSDESC(S + A)
+    R_RISCV_BRANCH            = (16,  4,     Signed,    PcRelative,         PC),              // B-type S + A - P
+    R_RISCV_JAL               = (17,  4,     Signed,    PcRelative,         PC),              // J-type S + A - P
+    R_RISCV_CALL              = (18,  8,     Signed,    PcRelative,         PC),              // U+I-type S + A - P
+    R_RISCV_CALL_PLT          = (19,  8,     Signed,    PcRelative,         PC | PLT),        // U+I-type S + A - P
+    R_RISCV_GOT_HI20          = (20,  4,     Signed,    GotEntryPcRelative, PC | GOT),        // U-type G + GOT + A - P
+    R_RISCV_TLS_GOT_HI20      = (21,  4,     Signed,    Other,              PC | GOT | TLS),  // U-type
+    R_RISCV_TLS_GD_HI20       = (22,  4,     Signed,    Other,              PC | GOT | TLS),  // U-type
+    R_RISCV_PCREL_HI20        = (23,  4,     Signed,    PcRelative,         PC),              // U-type S + A - P
+    R_RISCV_PCREL_LO12_I      = (24,  4,     Unchecked, PcRelative,         PC),              // I-type S - P (of the paired HI20)
+    R_RISCV_PCREL_LO12_S      = (25,  4,     Unchecked, PcRelative,         PC),              // S-type S - P (of the paired HI20)
+    R_RISCV_HI20              = (26,  4,     Signed,    Absolute,           0),               // U-type S + A
+    R_RISCV_LO12_I            = (27,  4,     Unchecked, Absolute,           0),               // I-type S + A
+    R_RISCV_LO12_S            = (28,  4,     Unchecked, Absolute,           0),               // S-type S + A
+    R_RISCV_TPREL_HI20        = (29,  4,     Signed,    Other,              TLS),             // U-type
+    R_RISCV_TPREL_LO12_I      = (30,  4,     Unchecked, Other,              TLS),             // I-type
+    R_RISCV_TPREL_LO12_S      = (31,  4,     Unchecked, Other,              TLS),             // S-type
+    R_RISCV_TPREL_ADD         = (32,  0,     Unchecked, None,               TLS),             // none   none
+    R_RISCV_ADD8              = (33,  1,     Unchecked, Other,              0),               // word8  V + S + A
+    R_RISCV_ADD16             = (34,  2,     Unchecked, Other,              0),               // word16 V + S + A
+    R_RISCV_ADD32             = (35,  4,     Unchecked, Other,              0),               // word32 V + S + A
+    R_RISCV_ADD64             = (36,  8,     Unchecked, Other,              0),               // word64 V + S + A
+    R_RISCV_SUB8              = (37,  1,     Unchecked, Other,              0),               // word8  V - S - A
+    R_RISCV_SUB16             = (38,  2,     Unchecked, Other,              0),               // word16 V - S - A
+    R_RISCV_SUB32             = (39,  4,     Unchecked, Other,              0),               // word32 V - S - A
+    R_RISCV_SUB64             = (40,  8,     Unchecked, Other,              0),               // word64 V - S - A
+    R_RISCV_GOT32_PCREL       = (41,  4,     Signed,    GotEntryPcRelative, PC | GOT),        // word32 G + GOT + A - P
+    R_RISCV_ALIGN             = (43,  0,     Unchecked, None,               0),               // none   none
+    R_RISCV_RVC_BRANCH        = (44,  2,     Signed,    PcRelative,         PC),              // CB-type S + A - P
+    R_RISCV_RVC_JUMP          = (45,  2,     Signed,    PcRelative,         PC),              // CJ-type S + A - P
+    R_RISCV_RVC_LUI           = (46,  2,     Signed,    Absolute,           0),               // CI-type S + A (removed)
+    R_RISCV_RELAX             = (51,  0,     Unchecked, None,               0),               // none   none
+    R_RISCV_SUB6              = (52,  1,     Unchecked, Other,              0),               // word6  V - S - A
+    R_RISCV_SET6              = (53,  1,     Unchecked, Absolute,           0),               // word6  S + A
+    R_RISCV_SET8              = (54,  1,     Unchecked, Absolute,           0),               // word8  S + A
+    R_RISCV_SET16             = (55,  2,     Unchecked, Absolute,           0),               // word16 S + A
+    R_RISCV_SET32             = (56,  4,     Unchecked, Absolute,           0),               // word32 S + A
+    R_RISCV_32_PCREL          = (57,  4,     Signed,    PcRelative,         PC),              // word32 S + A - P
+    R_RISCV_IRELATIVE         = (58,  WORD,  Unchecked, Other,              DYNAMIC),         // wordclass ifunc_resolver(B + A)
+    R_RISCV_PLT32             = (59,  4,     Signed,    PcRelative,         PC | PLT),        // word32 S + A - P
+    R_RISCV_SET_ULEB128       = (60,  0,     Unchecked, Absolute,           0),               // ULEB128 S + A
+    R_RISCV_SUB_ULEB128       = (61,  0,     Unchecked, Other,              0),               // ULEB128 V - S - A
+    R_RISCV_TLSDESC_HI20      = (62,  4,     Signed,    PcRelative,         PC | TLS),        // U-type S + A - P
+    R_RISCV_TLSDESC_LOAD_LO12 = (63,  4,     Unchecked, PcRelative,         PC | TLS),        // I-type S - P (of the paired HI20)
+    R_RISCV_TLSDESC_ADD_LO12  = (64,  4,     Unchecked, PcRelative,         PC | TLS),        // I-type S - P (of the paired HI20)
+    R_RISCV_TLSDESC_CALL      = (65,  0,     Unchecked, None,               TLS),             // none   none
+    R_RISCV_VENDOR            = (191, 0,     Unchecked, None,               0)                // none   none
+});
+
+// PowerPC (32-bit)
+relocations!(ppc_relocation_name, ppc_relocation_description, {
+    R_PPC_NONE             = (0,   0, Unchecked, None,          0),              // none   none
+    R_PPC_ADDR32           = (1,   4, Unchecked, Absolute,      0),              // word32 S + A
+    R_PPC_ADDR24           = (2,   4, Bitfield,  Absolute,      0),              // low24  (S + A) >> 2
+    R_PPC_ADDR16           = (3,   2, Bitfield,  Absolute,      0),              // half16 S + A
+    R_PPC_ADDR16_LO        = (4,   2, Unchecked, Absolute,      0),              // half16 #lo(S + A)
+    R_PPC_ADDR16_HI        = (5,   2, Unchecked, Absolute,      0),              // half16 #hi(S + A)
+    R_PPC_ADDR16_HA        = (6,   2, Unchecked, Absolute,      0),              // half16 #ha(S + A)
+    R_PPC_ADDR14           = (7,   4, Bitfield,  Absolute,      0),              // low14  (S + A) >> 2
+    R_PPC_ADDR14_BRTAKEN   = (8,   4, Bitfield,  Absolute,      0),              // low14  (S + A) >> 2
+    R_PPC_ADDR14_BRNTAKEN  = (9,   4, Bitfield,  Absolute,      0),              // low14  (S + A) >> 2
+    R_PPC_REL24            = (10,  4, Signed,    PcRelative,    PC),             // low24  (S + A - P) >> 2
+    R_PPC_REL14            = (11,  4, Signed,    PcRelative,    PC),             // low14  (S + A - P) >> 2
+    R_PPC_REL14_BRTAKEN    = (12,  4, Signed,    PcRelative,    PC),             // low14  (S + A - P) >> 2
+    R_PPC_REL14_BRNTAKEN   = (13,  4, Signed,    PcRelative,    PC),             // low14  (S + A - P) >> 2
+    R_PPC_GOT16            = (14,  2, Bitfield,  GotOffset,     GOT),            // half16 G + A
+    R_PPC_GOT16_LO         = (15,  2, Unchecked, GotOffset,     GOT),            // half16 #lo(G + A)
+    R_PPC_GOT16_HI         = (16,  2, Unchecked, GotOffset,     GOT),            // half16 #hi(G + A)
+    R_PPC_GOT16_HA         = (17,  2, Unchecked, GotOffset,     GOT),            // half16 #ha(G + A)
+    R_PPC_PLTREL24         = (18,  4, Signed,    PltPcRelative, PC | PLT),       // low24  (L + A - P) >> 2
+    R_PPC_COPY             = (19,  0, Unchecked, Copy,          DYNAMIC),        // none   none
+    R_PPC_GLOB_DAT         = (20,  4, Unchecked, Absolute,      DYNAMIC),        // word32 S + A
+    R_PPC_JMP_SLOT         = (21,  0, Unchecked, None,          DYNAMIC),        // none
+    R_PPC_RELATIVE         = (22,  4, Unchecked, BaseRelative,  DYNAMIC),        // word32 B + A
+    R_PPC_LOCAL24PC        = (23,  4, Bitfield,  Other,         0),              // low24
+    R_PPC_UADDR32          = (24,  4, Unchecked, Absolute,      0),              // word32 S + A
+    R_PPC_UADDR16          = (25,  2, Bitfield,  Absolute,      0),              // half16 S + A
+    R_PPC_REL32            = (26,  4, Signed,    PcRelative,    PC),             // word32 S + A - P
+    R_PPC_PLT32            = (27,  4, Unchecked, Plt,           PLT),            // word32 L + A
+    R_PPC_PLTREL32         = (28,  4, Signed,    PltPcRelative, PC | PLT),       // word32 L + A - P
+    R_PPC_PLT16_LO         = (29,  2, Unchecked, Plt,           PLT),            // half16 #lo(L + A)
+    R_PPC_PLT16_HI         = (30,  2, Unchecked, Plt,           PLT),            // half16 #hi(L + A)
+    R_PPC_PLT16_HA         = (31,  2, Unchecked, Plt,           PLT),            // half16 #ha(L + A)
+    R_PPC_SDAREL16         = (32,  2, Bitfield,  Other,         0),              // half16 S + A - _SDA_BASE_
+    R_PPC_SECTOFF          = (33,  2, Bitfield,  Other,         0),              // half16 R + A
+    R_PPC_SECTOFF_LO       = (34,  2, Unchecked, Other,         0),              // half16 #lo(R + A)
+    R_PPC_SECTOFF_HI       = (35,  2, Unchecked, Other,         0),              // half16 #hi(R + A)
+    R_PPC_SECTOFF_HA       = (36,  2, Unchecked, Other,         0),              // half16 #ha(R + A)
+    R_PPC_ADDR30           = (37,  4, Signed,    PcRelative,    PC),             // word30 (S + A - P) >> 2
+
+    // Thread-local storage relocations
+    R_PPC_TLS              = (67,  0, Unchecked, None,          TLS),            // none
+    R_PPC_DTPMOD32         = (68,  4, Unchecked, Other,         TLS | DYNAMIC),  // word32
+    R_PPC_TPREL16          = (69,  2, Bitfield,  Other,         TLS),            // half16
+    R_PPC_TPREL16_LO       = (70,  2, Unchecked, Other,         TLS),            // half16
+    R_PPC_TPREL16_HI       = (71,  2, Unchecked, Other,         TLS),            // half16
+    R_PPC_TPREL16_HA       = (72,  2, Unchecked, Other,         TLS),            // half16
+    R_PPC_TPREL32          = (73,  4, Unchecked, Other,         TLS),            // word32
+    R_PPC_DTPREL16         = (74,  2, Bitfield,  Other,         TLS),            // half16
+    R_PPC_DTPREL16_LO      = (75,  2, Unchecked, Other,         TLS),            // half16
+    R_PPC_DTPREL16_HI      = (76,  2, Unchecked, Other,         TLS),            // half16
+    R_PPC_DTPREL16_HA      = (77,  2, Unchecked, Other,         TLS),            // half16
+    R_PPC_DTPREL32         = (78,  4, Unchecked, Other,         TLS),            // word32
+    R_PPC_GOT_TLSGD16      = (79,  2, Bitfield,  Other,         GOT | TLS),      // half16
+    R_PPC_GOT_TLSGD16_LO   = (80,  2, Unchecked, Other,         GOT | TLS),      // half16
+    R_PPC_GOT_TLSGD16_HI   = (81,  2, Unchecked, Other,         GOT | TLS),      // half16
+    R_PPC_GOT_TLSGD16_HA   = (82,  2, Unchecked, Other,         GOT | TLS),      // half16
+    R_PPC_GOT_TLSLD16      = (83,  2, Bitfield,  Other,         GOT | TLS),      // half16
+    R_PPC_GOT_TLSLD16_LO   = (84,  2, Unchecked, Other,         GOT | TLS),      // half16
+    R_PPC_GOT_TLSLD16_HI   = (85,  2, Unchecked, Other,         GOT | TLS),      // half16
+    R_PPC_GOT_TLSLD16_HA   = (86,  2, Unchecked, Other,         GOT | TLS),      // half16
+    R_PPC_GOT_TPREL16      = (87,  2, Bitfield,  Other,         GOT | TLS),      // half16
+    R_PPC_GOT_TPREL16_LO   = (88,  2, Unchecked, Other,         GOT | TLS),      // half16
+    R_PPC_GOT_TPREL16_HI   = (89,  2, Unchecked, Other,         GOT | TLS),      // half16
+    R_PPC_GOT_TPREL16_HA   = (90,  2, Unchecked, Other,         GOT | TLS),      // half16
+    R_PPC_GOT_DTPREL16     = (91,  2, Bitfield,  Other,         GOT | TLS),      // half16
+    R_PPC_GOT_DTPREL16_LO  = (92,  2, Unchecked, Other,         GOT | TLS),      // half16
+    R_PPC_GOT_DTPREL16_HI  = (93,  2, Unchecked, Other,         GOT | TLS),      // half16
+    R_PPC_GOT_DTPREL16_HA  = (94,  2, Unchecked, Other,         GOT | TLS),      // half16
+    R_PPC_TLSGD            = (95,  0, Unchecked, None,          TLS),            // none
+    R_PPC_TLSLD            = (96,  0, Unchecked, None,          TLS),            // none
+
+    // Embedded ABI relocations
+    R_PPC_EMB_NADDR32      = (101, 4, Unchecked, Other,         0),              // word32 A - S
+    R_PPC_EMB_NADDR16      = (102, 2, Bitfield,  Other,         0),              // half16 A - S
+    R_PPC_EMB_NADDR16_LO   = (103, 2, Unchecked, Other,         0),              // half16 #lo(A - S)
+    R_PPC_EMB_NADDR16_HI   = (104, 2, Unchecked, Other,         0),              // half16 #hi(A - S)
+    R_PPC_EMB_NADDR16_HA   = (105, 2, Unchecked, Other,         0),              // half16 #ha(A - S)
+    R_PPC_EMB_SDAI16       = (106, 2, Bitfield,  Other,         0),              // half16
+    R_PPC_EMB_SDA2I16      = (107, 2, Bitfield,  Other,         0),              // half16
+    R_PPC_EMB_SDA2REL      = (108, 2, Bitfield,  Other,         0),              // half16 S + A - _SDA2_BASE_
+    R_PPC_EMB_SDA21        = (109, 4, Bitfield,  Other,         0),              // low21
+    R_PPC_EMB_MRKREF       = (110, 0, Unchecked, None,          0),              // none
+    R_PPC_EMB_RELSEC16     = (111, 2, Bitfield,  Other,         0),              // half16
+    R_PPC_EMB_RELST_LO     = (112, 2, Unchecked, Other,         0),              // half16
+    R_PPC_EMB_RELST_HI     = (113, 2, Unchecked, Other,         0),              // half16
+    R_PPC_EMB_RELST_HA     = (114, 2, Unchecked, Other,         0),              // half16
+    R_PPC_EMB_BIT_FLD      = (115, 4, Unchecked, Other,         0),              // word32
+    R_PPC_EMB_RELSDA       = (116, 2, Bitfield,  Other,         0),              // half16
+    R_PPC_PLTSEQ           = (119, 0, Unchecked, None,          PLT),            // none
+    R_PPC_PLTCALL          = (120, 0, Unchecked, None,          PLT),            // none
+
+    // Variable length encoding (VLE) relocations
+    R_PPC_VLE_REL8         = (216, 2, Signed,    PcRelative,    PC),             // bdh8   (S + A - P) >> 1
+    R_PPC_VLE_REL15        = (217, 4, Signed,    PcRelative,    PC),             // bdh15  (S + A - P) >> 1
+    R_PPC_VLE_REL24        = (218, 4, Signed,    PcRelative,    PC),             // bdh24  (S + A - P) >> 1
+    R_PPC_VLE_LO16A        = (219, 4, Unchecked, Absolute,      0),              // split16a #lo(S + A)
+    R_PPC_VLE_LO16D        = (220, 4, Unchecked, Absolute,      0),              // split16d #lo(S + A)
+    R_PPC_VLE_HI16A        = (221, 4, Unchecked, Absolute,      0),              // split16a #hi(S + A)
+    R_PPC_VLE_HI16D        = (222, 4, Unchecked, Absolute,      0),              // split16d #hi(S + A)
+    R_PPC_VLE_HA16A        = (223, 4, Bitfield,  Absolute,      0),              // split16a #ha(S + A)
+    R_PPC_VLE_HA16D        = (224, 4, Bitfield,  Absolute,      0),              // split16d #ha(S + A)
+    R_PPC_VLE_SDA21        = (225, 4, Bitfield,  Other,         0),              // low21
+    R_PPC_VLE_SDA21_LO     = (226, 4, Unchecked, Other,         0),              // low21
+    R_PPC_VLE_SDAREL_LO16A = (227, 4, Unchecked, Other,         0),              // split16a
+    R_PPC_VLE_SDAREL_LO16D = (228, 4, Unchecked, Other,         0),              // split16d
+    R_PPC_VLE_SDAREL_HI16A = (229, 4, Unchecked, Other,         0),              // split16a
+    R_PPC_VLE_SDAREL_HI16D = (230, 4, Unchecked, Other,         0),              // split16d
+    R_PPC_VLE_SDAREL_HA16A = (231, 4, Bitfield,  Other,         0),              // split16a
+    R_PPC_VLE_SDAREL_HA16D = (232, 4, Bitfield,  Other,         0),              // split16d
+    R_PPC_VLE_ADDR20       = (233, 4, Bitfield,  Absolute,      0),              // split20 S + A
+
+    // GNU extensions
+    R_PPC_REL16DX_HA       = (246, 4, Unchecked, PcRelative,    PC),             // dx16   #ha(S + A - P)
+    R_PPC_IRELATIVE        = (248, 4, Unchecked, Other,         DYNAMIC),        // word32
+    R_PPC_REL16            = (249, 2, Signed,    PcRelative,    PC),             // half16 S + A - P
+    R_PPC_REL16_LO         = (250, 2, Unchecked, PcRelative,    PC),             // half16 #lo(S + A - P)
+    R_PPC_REL16_HI         = (251, 2, Unchecked, PcRelative,    PC),             // half16 #hi(S + A - P)
+    R_PPC_REL16_HA         = (252, 2, Unchecked, PcRelative,    PC),             // half16 #ha(S + A - P)
+    R_PPC_GNU_VTINHERIT    = (253, 0, Unchecked, None,          0),              // none
+    R_PPC_GNU_VTENTRY      = (254, 0, Unchecked, None,          0),              // none
+    R_PPC_TOC16            = (255, 2, Bitfield,  Other,         GOT)             // half16
+});
+
+// PowerPC (64-bit)
+relocations!(ppc64_relocation_name, ppc64_relocation_description, {
+    R_PPC64_NONE               = (0,   0, Unchecked, None,               0),               // none   none
+    R_PPC64_ADDR32             = (1,   4, Bitfield,  Absolute,           0),               // word32 S + A
+    R_PPC64_ADDR24             = (2,   4, Bitfield,  Absolute,           0),               // low24  (S + A) >> 2
+    R_PPC64_ADDR16             = (3,   2, Bitfield,  Absolute,           0),               // half16 S + A
+    R_PPC64_ADDR16_LO          = (4,   2, Unchecked, Absolute,           0),               // half16 #lo(S + A)
+    R_PPC64_ADDR16_HI          = (5,   2, Unchecked, Absolute,           0),               // half16 #hi(S + A)
+    R_PPC64_ADDR16_HA          = (6,   2, Unchecked, Absolute,           0),               // half16 #ha(S + A)
+    R_PPC64_ADDR14             = (7,   4, Bitfield,  Absolute,           0),               // low14  (S + A) >> 2
+    R_PPC64_ADDR14_BRTAKEN     = (8,   4, Bitfield,  Absolute,           0),               // low14  (S + A) >> 2
+    R_PPC64_ADDR14_BRNTAKEN    = (9,   4, Bitfield,  Absolute,           0),               // low14  (S + A) >> 2
+    R_PPC64_REL24              = (10,  4, Signed,    PcRelative,         PC),              // low24  (S + A - P) >> 2
+    R_PPC64_REL14              = (11,  4, Signed,    PcRelative,         PC),              // low14  (S + A - P) >> 2
+    R_PPC64_REL14_BRTAKEN      = (12,  4, Signed,    PcRelative,         PC),              // low14  (S + A - P) >> 2
+    R_PPC64_REL14_BRNTAKEN     = (13,  4, Signed,    PcRelative,         PC),              // low14  (S + A - P) >> 2
+    R_PPC64_GOT16              = (14,  2, Bitfield,  GotOffset,          GOT),             // half16 G
+    R_PPC64_GOT16_LO           = (15,  2, Unchecked, GotOffset,          GOT),             // half16 #lo(G)
+    R_PPC64_GOT16_HI           = (16,  2, Unchecked, GotOffset,          GOT),             // half16 #hi(G)
+    R_PPC64_GOT16_HA           = (17,  2, Unchecked, GotOffset,          GOT),             // half16 #ha(G)
+    R_PPC64_COPY               = (19,  0, Unchecked, Copy,               DYNAMIC),         // none   none
+    R_PPC64_GLOB_DAT           = (20,  8, Unchecked, Absolute,           DYNAMIC),         // doubleword64 S + A
+    R_PPC64_JMP_SLOT           = (21,  8, Unchecked, Other,              DYNAMIC),         // doubleword64
+    R_PPC64_RELATIVE           = (22,  8, Unchecked, BaseRelative,       DYNAMIC),         // doubleword64 B + A
+    R_PPC64_UADDR32            = (24,  4, Bitfield,  Absolute,           0),               // word32 S + A
+    R_PPC64_UADDR16            = (25,  2, Bitfield,  Absolute,           0),               // half16 S + A
+    R_PPC64_REL32              = (26,  4, Signed,    PcRelative,         PC),              // word32 S + A - P
+    R_PPC64_PLT32              = (27,  4, Bitfield,  Plt,                PLT),             // word32 L
+    R_PPC64_PLTREL32           = (28,  4, Signed,    PltPcRelative,      PC | PLT),        // word32 L - P
+    R_PPC64_PLT16_LO           = (29,  2, Unchecked, Plt,                PLT),             // half16 #lo(L)
+    R_PPC64_PLT16_HI           = (30,  2, Unchecked, Plt,                PLT),             // half16 #hi(L)
+    R_PPC64_PLT16_HA           = (31,  2, Unchecked, Plt,                PLT),             // half16 #ha(L)
+    R_PPC64_SECTOFF            = (33,  2, Bitfield,  Other,              0),               // half16 R + A
+    R_PPC64_SECTOFF_LO         = (34,  2, Unchecked, Other,              0),               // half16 #lo(R + A)
+    R_PPC64_SECTOFF_HI         = (35,  2, Unchecked, Other,              0),               // half16 #hi(R + A)
+    R_PPC64_SECTOFF_HA         = (36,  2, Unchecked, Other,              0),               // half16 #ha(R + A)
+    R_PPC64_ADDR30             = (37,  4, Signed,    PcRelative,         PC),              // word30 (S + A - P) >> 2
+    R_PPC64_ADDR64             = (38,  8, Unchecked, Absolute,           0),               // doubleword64 S + A
+    R_PPC64_ADDR16_HIGHER      = (39,  2, Unchecked, Absolute,           0),               // half16 #higher(S + A)
+    R_PPC64_ADDR16_HIGHERA     = (40,  2, Unchecked, Absolute,           0),               // half16 #highera(S + A)
+    R_PPC64_ADDR16_HIGHEST     = (41,  2, Unchecked, Absolute,           0),               // half16 #highest(S + A)
+    R_PPC64_ADDR16_HIGHESTA    = (42,  2, Unchecked, Absolute,           0),               // half16 #highesta(S + A)
+    R_PPC64_UADDR64            = (43,  8, Unchecked, Absolute,           0),               // doubleword64 S + A
+    R_PPC64_REL64              = (44,  8, Unchecked, PcRelative,         PC),              // doubleword64 S + A - P
+    R_PPC64_PLT64              = (45,  8, Unchecked, Plt,                PLT),             // doubleword64 L
+    R_PPC64_PLTREL64           = (46,  8, Unchecked, PltPcRelative,      PC | PLT),        // doubleword64 L - P
+    R_PPC64_TOC16              = (47,  2, Bitfield,  Other,              GOT),             // half16 S + A - .TOC.
+    R_PPC64_TOC16_LO           = (48,  2, Unchecked, Other,              GOT),             // half16 #lo(S + A - .TOC.)
+    R_PPC64_TOC16_HI           = (49,  2, Unchecked, Other,              GOT),             // half16 #hi(S + A - .TOC.)
+    R_PPC64_TOC16_HA           = (50,  2, Unchecked, Other,              GOT),             // half16 #ha(S + A - .TOC.)
+    R_PPC64_TOC                = (51,  8, Unchecked, Other,              GOT),             // doubleword64 .TOC.
+    R_PPC64_PLTGOT16           = (52,  2, Bitfield,  Other,              GOT | PLT),       // half16 M
+    R_PPC64_PLTGOT16_LO        = (53,  2, Unchecked, Other,              GOT | PLT),       // half16 #lo(M)
+    R_PPC64_PLTGOT16_HI        = (54,  2, Unchecked, Other,              GOT | PLT),       // half16 #hi(M)
+    R_PPC64_PLTGOT16_HA        = (55,  2, Unchecked, Other,              GOT | PLT),       // half16 #ha(M)
+    R_PPC64_ADDR16_DS          = (56,  2, Unchecked, Absolute,           0),               // half16ds (S + A) >> 2
+    R_PPC64_ADDR16_LO_DS       = (57,  2, Unchecked, Absolute,           0),               // half16ds #lo(S + A) >> 2
+    R_PPC64_GOT16_DS           = (58,  2, Unchecked, GotOffset,          GOT),             // half16ds G >> 2
+    R_PPC64_GOT16_LO_DS        = (59,  2, Unchecked, GotOffset,          GOT),             // half16ds #lo(G) >> 2
+    R_PPC64_PLT16_LO_DS        = (60,  2, Unchecked, Plt,                PLT),             // half16ds #lo(L) >> 2
+    R_PPC64_SECTOFF_DS         = (61,  2, Unchecked, Other,              0),               // half16ds (R + A) >> 2
+    R_PPC64_SECTOFF_LO_DS      = (62,  2, Unchecked, Other,              0),               // half16ds #lo(R + A) >> 2
+    R_PPC64_TOC16_DS           = (63,  2, Unchecked, Other,              GOT),             // half16ds (S + A - .TOC.) >> 2
+    R_PPC64_TOC16_LO_DS        = (64,  2, Unchecked, Other,              GOT),             // half16ds #lo(S + A - .TOC.) >> 2
+    R_PPC64_PLTGOT16_DS        = (65,  2, Unchecked, Other,              GOT | PLT),       // half16ds M >> 2
+    R_PPC64_PLTGOT16_LO_DS     = (66,  2, Unchecked, Other,              GOT | PLT),       // half16ds #lo(M) >> 2
+
+    // Thread-local storage relocations
+    R_PPC64_TLS                = (67,  0, Unchecked, None,               TLS),             // none
+    R_PPC64_DTPMOD64           = (68,  8, Unchecked, Other,              TLS | DYNAMIC),   // doubleword64
+    R_PPC64_TPREL16            = (69,  2, Bitfield,  Other,              TLS),             // half16
+    R_PPC64_TPREL16_LO         = (70,  2, Unchecked, Other,              TLS),             // half16
+    R_PPC64_TPREL16_HI         = (71,  2, Unchecked, Other,              TLS),             // half16
+    R_PPC64_TPREL16_HA         = (72,  2, Unchecked, Other,              TLS),             // half16
+    R_PPC64_TPREL64            = (73,  8, Unchecked, Other,              TLS | DYNAMIC),   // doubleword64
+    R_PPC64_DTPREL16           = (74,  2, Bitfield,  Other,              TLS),             // half16
+    R_PPC64_DTPREL16_LO        = (75,  2, Unchecked, Other,              TLS),             // half16
+    R_PPC64_DTPREL16_HI        = (76,  2, Unchecked, Other,              TLS),             // half16
+    R_PPC64_DTPREL16_HA        = (77,  2, Unchecked, Other,              TLS),             // half16
+    R_PPC64_DTPREL64           = (78,  8, Unchecked, Other,              TLS),             // doubleword64
+    R_PPC64_GOT_TLSGD16        = (79,  2, Bitfield,  Other,              GOT | TLS),       // half16
+    R_PPC64_GOT_TLSGD16_LO     = (80,  2, Unchecked, Other,              GOT | TLS),       // half16
+    R_PPC64_GOT_TLSGD16_HI     = (81,  2, Unchecked, Other,              GOT | TLS),       // half16
+    R_PPC64_GOT_TLSGD16_HA     = (82,  2, Unchecked, Other,              GOT | TLS),       // half16
+    R_PPC64_GOT_TLSLD16        = (83,  2, Bitfield,  Other,              GOT | TLS),       // half16
+    R_PPC64_GOT_TLSLD16_LO     = (84,  2, Unchecked, Other,              GOT | TLS),       // half16
+    R_PPC64_GOT_TLSLD16_HI     = (85,  2, Unchecked, Other,              GOT | TLS),       // half16
+    R_PPC64_GOT_TLSLD16_HA     = (86,  2, Unchecked, Other,              GOT | TLS),       // half16
+    R_PPC64_GOT_TPREL16_DS     = (87,  2, Unchecked, Other,              GOT | TLS),       // half16ds
+    R_PPC64_GOT_TPREL16_LO_DS  = (88,  2, Unchecked, Other,              GOT | TLS),       // half16ds
+    R_PPC64_GOT_TPREL16_HI     = (89,  2, Unchecked, Other,              GOT | TLS),       // half16
+    R_PPC64_GOT_TPREL16_HA     = (90,  2, Unchecked, Other,              GOT | TLS),       // half16
+    R_PPC64_GOT_DTPREL16_DS    = (91,  2, Unchecked, Other,              GOT | TLS),       // half16ds
+    R_PPC64_GOT_DTPREL16_LO_DS = (92,  2, Unchecked, Other,              GOT | TLS),       // half16ds
+    R_PPC64_GOT_DTPREL16_HI    = (93,  2, Unchecked, Other,              GOT | TLS),       // half16
+    R_PPC64_GOT_DTPREL16_HA    = (94,  2, Unchecked, Other,              GOT | TLS),       // half16
+    R_PPC64_TPREL16_DS         = (95,  2, Unchecked, Other,              TLS),             // half16ds
+    R_PPC64_TPREL16_LO_DS      = (96,  2, Unchecked, Other,              TLS),             // half16ds
+    R_PPC64_TPREL16_HIGHER     = (97,  2, Unchecked, Other,              TLS),             // half16
+    R_PPC64_TPREL16_HIGHERA    = (98,  2, Unchecked, Other,              TLS),             // half16
+    R_PPC64_TPREL16_HIGHEST    = (99,  2, Unchecked, Other,              TLS),             // half16
+    R_PPC64_TPREL16_HIGHESTA   = (100, 2, Unchecked, Other,              TLS),             // half16
+    R_PPC64_DTPREL16_DS        = (101, 2, Unchecked, Other,              TLS),             // half16ds
+    R_PPC64_DTPREL16_LO_DS     = (102, 2, Unchecked, Other,              TLS),             // half16ds
+    R_PPC64_DTPREL16_HIGHER    = (103, 2, Unchecked, Other,              TLS),             // half16
+    R_PPC64_DTPREL16_HIGHERA   = (104, 2, Unchecked, Other,              TLS),             // half16
+    R_PPC64_DTPREL16_HIGHEST   = (105, 2, Unchecked, Other,              TLS),             // half16
+    R_PPC64_DTPREL16_HIGHESTA  = (106, 2, Unchecked, Other,              TLS),             // half16
+    R_PPC64_TLSGD              = (107, 0, Unchecked, None,               TLS),             // none
+    R_PPC64_TLSLD              = (108, 0, Unchecked, None,               TLS),             // none
+    R_PPC64_TOCSAVE            = (109, 0, Unchecked, None,               0),               // none
+    R_PPC64_ADDR16_HIGH        = (110, 2, Unchecked, Absolute,           0),               // half16 #high(S + A)
+    R_PPC64_ADDR16_HIGHA       = (111, 2, Unchecked, Absolute,           0),               // half16 #higha(S + A)
+    R_PPC64_TPREL16_HIGH       = (112, 2, Unchecked, Other,              TLS),             // half16
+    R_PPC64_TPREL16_HIGHA      = (113, 2, Unchecked, Other,              TLS),             // half16
+    R_PPC64_DTPREL16_HIGH      = (114, 2, Unchecked, Other,              TLS),             // half16
+    R_PPC64_DTPREL16_HIGHA     = (115, 2, Unchecked, Other,              TLS),             // half16
+    R_PPC64_REL24_NOTOC        = (116, 4, Signed,    PcRelative,         PC | GOT),        // low24  (S + A - P) >> 2
+    R_PPC64_ADDR64_LOCAL       = (117, 8, Unchecked, Absolute,           0),               // doubleword64 S + A (local entry point)
+    R_PPC64_ENTRY              = (118, 0, Unchecked, None,               0),               // none
+    R_PPC64_PLTSEQ             = (119, 0, Unchecked, None,               PLT),             // none
+    R_PPC64_PLTCALL            = (120, 0, Unchecked, None,               PLT),             // none
+    R_PPC64_PLTSEQ_NOTOC       = (121, 0, Unchecked, None,               PLT),             // none
+    R_PPC64_PLTCALL_NOTOC      = (122, 0, Unchecked, None,               PLT),             // none
+    R_PPC64_PCREL_OPT          = (123, 0, Unchecked, None,               0),               // none
+    R_PPC64_REL24_P9NOTOC      = (124, 4, Signed,    PcRelative,         PC | GOT),        // low24  (S + A - P) >> 2
+
+    // Prefixed instruction relocations (Power ISA 3.1)
+    R_PPC64_D34                = (128, 8, Bitfield,  Absolute,           0),               // prefix34 S + A
+    R_PPC64_D34_LO             = (129, 8, Unchecked, Absolute,           0),               // prefix34 #lo34(S + A)
+    R_PPC64_D34_HI30           = (130, 8, Unchecked, Absolute,           0),               // prefix34 #hi30(S + A)
+    R_PPC64_D34_HA30           = (131, 8, Unchecked, Absolute,           0),               // prefix34 #ha30(S + A)
+    R_PPC64_PCREL34            = (132, 8, Signed,    PcRelative,         PC),              // prefix34 S + A - P
+    R_PPC64_GOT_PCREL34        = (133, 8, Signed,    GotEntryPcRelative, PC | GOT),        // prefix34 G - P
+    R_PPC64_PLT_PCREL34        = (134, 8, Signed,    PltPcRelative,      PC | PLT),        // prefix34 L - P
+    R_PPC64_PLT_PCREL34_NOTOC  = (135, 8, Signed,    PltPcRelative,      PC | GOT | PLT),  // prefix34 L - P
+    R_PPC64_ADDR16_HIGHER34    = (136, 2, Unchecked, Absolute,           0),               // half16 #higher34(S + A)
+    R_PPC64_ADDR16_HIGHERA34   = (137, 2, Unchecked, Absolute,           0),               // half16 #highera34(S + A)
+    R_PPC64_ADDR16_HIGHEST34   = (138, 2, Unchecked, Absolute,           0),               // half16 #highest34(S + A)
+    R_PPC64_ADDR16_HIGHESTA34  = (139, 2, Unchecked, Absolute,           0),               // half16 #highesta34(S + A)
+    R_PPC64_REL16_HIGHER34     = (140, 2, Unchecked, PcRelative,         PC),              // half16 #higher34(S + A - P)
+    R_PPC64_REL16_HIGHERA34    = (141, 2, Unchecked, PcRelative,         PC),              // half16 #highera34(S + A - P)
+    R_PPC64_REL16_HIGHEST34    = (142, 2, Unchecked, PcRelative,         PC),              // half16 #highest34(S + A - P)
+    R_PPC64_REL16_HIGHESTA34   = (143, 2, Unchecked, PcRelative,         PC),              // half16 #highesta34(S + A - P)
+    R_PPC64_D28                = (144, 8, Bitfield,  Absolute,           0),               // prefix28 S + A
+    R_PPC64_PCREL28            = (145, 8, Signed,    PcRelative,         PC),              // prefix28 S + A - P
+    R_PPC64_TPREL34            = (146, 8, Bitfield,  Other,              TLS),             // prefix34
+    R_PPC64_DTPREL34           = (147, 8, Bitfield,  Other,              TLS),             // prefix34
+    R_PPC64_GOT_TLSGD_PCREL34  = (148, 8, Signed,    Other,              PC | GOT | TLS),  // prefix34
+    R_PPC64_GOT_TLSLD_PCREL34  = (149, 8, Signed,    Other,              PC | GOT | TLS),  // prefix34
+    R_PPC64_GOT_TPREL_PCREL34  = (150, 8, Signed,    Other,              PC | GOT | TLS),  // prefix34
+    R_PPC64_GOT_DTPREL_PCREL34 = (151, 8, Signed,    Other,              PC | GOT | TLS),  // prefix34
+
+    // GNU extensions
+    R_PPC64_REL16_HIGH         = (240, 2, Unchecked, PcRelative,         PC),              // half16 #high(S + A - P)
+    R_PPC64_REL16_HIGHA        = (241, 2, Unchecked, PcRelative,         PC),              // half16 #higha(S + A - P)
+    R_PPC64_REL16_HIGHER       = (242, 2, Unchecked, PcRelative,         PC),              // half16 #higher(S + A - P)
+    R_PPC64_REL16_HIGHERA      = (243, 2, Unchecked, PcRelative,         PC),              // half16 #highera(S + A - P)
+    R_PPC64_REL16_HIGHEST      = (244, 2, Unchecked, PcRelative,         PC),              // half16 #highest(S + A - P)
+    R_PPC64_REL16_HIGHESTA     = (245, 2, Unchecked, PcRelative,         PC),              // half16 #highesta(S + A - P)
+    R_PPC64_REL16DX_HA         = (246, 4, Unchecked, PcRelative,         PC),              // dx16   #ha(S + A - P)
+    R_PPC64_JMP_IREL           = (247, 0, Unchecked, None,               DYNAMIC),         // none
+    R_PPC64_IRELATIVE          = (248, 8, Unchecked, Other,              DYNAMIC),         // doubleword64
+    R_PPC64_REL16              = (249, 2, Signed,    PcRelative,         PC),              // half16 S + A - P
+    R_PPC64_REL16_LO           = (250, 2, Unchecked, PcRelative,         PC),              // half16 #lo(S + A - P)
+    R_PPC64_REL16_HI           = (251, 2, Unchecked, PcRelative,         PC),              // half16 #hi(S + A - P)
+    R_PPC64_REL16_HA           = (252, 2, Unchecked, PcRelative,         PC),              // half16 #ha(S + A - P)
+    R_PPC64_GNU_VTINHERIT      = (253, 0, Unchecked, None,               0),               // none
+    R_PPC64_GNU_VTENTRY        = (254, 0, Unchecked, None,               0)                // none
+});
+
+// MIPS
+relocations!(mips_relocation_name, mips_relocation_description, {
+    R_MIPS_NONE                 = (0,   0, Unchecked, None,       0),              // none   none
+    R_MIPS_16                   = (1,   2, Bitfield,  Absolute,   0),              // half16 S + sext(A)
+    R_MIPS_32                   = (2,   4, Bitfield,  Absolute,   0),              // word32 S + A
+    R_MIPS_REL32                = (3,   4, Bitfield,  Other,      DYNAMIC),        // word32 A - EA + S
+    R_MIPS_26                   = (4,   4, Signed,    Other,      PC),             // targ26 (((A << 2) | (P & 0xf0000000)) + S) >> 2
+    R_MIPS_HI16                 = (5,   4, Unchecked, Absolute,   0),              // hi16   %high(AHL + S)
+    R_MIPS_LO16                 = (6,   4, Unchecked, Absolute,   0),              // lo16   AHL + S
+    R_MIPS_GPREL16              = (7,   4, Bitfield,  Other,      GOT),            // gp16   S + sext(A) - GP
+    R_MIPS_LITERAL              = (8,   4, Bitfield,  Other,      GOT),            // gp16   S + sext(A) - GP
+    R_MIPS_GOT16                = (9,   2, Bitfield,  GotOffset,  GOT),            // half16 G
+    R_MIPS_PC16                 = (10,  2, Signed,    PcRelative, PC),             // half16 S + sext(A) - P
+    R_MIPS_CALL16               = (11,  2, Bitfield,  GotOffset,  GOT),            // half16 G
+    R_MIPS_GPREL32              = (12,  4, Bitfield,  Other,      GOT),            // word32 S + A + GP0 - GP
+    R_MIPS_UNUSED1              = (13,  0, Unchecked, Other,      0),
+    R_MIPS_UNUSED2              = (14,  0, Unchecked, Other,      0),
+    R_MIPS_UNUSED3              = (15,  0, Unchecked, Other,      0),
+    R_MIPS_SHIFT5               = (16,  4, Bitfield,  Absolute,   0),              // shift5 S + A
+    R_MIPS_SHIFT6               = (17,  4, Bitfield,  Absolute,   0),              // shift6 S + A
+    R_MIPS_64                   = (18,  8, Unchecked, Absolute,   0),              // word64 S + A
+    R_MIPS_GOT_DISP             = (19,  2, Signed,    GotOffset,  GOT),            // half16 G
+    R_MIPS_GOT_PAGE             = (20,  2, Signed,    GotOffset,  GOT),            // half16 G
+    R_MIPS_GOT_OFST             = (21,  2, Signed,    Other,      GOT),            // half16 S + A - page(S + A)
+    R_MIPS_GOT_HI16             = (22,  2, Unchecked, GotOffset,  GOT),            // half16 %high(G)
+    R_MIPS_GOT_LO16             = (23,  2, Unchecked, GotOffset,  GOT),            // half16 G
+    R_MIPS_SUB                  = (24,  8, Unchecked, Other,      0),              // word64 S - A
+    R_MIPS_INSERT_A             = (25,  0, Unchecked, Other,      0),
+    R_MIPS_INSERT_B             = (26,  0, Unchecked, Other,      0),
+    R_MIPS_DELETE               = (27,  0, Unchecked, Other,      0),
+    R_MIPS_HIGHER               = (28,  2, Unchecked, Absolute,   0),              // half16 %higher(S + A)
+    R_MIPS_HIGHEST              = (29,  2, Unchecked, Absolute,   0),              // half16 %highest(S + A)
+    R_MIPS_CALL_HI16            = (30,  2, Unchecked, GotOffset,  GOT),            // half16 %high(G)
+    R_MIPS_CALL_LO16            = (31,  2, Unchecked, GotOffset,  GOT),            // half16 G
+    R_MIPS_SCN_DISP             = (32,  4, Bitfield,  Other,      0),              // word32 S + A - scn_addr
+    R_MIPS_REL16                = (33,  2, Bitfield,  Other,      0),              // half16 S + A - EA
+    R_MIPS_ADD_IMMEDIATE        = (34,  0, Unchecked, Other,      0),
+    R_MIPS_PJUMP                = (35,  0, Unchecked, Other,      0),
+    R_MIPS_RELGOT               = (36,  0, Unchecked, Other,      GOT),
+    R_MIPS_JALR                 = (37,  0, Unchecked, None,       0),              // none
+
+    // Thread-local storage relocations
+    R_MIPS_TLS_DTPMOD32         = (38,  4, Bitfield,  Other,      TLS | DYNAMIC),  // word32
+    R_MIPS_TLS_DTPREL32         = (39,  4, Bitfield,  Other,      TLS),            // word32
+    R_MIPS_TLS_DTPMOD64         = (40,  8, Unchecked, Other,      TLS | DYNAMIC),  // word64
+    R_MIPS_TLS_DTPREL64         = (41,  8, Unchecked, Other,      TLS),            // word64
+    R_MIPS_TLS_GD               = (42,  2, Bitfield,  Other,      TLS),            // half16
+    R_MIPS_TLS_LDM              = (43,  2, Bitfield,  Other,      TLS),            // half16
+    R_MIPS_TLS_DTPREL_HI16      = (44,  2, Unchecked, Other,      TLS),            // half16
+    R_MIPS_TLS_DTPREL_LO16      = (45,  2, Unchecked, Other,      TLS),            // half16
+    R_MIPS_TLS_GOTTPREL         = (46,  2, Bitfield,  Other,      GOT | TLS),      // half16
+    R_MIPS_TLS_TPREL32          = (47,  4, Bitfield,  Other,      TLS | DYNAMIC),  // word32
+    R_MIPS_TLS_TPREL64          = (48,  8, Unchecked, Other,      TLS | DYNAMIC),  // word64
+    R_MIPS_TLS_TPREL_HI16       = (49,  2, Unchecked, Other,      TLS),            // half16
+    R_MIPS_TLS_TPREL_LO16       = (50,  2, Unchecked, Other,      TLS),            // half16
+    R_MIPS_GLOB_DAT             = (51,  4, Bitfield,  Absolute,   DYNAMIC),        // word32 S + A
+
+    // MIPS32r6 and MIPS64r6 PC-relative relocations
+    R_MIPS_PC21_S2              = (60,  4, Signed,    PcRelative, PC),             // low21  (S + A - P) >> 2
+    R_MIPS_PC26_S2              = (61,  4, Signed,    PcRelative, PC),             // low26  (S + A - P) >> 2
+    R_MIPS_PC18_S3              = (62,  4, Signed,    Other,      PC),             // low18  (S + A - (P & ~7)) >> 3
+    R_MIPS_PC19_S2              = (63,  4, Signed,    PcRelative, PC),             // low19  (S + A - P) >> 2
+    R_MIPS_PCHI16               = (64,  4, Unchecked, PcRelative, PC),             // hi16   %high(S + A - P)
+    R_MIPS_PCLO16               = (65,  4, Unchecked, PcRelative, PC),             // lo16   S + A - P
+
+    // MIPS16 relocations
+    R_MIPS16_26                 = (100, 4, Bitfield,  Other,      0),              // targ26
+    R_MIPS16_GPREL              = (101, 4, Signed,    Other,      PC),             // gp16
+    R_MIPS16_GOT16              = (102, 2, Bitfield,  Other,      GOT),            // half16
+    R_MIPS16_CALL16             = (103, 2, Bitfield,  Other,      0),              // half16
+    R_MIPS16_HI16               = (104, 4, Unchecked, Other,      0),              // hi16
+    R_MIPS16_LO16               = (105, 4, Unchecked, Other,      0),              // lo16
+    R_MIPS16_TLS_GD             = (106, 2, Bitfield,  Other,      TLS),            // half16
+    R_MIPS16_TLS_LDM            = (107, 2, Bitfield,  Other,      TLS),            // half16
+    R_MIPS16_TLS_DTPREL_HI16    = (108, 2, Unchecked, Other,      TLS),            // half16
+    R_MIPS16_TLS_DTPREL_LO16    = (109, 2, Unchecked, Other,      TLS),            // half16
+    R_MIPS16_TLS_GOTTPREL       = (110, 2, Bitfield,  Other,      GOT | TLS),      // half16
+    R_MIPS16_TLS_TPREL_HI16     = (111, 2, Unchecked, Other,      TLS),            // half16
+    R_MIPS16_TLS_TPREL_LO16     = (112, 2, Unchecked, Other,      TLS),            // half16
+    R_MIPS16_PC16_S1            = (113, 2, Signed,    Other,      PC),             // half16
+
+    // Dynamic relocations
+    R_MIPS_COPY                 = (126, 0, Unchecked, Copy,       DYNAMIC),        // none   none
+    R_MIPS_JUMP_SLOT            = (127, 4, Bitfield,  Symbol,     DYNAMIC),        // word32 S
+
+    // microMIPS relocations
+    R_MICROMIPS_26_S1           = (133, 4, Bitfield,  Other,      0),              // targ26
+    R_MICROMIPS_HI16            = (134, 4, Unchecked, Other,      0),              // hi16
+    R_MICROMIPS_LO16            = (135, 4, Unchecked, Other,      0),              // lo16
+    R_MICROMIPS_GPREL16         = (136, 4, Signed,    Other,      PC),             // gp16
+    R_MICROMIPS_LITERAL         = (137, 4, Bitfield,  Other,      0),              // gp16
+    R_MICROMIPS_GOT16           = (138, 2, Bitfield,  Other,      GOT),            // half16
+    R_MICROMIPS_PC7_S1          = (139, 2, Signed,    Other,      PC),             // low7
+    R_MICROMIPS_PC10_S1         = (140, 2, Signed,    Other,      PC),             // low10
+    R_MICROMIPS_PC16_S1         = (141, 2, Signed,    Other,      PC),             // half16
+    R_MICROMIPS_CALL16          = (142, 2, Bitfield,  Other,      0),              // half16
+    R_MICROMIPS_GOT_DISP        = (145, 2, Signed,    Other,      GOT),            // half16
+    R_MICROMIPS_GOT_PAGE        = (146, 2, Signed,    Other,      GOT),            // half16
+    R_MICROMIPS_GOT_OFST        = (147, 2, Signed,    Other,      GOT),            // half16
+    R_MICROMIPS_GOT_HI16        = (148, 2, Unchecked, Other,      GOT),            // half16
+    R_MICROMIPS_GOT_LO16        = (149, 2, Unchecked, Other,      GOT),            // half16
+    R_MICROMIPS_SUB             = (150, 8, Unchecked, Other,      0),              // word64
+    R_MICROMIPS_HIGHER          = (151, 2, Unchecked, Other,      0),              // half16
+    R_MICROMIPS_HIGHEST         = (152, 2, Unchecked, Other,      0),              // half16
+    R_MICROMIPS_CALL_HI16       = (153, 2, Unchecked, Other,      0),              // half16
+    R_MICROMIPS_CALL_LO16       = (154, 2, Unchecked, Other,      0),              // half16
+    R_MICROMIPS_SCN_DISP        = (155, 4, Bitfield,  Other,      0),              // word32
+    R_MICROMIPS_JALR            = (156, 0, Unchecked, None,       0),              // none
+    R_MICROMIPS_HI0_LO16        = (157, 2, Unchecked, Other,      0),              // half16
+    R_MICROMIPS_TLS_GD          = (162, 2, Bitfield,  Other,      TLS),            // half16
+    R_MICROMIPS_TLS_LDM         = (163, 2, Bitfield,  Other,      TLS),            // half16
+    R_MICROMIPS_TLS_DTPREL_HI16 = (164, 2, Unchecked, Other,      TLS),            // half16
+    R_MICROMIPS_TLS_DTPREL_LO16 = (165, 2, Unchecked, Other,      TLS),            // half16
+    R_MICROMIPS_TLS_GOTTPREL    = (166, 2, Bitfield,  Other,      GOT | TLS),      // half16
+    R_MICROMIPS_TLS_TPREL_HI16  = (169, 2, Unchecked, Other,      TLS),            // half16
+    R_MICROMIPS_TLS_TPREL_LO16  = (170, 2, Unchecked, Other,      TLS),            // half16
+    R_MICROMIPS_GPREL7_S2       = (172, 2, Signed,    Other,      PC),             // low7
+    R_MICROMIPS_PC23_S2         = (173, 4, Signed,    Other,      PC),             // low23
+
+    // GNU extensions
+    R_MIPS_PC32                 = (248, 4, Signed,    PcRelative, PC),             // word32 S + A - P
+    R_MIPS_EH                   = (249, 4, Bitfield,  Other,      0),              // word32
+    R_MIPS_GNU_REL16_S2         = (250, 2, Signed,    PcRelative, PC),             // half16 (S + A - P) >> 2
+    R_MIPS_GNU_VTINHERIT        = (253, 0, Unchecked, None,       0),              // none
+    R_MIPS_GNU_VTENTRY          = (254, 0, Unchecked, None,       0)               // none
+});
+
+// IBM System/390 and z/Architecture
+relocations!(s390_relocation_name, s390_relocation_description, {
+    R_390_NONE        = (0,  0,    Unchecked, None,               0),               // none   none
+    R_390_8           = (1,  1,    Bitfield,  Absolute,           0),               // byte8  S + A
+    R_390_12          = (2,  2,    Bitfield,  Absolute,           0),               // low12  S + A
+    R_390_16          = (3,  2,    Bitfield,  Absolute,           0),               // half16 S + A
+    R_390_32          = (4,  4,    Bitfield,  Absolute,           0),               // word32 S + A
+    R_390_PC32        = (5,  4,    Signed,    PcRelative,         PC),              // word32 S + A - P
+    R_390_GOT12       = (6,  2,    Bitfield,  GotOffset,          GOT),             // low12  O + A
+    R_390_GOT32       = (7,  4,    Bitfield,  GotOffset,          GOT),             // word32 O + A
+    R_390_PLT32       = (8,  4,    Signed,    PltPcRelative,      PC | PLT),        // word32 L + A - P
+    R_390_COPY        = (9,  0,    Unchecked, Copy,               DYNAMIC),         // none   none
+    R_390_GLOB_DAT    = (10, WORD, Unchecked, Absolute,           DYNAMIC),         // word64 S + A
+    R_390_JMP_SLOT    = (11, WORD, Unchecked, Symbol,             DYNAMIC),         // word64 S
+    R_390_RELATIVE    = (12, WORD, Unchecked, BaseRelative,       DYNAMIC),         // word64 B + A
+    R_390_GOTOFF32    = (13, 4,    Bitfield,  GotRelative,        GOT),             // word32 S + A - GOT
+    R_390_GOTPC       = (14, 4,    Signed,    GotPcRelative,      PC | GOT),        // word32 GOT + A - P
+    R_390_GOT16       = (15, 2,    Bitfield,  GotOffset,          GOT),             // half16 O + A
+    R_390_PC16        = (16, 2,    Signed,    PcRelative,         PC),              // half16 S + A - P
+    R_390_PC16DBL     = (17, 2,    Signed,    PcRelative,         PC),              // pc16   (S + A - P) >> 1
+    R_390_PLT16DBL    = (18, 2,    Signed,    PltPcRelative,      PC | PLT),        // pc16   (L + A - P) >> 1
+    R_390_PC32DBL     = (19, 4,    Signed,    PcRelative,         PC),              // pc32   (S + A - P) >> 1
+    R_390_PLT32DBL    = (20, 4,    Signed,    PltPcRelative,      PC | PLT),        // pc32   (L + A - P) >> 1
+    R_390_GOTPCDBL    = (21, 4,    Signed,    GotPcRelative,      PC | GOT),        // pc32   (GOT + A - P) >> 1
+    R_390_64          = (22, 8,    Unchecked, Absolute,           0),               // word64 S + A
+    R_390_PC64        = (23, 8,    Unchecked, PcRelative,         PC),              // word64 S + A - P
+    R_390_GOT64       = (24, 8,    Unchecked, GotOffset,          GOT),             // word64 O + A
+    R_390_PLT64       = (25, 8,    Unchecked, PltPcRelative,      PC | PLT),        // word64 L + A - P
+    R_390_GOTENT      = (26, 4,    Signed,    GotEntryPcRelative, PC | GOT),        // pc32   (G + O + A - P) >> 1
+    R_390_GOTOFF16    = (27, 2,    Bitfield,  GotRelative,        GOT),             // half16 S + A - GOT
+    R_390_GOTOFF64    = (28, 8,    Unchecked, GotRelative,        GOT),             // word64 S + A - GOT
+    R_390_GOTPLT12    = (29, 2,    Signed,    Other,              PC | GOT | PLT),  // low12  P + A
+    R_390_GOTPLT16    = (30, 2,    Signed,    Other,              PC | GOT | PLT),  // half16 P + A
+    R_390_GOTPLT32    = (31, 4,    Signed,    Other,              PC | GOT | PLT),  // word32 P + A
+    R_390_GOTPLT64    = (32, 8,    Unchecked, Other,              PC | GOT | PLT),  // word64 P + A
+    R_390_GOTPLTENT   = (33, 4,    Signed,    Other,              PC | GOT | PLT),  // pc32   (G + P + A - P) >> 1
+    R_390_PLTOFF16    = (34, 2,    Bitfield,  Other,              GOT | PLT),       // half16 L - GOT + A
+    R_390_PLTOFF32    = (35, 4,    Bitfield,  Other,              GOT | PLT),       // word32 L - GOT + A
+    R_390_PLTOFF64    = (36, 8,    Unchecked, Other,              GOT | PLT),       // word64 L - GOT + A
+
+    // Thread-local storage relocations
+    R_390_TLS_LOAD    = (37, 0,    Unchecked, None,               TLS),             // none
+    R_390_TLS_GDCALL  = (38, 0,    Unchecked, None,               TLS),             // none
+    R_390_TLS_LDCALL  = (39, 0,    Unchecked, None,               TLS),             // none
+    R_390_TLS_GD32    = (40, 4,    Bitfield,  Other,              TLS),             // word32
+    R_390_TLS_GD64    = (41, 8,    Unchecked, Other,              TLS),             // word64
+    R_390_TLS_GOTIE12 = (42, 2,    Bitfield,  Other,              GOT | TLS),       // low12
+    R_390_TLS_GOTIE32 = (43, 4,    Bitfield,  Other,              GOT | TLS),       // word32
+    R_390_TLS_GOTIE64 = (44, 8,    Unchecked, Other,              GOT | TLS),       // word64
+    R_390_TLS_LDM32   = (45, 4,    Bitfield,  Other,              TLS),             // word32
+    R_390_TLS_LDM64   = (46, 8,    Unchecked, Other,              TLS),             // word64
+    R_390_TLS_IE32    = (47, 4,    Bitfield,  Other,              TLS),             // word32
+    R_390_TLS_IE64    = (48, 8,    Unchecked, Other,              TLS),             // word64
+    R_390_TLS_IEENT   = (49, 4,    Bitfield,  Other,              TLS),             // pc32
+    R_390_TLS_LE32    = (50, 4,    Bitfield,  Other,              TLS),             // word32
+    R_390_TLS_LE64    = (51, 8,    Unchecked, Other,              TLS),             // word64
+    R_390_TLS_LDO32   = (52, 4,    Bitfield,  Other,              TLS),             // word32
+    R_390_TLS_LDO64   = (53, 8,    Unchecked, Other,              TLS),             // word64
+    R_390_TLS_DTPMOD  = (54, 8,    Unchecked, Other,              TLS | DYNAMIC),   // word64
+    R_390_TLS_DTPOFF  = (55, 8,    Unchecked, Other,              TLS),             // word64
+    R_390_TLS_TPOFF   = (56, 8,    Unchecked, Other,              TLS | DYNAMIC),   // word64
+
+    // Long-displacement and other relocations
+    R_390_20          = (57, 4,    Bitfield,  Absolute,           0),               // low20  S + A
+    R_390_GOT20       = (58, 4,    Bitfield,  GotOffset,          GOT),             // low20  O + A
+    R_390_GOTPLT20    = (59, 4,    Signed,    Other,              PC | GOT | PLT),  // low20  P + A
+    R_390_TLS_GOTIE20 = (60, 4,    Bitfield,  Other,              GOT | TLS),       // low20
+    R_390_IRELATIVE   = (61, WORD, Unchecked, Other,              DYNAMIC),         // word64
+    R_390_PC12DBL     = (62, 2,    Signed,    PcRelative,         PC),              // pc12   (S + A - P) >> 1
+    R_390_PLT12DBL    = (63, 2,    Signed,    PltPcRelative,      PC | PLT),        // pc12   (L + A - P) >> 1
+    R_390_PC24DBL     = (64, 4,    Signed,    PcRelative,         PC),              // pc24   (S + A - P) >> 1
+    R_390_PLT24DBL    = (65, 4,    Signed,    PltPcRelative,      PC | PLT)         // pc24   (L + A - P) >> 1
+});
+
+// SPARC
+relocations!(sparc_relocation_name, sparc_relocation_description, {
+    R_SPARC_NONE             = (0,   0, Unchecked, None,          0),              // none        none
+    R_SPARC_8                = (1,   1, Bitfield,  Absolute,      0),              // V-byte8     S + A
+    R_SPARC_16               = (2,   2, Bitfield,  Absolute,      0),              // V-half16    S + A
+    R_SPARC_32               = (3,   4, Bitfield,  Absolute,      0),              // V-word32    S + A
+    R_SPARC_DISP8            = (4,   1, Signed,    PcRelative,    PC),             // V-byte8     S + A - P
+    R_SPARC_DISP16           = (5,   2, Signed,    PcRelative,    PC),             // V-half16    S + A - P
+    R_SPARC_DISP32           = (6,   4, Signed,    PcRelative,    PC),             // V-disp32    S + A - P
+    R_SPARC_WDISP30          = (7,   4, Signed,    PcRelative,    PC),             // V-disp30    (S + A - P) >> 2
+    R_SPARC_WDISP22          = (8,   4, Signed,    PcRelative,    PC),             // V-disp22    (S + A - P) >> 2
+    R_SPARC_HI22             = (9,   4, Unchecked, Absolute,      0),              // T-imm22     (S + A) >> 10
+    R_SPARC_22               = (10,  4, Bitfield,  Absolute,      0),              // V-imm22     S + A
+    R_SPARC_13               = (11,  4, Bitfield,  Absolute,      0),              // V-simm13    S + A
+    R_SPARC_LO10             = (12,  4, Unchecked, Absolute,      0),              // T-simm13    (S + A) & 0x3ff
+    R_SPARC_GOT10            = (13,  4, Bitfield,  GotOffset,     GOT),            // T-simm13    G & 0x3ff
+    R_SPARC_GOT13            = (14,  4, Bitfield,  GotOffset,     GOT),            // V-simm13    G
+    R_SPARC_GOT22            = (15,  4, Bitfield,  GotOffset,     GOT),            // T-simm22    G >> 10
+    R_SPARC_PC10             = (16,  4, Signed,    PcRelative,    PC),             // T-simm13    (S + A - P) & 0x3ff
+    R_SPARC_PC22             = (17,  4, Signed,    PcRelative,    PC),             // V-disp22    (S + A - P) >> 10
+    R_SPARC_WPLT30           = (18,  4, Signed,    PltPcRelative, PC | PLT),       // V-disp30    (L + A - P) >> 2
+    R_SPARC_COPY             = (19,  0, Unchecked, Copy,          DYNAMIC),        // none        none
+    R_SPARC_GLOB_DAT         = (20,  4, Bitfield,  Absolute,      DYNAMIC),        // V-word32    S + A
+    R_SPARC_JMP_SLOT         = (21,  0, Unchecked, None,          DYNAMIC),        // none
+    R_SPARC_RELATIVE         = (22,  4, Bitfield,  BaseRelative,  DYNAMIC),        // V-word32    B + A
+    R_SPARC_UA32             = (23,  4, Bitfield,  Absolute,      0),              // V-word32    S + A
+    R_SPARC_PLT32            = (24,  4, Bitfield,  Plt,           PLT),            // V-word32    L + A
+    R_SPARC_HIPLT22          = (25,  4, Bitfield,  Plt,           PLT),            // T-imm22     (L + A) >> 10
+    R_SPARC_LOPLT10          = (26,  4, Bitfield,  Plt,           PLT),            // T-simm13    (L + A) & 0x3ff
+    R_SPARC_PCPLT32          = (27,  4, Signed,    PltPcRelative, PC | PLT),       // V-word32    L + A - P
+    R_SPARC_PCPLT22          = (28,  4, Signed,    PltPcRelative, PC | PLT),       // V-disp22    (L + A - P) >> 10
+    R_SPARC_PCPLT10          = (29,  4, Signed,    PltPcRelative, PC | PLT),       // V-simm13    (L + A - P) & 0x3ff
+    R_SPARC_10               = (30,  4, Bitfield,  Absolute,      0),              // V-simm10    S + A
+    R_SPARC_11               = (31,  4, Bitfield,  Absolute,      0),              // V-simm11    S + A
+    R_SPARC_64               = (32,  8, Unchecked, Absolute,      0),              // V-xword64   S + A
+    R_SPARC_OLO10            = (33,  4, Unchecked, Other,         GOT),            // V-simm13    ((S + A) & 0x3ff) + O
+    R_SPARC_HH22             = (34,  4, Unchecked, Absolute,      0),              // V-imm22     (S + A) >> 42
+    R_SPARC_HM10             = (35,  4, Unchecked, Absolute,      0),              // T-simm13    ((S + A) >> 32) & 0x3ff
+    R_SPARC_LM22             = (36,  4, Unchecked, Absolute,      0),              // T-imm22     (S + A) >> 10
+    R_SPARC_PC_HH22          = (37,  4, Unchecked, PcRelative,    PC),             // V-imm22     (S + A - P) >> 42
+    R_SPARC_PC_HM10          = (38,  4, Unchecked, PcRelative,    PC),             // T-simm13    ((S + A - P) >> 32) & 0x3ff
+    R_SPARC_PC_LM22          = (39,  4, Unchecked, PcRelative,    PC),             // T-imm22     (S + A - P) >> 10
+    R_SPARC_WDISP16          = (40,  4, Signed,    PcRelative,    PC),             // V-d2/disp14 (S + A - P) >> 2
+    R_SPARC_WDISP19          = (41,  4, Signed,    PcRelative,    PC),             // V-disp19    (S + A - P) >> 2
+    R_SPARC_GLOB_JMP         = (42,  0, Unchecked, Other,         0),
+    R_SPARC_7                = (43,  4, Bitfield,  Absolute,      0),              // V-imm7      S + A
+    R_SPARC_5                = (44,  4, Bitfield,  Absolute,      0),              // V-imm5      S + A
+    R_SPARC_6                = (45,  4, Bitfield,  Absolute,      0),              // V-imm6      S + A
+    R_SPARC_DISP64           = (46,  8, Unchecked, PcRelative,    PC),             // V-xword64   S + A - P
+    R_SPARC_PLT64            = (47,  8, Unchecked, Plt,           PLT),            // V-xword64   L + A
+    R_SPARC_HIX22            = (48,  4, Unchecked, Other,         0),              // V-imm22     ((S + A) ^ 0xffffffffffffffff) >> 10
+    R_SPARC_LOX10            = (49,  4, Unchecked, Absolute,      0),              // T-simm13    ((S + A) & 0x3ff) | 0x1c00
+    R_SPARC_H44              = (50,  4, Unchecked, Absolute,      0),              // V-imm22     (S + A) >> 22
+    R_SPARC_M44              = (51,  4, Unchecked, Absolute,      0),              // T-imm10     ((S + A) >> 12) & 0x3ff
+    R_SPARC_L44              = (52,  4, Unchecked, Absolute,      0),              // T-imm13     (S + A) & 0xfff
+    R_SPARC_REGISTER         = (53,  8, Unchecked, Absolute,      0),              // V-xword64   S + A
+    R_SPARC_UA64             = (54,  8, Unchecked, Absolute,      0),              // V-xword64   S + A
+    R_SPARC_UA16             = (55,  2, Bitfield,  Absolute,      0),              // V-half16    S + A
+
+    // Thread-local storage relocations
+    R_SPARC_TLS_GD_HI22      = (56,  4, Unchecked, Other,         TLS),            // T-simm22
+    R_SPARC_TLS_GD_LO10      = (57,  4, Unchecked, Other,         TLS),            // T-simm13
+    R_SPARC_TLS_GD_ADD       = (58,  0, Unchecked, None,          TLS),            // none
+    R_SPARC_TLS_GD_CALL      = (59,  4, Bitfield,  Other,         TLS),            // V-disp30
+    R_SPARC_TLS_LDM_HI22     = (60,  4, Unchecked, Other,         TLS),            // T-simm22
+    R_SPARC_TLS_LDM_LO10     = (61,  4, Unchecked, Other,         TLS),            // T-simm13
+    R_SPARC_TLS_LDM_ADD      = (62,  0, Unchecked, None,          TLS),            // none
+    R_SPARC_TLS_LDM_CALL     = (63,  4, Bitfield,  Other,         TLS),            // V-disp30
+    R_SPARC_TLS_LDO_HIX22    = (64,  4, Unchecked, Other,         TLS),            // T-simm22
+    R_SPARC_TLS_LDO_LOX10    = (65,  4, Unchecked, Other,         TLS),            // T-simm13
+    R_SPARC_TLS_LDO_ADD      = (66,  0, Unchecked, None,          TLS),            // none
+    R_SPARC_TLS_IE_HI22      = (67,  4, Unchecked, Other,         TLS),            // T-simm22
+    R_SPARC_TLS_IE_LO10      = (68,  4, Unchecked, Other,         TLS),            // T-simm13
+    R_SPARC_TLS_IE_LD        = (69,  0, Unchecked, None,          TLS),            // none
+    R_SPARC_TLS_IE_LDX       = (70,  0, Unchecked, None,          TLS),            // none
+    R_SPARC_TLS_IE_ADD       = (71,  0, Unchecked, None,          TLS),            // none
+    R_SPARC_TLS_LE_HIX22     = (72,  4, Unchecked, Other,         TLS),            // T-imm22
+    R_SPARC_TLS_LE_LOX10     = (73,  4, Unchecked, Other,         TLS),            // T-simm13
+    R_SPARC_TLS_DTPMOD32     = (74,  4, Bitfield,  Other,         TLS | DYNAMIC),  // V-word32
+    R_SPARC_TLS_DTPMOD64     = (75,  8, Unchecked, Other,         TLS | DYNAMIC),  // V-word64
+    R_SPARC_TLS_DTPOFF32     = (76,  4, Bitfield,  Other,         TLS),            // V-word32
+    R_SPARC_TLS_DTPOFF64     = (77,  8, Unchecked, Other,         TLS),            // V-word64
+    R_SPARC_TLS_TPOFF32      = (78,  4, Bitfield,  Other,         TLS | DYNAMIC),  // V-word32
+    R_SPARC_TLS_TPOFF64      = (79,  8, Unchecked, Other,         TLS | DYNAMIC),  // V-word64
+
+    // GOT         data relocations
+    R_SPARC_GOTDATA_HIX22    = (80,  4, Unchecked, Other,         GOT),            // V-imm22
+    R_SPARC_GOTDATA_LOX10    = (81,  4, Unchecked, Other,         GOT),            // T-imm13
+    R_SPARC_GOTDATA_OP_HIX22 = (82,  4, Unchecked, Other,         GOT),            // T-imm22
+    R_SPARC_GOTDATA_OP_LOX10 = (83,  4, Unchecked, Other,         GOT),            // T-imm13
+    R_SPARC_GOTDATA_OP       = (84,  0, Unchecked, None,          GOT),            // none
+    R_SPARC_H34              = (85,  4, Unchecked, Absolute,      0),              // V-imm22     (S + A) >> 12
+    R_SPARC_SIZE32           = (86,  4, Bitfield,  Size,          0),              // V-word32    Z + A
+    R_SPARC_SIZE64           = (87,  8, Unchecked, Size,          0),              // V-xword64   Z + A
+    R_SPARC_WDISP10          = (88,  4, Signed,    PcRelative,    PC),             // V-d2/disp8  (S + A - P) >> 2
+
+    // GNU         extensions
+    R_SPARC_JMP_IREL         = (248, 0, Unchecked, Other,         DYNAMIC),
+    R_SPARC_IRELATIVE        = (249, 0, Unchecked, Other,         DYNAMIC),
+    R_SPARC_GNU_VTINHERIT    = (250, 0, Unchecked, None,          0),              // none
+    R_SPARC_GNU_VTENTRY      = (251, 0, Unchecked, None,          0),              // none
+    R_SPARC_REV32            = (252, 4, Bitfield,  Absolute,      0)               // V-word32    S + A
+});
+
+// LoongArch
+relocations!(loongarch_relocation_name, loongarch_relocation_description, {
+    R_LARCH_NONE                       = (0,   0,    Unchecked, None,                   0),              // none   none
+    R_LARCH_32                         = (1,   4,    Bitfield,  Absolute,               0),              // word32 S + A
+    R_LARCH_64                         = (2,   8,    Unchecked, Absolute,               0),              // word64 S + A
+    R_LARCH_RELATIVE                   = (3,   WORD, Unchecked, BaseRelative,           DYNAMIC),        // word64 B + A
+    R_LARCH_COPY                       = (4,   0,    Unchecked, Copy,                   DYNAMIC),        // none   none
+    R_LARCH_JUMP_SLOT                  = (5,   WORD, Unchecked, Symbol,                 DYNAMIC),        // word64 S
+    R_LARCH_TLS_DTPMOD32               = (6,   4,    Bitfield,  Other,                  TLS | DYNAMIC),  // word32
+    R_LARCH_TLS_DTPMOD64               = (7,   8,    Unchecked, Other,                  TLS | DYNAMIC),  // word64
+    R_LARCH_TLS_DTPREL32               = (8,   4,    Bitfield,  Other,                  TLS),            // word32
+    R_LARCH_TLS_DTPREL64               = (9,   8,    Unchecked, Other,                  TLS),            // word64
+    R_LARCH_TLS_TPREL32                = (10,  4,    Bitfield,  Other,                  TLS | DYNAMIC),  // word32
+    R_LARCH_TLS_TPREL64                = (11,  8,    Unchecked, Other,                  TLS | DYNAMIC),  // word64
+    R_LARCH_IRELATIVE                  = (12,  WORD, Unchecked, Other,                  DYNAMIC),        // word64
+    R_LARCH_TLS_DESC32                 = (13,  4,    Unchecked, Other,                  TLS | DYNAMIC),  // word32
+    R_LARCH_TLS_DESC64                 = (14,  8,    Unchecked, Other,                  TLS | DYNAMIC),  // word64
+
+    // Stack-based relocations (deprecated)
+    R_LARCH_MARK_LA                    = (20,  0,    Unchecked, None,                   0),
+    R_LARCH_MARK_PCREL                 = (21,  0,    Unchecked, None,                   0),
+    R_LARCH_SOP_PUSH_PCREL             = (22,  0,    Unchecked, Other,                  PC),
+    R_LARCH_SOP_PUSH_ABSOLUTE          = (23,  0,    Unchecked, Other,                  0),
+    R_LARCH_SOP_PUSH_DUP               = (24,  0,    Unchecked, Other,                  0),
+    R_LARCH_SOP_PUSH_GPREL             = (25,  0,    Unchecked, Other,                  PC),
+    R_LARCH_SOP_PUSH_TLS_TPREL         = (26,  0,    Unchecked, Other,                  TLS),
+    R_LARCH_SOP_PUSH_TLS_GOT           = (27,  0,    Unchecked, Other,                  GOT | TLS),
+    R_LARCH_SOP_PUSH_TLS_GD            = (28,  0,    Unchecked, Other,                  TLS),
+    R_LARCH_SOP_PUSH_PLT_PCREL         = (29,  0,    Unchecked, Other,                  PC | PLT),
+    R_LARCH_SOP_ASSERT                 = (30,  0,    Unchecked, Other,                  0),
+    R_LARCH_SOP_NOT                    = (31,  0,    Unchecked, Other,                  0),
+    R_LARCH_SOP_SUB                    = (32,  0,    Unchecked, Other,                  0),
+    R_LARCH_SOP_SL                     = (33,  0,    Unchecked, Other,                  0),
+    R_LARCH_SOP_SR                     = (34,  0,    Unchecked, Other,                  0),
+    R_LARCH_SOP_ADD                    = (35,  0,    Unchecked, Other,                  0),
+    R_LARCH_SOP_AND                    = (36,  0,    Unchecked, Other,                  0),
+    R_LARCH_SOP_IF_ELSE                = (37,  0,    Unchecked, Other,                  0),
+    R_LARCH_SOP_POP_32_S_10_5          = (38,  0,    Unchecked, Other,                  0),
+    R_LARCH_SOP_POP_32_U_10_12         = (39,  0,    Unchecked, Other,                  0),
+    R_LARCH_SOP_POP_32_S_10_12         = (40,  0,    Unchecked, Other,                  0),
+    R_LARCH_SOP_POP_32_S_10_16         = (41,  0,    Unchecked, Other,                  0),
+    R_LARCH_SOP_POP_32_S_10_16_S2      = (42,  0,    Unchecked, Other,                  0),
+    R_LARCH_SOP_POP_32_S_5_20          = (43,  0,    Unchecked, Other,                  0),
+    R_LARCH_SOP_POP_32_S_0_5_10_16_S2  = (44,  0,    Unchecked, Other,                  0),
+    R_LARCH_SOP_POP_32_S_0_10_10_16_S2 = (45,  0,    Unchecked, Other,                  0),
+    R_LARCH_SOP_POP_32_U               = (46,  0,    Unchecked, Other,                  0),
+
+    // Arithmetic relocations
+    R_LARCH_ADD8                       = (47,  1,    Bitfield,  Other,                  0),              // byte8  V + S + A
+    R_LARCH_ADD16                      = (48,  2,    Bitfield,  Other,                  0),              // half16 V + S + A
+    R_LARCH_ADD24                      = (49,  3,    Bitfield,  Other,                  0),              // word24 V + S + A
+    R_LARCH_ADD32                      = (50,  4,    Bitfield,  Other,                  0),              // word32 V + S + A
+    R_LARCH_ADD64                      = (51,  8,    Unchecked, Other,                  0),              // word64 V + S + A
+    R_LARCH_SUB8                       = (52,  1,    Bitfield,  Other,                  0),              // byte8  V - S - A
+    R_LARCH_SUB16                      = (53,  2,    Bitfield,  Other,                  0),              // half16 V - S - A
+    R_LARCH_SUB24                      = (54,  3,    Bitfield,  Other,                  0),              // word24 V - S - A
+    R_LARCH_SUB32                      = (55,  4,    Bitfield,  Other,                  0),              // word32 V - S - A
+    R_LARCH_SUB64                      = (56,  8,    Unchecked, Other,                  0),              // word64 V - S - A
+    R_LARCH_GNU_VTINHERIT              = (57,  0,    Unchecked, None,                   0),              // none
+    R_LARCH_GNU_VTENTRY                = (58,  0,    Unchecked, None,                   0),              // none
+
+    // Instruction relocations
+    R_LARCH_B16                        = (64,  4,    Signed,    PcRelative,             PC),             // sk16   (S + A - P) >> 2
+    R_LARCH_B21                        = (65,  4,    Signed,    PcRelative,             PC),             // sk21   (S + A - P) >> 2
+    R_LARCH_B26                        = (66,  4,    Signed,    PcRelative,             PC),             // sk26   (S + A - P) >> 2
+    R_LARCH_ABS_HI20                   = (67,  4,    Signed,    Absolute,               0),              // sk20   (S + A) >> 12
+    R_LARCH_ABS_LO12                   = (68,  4,    Unchecked, Absolute,               0),              // uk12   (S + A) & 0xfff
+    R_LARCH_ABS64_LO20                 = (69,  4,    Unchecked, Absolute,               0),              // sk20   (S + A) >> 32
+    R_LARCH_ABS64_HI12                 = (70,  4,    Unchecked, Absolute,               0),              // sk12   (S + A) >> 52
+    R_LARCH_PCALA_HI20                 = (71,  4,    Signed,    PagePcRelative,         PC),             // sk20   (page(S + A) - page(P)) >> 12
+    R_LARCH_PCALA_LO12                 = (72,  4,    Unchecked, Absolute,               0),              // sk12   (S + A) & 0xfff
+    R_LARCH_PCALA64_LO20               = (73,  4,    Unchecked, Other,                  PC),             // sk20   (S + A - page(P)) >> 32
+    R_LARCH_PCALA64_HI12               = (74,  4,    Unchecked, Other,                  PC),             // sk12   (S + A - page(P)) >> 52
+    R_LARCH_GOT_PC_HI20                = (75,  4,    Signed,    GotEntryPagePcRelative, PC | GOT),       // sk20   (page(GP + G) - page(P)) >> 12
+    R_LARCH_GOT_PC_LO12                = (76,  4,    Unchecked, GotEntry,               GOT),            // sk12   (GP + G) & 0xfff
+    R_LARCH_GOT64_PC_LO20              = (77,  4,    Unchecked, Other,                  PC | GOT),       // sk20   (GP + G - page(P)) >> 32
+    R_LARCH_GOT64_PC_HI12              = (78,  4,    Unchecked, Other,                  PC | GOT),       // sk12   (GP + G - page(P)) >> 52
+    R_LARCH_GOT_HI20                   = (79,  4,    Signed,    GotEntry,               GOT),            // sk20   (GP + G) >> 12
+    R_LARCH_GOT_LO12                   = (80,  4,    Unchecked, GotEntry,               GOT),            // sk12   (GP + G) & 0xfff
+    R_LARCH_GOT64_LO20                 = (81,  4,    Unchecked, GotEntry,               GOT),            // sk20   (GP + G) >> 32
+    R_LARCH_GOT64_HI12                 = (82,  4,    Unchecked, GotEntry,               GOT),            // sk12   (GP + G) >> 52
+    R_LARCH_TLS_LE_HI20                = (83,  4,    Signed,    Other,                  TLS),            // sk20   TP >> 12
+    R_LARCH_TLS_LE_LO12                = (84,  4,    Unchecked, Other,                  TLS),            // uk12   TP & 0xfff
+    R_LARCH_TLS_LE64_LO20              = (85,  4,    Unchecked, Other,                  TLS),            // sk20   TP >> 32
+    R_LARCH_TLS_LE64_HI12              = (86,  4,    Unchecked, Other,                  TLS),            // sk12   TP >> 52
+    R_LARCH_TLS_IE_PC_HI20             = (87,  4,    Signed,    Other,                  PC | GOT | TLS), // sk20
+    R_LARCH_TLS_IE_PC_LO12             = (88,  4,    Unchecked, Other,                  PC | GOT | TLS), // sk12
+    R_LARCH_TLS_IE64_PC_LO20           = (89,  4,    Unchecked, Other,                  PC | GOT | TLS), // sk20
+    R_LARCH_TLS_IE64_PC_HI12           = (90,  4,    Unchecked, Other,                  PC | GOT | TLS), // sk12
+    R_LARCH_TLS_IE_HI20                = (91,  4,    Signed,    Other,                  GOT | TLS),      // sk20
+    R_LARCH_TLS_IE_LO12                = (92,  4,    Unchecked, Other,                  GOT | TLS),      // sk12
+    R_LARCH_TLS_IE64_LO20              = (93,  4,    Unchecked, Other,                  GOT | TLS),      // sk20
+    R_LARCH_TLS_IE64_HI12              = (94,  4,    Unchecked, Other,                  GOT | TLS),      // sk12
+    R_LARCH_TLS_LD_PC_HI20             = (95,  4,    Signed,    Other,                  PC | GOT | TLS), // sk20
+    R_LARCH_TLS_LD_HI20                = (96,  4,    Signed,    Other,                  GOT | TLS),      // sk20
+    R_LARCH_TLS_GD_PC_HI20             = (97,  4,    Signed,    Other,                  PC | GOT | TLS), // sk20
+    R_LARCH_TLS_GD_HI20                = (98,  4,    Signed,    Other,                  GOT | TLS),      // sk20
+    R_LARCH_32_PCREL                   = (99,  4,    Signed,    PcRelative,             PC),             // word32 S + A - P
+    R_LARCH_RELAX                      = (100, 0,    Unchecked, None,                   0),              // none
+    R_LARCH_DELETE                     = (101, 0,    Unchecked, Other,                  0),
+    R_LARCH_ALIGN                      = (102, 0,    Unchecked, None,                   0),              // none
+    R_LARCH_PCREL20_S2                 = (103, 4,    Signed,    PcRelative,             PC),             // sk20   (S + A - P) >> 2
+    R_LARCH_CFA                        = (104, 0,    Unchecked, Other,                  0),
+    R_LARCH_ADD6                       = (105, 1,    Bitfield,  Other,                  0),              // word6  V + S + A
+    R_LARCH_SUB6                       = (106, 1,    Bitfield,  Other,                  0),              // word6  V - S - A
+    R_LARCH_ADD_ULEB128                = (107, 0,    Unchecked, Other,                  0),              // uleb128 V + S + A
+    R_LARCH_SUB_ULEB128                = (108, 0,    Unchecked, Other,                  0),              // uleb128 V - S - A
+    R_LARCH_64_PCREL                   = (109, 8,    Unchecked, PcRelative,             PC),             // word64 S + A - P
+    R_LARCH_CALL36                     = (110, 8,    Signed,    PcRelative,             PC),             // sk36   (S + A - P) >> 2
+    R_LARCH_TLS_DESC_PC_HI20           = (111, 4,    Signed,    Other,                  PC | GOT | TLS), // sk20
+    R_LARCH_TLS_DESC_PC_LO12           = (112, 4,    Unchecked, Other,                  PC | GOT | TLS), // sk12
+    R_LARCH_TLS_DESC64_PC_LO20         = (113, 4,    Unchecked, Other,                  PC | GOT | TLS), // sk20
+    R_LARCH_TLS_DESC64_PC_HI12         = (114, 4,    Unchecked, Other,                  PC | GOT | TLS), // sk12
+    R_LARCH_TLS_DESC_HI20              = (115, 4,    Signed,    Other,                  GOT | TLS),      // sk20
+    R_LARCH_TLS_DESC_LO12              = (116, 4,    Unchecked, Other,                  GOT | TLS),      // sk12
+    R_LARCH_TLS_DESC64_LO20            = (117, 4,    Unchecked, Other,                  GOT | TLS),      // sk20
+    R_LARCH_TLS_DESC64_HI12            = (118, 4,    Unchecked, Other,                  GOT | TLS),      // sk12
+    R_LARCH_TLS_DESC_LD                = (119, 0,    Unchecked, None,                   GOT | TLS),      // none
+    R_LARCH_TLS_DESC_CALL              = (120, 0,    Unchecked, None,                   GOT | TLS),      // none
+    R_LARCH_TLS_LE_HI20_R              = (121, 4,    Unchecked, Other,                  TLS),            // sk20
+    R_LARCH_TLS_LE_ADD_R               = (122, 0,    Unchecked, None,                   TLS),            // none
+    R_LARCH_TLS_LE_LO12_R              = (123, 4,    Unchecked, Other,                  TLS),            // sk12
+    R_LARCH_TLS_LD_PCREL20_S2          = (124, 4,    Signed,    Other,                  PC | GOT | TLS), // sk20
+    R_LARCH_TLS_GD_PCREL20_S2          = (125, 4,    Signed,    Other,                  PC | GOT | TLS), // sk20
+    R_LARCH_TLS_DESC_PCREL20_S2        = (126, 4,    Signed,    Other,                  PC | GOT | TLS)  // sk20
 });
@@ -0,0 +1,146 @@
+use constants::*;
+use edit::*;
+use error::*;
+use types::*;
+use writer::*;
+
+/// What [ElfFile::strip](struct.ElfFile.html#method.strip) removes, like the options of `strip`
+/// and `objcopy`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StripMode {
+    /// Remove the debugging sections and the symbol table (`--strip-all`). Relocatable object
+    /// files keep the symbols used by relocations.
+    All,
+    /// Remove the debugging sections and the symbols defined in them (`--strip-debug`).
+    Debug,
+    /// Remove the debugging sections and the symbols which are not needed to process relocations
+    /// (`--strip-unneeded`). Relocatable object files keep their global symbols and the symbols
+    /// used by relocations, and executables and shared objects lose the whole symbol table.
+    Unneeded,
+    /// Keep only what a separate debug file needs: the debugging sections, the symbol table,
+    /// notes, and the other non-allocated sections (`--only-keep-debug`). The other sections
+    /// become `SHT_NOBITS` sections with their original addresses and sizes, and the segments
+    /// keep only the notes and headers they contain.
+    OnlyKeepDebug,
+}
+
+/// Returns `true` if `name` is the name of a debugging section, such as `.debug_info`,
+/// `.zdebug_info`, or `.stab`.
+pub fn is_debug_section(name: &[u8]) -> bool {
+    name.starts_with(b".debug") || name.starts_with(b".zdebug") || name.starts_with(b".stab") ||
+        name.starts_with(b".gnu.linkonce.wi.") || name.starts_with(b".gnu.debuglto_") ||
+        name == b".line" || name == b".gdb_index"
+}
+
+fn is_debug(section: &FileSection) -> bool {
+    section.header.sh_flags & SHF_ALLOC == 0 && is_debug_section(&section.name)
+}
+
+impl ElfFile {
+    /// Removes the debugging sections and symbols selected by `mode` and
+    /// [compacts](#method.compact) the file.
+    ///
+    /// Relocation sections for removed sections are removed as well, and the remaining sections
+    /// and symbols are renumbered.
+    pub fn strip(&mut self, mode: StripMode) -> Result<()> {
+        if mode == StripMode::OnlyKeepDebug {
+            self.keep_only_debug();
+            self.compact();
+            return Ok(());
+        }
+        let relocatable = self.header.e_type == ET_REL;
+        let symtab = self.sections.iter().position(|section| section.header.sh_type == SHT_SYMTAB);
+        let strtab = symtab.map(|symtab| self.sections[symtab].header.sh_link as usize);
+        let whole_symtab = !relocatable && (mode == StripMode::All || mode == StripMode::Unneeded);
+        // The symbol string table is removed with the symbol table unless something else uses it.
+        let strtab_shared = strtab.is_none_or(|strtab| {
+            strtab == 0 || strtab == self.shstrndx as usize ||
+                self.sections.iter().enumerate().any(|(index, section)| {
+                    Some(index) != symtab && section.header.sh_link as usize == strtab &&
+                        section.header.sh_type != SHT_SYMTAB_SHNDX &&
+                        section.header.sh_type != SHT_REL && section.header.sh_type != SHT_RELA
+                })
+        });
+        let indexes = self.remove_sections(|index, section| {
+            let index = Some(index as usize);
+            is_debug(section) ||
+                (whole_symtab && (index == symtab || (index == strtab && !strtab_shared)))
+        })?;
+        let (symtab, strtab) = match symtab.and_then(|symtab| indexes[symtab]) {
+            Some(symtab) => (symtab, self.sections[symtab as usize].header.sh_link),
+            None => {
+                self.compact();
+                return Ok(());
+            },
+        };
+
+        if relocatable && mode != StripMode::Debug {
+            let referenced = self.referenced_symbols(symtab);
+            let keep_global = mode == StripMode::Unneeded;
+            self.retain_symbols(symtab, |index, symbol| {
+                referenced.contains(&index) || (keep_global && symbol.binding() != STB_LOCAL)
+            })?;
+            // A symbol table with only the null symbol is not needed.
+            let entry_size = if self.format().is_64bit() { 24 } else { 16 };
+            if self.sections[symtab as usize].data.len() <= entry_size && strtab != self.shstrndx {
+                self.remove_sections(|index, _| index == symtab || index == strtab)?;
+            }
+        }
+        self.compact();
+        Ok(())
+    }
+
+    // Turns the sections which are not needed in a separate debug file into SHT_NOBITS sections
+    // and shrinks the segments to the parts of the file which remain.
+    fn keep_only_debug(&mut self) {
+        let mut nobits = vec![false; self.sections.len()];
+        for (index, section) in self.sections.iter().enumerate().skip(1) {
+            let header = &section.header;
+            nobits[index] = header.sh_flags & SHF_ALLOC != 0 && header.sh_type != SHT_NOTE;
+        }
+        for (index, section) in self.sections.iter().enumerate() {
+            let header = &section.header;
+            if (header.sh_type == SHT_REL || header.sh_type == SHT_RELA) &&
+               nobits.get(header.sh_info as usize) == Some(&true)
+            {
+                nobits[index] = true;
+            }
+        }
+        for (section, &nobits) in self.sections.iter_mut().zip(&nobits) {
+            if nobits && section.header.sh_type != SHT_NOBITS {
+                section.header.sh_type = SHT_NOBITS;
+                section.header.sh_size = section.data.len() as Elf64_Xword;
+                section.data = Vec::new();
+            }
+        }
+
+        // The parts of the file which remain: the headers and the sections with contents.
+        let (ehsize, phentsize, _) = header_sizes(self.format());
+        let mut ranges = vec![(0, Elf64_Off::from(ehsize))];
+        if !self.segments.is_empty() {
+            let size = Elf64_Off::from(phentsize) * self.segments.len() as Elf64_Off;
+            ranges.push((self.header.e_phoff, self.header.e_phoff + size));
+        }
+        for section in &self.sections {
+            let header = &section.header;
+            if header.sh_type != SHT_NOBITS && header.sh_type != SHT_NULL {
+                ranges.push((header.sh_offset, header.sh_offset + section.data.len() as Elf64_Off));
+            }
+        }
+        for segment in &mut self.segments {
+            let start = segment.p_offset;
+            let end = start + segment.p_filesz;
+            let filesz = ranges.iter()
+                .filter(|&&(offset, range_end)| start <= offset && range_end <= end && offset < range_end)
+                .map(|&(_, range_end)| range_end - start)
+                .max()
+                .unwrap_or(0);
+            segment.p_filesz = filesz;
+            // An empty segment keeps an offset congruent to its address, as close to the start
+            // of the file as possible.
+            if filesz == 0 {
+                segment.p_offset %= segment.p_align.max(1);
+            }
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use std::borrow::Cow;
+use std::collections::HashSet;
+
+use constants::*;
+use error::*;
+use reader::*;
+use types::*;
+
+/// Where a [NamedAddress](struct.NamedAddress.html) comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum AddressSource {
+    /// The static symbol table (`.symtab`).
+    SymbolTable,
+    /// The dynamic symbol table, located through the dynamic table.
+    DynamicSymbolTable,
+    /// A synthetic `name@plt` symbol for a [PLT entry](struct.PltEntry.html).
+    Plt,
+}
+
+/// A named range of addresses in the [symbol index](struct.SymbolIndex.html).
+#[derive(Debug, Clone)]
+pub struct NamedAddress<'a> {
+    pub address: Elf64_Addr,
+    /// The size of the range, which is 0 for symbols of unknown size.
+    pub size: Elf64_Xword,
+    pub name: Cow<'a, [u8]>,
+    pub source: AddressSource,
+}
+
+impl<'a> NamedAddress<'a> {
+    fn contains(&self, address: Elf64_Addr) -> bool {
+        address == self.address || address - self.address < self.size
+    }
+}
+
+/// The named addresses of an executable or shared object, sorted by address, for mapping
+/// addresses back to symbols as `objdump -d` and profilers do.
+#[derive(Debug, Clone)]
+pub struct SymbolIndex<'a> {
+    entries: Vec<NamedAddress<'a>>,
+}
+
+impl<'a> SymbolIndex<'a> {
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the named addresses, sorted by address.
+    pub fn entries(&self) -> &[NamedAddress<'a>] {
+        &self.entries
+    }
+
+    /// Returns the named address containing `address`, if any.
+    ///
+    /// Of the entries starting at or below `address` whose ranges contain it, one starting
+    /// closest to `address` is returned, and of those the first in the order
+    /// [AddressSource](enum.AddressSource.html) lists them. Symbols of unknown size only contain
+    /// their own address, so a label inside a function does not hide the function.
+    pub fn lookup(&self, address: Elf64_Addr) -> Option<&NamedAddress<'a>> {
+        let mut end = self.entries.partition_point(|entry| entry.address <= address);
+        while end > 0 {
+            let start = self.entries[end-1].address;
+            let first = self.entries[..end].iter().rposition(|entry| entry.address != start).map_or(0, |i| i + 1);
+            if let Some(entry) = self.entries[first..end].iter().find(|entry| entry.contains(address)) {
+                return Some(entry);
+            }
+            end = first;
+        }
+        None
+    }
+}
+
+// Returns `true` for the ARM and AArch64 mapping symbols, such as `$x` and `$d.1`, which mark
+// the starts of code and data rather than naming them.
+fn is_mapping_symbol(machine: Elf_Half, name: &[u8]) -> bool {
+    (machine == EM_ARM || machine == EM_AARCH64) && name.len() >= 2 && name[0] == b'$' &&
+        b"adtx".contains(&name[1]) && (name.len() == 2 || name[2] == b'.')
+}
+
+// Returns the symbols of `table` which name code or data in memory.
+fn named_addresses<'a>(table: &SymbolTableRef<'a>, source: AddressSource) -> Result<Vec<NamedAddress<'a>>> {
+    let mut entries = Vec::new();
+    for index in 1..table.len() {
+        let symbol = table.get(index)?;
+        let name = match symbol.symbol_name {
+            Some(name) if !name.is_empty() && !is_mapping_symbol(table.machine, name) => name,
+            _                                                                         => continue,
+        };
+        let defined = match symbol.section {
+            SectionIndex::Normal(index) => index != 0,
+            SectionIndex::Reserved(_)   => false,
+        };
+        // Section and file symbols name no code or data, and TLS symbols hold offsets.
+        if !defined || matches!(symbol.symbol_type(), STT_SECTION | STT_FILE | STT_TLS) {
+            continue;
+        }
+        entries.push(NamedAddress {
+            address: symbol.address(),
+            size: symbol.size,
+            name: Cow::Borrowed(name),
+            source,
+        });
+    }
+    Ok(entries)
+}
+
+impl<'a> Reader<'a> {
+    /// Returns an index of the addresses named by the symbol table, the dynamic symbol table, and
+    /// the synthetic `name@plt` symbols of the [PLT entries](#method.plt_entries).
+    ///
+    /// Unstripped files repeat their dynamic symbols in the symbol table, so dynamic symbols with
+    /// the same name and address as a symbol table entry are dropped.
+    pub fn symbol_index(&self) -> Result<SymbolIndex<'a>> {
+        let mut entries = Vec::new();
+        for section in self.sections_matching(|shdr| shdr.sh_type() == SHT_SYMTAB) {
+            if let SectionDataRef::SymbolTable(table) = section.data {
+                entries.extend(named_addresses(&table, AddressSource::SymbolTable)?);
+            }
+        }
+        let dynamic = match self.dynamic_symbol_table()? {
+            Some(table) => named_addresses(&table, AddressSource::DynamicSymbolTable)?,
+            None        => Vec::new(),
+        };
+        let dynamic: Vec<_> = {
+            let known: HashSet<_> = entries.iter().map(|entry| (entry.address, &entry.name[..])).collect();
+            dynamic.into_iter().filter(|entry| !known.contains(&(entry.address, &entry.name[..]))).collect()
+        };
+        entries.extend(dynamic);
+        for entry in self.plt_entries()? {
+            entries.push(NamedAddress {
+                address: entry.address,
+                size: entry.size,
+                name: Cow::Owned(entry.name()),
+                source: AddressSource::Plt,
+            });
+        }
+        entries.sort_by_key(|entry| (entry.address, entry.source));
+        Ok(SymbolIndex { entries })
+    }
+}
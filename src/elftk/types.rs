@@ -181,6 +181,21 @@ pub struct Elf64_Rela {
     pub r_addend:   Elf64_Sxword,
 }
 
+// d_val also stands for the d_ptr member of the d_un union.
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct Elf32_Dyn {
+    pub d_tag: Elf32_Sword,
+    pub d_val: Elf32_Word,
+}
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct Elf64_Dyn {
+    pub d_tag: Elf64_Sxword,
+    pub d_val: Elf64_Xword,
+}
+
 #[derive(Clone, Copy, Debug)]
 #[repr(C)]
 pub struct Elf32_Phdr {
@@ -207,6 +222,25 @@ pub struct Elf64_Phdr {
     pub p_align:  Elf64_Xword,
 }
 
+/// Contents of the MIPS `.MIPS.abiflags` section (version 0).
+///
+/// The layout is the same for 32-bit and 64-bit object files.
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct Elf_MIPS_ABIFlags_v0 {
+    pub version:   Elf_Half,
+    pub isa_level: u8,
+    pub isa_rev:   u8,
+    pub gpr_size:  u8,
+    pub cpr1_size: u8,
+    pub cpr2_size: u8,
+    pub fp_abi:    u8,
+    pub isa_ext:   Elf_Word,
+    pub ases:      Elf_Word,
+    pub flags1:    Elf_Word,
+    pub flags2:    Elf_Word,
+}
+
 #[doc(hidden)]
 pub unsafe trait ElfType {}
 unsafe impl ElfType for Elf_Half {}
@@ -226,5 +260,8 @@ unsafe impl ElfType for Elf32_Rel {}
 unsafe impl ElfType for Elf64_Rel {}
 unsafe impl ElfType for Elf32_Rela {}
 unsafe impl ElfType for Elf64_Rela {}
+unsafe impl ElfType for Elf32_Dyn {}
+unsafe impl ElfType for Elf64_Dyn {}
 unsafe impl ElfType for Elf32_Phdr {}
 unsafe impl ElfType for Elf64_Phdr {}
+unsafe impl ElfType for Elf_MIPS_ABIFlags_v0 {}
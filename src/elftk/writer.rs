@@ -0,0 +1,835 @@
+use std::collections::{HashMap, HashSet};
+use std::mem;
+
+use constants::*;
+use error::*;
+use format::*;
+use reader::SectionIndex;
+use types::*;
+
+/// Serializes ELF data structures in the class and byte order of an object file.
+///
+/// Values are given as their 64-bit types and fields which are 32 bits wide in 32-bit object
+/// files are checked to fit.
+pub(super) struct Encoder {
+    format: ElfFormat,
+    pub(super) data: Vec<u8>,
+}
+
+impl Encoder {
+    pub(super) fn new(format: ElfFormat) -> Self {
+        Encoder { format, data: Vec::new() }
+    }
+
+    pub(super) fn is_64bit(&self) -> bool {
+        self.format.is_64bit()
+    }
+
+    pub(super) fn u8(&mut self, x: u8) {
+        self.data.push(x);
+    }
+
+    pub(super) fn half(&mut self, x: Elf_Half) {
+        if self.format.is_little_endian() {
+            self.data.extend_from_slice(&x.to_le_bytes());
+        } else {
+            self.data.extend_from_slice(&x.to_be_bytes());
+        }
+    }
+
+    pub(super) fn word(&mut self, x: Elf_Word) {
+        if self.format.is_little_endian() {
+            self.data.extend_from_slice(&x.to_le_bytes());
+        } else {
+            self.data.extend_from_slice(&x.to_be_bytes());
+        }
+    }
+
+    pub(super) fn xword(&mut self, x: Elf_Xword) {
+        if self.format.is_little_endian() {
+            self.data.extend_from_slice(&x.to_le_bytes());
+        } else {
+            self.data.extend_from_slice(&x.to_be_bytes());
+        }
+    }
+
+    /// Appends a field which is a word in 32-bit object files and an extended word in 64-bit ones,
+    /// such as an address, offset, or size.
+    pub(super) fn class_word(&mut self, header: &'static str, field: &'static str, x: Elf_Xword) -> Result<()> {
+        if self.is_64bit() {
+            self.xword(x);
+        } else if x <= Elf_Xword::from(Elf_Word::MAX) {
+            self.word(x as Elf_Word);
+        } else {
+            return Err(Error::InvalidHeaderField { header, field, value: x });
+        }
+        Ok(())
+    }
+
+    /// Pads the data with zeros to `offset`.
+    pub(super) fn pad_to(&mut self, offset: Elf64_Off) {
+        debug_assert!(offset as usize >= self.data.len());
+        self.data.resize(offset as usize, 0);
+    }
+
+    /// Appends an ELF header. The class and data encoding in `e_ident` are taken from the
+    /// format.
+    pub(super) fn ehdr(&mut self, ehdr: &Elf64_Ehdr) -> Result<()> {
+        let mut ident = ehdr.e_ident;
+        ident[EI_CLASS] = if self.is_64bit() { ELFCLASS64 } else { ELFCLASS32 };
+        ident[EI_DATA] = if self.format.is_little_endian() { ELFDATA2LSB } else { ELFDATA2MSB };
+        self.data.extend_from_slice(&ident);
+        self.half(ehdr.e_type);
+        self.half(ehdr.e_machine);
+        self.word(ehdr.e_version);
+        self.class_word("ELF", "e_entry", ehdr.e_entry)?;
+        self.class_word("ELF", "e_phoff", ehdr.e_phoff)?;
+        self.class_word("ELF", "e_shoff", ehdr.e_shoff)?;
+        self.word(ehdr.e_flags);
+        self.half(ehdr.e_ehsize);
+        self.half(ehdr.e_phentsize);
+        self.half(ehdr.e_phnum);
+        self.half(ehdr.e_shentsize);
+        self.half(ehdr.e_shnum);
+        self.half(ehdr.e_shstrndx);
+        Ok(())
+    }
+
+    pub(super) fn phdr(&mut self, phdr: &Elf64_Phdr) -> Result<()> {
+        self.word(phdr.p_type);
+        if self.is_64bit() {
+            self.word(phdr.p_flags);
+        }
+        self.class_word("program", "p_offset", phdr.p_offset)?;
+        self.class_word("program", "p_vaddr", phdr.p_vaddr)?;
+        self.class_word("program", "p_paddr", phdr.p_paddr)?;
+        self.class_word("program", "p_filesz", phdr.p_filesz)?;
+        self.class_word("program", "p_memsz", phdr.p_memsz)?;
+        if !self.is_64bit() {
+            self.word(phdr.p_flags);
+        }
+        self.class_word("program", "p_align", phdr.p_align)
+    }
+
+    pub(super) fn shdr(&mut self, shdr: &Elf64_Shdr) -> Result<()> {
+        self.word(shdr.sh_name);
+        self.word(shdr.sh_type);
+        self.class_word("section", "sh_flags", shdr.sh_flags)?;
+        self.class_word("section", "sh_addr", shdr.sh_addr)?;
+        self.class_word("section", "sh_offset", shdr.sh_offset)?;
+        self.class_word("section", "sh_size", shdr.sh_size)?;
+        self.word(shdr.sh_link);
+        self.word(shdr.sh_info);
+        self.class_word("section", "sh_addralign", shdr.sh_addralign)?;
+        self.class_word("section", "sh_entsize", shdr.sh_entsize)
+    }
+
+    pub(super) fn dyn_entry(&mut self, entry: &Elf64_Dyn) -> Result<()> {
+        if self.is_64bit() {
+            self.xword(entry.d_tag as Elf_Xword);
+            self.xword(entry.d_val);
+        } else {
+            if entry.d_tag < Elf_Sxword::from(Elf_Sword::MIN) || entry.d_tag > Elf_Sxword::from(Elf_Sword::MAX) {
+                return Err(Error::InvalidHeaderField { header: "dynamic", field: "d_tag", value: entry.d_tag as u64 });
+            }
+            self.word(entry.d_tag as Elf_Word);
+            self.class_word("dynamic", "d_val", entry.d_val)?;
+        }
+        Ok(())
+    }
+
+    pub(super) fn sym(&mut self, sym: &Elf64_Sym) -> Result<()> {
+        self.word(sym.st_name);
+        if self.is_64bit() {
+            self.u8(sym.st_info);
+            self.u8(sym.st_other);
+            self.half(sym.st_shndx);
+            self.xword(sym.st_value);
+            self.xword(sym.st_size);
+        } else {
+            self.class_word("symbol", "st_value", sym.st_value)?;
+            self.class_word("symbol", "st_size", sym.st_size)?;
+            self.u8(sym.st_info);
+            self.u8(sym.st_other);
+            self.half(sym.st_shndx);
+        }
+        Ok(())
+    }
+}
+
+/// The sizes of the ELF header, a program header, and a section header in object files of
+/// `format`.
+pub(super) fn header_sizes(format: ElfFormat) -> (Elf_Half, Elf_Half, Elf_Half) {
+    if format.is_64bit() {
+        (mem::size_of::<Elf64_Ehdr>() as Elf_Half,
+         mem::size_of::<Elf64_Phdr>() as Elf_Half,
+         mem::size_of::<Elf64_Shdr>() as Elf_Half)
+    } else {
+        (mem::size_of::<Elf32_Ehdr>() as Elf_Half,
+         mem::size_of::<Elf32_Phdr>() as Elf_Half,
+         mem::size_of::<Elf32_Shdr>() as Elf_Half)
+    }
+}
+
+pub(super) fn align_up(offset: Elf64_Off, alignment: Elf64_Xword) -> Elf64_Off {
+    if alignment <= 1 {
+        offset
+    } else {
+        (offset + alignment - 1) & !(alignment - 1)
+    }
+}
+
+/// The fields of the ELF header which are not determined by the layout of the object file.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FileHeader {
+    /// The operating system ABI (`e_ident[EI_OSABI]`), such as
+    /// [ELFOSABI_NONE](constant.ELFOSABI_NONE.html).
+    pub os_abi: u8,
+    /// The ABI version (`e_ident[EI_ABIVERSION]`).
+    pub abi_version: u8,
+    pub e_type: Elf_Half,
+    pub e_machine: Elf_Half,
+    pub e_entry: Elf64_Addr,
+    pub e_flags: Elf_Word,
+}
+
+/// A section of an object file built by an [ElfBuilder](struct.ElfBuilder.html).
+///
+/// `sh_link` and `sh_info` hold section indexes as returned by
+/// [ElfBuilder::add_section](struct.ElfBuilder.html#method.add_section) where the section type
+/// calls for them.
+#[derive(Debug, Clone, Default)]
+pub struct OutputSection {
+    pub name: Vec<u8>,
+    pub sh_type: Elf_Word,
+    pub sh_flags: Elf64_Xword,
+    pub sh_addr: Elf64_Addr,
+    pub sh_link: Elf_Word,
+    pub sh_info: Elf_Word,
+    /// The alignment of the section in the file and in memory, a power of two. Zero and one
+    /// mean no alignment.
+    pub sh_addralign: Elf64_Xword,
+    pub sh_entsize: Elf64_Xword,
+    /// The contents of the section, which are ignored for `SHT_NOBITS` sections.
+    pub data: Vec<u8>,
+    /// The size of an `SHT_NOBITS` section. The size of any other section is the length of
+    /// `data`.
+    pub size: Elf64_Xword,
+}
+
+impl OutputSection {
+    /// Returns a new section with contents `data` and no alignment.
+    pub fn new(name: &[u8], sh_type: Elf_Word, sh_flags: Elf64_Xword, data: Vec<u8>) -> Self {
+        OutputSection {
+            name: name.to_vec(),
+            sh_type,
+            sh_flags,
+            sh_addralign: 1,
+            data,
+            ..OutputSection::default()
+        }
+    }
+
+    /// Returns a new `SHT_NOBITS` section of `size` bytes.
+    pub fn nobits(name: &[u8], sh_flags: Elf64_Xword, size: Elf64_Xword) -> Self {
+        OutputSection {
+            name: name.to_vec(),
+            sh_type: SHT_NOBITS,
+            sh_flags,
+            sh_addralign: 1,
+            size,
+            ..OutputSection::default()
+        }
+    }
+
+    /// Returns the size of the section (`sh_size`).
+    pub fn size(&self) -> Elf64_Xword {
+        if self.sh_type == SHT_NOBITS {
+            self.size
+        } else {
+            self.data.len() as Elf64_Xword
+        }
+    }
+
+    fn occupies_file(&self) -> bool {
+        self.sh_type != SHT_NOBITS && self.sh_type != SHT_NULL
+    }
+}
+
+/// A segment of an object file built by an [ElfBuilder](struct.ElfBuilder.html).
+///
+/// The builder computes `p_offset` and, unless they are given, `p_filesz` and `p_memsz` from the
+/// sections the segment contains. The file offsets of the sections in a [PT_LOAD](constant.PT_LOAD.html) segment are
+/// congruent to their addresses modulo `p_align`.
+#[derive(Debug, Clone, Default)]
+pub struct OutputSegment {
+    pub p_type: Elf_Word,
+    pub p_flags: Elf_Word,
+    /// The address of the segment, which defaults to the address of its first section. A segment
+    /// which starts before its first section, such as one which covers the ELF header, is laid out
+    /// so that the section is at the same distance from the start of the segment in the file.
+    pub p_vaddr: Option<Elf64_Addr>,
+    /// The physical (load) address of the segment, which defaults to `p_vaddr`.
+    pub p_paddr: Option<Elf64_Addr>,
+    /// The size of the segment in the file, which defaults to the size covering its sections.
+    /// Segments padded past their last section, such as a [PT_GNU_RELRO](constant.PT_GNU_RELRO.html)
+    /// segment ending at a page boundary, are given their size.
+    pub p_filesz: Option<Elf64_Xword>,
+    /// The size of the segment in memory, which defaults to the size covering its sections.
+    pub p_memsz: Option<Elf64_Xword>,
+    pub p_align: Elf64_Xword,
+    /// The indexes of the sections in the segment, which are laid out in index order. A
+    /// [PT_PHDR](constant.PT_PHDR.html) segment without sections covers the program headers.
+    pub sections: Vec<Elf_Word>,
+}
+
+impl OutputSegment {
+    /// Returns a new segment containing `sections`.
+    pub fn new(p_type: Elf_Word, p_flags: Elf_Word, p_align: Elf64_Xword, sections: Vec<Elf_Word>) -> Self {
+        OutputSegment { p_type, p_flags, p_align, sections, ..OutputSegment::default() }
+    }
+}
+
+/// Builds an object file in any of the four formats from sections and segments.
+///
+/// The ELF header is followed by the program headers, the sections in index order, a generated
+/// `.shstrtab` section, and the section headers. When there are at least
+/// [SHN_LORESERVE](constant.SHN_LORESERVE.html) sections, the number of sections and the index of
+/// `.shstrtab` are stored in the `sh_size` and `sh_link` fields of section 0 and `e_shnum` and
+/// `e_shstrndx` hold 0 and [SHN_XINDEX](constant.SHN_XINDEX.html).
+///
+/// # Examples
+/// ``` rust
+/// use elftk::*;
+/// let mut builder = ElfBuilder::new(ElfT::Elf64LE(()), FileHeader {
+///     e_type: ET_REL,
+///     e_machine: EM_X86_64,
+///     ..FileHeader::default()
+/// });
+/// builder.add_section(OutputSection::new(b".text", SHT_PROGBITS, SHF_ALLOC | SHF_EXECINSTR,
+///                                        vec![0xc3]));
+/// let data = builder.build().unwrap();
+/// let reader = Reader::new(&data).unwrap();
+/// assert!(reader.section_by_name(b".text").is_some());
+/// ```
+#[derive(Debug, Clone)]
+pub struct ElfBuilder {
+    format: ElfFormat,
+    header: FileHeader,
+    sections: Vec<OutputSection>,
+    segments: Vec<OutputSegment>,
+}
+
+struct Layout {
+    phoff: Elf64_Off,
+    section_offsets: Vec<Elf64_Off>,
+    shoff: Elf64_Off,
+}
+
+impl ElfBuilder {
+    pub fn new(format: ElfFormat, header: FileHeader) -> Self {
+        ElfBuilder { format, header, sections: Vec::new(), segments: Vec::new() }
+    }
+
+    pub fn format(&self) -> ElfFormat {
+        self.format
+    }
+
+    pub fn header(&self) -> &FileHeader {
+        &self.header
+    }
+
+    pub fn header_mut(&mut self) -> &mut FileHeader {
+        &mut self.header
+    }
+
+    /// Adds a section and returns its index. The first section has index 1 since section 0 is
+    /// the null section.
+    pub fn add_section(&mut self, section: OutputSection) -> Elf_Word {
+        self.sections.push(section);
+        self.sections.len() as Elf_Word
+    }
+
+    /// Returns the section with index `index`.
+    pub fn section(&self, index: Elf_Word) -> Option<&OutputSection> {
+        (index as usize).checked_sub(1).and_then(|i| self.sections.get(i))
+    }
+
+    /// Returns the section with index `index`.
+    pub fn section_mut(&mut self, index: Elf_Word) -> Option<&mut OutputSection> {
+        (index as usize).checked_sub(1).and_then(move |i| self.sections.get_mut(i))
+    }
+
+    /// Returns the sections, the first of which has index 1.
+    pub fn sections(&self) -> &[OutputSection] {
+        &self.sections
+    }
+
+    /// Adds a `.symtab` section holding `symbols`, followed by a `.symtab_shndx` section if one is
+    /// needed and the `.strtab` section holding the symbol names. Returns the index of the
+    /// `.symtab` section and the laid out table, which maps the symbols to their indexes.
+    pub fn add_symbol_table(&mut self, symbols: &SymbolTableBuilder) -> Result<(Elf_Word, SymbolTable)> {
+        let table = symbols.build(self.format)?;
+        let (symbol_size, alignment) = if self.format.is_64bit() {
+            (mem::size_of::<Elf64_Sym>(), 8)
+        } else {
+            (mem::size_of::<Elf32_Sym>(), 4)
+        };
+        let symtab_index = self.sections.len() as Elf_Word + 1;
+        let strtab_index = symtab_index + if table.section_indexes.is_some() { 2 } else { 1 };
+        let mut symtab = OutputSection::new(b".symtab", SHT_SYMTAB, 0, table.symbols.clone());
+        symtab.sh_link = strtab_index;
+        symtab.sh_info = table.first_non_local;
+        symtab.sh_addralign = alignment;
+        symtab.sh_entsize = symbol_size as Elf64_Xword;
+        self.add_section(symtab);
+        if let Some(ref section_indexes) = table.section_indexes {
+            let mut shndx = OutputSection::new(b".symtab_shndx", SHT_SYMTAB_SHNDX, 0, section_indexes.clone());
+            shndx.sh_link = symtab_index;
+            shndx.sh_addralign = 4;
+            shndx.sh_entsize = 4;
+            self.add_section(shndx);
+        }
+        self.add_section(OutputSection::new(b".strtab", SHT_STRTAB, 0, table.strings.clone()));
+        Ok((symtab_index, table))
+    }
+
+    pub fn add_segment(&mut self, segment: OutputSegment) {
+        self.segments.push(segment);
+    }
+
+    pub fn segments(&self) -> &[OutputSegment] {
+        &self.segments
+    }
+
+    fn check_segment_sections(&self) -> Result<()> {
+        for segment in &self.segments {
+            for &index in &segment.sections {
+                if index == 0 || index as usize > self.sections.len() {
+                    return Err(Error::IndexOutOfBounds { index: index as usize, length: self.sections.len() + 1 });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn layout(&self, shstrtab_size: Elf64_Xword) -> Result<Layout> {
+        let (ehsize, phentsize, _) = header_sizes(self.format);
+        let phoff = Elf64_Off::from(ehsize);
+        let mut position = phoff + Elf64_Off::from(phentsize) * self.segments.len() as Elf64_Off;
+
+        // The strictest PT_LOAD alignment of each section and the minimum offset which leaves
+        // room for the part of a segment preceding its first section.
+        let mut modulus = vec![1; self.sections.len()];
+        let mut minimum = vec![0; self.sections.len()];
+        for segment in &self.segments {
+            if segment.p_type == PT_LOAD {
+                for &index in &segment.sections {
+                    let m = &mut modulus[index as usize - 1];
+                    *m = (*m).max(segment.p_align);
+                }
+            }
+            if let (Some(&first), Some(vaddr)) = (segment.sections.iter().min(), segment.p_vaddr) {
+                let addr = self.sections[first as usize - 1].sh_addr;
+                if addr < vaddr {
+                    return Err(Error::InvalidHeaderField { header: "program", field: "p_vaddr", value: vaddr });
+                }
+                let m = &mut minimum[first as usize - 1];
+                *m = (*m).max(addr - vaddr);
+            }
+        }
+
+        let mut section_offsets = Vec::with_capacity(self.sections.len());
+        for (index, section) in self.sections.iter().enumerate() {
+            let alignment = section.sh_addralign.max(1);
+            if !alignment.is_power_of_two() {
+                return Err(Error::InvalidHeaderField {
+                    header: "section",
+                    field: "sh_addralign",
+                    value: section.sh_addralign,
+                });
+            }
+            if section.sh_type == SHT_NULL {
+                section_offsets.push(0);
+                continue;
+            }
+            let mut offset = align_up(position.max(minimum[index]), alignment);
+            let m = modulus[index];
+            if m > 1 && m.is_power_of_two() {
+                offset += section.sh_addr.wrapping_sub(offset) & (m - 1);
+            }
+            section_offsets.push(offset);
+            if section.occupies_file() {
+                position = offset + section.size();
+            }
+        }
+        position += shstrtab_size;
+        let shoff = align_up(position, if self.format.is_64bit() { 8 } else { 4 });
+        Ok(Layout { phoff, section_offsets, shoff })
+    }
+
+    fn program_header(&self, segment: &OutputSegment, layout: &Layout) -> Elf64_Phdr {
+        let (_, phentsize, _) = header_sizes(self.format);
+        let (offset, vaddr, filesz, memsz) = match segment.sections.iter().min() {
+            Some(&first) => {
+                let first_section = &self.sections[first as usize - 1];
+                let vaddr = segment.p_vaddr.unwrap_or(first_section.sh_addr);
+                let offset = layout.section_offsets[first as usize - 1] - (first_section.sh_addr - vaddr);
+                let mut file_end = offset;
+                let mut memory_end = vaddr;
+                for &index in &segment.sections {
+                    let section = &self.sections[index as usize - 1];
+                    if section.occupies_file() {
+                        file_end = file_end.max(layout.section_offsets[index as usize - 1] + section.size());
+                    }
+                    if section.sh_flags & SHF_ALLOC != 0 {
+                        memory_end = memory_end.max(section.sh_addr + section.size());
+                    }
+                }
+                let filesz = segment.p_filesz.unwrap_or(file_end - offset);
+                (offset, vaddr, filesz, segment.p_memsz.unwrap_or_else(|| filesz.max(memory_end - vaddr)))
+            },
+            None if segment.p_type == PT_PHDR => {
+                let size = Elf64_Xword::from(phentsize) * self.segments.len() as Elf64_Xword;
+                let filesz = segment.p_filesz.unwrap_or(size);
+                (layout.phoff, segment.p_vaddr.unwrap_or(0), filesz, segment.p_memsz.unwrap_or(filesz))
+            },
+            None => (0, segment.p_vaddr.unwrap_or(0), segment.p_filesz.unwrap_or(0),
+                     segment.p_memsz.unwrap_or_else(|| segment.p_filesz.unwrap_or(0))),
+        };
+        Elf64_Phdr {
+            p_type: segment.p_type,
+            p_flags: segment.p_flags,
+            p_offset: offset,
+            p_vaddr: vaddr,
+            p_paddr: segment.p_paddr.unwrap_or(vaddr),
+            p_filesz: filesz,
+            p_memsz: memsz,
+            p_align: segment.p_align,
+        }
+    }
+
+    /// Lays out the object file and returns its contents.
+    pub fn build(&self) -> Result<Vec<u8>> {
+        self.check_segment_sections()?;
+        if self.segments.len() >= usize::from(Elf_Half::MAX) {
+            return Err(Error::Msg { msg: "too many segments" });
+        }
+
+        let mut names = StringTableBuilder::new();
+        for section in &self.sections {
+            names.add(&section.name);
+        }
+        names.add(b".shstrtab");
+        let names = names.build();
+        let sh_names: Vec<Elf_Word> = self.sections.iter()
+            .map(|section| names.offset(&section.name).unwrap_or(0))
+            .collect();
+        let shstrtab_name = names.offset(b".shstrtab").unwrap_or(0);
+        let shstrtab = names.into_data();
+
+        let layout = self.layout(shstrtab.len() as Elf64_Xword)?;
+        let (ehsize, phentsize, shentsize) = header_sizes(self.format);
+        let shstrndx = self.sections.len() + 1;
+        let shnum = shstrndx + 1;
+        let extended_shnum = shnum >= usize::from(SHN_LORESERVE);
+        let extended_shstrndx = shstrndx >= usize::from(SHN_LORESERVE);
+
+        let mut ident = [0; EI_NIDENT];
+        ident[EI_MAG0] = ELFMAG0;
+        ident[EI_MAG1] = ELFMAG1;
+        ident[EI_MAG2] = ELFMAG2;
+        ident[EI_MAG3] = ELFMAG3;
+        ident[EI_VERSION] = EV_CURRENT as u8;
+        ident[EI_OSABI] = self.header.os_abi;
+        ident[EI_ABIVERSION] = self.header.abi_version;
+        let phnum = self.segments.len() as Elf_Half;
+        let ehdr = Elf64_Ehdr {
+            e_ident: ident,
+            e_type: self.header.e_type,
+            e_machine: self.header.e_machine,
+            e_version: EV_CURRENT,
+            e_entry: self.header.e_entry,
+            e_phoff: if phnum > 0 { layout.phoff } else { 0 },
+            e_shoff: layout.shoff,
+            e_flags: self.header.e_flags,
+            e_ehsize: ehsize,
+            e_phentsize: if phnum > 0 { phentsize } else { 0 },
+            e_phnum: phnum,
+            e_shentsize: shentsize,
+            e_shnum: if extended_shnum { 0 } else { shnum as Elf_Half },
+            e_shstrndx: if extended_shstrndx { SHN_XINDEX } else { shstrndx as Elf_Half },
+        };
+
+        let mut encoder = Encoder::new(self.format);
+        encoder.data.reserve(layout.shoff as usize + shnum * usize::from(shentsize));
+        encoder.ehdr(&ehdr)?;
+        for segment in &self.segments {
+            let phdr = self.program_header(segment, &layout);
+            encoder.phdr(&phdr)?;
+        }
+        for (section, &offset) in self.sections.iter().zip(&layout.section_offsets) {
+            if section.occupies_file() {
+                encoder.pad_to(offset);
+                encoder.data.extend_from_slice(&section.data);
+            }
+        }
+        let shstrtab_offset = encoder.data.len() as Elf64_Off;
+        encoder.data.extend_from_slice(&shstrtab);
+        encoder.pad_to(layout.shoff);
+
+        encoder.shdr(&Elf64_Shdr {
+            sh_name: 0,
+            sh_type: SHT_NULL,
+            sh_flags: 0,
+            sh_addr: 0,
+            sh_offset: 0,
+            sh_size: if extended_shnum { shnum as Elf64_Xword } else { 0 },
+            sh_link: if extended_shstrndx { shstrndx as Elf_Word } else { 0 },
+            sh_info: 0,
+            sh_addralign: 0,
+            sh_entsize: 0,
+        })?;
+        for (index, section) in self.sections.iter().enumerate() {
+            encoder.shdr(&Elf64_Shdr {
+                sh_name: sh_names[index],
+                sh_type: section.sh_type,
+                sh_flags: section.sh_flags,
+                sh_addr: section.sh_addr,
+                sh_offset: layout.section_offsets[index],
+                sh_size: section.size(),
+                sh_link: section.sh_link,
+                sh_info: section.sh_info,
+                sh_addralign: section.sh_addralign,
+                sh_entsize: section.sh_entsize,
+            })?;
+        }
+        encoder.shdr(&Elf64_Shdr {
+            sh_name: shstrtab_name,
+            sh_type: SHT_STRTAB,
+            sh_flags: 0,
+            sh_addr: 0,
+            sh_offset: shstrtab_offset,
+            sh_size: shstrtab.len() as Elf64_Xword,
+            sh_link: 0,
+            sh_info: 0,
+            sh_addralign: 1,
+            sh_entsize: 0,
+        })?;
+        Ok(encoder.data)
+    }
+}
+
+/// Builds a string table such as `.strtab` or `.shstrtab`.
+///
+/// Like a linker, the builder stores each distinct string once and stores strings which are
+/// suffixes of other strings as the tails of those strings. For example, `.rela.text` also holds
+/// `.text`.
+#[derive(Debug, Clone, Default)]
+pub struct StringTableBuilder {
+    strings: HashSet<Vec<u8>>,
+}
+
+impl StringTableBuilder {
+    pub fn new() -> Self {
+        StringTableBuilder::default()
+    }
+
+    /// Adds `string`, which must not contain null bytes, to the table.
+    pub fn add(&mut self, string: &[u8]) {
+        if !self.strings.contains(string) {
+            self.strings.insert(string.to_vec());
+        }
+    }
+
+    /// Lays out the string table.
+    pub fn build(&self) -> StringTable {
+        // Sorting the strings by their reversed bytes in descending order puts each string after
+        // the strings it is a suffix of.
+        let mut strings: Vec<&[u8]> = self.strings.iter().map(|string| &string[..]).collect();
+        strings.sort_by(|a, b| b.iter().rev().cmp(a.iter().rev()));
+        let mut data = vec![0];
+        let mut offsets = HashMap::with_capacity(strings.len() + 1);
+        offsets.insert(Vec::new(), 0);
+        let mut previous: Option<(&[u8], usize)> = None;
+        for string in strings {
+            if string.is_empty() {
+                continue;
+            }
+            let offset = match previous {
+                Some((longer, offset)) if longer.ends_with(string) => offset + longer.len() - string.len(),
+                _ => {
+                    let offset = data.len();
+                    data.extend_from_slice(string);
+                    data.push(0);
+                    previous = Some((string, offset));
+                    offset
+                },
+            };
+            offsets.insert(string.to_vec(), offset as Elf_Word);
+        }
+        StringTable { data, offsets }
+    }
+}
+
+/// A string table laid out by a [StringTableBuilder](struct.StringTableBuilder.html).
+#[derive(Debug, Clone)]
+pub struct StringTable {
+    data: Vec<u8>,
+    offsets: HashMap<Vec<u8>, Elf_Word>,
+}
+
+impl StringTable {
+    /// Returns the contents of the string table section.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    pub fn into_data(self) -> Vec<u8> {
+        self.data
+    }
+
+    /// Returns the offset of `string` in the table, for use as an `sh_name` or `st_name`. The
+    /// empty string is at offset 0.
+    pub fn offset(&self, string: &[u8]) -> Option<Elf_Word> {
+        self.offsets.get(string).cloned()
+    }
+}
+
+/// A symbol of a symbol table built by a [SymbolTableBuilder](struct.SymbolTableBuilder.html).
+#[derive(Debug, Clone)]
+pub struct OutputSymbol {
+    pub name: Vec<u8>,
+    pub section: SectionIndex,
+    pub value: Elf64_Addr,
+    pub size: Elf64_Xword,
+    pub info: u8,
+    pub other: u8,
+}
+
+impl OutputSymbol {
+    /// Returns a new symbol with binding `binding` and type `symbol_type`.
+    pub fn new(name: &[u8], binding: u8, symbol_type: u8, section: SectionIndex, value: Elf64_Addr,
+               size: Elf64_Xword) -> Self
+    {
+        OutputSymbol {
+            name: name.to_vec(),
+            section,
+            value,
+            size,
+            info: (binding << 4) | (symbol_type & 0xf),
+            other: 0,
+        }
+    }
+
+    pub fn binding(&self) -> u8 {
+        (self.info >> 4) & 0xf
+    }
+
+    pub fn symbol_type(&self) -> u8 {
+        self.info & 0xf
+    }
+}
+
+/// Builds a symbol table (`SHT_SYMTAB` or `SHT_DYNSYM`) and its string table.
+///
+/// The null symbol is added automatically and local symbols are placed before all other symbols,
+/// as the ELF specification requires. Symbols whose section index is at least
+/// [SHN_LORESERVE](constant.SHN_LORESERVE.html) have the index
+/// [SHN_XINDEX](constant.SHN_XINDEX.html) and their real section index is stored in an
+/// `SHT_SYMTAB_SHNDX` section.
+#[derive(Debug, Clone, Default)]
+pub struct SymbolTableBuilder {
+    symbols: Vec<OutputSymbol>,
+}
+
+impl SymbolTableBuilder {
+    pub fn new() -> Self {
+        SymbolTableBuilder::default()
+    }
+
+    /// Adds `symbol` and returns its position among the added symbols, which
+    /// [SymbolTable::index](struct.SymbolTable.html#method.index) maps to its index in the table.
+    pub fn add(&mut self, symbol: OutputSymbol) -> usize {
+        self.symbols.push(symbol);
+        self.symbols.len() - 1
+    }
+
+    pub fn symbols(&self) -> &[OutputSymbol] {
+        &self.symbols
+    }
+
+    /// Lays out the symbol table in the format `format`.
+    pub fn build(&self, format: ElfFormat) -> Result<SymbolTable> {
+        let mut order: Vec<usize> = (0..self.symbols.len()).collect();
+        order.sort_by_key(|&position| self.symbols[position].binding() != STB_LOCAL);
+        let mut indexes = vec![0; self.symbols.len()];
+        for (index, &position) in order.iter().enumerate() {
+            indexes[position] = index as Elf_Word + 1;
+        }
+        let first_non_local = order.iter()
+            .position(|&position| self.symbols[position].binding() != STB_LOCAL)
+            .unwrap_or(order.len()) as Elf_Word + 1;
+
+        let mut names = StringTableBuilder::new();
+        for symbol in &self.symbols {
+            names.add(&symbol.name);
+        }
+        let strings = names.build();
+        let extended = self.symbols.iter().any(|symbol| match symbol.section {
+            SectionIndex::Normal(index) => index >= Elf_Word::from(SHN_LORESERVE),
+            SectionIndex::Reserved(_)   => false,
+        });
+
+        let mut symbols = Encoder::new(format);
+        let mut section_indexes = Encoder::new(format);
+        symbols.sym(&Elf64_Sym { st_name: 0, st_info: 0, st_other: 0, st_shndx: SHN_UNDEF, st_value: 0, st_size: 0 })?;
+        section_indexes.word(0);
+        for &position in &order {
+            let symbol = &self.symbols[position];
+            let (st_shndx, shndx) = match symbol.section {
+                SectionIndex::Normal(index) if index >= Elf_Word::from(SHN_LORESERVE) => (SHN_XINDEX, index),
+                SectionIndex::Normal(index) => (index as Elf_Half, 0),
+                SectionIndex::Reserved(index) => (index, 0),
+            };
+            symbols.sym(&Elf64_Sym {
+                st_name: strings.offset(&symbol.name).unwrap_or(0),
+                st_info: symbol.info,
+                st_other: symbol.other,
+                st_shndx,
+                st_value: symbol.value,
+                st_size: symbol.size,
+            })?;
+            section_indexes.word(shndx);
+        }
+        Ok(SymbolTable {
+            symbols: symbols.data,
+            strings: strings.into_data(),
+            section_indexes: if extended { Some(section_indexes.data) } else { None },
+            first_non_local,
+            indexes,
+        })
+    }
+}
+
+/// A symbol table laid out by a [SymbolTableBuilder](struct.SymbolTableBuilder.html).
+#[derive(Debug, Clone)]
+pub struct SymbolTable {
+    /// The contents of the symbol table section.
+    pub symbols: Vec<u8>,
+    /// The contents of the string table section.
+    pub strings: Vec<u8>,
+    /// The contents of the `SHT_SYMTAB_SHNDX` section, if one is needed.
+    pub section_indexes: Option<Vec<u8>>,
+    /// The index of the first non-local symbol, which is the `sh_info` of the symbol table
+    /// section.
+    pub first_non_local: Elf_Word,
+    indexes: Vec<Elf_Word>,
+}
+
+impl SymbolTable {
+    /// Returns the index in the table of the symbol at `position` among the added symbols.
+    pub fn index(&self, position: usize) -> Option<Elf_Word> {
+        self.indexes.get(position).cloned()
+    }
+}
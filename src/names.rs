@@ -44,10 +44,213 @@ pub fn symbol_index(index: elf::SectionIndex) -> String {
     }
 }
 
-pub fn relocation_name(machine: elf::Elf_Half, relocation_type: elf::Elf_Word) -> &'static str {
+// Appends `name` to `s` if `flag` is set in `flags` and clears it.
+fn check_header_flag(flags: &mut elf::Elf_Word, s: &mut String, flag: elf::Elf_Word, name: &str) {
+    if *flags & flag != 0 {
+        *s += name;
+        *flags &= !flag;
+    }
+}
+
+fn arm_flags(flags: elf::Elf_Word, s: &mut String) {
+    let mut rest = flags & !elf::EF_ARM_EABIMASK;
+    match (flags & elf::EF_ARM_EABIMASK) >> 24 {
+        0         => *s += ", GNU EABI",
+        v @ 1..=5 => *s += &format!(", Version{} EABI", v),
+        _         => *s += ", <unrecognized EABI>",
+    }
+    {
+        let mut check_flag = |f, name| check_header_flag(&mut rest, s, f, name);
+        match flags & elf::EF_ARM_EABIMASK {
+            elf::EF_ARM_EABI_UNKNOWN => {
+                check_flag(elf::EF_ARM_INTERWORK,      ", interworking enabled");
+                check_flag(elf::EF_ARM_APCS_26,        ", uses APCS/26");
+                check_flag(elf::EF_ARM_APCS_FLOAT,     ", uses APCS/float");
+                check_flag(elf::EF_ARM_PIC,            ", position independent");
+                check_flag(elf::EF_ARM_ALIGN8,         ", 8 bit structure alignment");
+                check_flag(elf::EF_ARM_NEW_ABI,        ", uses new ABI");
+                check_flag(elf::EF_ARM_OLD_ABI,        ", uses old ABI");
+                check_flag(elf::EF_ARM_SOFT_FLOAT,     ", software FP");
+                check_flag(elf::EF_ARM_VFP_FLOAT,      ", VFP");
+                check_flag(elf::EF_ARM_MAVERICK_FLOAT, ", Maverick FP");
+            },
+            elf::EF_ARM_EABI_VER1 |
+            elf::EF_ARM_EABI_VER2 |
+            elf::EF_ARM_EABI_VER3 => {},
+            elf::EF_ARM_EABI_VER4 => {
+                check_flag(elf::EF_ARM_BE8, ", BE8");
+                check_flag(elf::EF_ARM_LE8, ", LE8");
+            },
+            elf::EF_ARM_EABI_VER5 => {
+                check_flag(elf::EF_ARM_BE8,            ", BE8");
+                check_flag(elf::EF_ARM_LE8,            ", LE8");
+                check_flag(elf::EF_ARM_ABI_FLOAT_SOFT, ", soft-float ABI");
+                check_flag(elf::EF_ARM_ABI_FLOAT_HARD, ", hard-float ABI");
+            },
+            _ => {},
+        }
+    }
+    if rest != 0 {
+        *s += ", <unknown>";
+    }
+}
+
+fn riscv_flags(flags: elf::Elf_Word, s: &mut String) {
+    let mut rest = flags;
+    check_header_flag(&mut rest, s, elf::EF_RISCV_RVC, ", RVC");
+    *s += match flags & elf::EF_RISCV_FLOAT_ABI {
+        elf::EF_RISCV_FLOAT_ABI_SOFT   => ", soft-float ABI",
+        elf::EF_RISCV_FLOAT_ABI_SINGLE => ", single-float ABI",
+        elf::EF_RISCV_FLOAT_ABI_DOUBLE => ", double-float ABI",
+        _                              => ", quad-float ABI",
+    };
+    rest &= !elf::EF_RISCV_FLOAT_ABI;
+    check_header_flag(&mut rest, s, elf::EF_RISCV_RVE, ", RVE");
+    check_header_flag(&mut rest, s, elf::EF_RISCV_TSO, ", TSO");
+    if rest != 0 {
+        *s += ", <unknown>";
+    }
+}
+
+fn mips_flags(flags: elf::Elf_Word, s: &mut String) {
+    let mut rest = flags & !(elf::EF_MIPS_MACH | elf::EF_MIPS_ABI | elf::EF_MIPS_ARCH);
+    {
+        let mut check_flag = |f, name| check_header_flag(&mut rest, s, f, name);
+        check_flag(elf::EF_MIPS_NOREORDER,     ", noreorder");
+        check_flag(elf::EF_MIPS_PIC,           ", pic");
+        check_flag(elf::EF_MIPS_CPIC,          ", cpic");
+        check_flag(elf::EF_MIPS_XGOT,          ", xgot");
+        check_flag(elf::EF_MIPS_UCODE,         ", ugen_reserved");
+        check_flag(elf::EF_MIPS_ABI2,          ", abi2");
+        check_flag(elf::EF_MIPS_OPTIONS_FIRST, ", odk first");
+        check_flag(elf::EF_MIPS_32BITMODE,     ", 32bitmode");
+        check_flag(elf::EF_MIPS_FP64,          ", fp64");
+        check_flag(elf::EF_MIPS_NAN2008,       ", nan2008");
+    }
+    *s += match flags & elf::EF_MIPS_MACH {
+        0                          => "",
+        elf::EF_MIPS_MACH_3900     => ", 3900",
+        elf::EF_MIPS_MACH_4010     => ", 4010",
+        elf::EF_MIPS_MACH_4100     => ", 4100",
+        elf::EF_MIPS_MACH_4650     => ", 4650",
+        elf::EF_MIPS_MACH_4120     => ", 4120",
+        elf::EF_MIPS_MACH_4111     => ", 4111",
+        elf::EF_MIPS_MACH_SB1      => ", sb1",
+        elf::EF_MIPS_MACH_OCTEON   => ", octeon",
+        elf::EF_MIPS_MACH_XLR      => ", xlr",
+        elf::EF_MIPS_MACH_OCTEON2  => ", octeon2",
+        elf::EF_MIPS_MACH_OCTEON3  => ", octeon3",
+        elf::EF_MIPS_MACH_5400     => ", 5400",
+        elf::EF_MIPS_MACH_5900     => ", 5900",
+        elf::EF_MIPS_MACH_5500     => ", 5500",
+        elf::EF_MIPS_MACH_9000     => ", 9000",
+        elf::EF_MIPS_MACH_LS2E     => ", loongson-2e",
+        elf::EF_MIPS_MACH_LS2F     => ", loongson-2f",
+        elf::EF_MIPS_MACH_GS464    => ", gs464",
+        elf::EF_MIPS_MACH_GS464E   => ", gs464e",
+        elf::EF_MIPS_MACH_GS264E   => ", gs264e",
+        _                          => ", unknown CPU",
+    };
+    *s += match flags & elf::EF_MIPS_ABI {
+        0                       => "",
+        elf::EF_MIPS_ABI_O32    => ", o32",
+        elf::EF_MIPS_ABI_O64    => ", o64",
+        elf::EF_MIPS_ABI_EABI32 => ", eabi32",
+        elf::EF_MIPS_ABI_EABI64 => ", eabi64",
+        _                       => ", unknown ABI",
+    };
+    {
+        let mut check_flag = |f, name| check_header_flag(&mut rest, s, f, name);
+        check_flag(elf::EF_MIPS_ARCH_ASE_MDMX, ", mdmx");
+        check_flag(elf::EF_MIPS_ARCH_ASE_M16,  ", mips16");
+        check_flag(elf::EF_MIPS_MICROMIPS,     ", micromips");
+    }
+    *s += match flags & elf::EF_MIPS_ARCH {
+        elf::EF_MIPS_ARCH_1    => ", mips1",
+        elf::EF_MIPS_ARCH_2    => ", mips2",
+        elf::EF_MIPS_ARCH_3    => ", mips3",
+        elf::EF_MIPS_ARCH_4    => ", mips4",
+        elf::EF_MIPS_ARCH_5    => ", mips5",
+        elf::EF_MIPS_ARCH_32   => ", mips32",
+        elf::EF_MIPS_ARCH_64   => ", mips64",
+        elf::EF_MIPS_ARCH_32R2 => ", mips32r2",
+        elf::EF_MIPS_ARCH_64R2 => ", mips64r2",
+        elf::EF_MIPS_ARCH_32R6 => ", mips32r6",
+        elf::EF_MIPS_ARCH_64R6 => ", mips64r6",
+        _                      => ", unknown ISA",
+    };
+    if rest != 0 {
+        *s += ", <unknown>";
+    }
+}
+
+fn ppc_flags(flags: elf::Elf_Word, s: &mut String) {
+    let mut rest = flags;
+    check_header_flag(&mut rest, s, elf::EF_PPC_EMB, ", emb");
+    check_header_flag(&mut rest, s, elf::EF_PPC_RELOCATABLE, ", relocatable");
+    check_header_flag(&mut rest, s, elf::EF_PPC_RELOCATABLE_LIB, ", relocatable-lib");
+    if rest != 0 {
+        *s += ", <unknown>";
+    }
+}
+
+fn ppc64_flags(flags: elf::Elf_Word, s: &mut String) {
+    if flags & elf::EF_PPC64_ABI != 0 {
+        *s += &format!(", abiv{}", flags & elf::EF_PPC64_ABI);
+    }
+    if flags & !elf::EF_PPC64_ABI != 0 {
+        *s += ", <unknown>";
+    }
+}
+
+pub fn header_flags(machine: elf::Elf_Half, flags: elf::Elf_Word) -> String {
+    let mut s = format!("0x{:x}", flags);
     match machine {
-        elf::EM_386    => elf::i386_relocation_name(relocation_type),
-        elf::EM_X86_64 => elf::x86_64_relocation_name(relocation_type),
-        _              => "<unimplemented>",
+        // The AArch64 ELF ABI does not define any flags.
+        elf::EM_AARCH64 if flags != 0 => s += ", <unknown>",
+        elf::EM_ARM                   => arm_flags(flags, &mut s),
+        elf::EM_RISCV                 => riscv_flags(flags, &mut s),
+        elf::EM_MIPS                  => mips_flags(flags, &mut s),
+        elf::EM_PPC                   => ppc_flags(flags, &mut s),
+        elf::EM_PPC64                 => ppc64_flags(flags, &mut s),
+        _                             => {},
     }
+    s
+}
+
+pub fn mips_isa(level: u8, revision: u8) -> String {
+    match (level, revision) {
+        (1..=5, _) => format!("MIPS{}", level),
+        (32, 1) | (64, 1) => format!("MIPS{}", level),
+        (32, _) | (64, _) => format!("MIPS{}r{}", level, revision),
+        _ => format!("Unknown ISA {}", level),
+    }
+}
+
+pub fn mips_ases(ases: elf::Elf_Word) -> String {
+    let names = [
+        (elf::AFL_ASE_DSP,           "DSP ASE"),
+        (elf::AFL_ASE_DSPR2,         "DSP R2 ASE"),
+        (elf::AFL_ASE_DSPR3,         "DSP R3 ASE"),
+        (elf::AFL_ASE_EVA,           "Enhanced VA Scheme"),
+        (elf::AFL_ASE_MCU,           "MCU (MicroController) ASE"),
+        (elf::AFL_ASE_MDMX,          "MDMX ASE"),
+        (elf::AFL_ASE_MIPS3D,        "MIPS-3D ASE"),
+        (elf::AFL_ASE_MT,            "MT ASE"),
+        (elf::AFL_ASE_SMARTMIPS,     "SmartMIPS ASE"),
+        (elf::AFL_ASE_VIRT,          "VZ ASE"),
+        (elf::AFL_ASE_MSA,           "MSA ASE"),
+        (elf::AFL_ASE_MIPS16,        "MIPS16 ASE"),
+        (elf::AFL_ASE_MICROMIPS,     "MICROMIPS ASE"),
+        (elf::AFL_ASE_XPA,           "XPA ASE"),
+        (elf::AFL_ASE_MIPS16E2,      "MIPS16e2 ASE"),
+        (elf::AFL_ASE_CRC,           "CRC ASE"),
+        (elf::AFL_ASE_GINV,          "GINV ASE"),
+        (elf::AFL_ASE_LOONGSON_MMI,  "Loongson MMI ASE"),
+        (elf::AFL_ASE_LOONGSON_CAM,  "Loongson CAM ASE"),
+        (elf::AFL_ASE_LOONGSON_EXT,  "Loongson EXT ASE"),
+        (elf::AFL_ASE_LOONGSON_EXT2, "Loongson EXT2 ASE"),
+    ];
+    let s: Vec<_> = names.iter().filter(|&&(flag, _)| ases & flag != 0).map(|&(_, name)| name).collect();
+    if s.is_empty() { String::from("None") } else { s.join(", ") }
 }
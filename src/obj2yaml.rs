@@ -0,0 +1,57 @@
+#[macro_use]
+extern crate clap;
+extern crate elftk;
+extern crate failure;
+extern crate serde_json;
+extern crate serde_yaml;
+
+use std::fs;
+use std::io::{self, Write};
+use std::result;
+
+use clap::{Arg, AppSettings};
+use elftk as elf;
+
+use failure::Error;
+
+type Result<T> = result::Result<T, Error>;
+
+fn main() -> Result<()> {
+    let matches = app_from_crate!()
+        .name("elftk-obj2yaml")
+        .about("Describe the structure of an ELF file as YAML or JSON")
+        .setting(AppSettings::DeriveDisplayOrder)
+        .setting(AppSettings::UnifiedHelpMessage)
+        .arg(Arg::with_name("json")
+             .help("Write JSON rather than YAML")
+             .long("json"))
+        .arg(Arg::with_name("output")
+             .help("Write the description to FILE rather than to standard output")
+             .short("o")
+             .long("output")
+             .value_name("FILE"))
+        .arg(Arg::with_name("elf-file")
+             .help("Input ELF file")
+             .required(true))
+        .help_short("H")
+        .version_short("v")
+        .get_matches();
+
+    let data = fs::read(matches.value_of_os("elf-file").unwrap())?;
+    let reader = elf::Reader::new(&data)?;
+    let description = reader.describe()?;
+    let mut text = if matches.is_present("json") {
+        serde_json::to_string_pretty(&description)?
+    } else {
+        serde_yaml::to_string(&description)?
+    };
+    if !text.ends_with('\n') {
+        text.push('\n');
+    }
+
+    match matches.value_of_os("output") {
+        Some(output) => fs::write(output, text)?,
+        None         => io::stdout().write_all(text.as_bytes())?,
+    }
+    Ok(())
+}
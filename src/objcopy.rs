@@ -0,0 +1,377 @@
+#[macro_use]
+extern crate clap;
+extern crate elftk;
+extern crate failure;
+
+use std::collections::HashMap;
+use std::ffi::OsStr;
+use std::fs;
+use std::os::unix::ffi::OsStrExt;
+use std::result;
+use std::str;
+
+use clap::{Arg, AppSettings};
+use elftk as elf;
+
+use failure::{Error, err_msg};
+
+type Result<T> = result::Result<T, Error>;
+
+// Parses a decimal or `0x`-prefixed hexadecimal number.
+fn parse_number(s: &str) -> Result<u64> {
+    let parsed = if s.starts_with("0x") || s.starts_with("0X") {
+        u64::from_str_radix(&s[2..], 16)
+    } else {
+        s.parse()
+    };
+    parsed.map_err(|_| err_msg(format!("invalid number: {}", s)))
+}
+
+// The machine, whether it is 64-bit, and whether it is little-endian by default, of the
+// architectures of `--binary-architecture`.
+const ARCHITECTURES: &[(&str, elf::Elf_Half, bool, bool)] = &[
+    ("i386",        elf::EM_386,       false, true),
+    ("x86-64",      elf::EM_X86_64,    true,  true),
+    ("x86_64",      elf::EM_X86_64,    true,  true),
+    ("arm",         elf::EM_ARM,       false, true),
+    ("aarch64",     elf::EM_AARCH64,   true,  true),
+    ("riscv32",     elf::EM_RISCV,     false, true),
+    ("riscv64",     elf::EM_RISCV,     true,  true),
+    ("mips",        elf::EM_MIPS,      false, false),
+    ("mipsel",      elf::EM_MIPS,      false, true),
+    ("mips64",      elf::EM_MIPS,      true,  false),
+    ("mips64el",    elf::EM_MIPS,      true,  true),
+    ("powerpc",     elf::EM_PPC,       false, false),
+    ("powerpc64",   elf::EM_PPC64,     true,  false),
+    ("powerpc64le", elf::EM_PPC64,     true,  true),
+    ("sparc",       elf::EM_SPARC,     false, false),
+    ("sparc64",     elf::EM_SPARCV9,   true,  false),
+    ("s390x",       elf::EM_S390,      true,  false),
+    ("loongarch64", elf::EM_LOONGARCH, true,  true),
+    ("m68k",        elf::EM_68K,       false, false),
+    ("avr",         elf::EM_AVR,       false, true),
+    ("msp430",      elf::EM_MSP430,    false, true),
+    ("xtensa",      elf::EM_XTENSA,    false, true),
+];
+
+// Returns the format and machine of the object files created from non-ELF input.
+fn object_format(matches: &clap::ArgMatches) -> Result<(elf::ElfFormat, elf::Elf_Half)> {
+    let (machine, mut is_64bit, mut little_endian) = match matches.value_of("binary-architecture") {
+        Some(arch) => match ARCHITECTURES.iter().find(|&&(name, ..)| name == arch) {
+            Some(&(_, machine, is_64bit, little_endian)) => (machine, is_64bit, little_endian),
+            None if parse_number(arch)? <= u64::from(elf::Elf_Half::MAX) =>
+                (parse_number(arch)? as elf::Elf_Half, false, true),
+            None => return Err(err_msg(format!("invalid machine: {}", arch))),
+        },
+        None => (elf::EM_NONE, true, true),
+    };
+    if let Some(class) = matches.value_of("elf-class") {
+        is_64bit = class == "64";
+    }
+    if let Some(endian) = matches.value_of("endian") {
+        little_endian = endian == "little";
+    }
+    let format = match (is_64bit, little_endian) {
+        (false, true)  => elf::ElfFormat::Elf32LE(()),
+        (false, false) => elf::ElfFormat::Elf32BE(()),
+        (true, true)   => elf::ElfFormat::Elf64LE(()),
+        (true, false)  => elf::ElfFormat::Elf64BE(()),
+    };
+    Ok((format, machine))
+}
+
+// Splits an option value of the form `NAME=VALUE`.
+fn split_assignment(value: &OsStr) -> Result<(&[u8], &[u8])> {
+    let value = value.as_bytes();
+    match value.iter().position(|&b| b == b'=') {
+        Some(position) => Ok((&value[..position], &value[position+1..])),
+        None => Err(err_msg(format!("expected NAME=VALUE: {}", String::from_utf8_lossy(value)))),
+    }
+}
+
+fn values<'a>(matches: &'a clap::ArgMatches, name: &str) -> Vec<&'a [u8]> {
+    matches.values_of_os(name).map_or_else(Vec::new, |values| values.map(OsStr::as_bytes).collect())
+}
+
+fn matching_sections(elf_file: &elf::ElfFile, pattern: &[u8]) -> Vec<elf::Elf_Word> {
+    let mut indexes = Vec::new();
+    for (index, section) in elf_file.sections.iter().enumerate().skip(1) {
+        if elf::wildcard_match(pattern, &section.name) {
+            indexes.push(index as elf::Elf_Word);
+        }
+    }
+    indexes
+}
+
+// Applies the section and symbol edits requested by the options.
+fn edit(elf_file: &mut elf::ElfFile, matches: &clap::ArgMatches) -> Result<()> {
+    let removed = values(matches, "remove-section");
+    if !removed.is_empty() {
+        elf_file.remove_sections_matching(&removed)?;
+        elf_file.compact();
+    }
+    for value in matches.values_of_os("set-section-flags").into_iter().flatten() {
+        let (pattern, flags) = split_assignment(value)?;
+        let flags = elf::parse_section_flags(text(flags)?)?;
+        for index in matching_sections(elf_file, pattern) {
+            elf_file.set_section_flags(index, flags)?;
+        }
+    }
+    for value in matches.values_of_os("set-section-alignment").into_iter().flatten() {
+        let (pattern, alignment) = split_assignment(value)?;
+        let alignment = parse_number(text(alignment)?)?;
+        for index in matching_sections(elf_file, pattern) {
+            elf_file.set_section_alignment(index, alignment)?;
+        }
+    }
+    for (option, set) in &[("set-exclude", true), ("clear-exclude", false)] {
+        for pattern in values(matches, option) {
+            for index in matching_sections(elf_file, pattern) {
+                elf_file.set_exclude(index, *set)?;
+            }
+        }
+    }
+    for value in matches.values_of_os("rename-section").into_iter().flatten() {
+        let (old, new) = split_assignment(value)?;
+        let (new, flags) = match new.iter().position(|&b| b == b',') {
+            Some(position) => (&new[..position], Some(elf::parse_section_flags(text(&new[position+1..])?)?)),
+            None           => (new, None),
+        };
+        let index = elf_file.section_index(old)
+            .ok_or_else(|| err_msg(format!("no section named {}", String::from_utf8_lossy(old))))?;
+        elf_file.rename_section(index, new)?;
+        if let Some(flags) = flags {
+            elf_file.set_section_flags(index, flags)?;
+        }
+    }
+    for value in matches.values_of_os("add-section").into_iter().flatten() {
+        let (name, file) = split_assignment(value)?;
+        elf_file.add_section_from(name, fs::read(OsStr::from_bytes(file))?);
+    }
+
+    if matches.is_present("compress-debug-sections") {
+        elf_file.compress_debug_sections(match matches.value_of("compress-debug-sections") {
+            Some("zstd") => elf::Compression::Zstd,
+            _            => elf::Compression::Zlib,
+        })?;
+    } else if matches.is_present("decompress-debug-sections") {
+        elf_file.decompress_sections()?;
+    }
+
+    let mut renamed = HashMap::new();
+    if let Some(file) = matches.value_of_os("redefine-syms") {
+        renamed = elf::parse_symbol_map(&fs::read(file)?)?;
+    }
+    for value in matches.values_of_os("redefine-sym").into_iter().flatten() {
+        let (old, new) = split_assignment(value)?;
+        renamed.insert(old.to_vec(), new.to_vec());
+    }
+    elf_file.rename_symbols(&renamed)?;
+    elf_file.globalize_symbols(&values(matches, "globalize-symbol"))?;
+    elf_file.weaken_symbols(&values(matches, "weaken-symbol"))?;
+    elf_file.localize_symbols(&values(matches, "localize-symbol"))?;
+    Ok(())
+}
+
+fn text(data: &[u8]) -> Result<&str> {
+    str::from_utf8(data).map_err(|_| err_msg("input is not a text file"))
+}
+
+fn main() -> Result<()> {
+    let matches = app_from_crate!()
+        .name("elftk-objcopy")
+        .about("Copy and translate ELF files")
+        .setting(AppSettings::DeriveDisplayOrder)
+        .setting(AppSettings::UnifiedHelpMessage)
+        .arg(Arg::with_name("input-target")
+             .help("Read the input in the format FORMAT, converting non-ELF input to a relocatable object")
+             .short("I")
+             .long("input-target")
+             .value_name("FORMAT")
+             .possible_values(&["elf", "binary", "ihex", "srec"])
+             .default_value("elf"))
+        .arg(Arg::with_name("output-target")
+             .help("Write the output in the format FORMAT")
+             .short("O")
+             .long("output-target")
+             .value_name("FORMAT")
+             .possible_values(&["elf", "binary", "ihex", "srec", "uf2"])
+             .default_value("elf"))
+        .arg(Arg::with_name("binary-architecture")
+             .help("Set the machine of objects created from non-ELF input, by name or number")
+             .short("B")
+             .long("binary-architecture")
+             .value_name("ARCH"))
+        .arg(Arg::with_name("elf-class")
+             .help("Set the class of objects created from non-ELF input")
+             .long("elf-class")
+             .value_name("CLASS")
+             .possible_values(&["32", "64"]))
+        .arg(Arg::with_name("endian")
+             .help("Set the byte order of objects created from non-ELF input")
+             .long("endian")
+             .value_name("ORDER")
+             .possible_values(&["little", "big"]))
+        .arg(Arg::with_name("binary-section")
+             .help("Set the name of the section holding binary input")
+             .long("binary-section")
+             .value_name("NAME")
+             .default_value(".data"))
+        .arg(Arg::with_name("binary-section-flags")
+             .help("Set the flags of the section holding binary input, such as alloc,readonly")
+             .long("binary-section-flags")
+             .value_name("FLAGS"))
+        .arg(Arg::with_name("binary-section-align")
+             .help("Set the alignment of the section holding binary input")
+             .long("binary-section-align")
+             .value_name("ALIGN"))
+        .arg(Arg::with_name("gap-fill")
+             .help("Fill the gaps between sections with BYTE in binary and UF2 output")
+             .long("gap-fill")
+             .value_name("BYTE"))
+        .arg(Arg::with_name("uf2-family")
+             .help("Set the family ID of UF2 output")
+             .long("uf2-family")
+             .value_name("ID"))
+        .arg(Arg::with_name("remove-section")
+             .help("Remove the sections matching PATTERN")
+             .short("R")
+             .long("remove-section")
+             .value_name("PATTERN")
+             .multiple(true)
+             .number_of_values(1))
+        .arg(Arg::with_name("rename-section")
+             .help("Rename section OLD to NEW, optionally setting its flags")
+             .long("rename-section")
+             .value_name("OLD=NEW[,FLAGS]")
+             .multiple(true)
+             .number_of_values(1))
+        .arg(Arg::with_name("set-section-flags")
+             .help("Set the flags of the sections matching PATTERN, such as alloc,readonly,code")
+             .long("set-section-flags")
+             .value_name("PATTERN=FLAGS")
+             .multiple(true)
+             .number_of_values(1))
+        .arg(Arg::with_name("set-section-alignment")
+             .help("Set the alignment of the sections matching PATTERN")
+             .long("set-section-alignment")
+             .value_name("PATTERN=ALIGN")
+             .multiple(true)
+             .number_of_values(1))
+        .arg(Arg::with_name("set-exclude")
+             .help("Set SHF_EXCLUDE on the sections matching PATTERN")
+             .long("set-exclude")
+             .value_name("PATTERN")
+             .multiple(true)
+             .number_of_values(1))
+        .arg(Arg::with_name("clear-exclude")
+             .help("Clear SHF_EXCLUDE on the sections matching PATTERN")
+             .long("clear-exclude")
+             .value_name("PATTERN")
+             .multiple(true)
+             .number_of_values(1))
+        .arg(Arg::with_name("add-section")
+             .help("Add section NAME with the contents of FILE")
+             .long("add-section")
+             .value_name("NAME=FILE")
+             .multiple(true)
+             .number_of_values(1))
+        .arg(Arg::with_name("redefine-sym")
+             .help("Rename symbol OLD to NEW")
+             .long("redefine-sym")
+             .value_name("OLD=NEW")
+             .multiple(true)
+             .number_of_values(1))
+        .arg(Arg::with_name("redefine-syms")
+             .help("Rename the symbols as listed in FILE, one OLD NEW pair per line")
+             .long("redefine-syms")
+             .value_name("FILE"))
+        .arg(Arg::with_name("localize-symbol")
+             .help("Make the symbols matching PATTERN local")
+             .short("L")
+             .long("localize-symbol")
+             .value_name("PATTERN")
+             .multiple(true)
+             .number_of_values(1))
+        .arg(Arg::with_name("globalize-symbol")
+             .help("Make the symbols matching PATTERN global")
+             .long("globalize-symbol")
+             .value_name("PATTERN")
+             .multiple(true)
+             .number_of_values(1))
+        .arg(Arg::with_name("weaken-symbol")
+             .help("Make the symbols matching PATTERN weak")
+             .short("W")
+             .long("weaken-symbol")
+             .value_name("PATTERN")
+             .multiple(true)
+             .number_of_values(1))
+        .arg(Arg::with_name("compress-debug-sections")
+             .help("Compress the .debug_* sections with zlib, or with ALGORITHM")
+             .long("compress-debug-sections")
+             .value_name("ALGORITHM")
+             .possible_values(&["zlib", "zstd"])
+             .min_values(0)
+             .require_equals(true))
+        .arg(Arg::with_name("decompress-debug-sections")
+             .help("Decompress the compressed sections")
+             .long("decompress-debug-sections")
+             .conflicts_with("compress-debug-sections"))
+        .arg(Arg::with_name("input")
+             .help("Input file")
+             .required(true))
+        .arg(Arg::with_name("output")
+             .help("Output file, which is the input file if not given"))
+        .help_short("H")
+        .version_short("v")
+        .get_matches();
+
+    let gap_fill = match matches.value_of("gap-fill") {
+        Some(byte) if parse_number(byte)? <= 0xff => parse_number(byte)? as u8,
+        Some(byte) => return Err(err_msg(format!("invalid gap fill byte: {}", byte))),
+        None => 0,
+    };
+    let family_id = match matches.value_of("uf2-family") {
+        Some(id) if parse_number(id)? <= u64::from(u32::MAX) => Some(parse_number(id)? as u32),
+        Some(id) => return Err(err_msg(format!("invalid UF2 family ID: {}", id))),
+        None => None,
+    };
+
+    let input = matches.value_of_os("input").unwrap();
+    let output = matches.value_of_os("output").unwrap_or(input);
+    let mut data = fs::read(input)?;
+    let input_target = matches.value_of("input-target").unwrap();
+    if input_target != "elf" {
+        let (format, e_machine) = object_format(&matches)?;
+        let header = elf::FileHeader { e_machine, ..elf::FileHeader::default() };
+        data = match input_target {
+            "ihex" => elf::LoadImage::from_intel_hex(text(&data)?)?.to_relocatable(format, header)?,
+            "srec" => elf::LoadImage::from_srec(text(&data)?)?.to_relocatable(format, header)?,
+            _ => {
+                let mut object = elf::BinaryObject::new(input.as_bytes());
+                object.section_name = matches.value_of_os("binary-section").map(OsStr::as_bytes).unwrap().to_vec();
+                if let Some(flags) = matches.value_of("binary-section-flags") {
+                    object.sh_flags = elf::parse_section_flags(flags)?;
+                }
+                if let Some(align) = matches.value_of("binary-section-align") {
+                    object.sh_addralign = parse_number(align)?;
+                }
+                object.build(format, header, &data)?
+            },
+        };
+    }
+    let mut elf_file = elf::ElfFile::new(&elf::Reader::new(&data)?)?;
+    edit(&mut elf_file, &matches)?;
+    let data = elf_file.write()?;
+    let reader = elf::Reader::new(&data)?;
+    let out = match matches.value_of("output-target").unwrap() {
+        "binary" => reader.load_image()?.to_binary(gap_fill),
+        "ihex"   => reader.load_image()?.to_intel_hex()?.into_bytes(),
+        "srec"   => reader.load_image()?.to_srec()?.into_bytes(),
+        "uf2"    => reader.load_image()?.to_uf2(family_id, gap_fill)?,
+        _        => data,
+    };
+    fs::write(output, out)?;
+    fs::set_permissions(output, fs::metadata(input)?.permissions())?;
+    Ok(())
+}
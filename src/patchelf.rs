@@ -0,0 +1,158 @@
+#[macro_use]
+extern crate clap;
+extern crate elftk;
+extern crate failure;
+
+use std::fs;
+use std::os::unix::ffi::OsStrExt;
+use std::result;
+use std::str;
+
+use clap::{Arg, AppSettings};
+use elftk as elf;
+
+use failure::Error;
+
+type Result<T> = result::Result<T, Error>;
+
+fn print_string(s: Option<&[u8]>) {
+    if let Some(s) = s {
+        println!("{}", str::from_utf8(s).unwrap_or("<invalid>"));
+    }
+}
+
+fn main() -> Result<()> {
+    let matches = app_from_crate!()
+        .name("elftk-patch")
+        .about("Modify the dynamic linking information of ELF executables and shared objects")
+        .setting(AppSettings::DeriveDisplayOrder)
+        .setting(AppSettings::UnifiedHelpMessage)
+        .arg(Arg::with_name("set-interpreter")
+             .help("Set the program interpreter")
+             .long("set-interpreter")
+             .value_name("INTERPRETER"))
+        .arg(Arg::with_name("print-interpreter")
+             .help("Print the program interpreter")
+             .long("print-interpreter"))
+        .arg(Arg::with_name("set-soname")
+             .help("Set the DT_SONAME")
+             .long("set-soname")
+             .value_name("SONAME"))
+        .arg(Arg::with_name("print-soname")
+             .help("Print the DT_SONAME")
+             .long("print-soname"))
+        .arg(Arg::with_name("set-rpath")
+             .help("Set the DT_RUNPATH (or the DT_RPATH with --force-rpath)")
+             .long("set-rpath")
+             .value_name("RPATH"))
+        .arg(Arg::with_name("force-rpath")
+             .help("Set the DT_RPATH rather than the DT_RUNPATH")
+             .long("force-rpath"))
+        .arg(Arg::with_name("remove-rpath")
+             .help("Remove the DT_RPATH and DT_RUNPATH")
+             .long("remove-rpath"))
+        .arg(Arg::with_name("print-rpath")
+             .help("Print the DT_RUNPATH or DT_RPATH")
+             .long("print-rpath"))
+        .arg(Arg::with_name("add-needed")
+             .help("Add a DT_NEEDED entry")
+             .long("add-needed")
+             .value_name("LIBRARY")
+             .multiple(true)
+             .number_of_values(1))
+        .arg(Arg::with_name("remove-needed")
+             .help("Remove a DT_NEEDED entry and its version needs")
+             .long("remove-needed")
+             .value_name("LIBRARY")
+             .multiple(true)
+             .number_of_values(1))
+        .arg(Arg::with_name("replace-needed")
+             .help("Replace a DT_NEEDED entry")
+             .long("replace-needed")
+             .value_names(&["OLD", "NEW"])
+             .multiple(true)
+             .number_of_values(2))
+        .arg(Arg::with_name("print-needed")
+             .help("Print the DT_NEEDED entries")
+             .long("print-needed"))
+        .arg(Arg::with_name("output")
+             .help("Write the result to FILE rather than modifying the input file")
+             .short("o")
+             .long("output")
+             .value_name("FILE"))
+        .arg(Arg::with_name("elf-file")
+             .help("Input ELF file")
+             .required(true))
+        .help_short("H")
+        .version_short("v")
+        .get_matches();
+
+    let file = matches.value_of_os("elf-file").unwrap();
+    let data = fs::read(file)?;
+    let reader = elf::Reader::new(&data)?;
+    let mut patcher = elf::ElfPatcher::new(&reader)?;
+    let mut modified = false;
+
+    if let Some(interpreter) = matches.value_of_os("set-interpreter") {
+        patcher.set_interpreter(interpreter.as_bytes())?;
+        modified = true;
+    }
+    if let Some(soname) = matches.value_of_os("set-soname") {
+        patcher.set_soname(soname.as_bytes());
+        modified = true;
+    }
+    if matches.is_present("remove-rpath") {
+        patcher.remove_rpath();
+        modified = true;
+    }
+    if let Some(rpath) = matches.value_of_os("set-rpath") {
+        if matches.is_present("force-rpath") {
+            patcher.set_rpath(rpath.as_bytes());
+        } else {
+            patcher.set_runpath(rpath.as_bytes());
+        }
+        modified = true;
+    }
+    if let Some(libraries) = matches.values_of_os("remove-needed") {
+        for library in libraries {
+            patcher.remove_needed(library.as_bytes());
+        }
+        modified = true;
+    }
+    if let Some(libraries) = matches.values_of_os("replace-needed") {
+        let libraries: Vec<_> = libraries.collect();
+        for pair in libraries.chunks(2) {
+            patcher.replace_needed(pair[0].as_bytes(), pair[1].as_bytes());
+        }
+        modified = true;
+    }
+    if let Some(libraries) = matches.values_of_os("add-needed") {
+        for library in libraries {
+            patcher.add_needed(library.as_bytes());
+        }
+        modified = true;
+    }
+
+    if matches.is_present("print-interpreter") {
+        print_string(patcher.interpreter());
+    }
+    if matches.is_present("print-soname") {
+        print_string(patcher.soname());
+    }
+    if matches.is_present("print-rpath") {
+        print_string(patcher.runpath().or_else(|| patcher.rpath()));
+    }
+    if matches.is_present("print-needed") {
+        for library in patcher.needed() {
+            print_string(Some(library));
+        }
+    }
+
+    let output = matches.value_of_os("output");
+    if modified || output.is_some() {
+        let output = output.unwrap_or(file);
+        fs::write(output, patcher.write()?)?;
+        fs::set_permissions(output, fs::metadata(file)?.permissions())?;
+    }
+    Ok(())
+}
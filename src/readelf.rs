@@ -65,7 +65,7 @@ fn print_header(reader: &elf::Reader) {
     println!("  {:34} 0x{:x}", "Entry point address", ehdr.e_entry());
     println!("  {:34} {} (bytes into file)", "Start of program headers", ehdr.e_phoff());
     println!("  {:34} {} (bytes into file)", "Start of section headers", ehdr.e_shoff());
-    println!("  {:34} 0x{:x}", "Flags", ehdr.e_flags());
+    println!("  {:34} {}", "Flags", header_flags(ehdr.e_machine(), ehdr.e_flags()));
     println!("  {:34} {} (bytes)", "Size of this header", ehdr.e_ehsize());
     println!("  {:34} {} (bytes)", "Size of program headers", ehdr.e_phentsize());
     println!("  {:34} {}", "Number of program headers", ehdr.e_phnum());
@@ -160,18 +160,8 @@ fn print_segments(reader: &elf::Reader, print_info: bool) {
     }
 }
 
-fn symbol_name<'a>(reader: &elf::Reader<'a>, sym: &elf::SymbolRef<'a>) -> &'a str {
-    if sym.symbol_type() == elf::STT_SECTION {
-        if let elf::SectionIndex::Normal(index) = sym.section {
-            reader.section_headers().get(index as usize)
-                .map(|shdr| reader.section_name(shdr))
-                .ok()
-        } else {
-            None
-        }
-    } else {
-        sym.symbol_name
-    }.map_or("", to_utf8)
+fn symbol_name<'a>(sym: &elf::SymbolRef<'a>) -> &'a str {
+    sym.name().map_or("", to_utf8)
 }
 
 fn print_symbols(reader: &elf::Reader, dynamic: bool) -> Result<()> {
@@ -188,58 +178,158 @@ fn print_symbols(reader: &elf::Reader, dynamic: bool) -> Result<()> {
     for i in 0 .. symtab.len() {
         let symbol = symtab.get(i)?;
         // This would be sane. But readelf doesn't do that.
-        // let name = symbol_name(reader, &symbol);
+        // let name = symbol_name(&symbol);
         let name = symbol.symbol_name.map_or("", to_utf8);
+        let mut visibility = elf::symbol_visibility_name(symbol.visibility()).to_string();
+        let local_entry = symbol.ppc64_local_entry_offset();
+        if local_entry != 0 {
+            visibility += &format!(" [<localentry>: {}]", local_entry);
+        }
         println!("{:6}: {:08x} {:5} {:<7} {:<6} {:<6} {:>3} {}",
                  i, symbol.value, symbol.size,
                  elf::symbol_type_name(symbol.symbol_type()), elf::symbol_binding_name(symbol.binding()),
-                 elf::symbol_visibility_name(symbol.visibility()), symbol_index(symbol.section), name);
+                 visibility, symbol_index(symbol.section), name);
     }
 
     Ok(())
 }
 
-fn print_relocations(reader: &elf::Reader) -> Result<()> {
+// readelf shows MIPS64 r_info fields in the order of the big-endian layout.
+fn mips64_info_value(info: &elf::RelocationInfo) -> u64 {
+    (info.symbol_index as u64) << 32 | (info.special_symbol as u64) << 24 |
+        (info.types[2] as u64) << 16 | (info.types[1] as u64) << 8 | info.types[0] as u64
+}
+
+fn print_mips64_types(info: &elf::RelocationInfo) {
+    println!("                    Type2: {}", elf::mips_relocation_name(info.types[1]));
+    println!("                    Type3: {}", elf::mips_relocation_name(info.types[2]));
+}
+
+fn print_rel_table<'a>(reader: &elf::Reader<'a>, tab: &elf::RelTableRef<'a>) -> Result<()> {
     let machine = reader.elf_header().e_machine();
-    for section in reader.sections_matching(|shdr| { let t = shdr.sh_type(); t == elf::SHT_REL || t == elf::SHT_RELA}) {
-        match section.data {
-            elf::SectionDataRef::RelocationTable(tab) => {
-                // 32-bit
-                println!("\nRelocation section '{}' at offset 0x{:x} contains {} {}:",
-                         section.name.map_or("", to_utf8), section.shdr.sh_offset(),
-                         tab.entries.len(), entries(tab.entries.len()));
-                println!(" Offset     Info    Type            Sym.Value  Sym. Name");
-                let symtab = tab.symbol_table;
-                for entry in tab.entries {
-                    let symbol = symtab.get(entry.symbol_index() as usize)?;
-                    let name = symbol_name(reader, &symbol);
-                    println!("{:08x}  {:08x} {:16}  {:08x}   {}",
-                             entry.r_offset(), entry.r_info(),
-                             relocation_name(machine, entry.relocation_type()),
-                             symbol.value, name);
-                }
-            },
-            elf::SectionDataRef::ExplicitRelocationTable(tab) => {
-                // 64-bit
-                println!("\nRelocation section '{}' at offset 0x{:x} contains {} {}:",
-                         section.name.map_or("", to_utf8), section.shdr.sh_offset(),
-                         tab.entries.len(), entries(tab.entries.len()));
-                println!("  Offset          Info           Type           Sym. Value    Sym. Name + Addend");
-                let symtab = tab.symbol_table;
-                for entry in tab.entries {
-                    let symbol = symtab.get(entry.symbol_index() as usize)?;
-                    let name = symbol_name(reader, &symbol);
-                    let addend = entry.r_addend();
-                    let (addend, sign) = if addend < 0 { (-addend, '-') } else { (addend, '+') };
-                    println!("{:012x}  {:012x} {:16}  {:016x} {} {} {}",
-                             entry.r_offset(), entry.r_info(),
-                             relocation_name(machine, entry.relocation_type()),
-                             symbol.value, name, sign, addend);
-                }
-            },
-            _ => unreachable!(),
+    let class = reader.elf_header().e_ident()[elf::EI_CLASS];
+    let mips64 = machine == elf::EM_MIPS && class == elf::ELFCLASS64;
+    println!(" Offset     Info    Type            Sym.Value  Sym. Name");
+    for (index, entry) in tab.entries.iter().enumerate() {
+        let elf::RelocationRef { info, symbol, .. } = tab.get(index)?;
+        let name = symbol_name(&symbol);
+        let raw_info = if mips64 { mips64_info_value(&info) } else { entry.r_info() };
+        println!("{:08x}  {:08x} {:16}  {:08x}   {}",
+                 entry.r_offset(), raw_info,
+                 elf::relocation_name(machine, class, info.relocation_type()),
+                 symbol.value, name);
+        if mips64 {
+            print_mips64_types(&info);
+        }
+    }
+    Ok(())
+}
+
+fn print_rela_table<'a>(reader: &elf::Reader<'a>, tab: &elf::RelaTableRef<'a>) -> Result<()> {
+    let machine = reader.elf_header().e_machine();
+    let class = reader.elf_header().e_ident()[elf::EI_CLASS];
+    let mips64 = machine == elf::EM_MIPS && class == elf::ELFCLASS64;
+    println!("  Offset          Info           Type           Sym. Value    Sym. Name + Addend");
+    let hi_indexes = tab.riscv_pcrel_hi_indexes()?;
+    for (index, entry) in tab.entries.iter().enumerate() {
+        let elf::RelocationRef { info, symbol, .. } = tab.get(index)?;
+        let name = symbol_name(&symbol);
+        let addend = entry.r_addend();
+        let (addend, sign) = if addend < 0 { (-addend, '-') } else { (addend, '+') };
+        let raw_info = if mips64 { mips64_info_value(&info) } else { entry.r_info() };
+        print!("{:012x}  {:012x} {:16}  {:016x} {} {} {}",
+               entry.r_offset(), raw_info,
+               elf::relocation_name(machine, class, info.relocation_type()),
+               symbol.value, name, sign, addend);
+        if machine == elf::EM_SPARCV9 && info.relocation_type() == elf::R_SPARC_OLO10 {
+            print!(" + {:x}", info.type_data);
+        }
+        // Show the target of RISC-V %pcrel_lo relocations.
+        if let Some(hi_index) = hi_indexes[index] {
+            let hi = tab.get(hi_index)?;
+            let hi_addend = hi.addend.unwrap_or(0);
+            let (hi_addend, hi_sign) = if hi_addend < 0 { (-hi_addend, '-') } else { (hi_addend, '+') };
+            print!(" ({} at {:x}: {} {} {})",
+                   elf::relocation_name(machine, class, hi.relocation_type()), hi.offset,
+                   symbol_name(&hi.symbol), hi_sign, hi_addend);
+        }
+        println!();
+        if mips64 {
+            print_mips64_types(&info);
+        }
+    }
+    Ok(())
+}
+
+fn print_relr_table(reader: &elf::Reader, tab: &elf::RelrTableRef) {
+    println!("  {} offsets", tab.addresses().count());
+    for address in tab.addresses() {
+        if reader.is_64bit() {
+            println!("{:016x}", address);
+        } else {
+            println!("{:08x}", address);
         }
     }
+}
+
+// Prints the entries of a relocation table, after the caller has printed its header.
+fn print_relocation_table<'a>(reader: &elf::Reader<'a>, data: &elf::SectionDataRef<'a>) -> Result<()> {
+    match *data {
+        elf::SectionDataRef::RelocationTable(ref tab) => print_rel_table(reader, tab)?,
+        elf::SectionDataRef::ExplicitRelocationTable(ref tab) => print_rela_table(reader, tab)?,
+        elf::SectionDataRef::RelativeRelocationTable(ref tab) => print_relr_table(reader, tab),
+        elf::SectionDataRef::AndroidPackedRelocationTable(ref packed) => {
+            let unpacked = packed.unpack()?;
+            print_relocation_table(reader, &unpacked.data())?;
+        },
+        _ => unreachable!(),
+    }
+    Ok(())
+}
+
+fn print_relocations(reader: &elf::Reader) -> Result<()> {
+    for section in reader.sections_matching(|shdr| {
+        let t = shdr.sh_type();
+        t == elf::SHT_REL || t == elf::SHT_RELA || t == elf::SHT_RELR ||
+            t == elf::SHT_ANDROID_REL || t == elf::SHT_ANDROID_RELA || t == elf::SHT_ANDROID_RELR
+    }) {
+        // Packed Android relocations are counted after unpacking.
+        let count = match section.data {
+            elf::SectionDataRef::RelocationTable(ref tab) => tab.entries.len(),
+            elf::SectionDataRef::ExplicitRelocationTable(ref tab) => tab.entries.len(),
+            elf::SectionDataRef::RelativeRelocationTable(ref tab) => tab.entries.len(),
+            elf::SectionDataRef::AndroidPackedRelocationTable(ref packed) => packed.unpack()?.len(),
+            _ => unreachable!(),
+        };
+        println!("\nRelocation section '{}' at offset 0x{:x} contains {} {}:",
+                 section.name.map_or("", to_utf8), section.shdr.sh_offset(), count, entries(count));
+        print_relocation_table(reader, &section.data)?;
+    }
+    Ok(())
+}
+
+// The equivalent of readelf -D -r, for object files whose section headers may have been stripped.
+fn print_dynamic_relocations(reader: &elf::Reader) -> Result<()> {
+    for table in reader.dynamic_relocations()? {
+        let name = if table.tag == elf::DT_JMPREL { "PLT" } else { elf::dynamic_tag_name(table.tag) };
+        println!("\n'{}' relocation section at offset 0x{:x} contains {} bytes:",
+                 name, table.offset, table.size);
+        print_relocation_table(reader, &table.data)?;
+    }
+    Ok(())
+}
+
+// Lists the PLT stubs under the synthetic name@plt symbols which objdump -d gives them.
+fn print_plt_entries(reader: &elf::Reader) -> Result<()> {
+    let plt = reader.plt_entries()?;
+    if plt.is_empty() {
+        return Ok(());
+    }
+    println!("\nPLT contains {} {}:", plt.len(), entries(plt.len()));
+    println!("Address   Size GOT slot Name");
+    for entry in plt {
+        println!("{:08x} {:5} {:08x} {}", entry.address, entry.size, entry.got_address, to_utf8(&entry.name()));
+    }
     Ok(())
 }
 
@@ -258,6 +348,20 @@ fn print_notes(reader: &elf::Reader) -> Result<()> {
             println!("  {:14} Type Desc", "Name");
             for note in notes {
                 let name = note.name.map_or("<none>", to_utf8);
+                if note.name == Some(&b"Android"[..]) &&
+                   note.note_type == elf::Elf_Xword::from(elf::NT_ANDROID_TYPE_IDENT)
+                {
+                    if let Some(ident) = note.desc.and_then(|desc| {
+                        elf::AndroidIdent::parse(desc, reader.little_endian())
+                    }) {
+                        println!("  {:14} {:4} API level {}", name, note.note_type, ident.api_level);
+                        if let (Some(version), Some(build)) = (ident.ndk_version, ident.ndk_build_number) {
+                            println!("    NDK version: {}", to_utf8(version));
+                            println!("    NDK build number: {}", to_utf8(build));
+                        }
+                        continue;
+                    }
+                }
                 let desc = note.desc.map_or(String::from("<none>"),
                                             |s| str::from_utf8(s).map(String::from)
                                                 .unwrap_or_else(|_| hex_string(s)));
@@ -269,6 +373,24 @@ fn print_notes(reader: &elf::Reader) -> Result<()> {
     Ok(())
 }
 
+fn print_arch_specific(reader: &elf::Reader) -> Result<()> {
+    for section in reader.sections_matching(|shdr| shdr.sh_type() == elf::SHT_MIPS_ABIFLAGS) {
+        if let elf::SectionDataRef::MipsAbiFlags(flags) = section.data {
+            println!("\nMIPS ABI Flags Version: {}", flags.version());
+            println!("\nISA: {}", mips_isa(flags.isa_level(), flags.isa_rev()));
+            println!("GPR size: {}", elf::mips_abiflags_reg_size_name(flags.gpr_size()));
+            println!("CPR1 size: {}", elf::mips_abiflags_reg_size_name(flags.cpr1_size()));
+            println!("CPR2 size: {}", elf::mips_abiflags_reg_size_name(flags.cpr2_size()));
+            println!("FP ABI: {}", elf::mips_fp_abi_name(flags.fp_abi()));
+            println!("ISA Extension: {}", elf::mips_isa_ext_name(flags.isa_ext()));
+            println!("ASEs: {}", mips_ases(flags.ases()));
+            println!("FLAGS 1: {:08x}", flags.flags1());
+            println!("FLAGS 2: {:08x}", flags.flags2());
+        }
+    }
+    Ok(())
+}
+
 fn main() -> Result<()> {
     let matches = app_from_crate!()
         .setting(AppSettings::DeriveDisplayOrder)
@@ -314,6 +436,17 @@ fn main() -> Result<()> {
              .help("Display the relocations (if present)")
              .short("r")
              .long("relocs"))
+        .arg(Arg::with_name("use-dynamic")
+             .help("Use the dynamic section info when displaying relocations")
+             .short("D")
+             .long("use-dynamic"))
+        .arg(Arg::with_name("arch-specific")
+             .help("Display architecture specific information (if any)")
+             .short("A")
+             .long("arch-specific"))
+        .arg(Arg::with_name("plt")
+             .help("Display the PLT entries and the GOT slots they jump through")
+             .long("plt"))
         .arg(Arg::with_name("elf-file")
              .help("Input ELF files")
              .multiple(true)
@@ -331,6 +464,9 @@ fn main() -> Result<()> {
     let dynsyms = matches.is_present("dyn-sym");
     let notes = matches.is_present("notes");
     let relocations = all || matches.is_present("relocs");
+    let use_dynamic = matches.is_present("use-dynamic");
+    let arch_specific = all || matches.is_present("arch-specific");
+    let plt = matches.is_present("plt");
     let input = matches.values_of("elf-file").unwrap();
 
     for file in input {
@@ -346,7 +482,9 @@ fn main() -> Result<()> {
             print_segments(&reader, !file_header);
         }
         // TODO: dynamic
-        if relocations {
+        if relocations && use_dynamic {
+            print_dynamic_relocations(&reader)?;
+        } else if relocations {
             print_relocations(&reader)?;
         }
         if dynsyms {
@@ -358,6 +496,12 @@ fn main() -> Result<()> {
         if notes {
             print_notes(&reader)?;
         }
+        if arch_specific {
+            print_arch_specific(&reader)?;
+        }
+        if plt {
+            print_plt_entries(&reader)?;
+        }
         // TODO: histogram
     }
     Ok(())
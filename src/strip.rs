@@ -0,0 +1,82 @@
+#[macro_use]
+extern crate clap;
+extern crate elftk;
+extern crate failure;
+
+use std::fs;
+use std::os::unix::ffi::OsStrExt;
+use std::path::Path;
+use std::result;
+
+use clap::{Arg, AppSettings};
+use elftk as elf;
+
+use failure::Error;
+
+type Result<T> = result::Result<T, Error>;
+
+fn main() -> Result<()> {
+    let matches = app_from_crate!()
+        .name("elftk-strip")
+        .about("Remove symbols and debugging sections from ELF files")
+        .setting(AppSettings::DeriveDisplayOrder)
+        .setting(AppSettings::UnifiedHelpMessage)
+        .arg(Arg::with_name("strip-all")
+             .help("Remove the symbol table and debugging sections (the default)")
+             .short("s")
+             .long("strip-all"))
+        .arg(Arg::with_name("strip-debug")
+             .help("Remove the debugging sections")
+             .short("g")
+             .long("strip-debug")
+             .conflicts_with("strip-all"))
+        .arg(Arg::with_name("strip-unneeded")
+             .help("Remove the debugging sections and the symbols not needed by relocations")
+             .long("strip-unneeded")
+             .conflicts_with_all(&["strip-all", "strip-debug"]))
+        .arg(Arg::with_name("only-keep-debug")
+             .help("Keep only what a separate debug file needs")
+             .long("only-keep-debug")
+             .conflicts_with_all(&["strip-all", "strip-debug", "strip-unneeded"]))
+        .arg(Arg::with_name("add-gnu-debuglink")
+             .help("Add a .gnu_debuglink section referring to the separate debug file FILE")
+             .long("add-gnu-debuglink")
+             .value_name("FILE"))
+        .arg(Arg::with_name("output")
+             .help("Write the result to FILE rather than modifying the input file")
+             .short("o")
+             .long("output")
+             .value_name("FILE"))
+        .arg(Arg::with_name("elf-file")
+             .help("Input ELF file")
+             .required(true))
+        .help_short("H")
+        .version_short("v")
+        .get_matches();
+
+    let mode = if matches.is_present("strip-debug") {
+        elf::StripMode::Debug
+    } else if matches.is_present("strip-unneeded") {
+        elf::StripMode::Unneeded
+    } else if matches.is_present("only-keep-debug") {
+        elf::StripMode::OnlyKeepDebug
+    } else {
+        elf::StripMode::All
+    };
+
+    let file = matches.value_of_os("elf-file").unwrap();
+    let data = fs::read(file)?;
+    let reader = elf::Reader::new(&data)?;
+    let mut elf_file = elf::ElfFile::new(&reader)?;
+    elf_file.strip(mode)?;
+    if let Some(debug_file) = matches.value_of_os("add-gnu-debuglink") {
+        let debug_data = fs::read(debug_file)?;
+        let file_name = Path::new(debug_file).file_name().unwrap_or(debug_file);
+        elf_file.set_debug_link(file_name.as_bytes(), elf::crc32(&debug_data));
+    }
+
+    let output = matches.value_of_os("output").unwrap_or(file);
+    fs::write(output, elf_file.write()?)?;
+    fs::set_permissions(output, fs::metadata(file)?.permissions())?;
+    Ok(())
+}
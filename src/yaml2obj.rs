@@ -0,0 +1,45 @@
+#[macro_use]
+extern crate clap;
+extern crate elftk;
+extern crate failure;
+extern crate serde_json;
+
+use std::fs;
+use std::result;
+
+use clap::{Arg, AppSettings};
+use elftk as elf;
+
+use failure::Error;
+
+type Result<T> = result::Result<T, Error>;
+
+fn main() -> Result<()> {
+    let matches = app_from_crate!()
+        .name("elftk-yaml2obj")
+        .about("Build an ELF file from a YAML or JSON description written by elftk-obj2yaml")
+        .setting(AppSettings::DeriveDisplayOrder)
+        .setting(AppSettings::UnifiedHelpMessage)
+        .arg(Arg::with_name("output")
+             .help("Write the ELF file to FILE")
+             .short("o")
+             .long("output")
+             .value_name("FILE")
+             .required(true))
+        .arg(Arg::with_name("description")
+             .help("Input YAML or JSON description")
+             .required(true))
+        .help_short("H")
+        .version_short("v")
+        .get_matches();
+
+    let text = fs::read_to_string(matches.value_of_os("description").unwrap())?;
+    // JSON descriptions are objects; YAML ones are block mappings.
+    let description: elf::ElfDescription = if text.trim_start().starts_with('{') {
+        serde_json::from_str(&text)?
+    } else {
+        elf::ElfDescription::from_yaml(&text)?
+    };
+    fs::write(matches.value_of_os("output").unwrap(), description.build()?)?;
+    Ok(())
+}
@@ -0,0 +1,53 @@
+#![allow(dead_code)]
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+
+use elftk::ElfDescription;
+
+// Returns the contents of the object file `tests/fixtures/<name>.elf`, which `elftk-yaml2obj`
+// builds from `tests/fixtures/<name>.yaml`. Reading it into a `Vec` rather than using
+// `include_bytes!` keeps it suitably aligned for the `Reader`.
+pub fn fixture(name: &str) -> Vec<u8> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures").join(format!("{}.elf", name));
+    fs::read(&path).unwrap_or_else(|err| panic!("cannot read {}: {}", path.display(), err))
+}
+
+// Returns the description `tests/fixtures/<name>.yaml` from which the fixture `name` is built.
+pub fn fixture_description(name: &str) -> ElfDescription {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures").join(format!("{}.yaml", name));
+    let text = fs::read_to_string(&path).unwrap_or_else(|err| panic!("cannot read {}: {}", path.display(), err));
+    ElfDescription::from_yaml(&text).unwrap_or_else(|err| panic!("cannot parse {}: {}", path.display(), err))
+}
+
+// A fresh directory under the system temporary directory, removed when dropped.
+pub struct TempDir(PathBuf);
+
+impl TempDir {
+    pub fn new(name: &str) -> TempDir {
+        let path = env::temp_dir().join(format!("elftk-{}-{}", name, process::id()));
+        let _ = fs::remove_dir_all(&path);
+        fs::create_dir_all(&path).unwrap();
+        TempDir(path.canonicalize().unwrap())
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.0
+    }
+
+    // Writes `data` to the file at `relative`, creating its directories, and returns its path.
+    pub fn write(&self, relative: &str, data: &[u8]) -> PathBuf {
+        let path = self.0.join(relative);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, data).unwrap();
+        path
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
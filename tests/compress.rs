@@ -0,0 +1,81 @@
+extern crate elftk;
+extern crate miniz_oxide;
+
+use elftk::*;
+
+mod common;
+use common::fixture;
+
+// Returns an editable copy of the i386 fixture with a compressed `.debug_str` section.
+fn compressed_file(compression: Compression) -> (ElfFile, Elf_Word, Vec<u8>) {
+    let data = fixture("elf32le_rel");
+    let mut file = ElfFile::new(&Reader::new(&data).unwrap()).unwrap();
+    let strings = b"int\0char\0unsigned int\0".repeat(50);
+    let index = file.add_section(FileSection::new(b".debug_str", SHT_PROGBITS, SHF_MERGE | SHF_STRINGS, strings.clone()));
+    assert!(file.compress_section(index, compression).unwrap());
+    (file, index, strings)
+}
+
+#[test]
+fn decompress_round_trip() {
+    for &compression in &[Compression::Zlib, Compression::Zstd] {
+        let (file, _, strings) = compressed_file(compression);
+        let data = file.write().unwrap();
+        let mut file = ElfFile::new(&Reader::new(&data).unwrap()).unwrap();
+        let index = file.section_index(b".debug_str").unwrap();
+        assert_eq!(file.sections[index as usize].header.sh_addralign, 4);
+        assert!(file.decompress_section(index).unwrap());
+        let section = &file.sections[index as usize];
+        assert_eq!((&section.data, section.header.sh_flags & SHF_COMPRESSED), (&strings, 0));
+    }
+}
+
+#[test]
+fn decompress_oversized_ch_size() {
+    for &compression in &[Compression::Zlib, Compression::Zstd] {
+        let (mut file, index, _) = compressed_file(compression);
+        // An Elf32_Chdr claiming 1 GiB of contents must not be trusted for the allocation.
+        file.sections[index as usize].data[4..8].copy_from_slice(&0x4000_0000u32.to_le_bytes());
+        assert!(file.decompress_section(index).is_err());
+    }
+}
+
+#[test]
+fn compress_debug_sections() {
+    let data = fixture("elf64le_x86_64_rel");
+    let mut file = ElfFile::new(&Reader::new(&data).unwrap()).unwrap();
+    let strings = b"long\0double\0".repeat(100);
+    file.add_section(FileSection::new(b".debug_str", SHT_PROGBITS, SHF_MERGE | SHF_STRINGS, strings.clone()));
+    // Too small to get smaller.
+    file.add_section(FileSection::new(b".debug_abbrev", SHT_PROGBITS, 0, vec![1, 0x11, 0]));
+    file.compress_debug_sections(Compression::Zlib).unwrap();
+    let data = file.write().unwrap();
+
+    let reader = Reader::new(&data).unwrap();
+    let debug_str = reader.section_by_name(b".debug_str").unwrap().shdr;
+    assert_eq!((debug_str.sh_flags() & SHF_COMPRESSED, debug_str.sh_addralign()), (SHF_COMPRESSED, 8));
+    assert!(debug_str.sh_size() < strings.len() as u64);
+    assert_eq!(reader.section_by_name(b".debug_abbrev").unwrap().shdr.sh_flags(), 0);
+    assert_eq!(reader.section_by_name(b".text").unwrap().shdr.sh_flags() & SHF_COMPRESSED, 0);
+
+    let mut file = ElfFile::new(&reader).unwrap();
+    file.decompress_sections().unwrap();
+    let index = file.section_index(b".debug_str").unwrap();
+    assert_eq!(file.sections[index as usize].data, strings);
+}
+
+#[test]
+fn decompress_zdebug_section() {
+    let data = fixture("elf64le_x86_64_rel");
+    let mut file = ElfFile::new(&Reader::new(&data).unwrap()).unwrap();
+    let strings = b"float\0".repeat(40);
+    // The magic "ZLIB" and the big-endian size of the contents precede the zlib stream.
+    let mut zdebug = b"ZLIB".to_vec();
+    zdebug.extend_from_slice(&(strings.len() as u64).to_be_bytes());
+    zdebug.extend(miniz_oxide::deflate::compress_to_vec_zlib(&strings, 6));
+    let index = file.add_section(FileSection::new(b".zdebug_str", SHT_PROGBITS, 0, zdebug));
+    assert!(file.decompress_section(index).unwrap());
+    let section = &file.sections[index as usize];
+    assert_eq!((&section.name[..], &section.data), (&b".debug_str"[..], &strings));
+    assert!(!file.decompress_section(index).unwrap());
+}
@@ -0,0 +1,99 @@
+extern crate elftk;
+
+use std::collections::HashMap;
+
+use elftk::*;
+
+mod common;
+use common::fixture;
+
+// Applies `edit` to the x86-64 relocatable fixture and returns the written file.
+fn edited<F>(edit: F) -> Vec<u8> where F: FnOnce(&mut ElfFile) {
+    let data = fixture("elf64le_x86_64_rel");
+    let mut file = ElfFile::new(&Reader::new(&data).unwrap()).unwrap();
+    edit(&mut file);
+    file.write().unwrap()
+}
+
+fn section_names(reader: &Reader) -> Vec<String> {
+    reader.section_headers().into_iter()
+        .map(|shdr| String::from_utf8_lossy(reader.section_name(shdr)).into_owned())
+        .collect()
+}
+
+// Returns the names and bindings of the symbols of the symbol table, but the null symbol.
+fn symbol_bindings(reader: &Reader) -> Vec<(String, u8)> {
+    let symtab = match reader.symtab().unwrap().unwrap().data {
+        SectionDataRef::SymbolTable(symtab) => symtab,
+        _                                   => panic!("not a symbol table"),
+    };
+    (1..symtab.len())
+        .map(|index| symtab.get(index).unwrap())
+        .map(|symbol| (String::from_utf8_lossy(symbol.name().unwrap()).into_owned(), symbol.binding()))
+        .collect()
+}
+
+#[test]
+fn section_flags() {
+    assert_eq!(parse_section_flags("alloc").unwrap(), SHF_ALLOC | SHF_WRITE);
+    assert_eq!(parse_section_flags("alloc, readonly, code").unwrap(), SHF_ALLOC | SHF_EXECINSTR);
+    assert_eq!(parse_section_flags("merge,strings,contents").unwrap(), SHF_MERGE | SHF_STRINGS);
+    assert_eq!(parse_section_flags("alloc,load,tls").unwrap(), SHF_ALLOC | SHF_WRITE | SHF_TLS);
+    assert_eq!(parse_section_flags("readonly,exclude").unwrap(), SHF_EXCLUDED);
+    assert!(parse_section_flags("alloc,writable").is_err());
+    assert!(parse_section_flags("").is_err());
+}
+
+#[test]
+fn symbol_map() {
+    let map = parse_symbol_map(b"old new\n  # comment\n\nfoo\tbar # renamed\n").unwrap();
+    assert_eq!(map.len(), 2);
+    assert_eq!(map[&b"old"[..]], b"new");
+    assert_eq!(map[&b"foo"[..]], b"bar");
+    assert!(parse_symbol_map(b"old\n").is_err());
+    assert!(parse_symbol_map(b"old new extra\n").is_err());
+}
+
+#[test]
+fn rename_and_remove_sections() {
+    let data = edited(|file| {
+        let text = file.section_index(b".text").unwrap();
+        file.rename_section(text, b".text.boot").unwrap();
+        file.set_section_flags(text, parse_section_flags("alloc,readonly").unwrap()).unwrap();
+        file.set_exclude(text, true).unwrap();
+        file.set_section_alignment(text, 64).unwrap();
+        assert!(file.set_section_alignment(text, 24).is_err());
+        file.add_section_from(b".debug_info", vec![0; 8]);
+        file.remove_sections_matching(&[b".rela.d?ta", b".debug_*"]).unwrap();
+        let note = file.add_section_from(b".note.tag", vec![0; 12]);
+        let blob = file.add_section_from(b".blob", vec![1, 2, 3]);
+        assert_eq!((file.sections[note as usize].header.sh_type, file.sections[blob as usize].header.sh_type),
+                   (SHT_NOTE, SHT_PROGBITS));
+        file.compact();
+    });
+    let reader = Reader::new(&data).unwrap();
+    // .rela.text is renamed with .text.
+    assert_eq!(section_names(&reader), ["", ".text.boot", ".rela.text.boot", ".data", ".symtab", ".strtab",
+                                        ".shstrtab", ".note.tag", ".blob"]);
+    let text = reader.section_by_name(b".text.boot").unwrap().shdr;
+    assert_eq!(text.sh_flags(), SHF_ALLOC | SHF_EXCLUDED);
+    assert_eq!((text.sh_addralign(), text.sh_offset() % 64), (64, 0));
+}
+
+#[test]
+fn rebind_symbols() {
+    let data = edited(|file| {
+        let mut renamed = HashMap::new();
+        renamed.insert(b"load".to_vec(), b"start".to_vec());
+        file.rename_symbols(&renamed).unwrap();
+        file.localize_symbols(&[b"v*", b"func"]).unwrap();
+        file.globalize_symbols(&[b"var"]).unwrap();
+        file.weaken_symbols(&[b"st?rt", b"func"]).unwrap();
+    });
+    let reader = Reader::new(&data).unwrap();
+    // The undefined func stays global when localized, but is weakened like a defined symbol.
+    // Local symbols are moved before the others.
+    assert_eq!(symbol_bindings(&reader), [("var".to_string(), STB_GLOBAL),
+                                          ("start".to_string(), STB_WEAK),
+                                          ("func".to_string(), STB_WEAK)]);
+}
@@ -0,0 +1,94 @@
+extern crate elftk;
+
+use std::path::Path;
+
+use elftk::*;
+
+mod common;
+use common::{fixture, TempDir};
+
+// Returns the object file, its debug file, and the supplementary debug file.
+fn fixtures() -> (Vec<u8>, Vec<u8>, Vec<u8>) {
+    (fixture("elf64le_debuglink"), fixture("elf64le_debug"), fixture("elf64le_debugalt"))
+}
+
+fn file_path(file: &Option<DebugFile>) -> Option<&Path> {
+    file.as_ref().map(DebugFile::path)
+}
+
+#[test]
+fn crc32_check_value() {
+    assert_eq!(crc32(b""), 0);
+    assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+}
+
+#[test]
+fn notes() {
+    let (data, _, _) = fixtures();
+    let reader = Reader::new(&data).unwrap();
+    let notes = |name: &[u8]| match reader.section_by_name(name).unwrap().data {
+        SectionDataRef::NoteTable(notes) => notes.iter()
+            .map(|note| (note.name, note.note_type, note.desc.map(<[u8]>::len)))
+            .collect::<Vec<_>>(),
+        _                                => panic!("not a note table"),
+    };
+    // The descriptor of the GNU property note is padded to 8 bytes rather than 4.
+    assert_eq!(notes(b".note.gnu.property"), [(Some(&b"GNU"[..]), 5, Some(16))]);
+    assert_eq!(notes(b".note.gnu.build-id"), [(Some(&b"GNU"[..]), 3, Some(8)), (None, 0x1234, None)]);
+    assert_eq!(reader.build_id(), Some(&[0xab, 0xcd, 0xef, 0x01, 0x23, 0x45, 0x67, 0x89][..]));
+}
+
+#[test]
+fn debug_links() {
+    let (data, debug_data, alt_data) = fixtures();
+    let link = Reader::new(&data).unwrap().debug_link().unwrap();
+    assert_eq!((link.file_name, link.crc), (&b"elf64le_debug.elf"[..], crc32(&debug_data)));
+    let alt_link = Reader::new(&debug_data).unwrap().debug_alt_link().unwrap();
+    assert_eq!((alt_link.file_name, alt_link.build_id), (&b"elf64le_debugalt.elf"[..], &[0x11; 8][..]));
+    assert!(Reader::new(&alt_data).unwrap().debug_link().is_none());
+}
+
+#[test]
+fn locate_by_build_id() {
+    let (data, debug_data, alt_data) = fixtures();
+    let dir = TempDir::new("build-id");
+    let main = dir.write("bin/prog", &data);
+    let debug = dir.write("debug/.build-id/ab/cdef0123456789.debug", &debug_data);
+    // The supplementary file is not next to the debug file, so it is found by its build ID too.
+    let alt = dir.write("debug/.build-id/11/11111111111111.debug", &alt_data);
+    let locator = DebugLocator::with_roots(&[dir.path().join("empty"), dir.path().join("debug")]);
+    let info = locator.locate(&Reader::new(&data).unwrap(), &main);
+    assert_eq!(file_path(&info.debug), Some(&*debug));
+    assert_eq!(file_path(&info.alt), Some(&*alt));
+
+    // .text has data in the object file and .debug_str only in the debug file.
+    let view = info.view(Reader::new(&data).unwrap()).unwrap();
+    assert_eq!(view.section_by_name(b".text").unwrap().shdr.sh_type(), SHT_PROGBITS);
+    assert!(view.main().section_by_name(b".debug_str").is_none());
+    assert!(view.section_by_name(b".debug_str").is_some());
+    assert!(view.alt_section_by_name(b".debug_str").is_some());
+}
+
+#[test]
+fn locate_by_debug_link() {
+    let (data, debug_data, alt_data) = fixtures();
+    let dir = TempDir::new("debuglink");
+    let main = dir.write("bin/prog", &data);
+    // A file with the right name but the wrong CRC is skipped.
+    dir.write("bin/elf64le_debug.elf", &alt_data);
+    let debug = dir.write("bin/.debug/elf64le_debug.elf", &debug_data);
+    let alt = dir.write("bin/.debug/elf64le_debugalt.elf", &alt_data);
+    let locator = DebugLocator::with_roots(&[dir.path().join("debug")]);
+    let info = locator.locate(&Reader::new(&data).unwrap(), &main);
+    assert_eq!(file_path(&info.debug), Some(&*debug));
+    assert_eq!(file_path(&info.alt), Some(&*alt));
+
+    // Under a root directory, the debug file is in the object file's directory.
+    let dir = TempDir::new("debuglink-root");
+    let main = dir.write("bin/prog", &data);
+    let debug = dir.write(&format!("debug{}/elf64le_debug.elf", dir.path().join("bin").display()), &debug_data);
+    let locator = DebugLocator::with_roots(&[dir.path().join("debug")]);
+    let info = locator.locate(&Reader::new(&data).unwrap(), &main);
+    assert_eq!(file_path(&info.debug), Some(&*debug));
+    assert!(info.alt.is_none());
+}
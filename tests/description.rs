@@ -0,0 +1,255 @@
+extern crate elftk;
+extern crate serde_json;
+extern crate serde_yaml;
+
+use std::fs;
+use std::path::Path;
+
+use elftk::*;
+
+mod common;
+use common::{fixture, fixture_description};
+
+// Builds the fixture `name` from its description and checks that it matches the checked-in object
+// file, and that describing it gives a description which builds the same file and survives
+// serialization.
+fn build(name: &str) -> Vec<u8> {
+    let data = fixture_description(name).build().unwrap();
+    assert_eq!(data, fixture(name), "{}.yaml does not build {}.elf", name, name);
+    let described = Reader::new(&data).unwrap().describe().unwrap();
+    assert_eq!(described.build().unwrap(), data);
+    let yaml = serde_yaml::to_string(&described).unwrap();
+    assert_eq!(serde_yaml::from_str::<ElfDescription>(&yaml).unwrap(), described);
+    let json = serde_json::to_string(&described).unwrap();
+    assert_eq!(serde_json::from_str::<ElfDescription>(&json).unwrap(), described);
+    data
+}
+
+fn section_names(reader: &Reader) -> Vec<String> {
+    reader.section_headers().into_iter()
+        .map(|shdr| String::from_utf8_lossy(reader.section_name(shdr)).into_owned())
+        .collect()
+}
+
+fn symbol_table<'a>(section: SectionRef<'a>) -> SymbolTableRef<'a> {
+    match section.data {
+        SectionDataRef::SymbolTable(symtab) => symtab,
+        _                                   => panic!("not a symbol table"),
+    }
+}
+
+fn relocation_entries<'a>(table: RelocationTableRef<'a>) -> Vec<RelocationRef<'a>> {
+    table.iter().map(Result::unwrap).collect()
+}
+
+fn contents<'a>(reader: &Reader<'a>, name: &[u8]) -> &'a [u8] {
+    match reader.uninterpreted_section_data(reader.section_by_name(name).unwrap().shdr) {
+        SectionDataRef::Uninterpreted(data) => data,
+        _                                   => panic!("section without contents"),
+    }
+}
+
+fn section_index(section: SectionIndex) -> Option<Elf_Word> {
+    match section {
+        SectionIndex::Normal(index) => Some(index),
+        SectionIndex::Reserved(_)   => None,
+    }
+}
+
+fn reserved_index(section: SectionIndex) -> Option<Elf_Half> {
+    match section {
+        SectionIndex::Normal(_)       => None,
+        SectionIndex::Reserved(index) => Some(index),
+    }
+}
+
+#[test]
+fn elf32le_relocatable() {
+    let data = build("elf32le_rel");
+    let reader = Reader::new(&data).unwrap();
+    assert!(!reader.is_64bit() && reader.little_endian());
+    assert_eq!(reader.elf_header().e_type(), ET_REL);
+    assert_eq!(reader.elf_header().e_machine(), EM_386);
+    assert_eq!(section_names(&reader), ["", ".group", ".text", ".rel.text", ".text.inline", ".rel.text.inline",
+                                        ".data", ".bss", ".note.GNU-stack", ".symtab", ".strtab", ".shstrtab"]);
+    assert_eq!(reader.section_string_table_index(), Some(11));
+
+    let group = reader.section_by_name(b".group").unwrap();
+    assert_eq!(group.shdr.sh_type(), SHT_GROUP);
+    assert_eq!(group.shdr.sh_info(), 5);
+    assert_eq!(contents(&reader, b".group"), [1, 0, 0, 0, 4, 0, 0, 0, 5, 0, 0, 0]);
+
+    let symtab = symbol_table(reader.symtab().unwrap().unwrap());
+    assert_eq!(symtab.len(), 10);
+    assert_eq!(reader.symtab().unwrap().unwrap().shdr.sh_info(), 4);
+    let helper = symtab.get(4).unwrap();
+    assert_eq!(helper.name(), Some(&b"helper"[..]));
+    assert_eq!((helper.binding(), helper.symbol_type(), helper.size), (STB_GLOBAL, STT_FUNC, 17));
+    assert_eq!(section_index(helper.section), Some(2));
+    assert_eq!(symtab.get(2).unwrap().name(), Some(&b".text"[..]));
+    assert_eq!(symtab.get(5).unwrap().binding(), STB_WEAK);
+    assert_eq!(section_index(symtab.get(7).unwrap().section), Some(0));
+    let common = symtab.get(8).unwrap();
+    assert_eq!((reserved_index(common.section), common.value, common.size), (Some(SHN_COMMON), 4, 4));
+    let abs = symtab.get(9).unwrap();
+    assert_eq!((reserved_index(abs.section), abs.value), (Some(SHN_ABS), 0x1234));
+
+    let text = reader.section_by_name(b".text").unwrap();
+    let relocations = relocation_entries(reader.relocations_for(&text).next().unwrap().unwrap());
+    let decoded: Vec<_> = relocations.iter()
+        .map(|r| (r.offset, r.relocation_type(), r.symbol.name().unwrap(), r.addend))
+        .collect();
+    assert_eq!(decoded, [(1, R_386_32, &b"g"[..], None),
+                         (6, R_386_PC32, &b"printf"[..], None),
+                         (12, R_386_32, &b".text"[..], None)]);
+    assert_eq!(reader.section_by_name(b".bss").unwrap().shdr.sh_size(), 4);
+}
+
+#[test]
+fn elf32be_shared_object() {
+    let data = build("elf32be_dyn");
+    let reader = Reader::new(&data).unwrap();
+    assert!(!reader.is_64bit() && !reader.little_endian());
+    assert_eq!(reader.elf_header().e_type(), ET_DYN);
+    assert_eq!(reader.elf_header().e_machine(), EM_PPC);
+
+    let dynstr = reader.section_by_name(b".dynstr").unwrap();
+    let strings = match dynstr.data {
+        SectionDataRef::StringTable(strings) => strings,
+        _                                    => panic!("not a string table"),
+    };
+    let dynamic = reader.dynamic_table().unwrap().unwrap();
+    assert_eq!(dynamic.iter().count(), 8);
+    let string = |tag| strings.get_string(dynamic.get(tag).unwrap() as Elf_Word).unwrap();
+    assert_eq!(string(DT_NEEDED), b"libc.so.6");
+    assert_eq!(string(DT_SONAME), b"libfoo.so");
+    assert_eq!(dynamic.get(DT_HASH), Some(0x1000));
+    assert_eq!(dynamic.get(DT_RELAENT), Some(12));
+
+    let dynsym = symbol_table(reader.dynsym().unwrap().unwrap());
+    assert_eq!(dynsym.len(), 3);
+    let foo = dynsym.get(1).unwrap();
+    assert_eq!((foo.name(), foo.value, foo.symbol_type()), (Some(&b"foo"[..]), 0x1100, STT_FUNC));
+    assert_eq!(section_index(foo.section), Some(6));
+
+    let relocations = relocation_entries(reader.section_by_name(b".rela.dyn").unwrap().data.relocation_table().unwrap());
+    assert_eq!((relocations[0].offset, relocations[0].relocation_type(), relocations[0].addend),
+               (0x2100, R_PPC_RELATIVE, Some(0x1100)));
+    assert_eq!(relocations[0].info.symbol_index, 0);
+    assert_eq!((relocations[1].symbol.name(), relocations[1].addend), (Some(&b"bar"[..]), Some(4)));
+
+    assert_eq!(reader.build_id(), Some(&[0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef][..]));
+    assert_eq!(reader.virtual_address_data(0x1100, 8), Some(&[0x38, 0x60, 0, 0, 0x4e, 0x80, 0, 0x20][..]));
+
+    let phdrs: Vec<_> = reader.program_headers().into_iter().map(|phdr| phdr.decode()).collect();
+    let types: Vec<_> = phdrs.iter().map(|phdr| phdr.p_type).collect();
+    assert_eq!(types, [PT_LOAD, PT_LOAD, PT_DYNAMIC, PT_NOTE, PT_GNU_STACK]);
+    assert_eq!((phdrs[1].p_vaddr, phdrs[1].p_filesz, phdrs[1].p_memsz, phdrs[1].p_flags),
+               (0x2000, 0x108, 0x110, PF_R | PF_W));
+    assert_eq!((phdrs[2].p_offset, phdrs[2].p_filesz), (phdrs[1].p_offset, 0x48));
+    for phdr in &phdrs[..2] {
+        assert_eq!(phdr.p_offset % phdr.p_align, phdr.p_vaddr % phdr.p_align);
+    }
+    assert_eq!(phdrs[4].p_memsz, 0);
+}
+
+#[test]
+fn elf64le_executable() {
+    let data = build("elf64le_exec");
+    let reader = Reader::new(&data).unwrap();
+    assert!(reader.is_64bit() && reader.little_endian());
+    assert_eq!(reader.elf_header().e_type(), ET_EXEC);
+    assert_eq!(reader.elf_header().e_machine(), EM_X86_64);
+    assert_eq!(reader.elf_header().e_entry(), 0x401000);
+
+    let phdrs: Vec<_> = reader.program_headers().into_iter().collect();
+    assert_eq!(phdrs.len(), 8);
+    let phdr = phdrs[0].decode();
+    assert_eq!((phdr.p_type, phdr.p_offset, phdr.p_vaddr, phdr.p_filesz), (PT_PHDR, 64, 0x400040, 8 * 56));
+    assert_eq!(reader.segment_data(phdrs[1]), Some(&b"/lib64/ld-linux-x86-64.so.2\0"[..]));
+    let phdr = phdrs[2].decode();
+    assert_eq!((phdr.p_offset, phdr.p_vaddr, phdr.p_filesz), (0, 0x400000, 0x23c));
+    let phdr = phdrs[4].decode();
+    assert_eq!((phdr.p_vaddr, phdr.p_filesz, phdr.p_memsz), (0x402000, 8, 0x18));
+    let phdr = phdrs[6].decode();
+    assert_eq!((phdr.p_type, phdr.p_vaddr, phdr.p_filesz, phdr.p_memsz), (PT_TLS, 0x402000, 4, 8));
+    assert_eq!(reader.virtual_address_data(0x401000, 3), Some(&[0x31, 0xc0, 0xc3][..]));
+    assert_eq!(reader.virtual_address_data(0x402004, 4), Some(&[7, 0, 0, 0][..]));
+
+    let notes = reader.segment_notes(phdrs[5]).unwrap().unwrap();
+    let notes: Vec<_> = notes.iter().collect();
+    assert_eq!(notes.len(), 1);
+    assert_eq!((notes[0].name, notes[0].note_type), (Some(&b"GNU"[..]), Elf64_Xword::from(NT_GNU_ABI_TAG)));
+    assert_eq!(notes[0].desc, Some(&[0, 0, 0, 0, 3, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0][..]));
+
+    let comment = reader.section_by_name(b".comment").unwrap();
+    assert_eq!((comment.shdr.sh_flags(), comment.shdr.sh_entsize()), (SHF_MERGE | SHF_STRINGS, 1));
+
+    let symtab = symbol_table(reader.symtab().unwrap().unwrap());
+    assert_eq!(reader.symtab().unwrap().unwrap().shdr.sh_info(), 3);
+    let tzero = symtab.get(2).unwrap();
+    assert_eq!((tzero.symbol_type(), tzero.binding(), tzero.value), (STT_TLS, STB_LOCAL, 4));
+    assert_eq!(section_index(tzero.section), Some(5));
+    let data_var = symtab.get(5).unwrap();
+    assert_eq!((data_var.name(), data_var.visibility()), (Some(&b"data_var"[..]), STV_PROTECTED));
+}
+
+#[test]
+fn elf64be_relocatable() {
+    let data = build("elf64be_rel");
+    let reader = Reader::new(&data).unwrap();
+    assert!(reader.is_64bit() && !reader.little_endian());
+    assert_eq!(reader.elf_header().e_type(), ET_REL);
+    assert_eq!(reader.elf_header().e_machine(), EM_S390);
+    assert_eq!(reader.section_string_table_index(), Some(10));
+
+    let data_section = reader.section_by_name(b".data").unwrap();
+    let relocations = relocation_entries(reader.relocations_for(&data_section).next().unwrap().unwrap());
+    assert_eq!(relocations.len(), 2);
+    assert_eq!((relocations[0].relocation_type(), relocations[0].symbol.name(), relocations[0].addend),
+               (R_390_64, Some(&b"counter"[..]), Some(-8)));
+    assert_eq!((relocations[1].offset, relocations[1].symbol.name()), (8, Some(&b".rodata.str1.2"[..])));
+    let text = reader.section_by_name(b".text").unwrap();
+    let relocations = relocation_entries(reader.relocations_for(&text).next().unwrap().unwrap());
+    assert_eq!((relocations[0].relocation_type(), relocations[0].info.symbol_index), (R_390_PLT32DBL, 6));
+    assert_eq!(relocation_name(EM_S390, ELFCLASS64, relocations[1].relocation_type()), "R_390_PC32DBL");
+
+    let symtab = symbol_table(reader.symtab().unwrap().unwrap());
+    let helper = symtab.get(5).unwrap();
+    assert_eq!((helper.name(), helper.visibility(), helper.value), (Some(&b"helper"[..]), STV_HIDDEN, 12));
+    let common_buf = symtab.get(7).unwrap();
+    assert_eq!((reserved_index(common_buf.section), common_buf.value, common_buf.size),
+               (Some(SHN_COMMON), 8, 64));
+    let weak_ref = symtab.get(8).unwrap();
+    assert_eq!((weak_ref.binding(), weak_ref.other), (STB_WEAK, 0x40));
+    assert_eq!(reserved_index(symtab.get(9).unwrap().section), Some(SHN_ABS));
+
+    assert_eq!(contents(&reader, b".rodata.str1.2"), b"hello\0");
+    assert_eq!(reader.section_by_name(b".bss").unwrap().shdr.sh_size(), 8);
+}
+
+#[test]
+fn invalid_descriptions() {
+    let header = "header: {class: ELF64, data: LE, type: REL, machine: 62}\n";
+    let build = |text: &str| ElfDescription::from_yaml(text).unwrap().build();
+    assert!(build("header: {class: ELF64, data: LE, type: REL, machine: Z80}").is_err());
+    assert!(build(&format!("{}sections: [{{name: .text, type: PROGBITS, flags: AQ}}]", header)).is_err());
+    assert!(build(&format!("{}sections: [{{name: .text, type: PROGBITS, content: 123}}]", header)).is_err());
+    assert!(build(&format!("{}segments: [{{type: LOAD, sections: [.text]}}]", header)).is_err());
+    assert!(ElfDescription::from_yaml(&format!("{}sectoins: []", header)).is_err());
+}
+
+#[test]
+fn all_fixtures() {
+    let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+    let mut names: Vec<_> = fs::read_dir(directory).unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "yaml"))
+        .map(|path| path.file_stem().unwrap().to_string_lossy().into_owned())
+        .collect();
+    names.sort();
+    assert!(names.len() >= 20);
+    for name in &names {
+        build(name);
+    }
+}
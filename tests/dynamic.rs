@@ -0,0 +1,241 @@
+extern crate elftk;
+
+use elftk::*;
+
+mod common;
+use common::fixture;
+
+#[test]
+fn relr_addresses() {
+    let data = fixture("elf64le_android");
+    let reader = Reader::new(&data).unwrap();
+    let addresses: Vec<_> = match reader.section_by_name(b".relr.dyn").unwrap().data {
+        SectionDataRef::RelativeRelocationTable(table) => table.addresses().collect(),
+        _                                              => panic!("not a RELR table"),
+    };
+    // Two address entries, then a bitmap whose bits 1 and 2 relocate the two words after 0x2210.
+    assert_eq!(addresses, [0x2010, 0x2210, 0x2218, 0x2220]);
+}
+
+#[test]
+fn android_packed_relocations() {
+    let data = fixture("elf64le_android");
+    let reader = Reader::new(&data).unwrap();
+    let unpacked = match reader.section_by_name(b".rela.dyn").unwrap().data {
+        SectionDataRef::AndroidPackedRelocationTable(packed) => packed.unpack().unwrap(),
+        _                                                    => panic!("not an APS2 table"),
+    };
+    let table = match unpacked.data() {
+        SectionDataRef::ExplicitRelocationTable(table) => table,
+        _                                              => panic!("not a RELA table"),
+    };
+    let entries: Vec<_> = table.entries.iter()
+        .map(|entry| {
+            let symbol = table.symbol_table.get(entry.symbol_index() as usize).unwrap();
+            (entry.r_offset(), entry.relocation_type(), symbol.symbol_name, entry.r_addend())
+        })
+        .collect();
+    assert_eq!(entries, [
+        (0x2200, R_AARCH64_RELATIVE, Some(&b""[..]), 0x1000),
+        (0x2208, R_AARCH64_RELATIVE, Some(&b""[..]), 0x1010),
+        (0x2000, R_AARCH64_GLOB_DAT, Some(&b"foo"[..]), 0),
+        (0x2008, R_AARCH64_GLOB_DAT, Some(&b"bar"[..]), 0),
+    ]);
+}
+
+#[test]
+fn android_ident() {
+    let data = fixture("elf64le_android");
+    let ident = Reader::new(&data).unwrap().android_ident().unwrap();
+    assert_eq!((ident.api_level, ident.ndk_version, ident.ndk_build_number), (30, None, None));
+}
+
+// Returns the (tag, address, offset, size, relocation offsets) of the dynamic relocation tables.
+fn dynamic_relocations(reader: &Reader) -> Vec<(Elf_Sxword, Elf64_Addr, Elf64_Off, Elf64_Xword, Vec<Elf64_Addr>)> {
+    reader.dynamic_relocations().unwrap().into_iter()
+        .map(|table| {
+            let offsets = table.data.relocation_table().unwrap().iter()
+                .map(|relocation| relocation.unwrap().offset)
+                .collect();
+            (table.tag, table.address, table.offset, table.size, offsets)
+        })
+        .collect()
+}
+
+#[test]
+fn dynamic_relocations_without_section_headers() {
+    let mut data = fixture("elf64le_plt");
+    let expected = vec![
+        (DT_RELA, 0x2a0, 0x2a0, 24, vec![0x2120]),
+        (DT_JMPREL, 0x2b8, 0x2b8, 24, vec![0x2140]),
+    ];
+    assert_eq!(dynamic_relocations(&Reader::new(&data).unwrap()), expected);
+
+    // Clear e_shoff, e_shnum and e_shstrndx, as sstrip does.
+    data[0x28..0x30].copy_from_slice(&[0; 8]);
+    data[0x3c..0x40].copy_from_slice(&[0; 4]);
+    let reader = Reader::new(&data).unwrap();
+    assert!(reader.section_headers().is_empty());
+    assert_eq!(dynamic_relocations(&reader), expected);
+}
+
+// Replaces the entry of the dynamic table of `data` whose tag is `tag` with (`new_tag`, `value`).
+fn set_dynamic_entry(data: &mut [u8], tag: Elf_Sxword, new_tag: Elf_Sxword, value: Elf64_Xword) {
+    let (offset, size) = {
+        let reader = Reader::new(data).unwrap();
+        let shdr = reader.section_by_name(b".dynamic").unwrap().shdr;
+        (shdr.sh_offset() as usize, shdr.sh_size() as usize)
+    };
+    let entry = (offset..offset + size).step_by(16)
+        .find(|&entry| data[entry..entry + 8] == tag.to_le_bytes())
+        .unwrap();
+    data[entry..entry + 8].copy_from_slice(&new_tag.to_le_bytes());
+    data[entry + 8..entry + 16].copy_from_slice(&value.to_le_bytes());
+}
+
+#[test]
+fn dynamic_relocations_overlapping_plt_relocations() {
+    // DT_RELASZ covers .rela.plt too, as with GNU ld: it is trimmed.
+    let mut data = fixture("elf64le_plt");
+    set_dynamic_entry(&mut data, DT_RELASZ, DT_RELASZ, 48);
+    assert_eq!(dynamic_relocations(&Reader::new(&data).unwrap()), vec![
+        (DT_RELA, 0x2a0, 0x2a0, 24, vec![0x2120]),
+        (DT_JMPREL, 0x2b8, 0x2b8, 24, vec![0x2140]),
+    ]);
+
+    // DT_RELA only holds the PLT relocations: it is dropped.
+    let mut data = fixture("elf64le_plt");
+    set_dynamic_entry(&mut data, DT_RELA, DT_RELA, 0x2b8);
+    assert_eq!(dynamic_relocations(&Reader::new(&data).unwrap()), vec![
+        (DT_JMPREL, 0x2b8, 0x2b8, 24, vec![0x2140]),
+    ]);
+}
+
+#[test]
+fn dynamic_relocations_without_pltrel() {
+    // DT_JMPREL is skipped, but DT_RELA is still returned.
+    let mut data = fixture("elf64le_plt");
+    set_dynamic_entry(&mut data, DT_PLTREL, DT_DEBUG, 0);
+    let expected = vec![(DT_RELA, 0x2a0, 0x2a0, 24, vec![0x2120])];
+    assert_eq!(dynamic_relocations(&Reader::new(&data).unwrap()), expected);
+
+    let mut data = fixture("elf64le_plt");
+    set_dynamic_entry(&mut data, DT_PLTREL, DT_PLTREL, 42);
+    assert_eq!(dynamic_relocations(&Reader::new(&data).unwrap()), expected);
+}
+
+#[test]
+fn dynamic_symbol_table_past_the_end_of_memory() {
+    let mut data = fixture("elf64le_plt");
+    let (phoff, dynamic) = {
+        let reader = Reader::new(&data).unwrap();
+        (reader.elf_header().e_phoff() as usize,
+         reader.section_by_name(b".dynamic").unwrap().shdr.sh_offset() as usize)
+    };
+    // Move the first PT_LOAD segment, which holds .dynsym and .dynstr at its start, close to the
+    // end of the address space. Without a hash table, the dynamic symbol table extends to the end
+    // of that segment, past the end of the address space.
+    let vaddr = 0xffff_ffff_ffff_ff40u64;
+    data[phoff + 0x10..phoff + 0x18].copy_from_slice(&vaddr.to_le_bytes());
+    data[dynamic + 0x08..dynamic + 0x10].copy_from_slice(&(vaddr + 0x80).to_le_bytes());
+    data[dynamic + 0x18..dynamic + 0x20].copy_from_slice(&vaddr.to_le_bytes());
+    let reader = Reader::new(&data).unwrap();
+    match reader.dynamic_symbol_table() {
+        Err(Error::NotContainedInFile { what: "address", which }) => assert_eq!(which, vaddr),
+        result => panic!("unexpected result {:?}", result.map(|table| table.map(|table| table.len()))),
+    }
+}
+
+// Returns the (address, size, GOT slot, name) of the PLT entries of the fixture `name`.
+fn plt_entries(name: &str) -> Vec<(Elf64_Addr, Elf64_Xword, Elf64_Addr, String)> {
+    let data = fixture(name);
+    let reader = Reader::new(&data).unwrap();
+    reader.plt_entries().unwrap().iter()
+        .map(|entry| (entry.address, entry.size, entry.got_address, String::from_utf8(entry.name()).unwrap()))
+        .collect()
+}
+
+#[test]
+fn plt_entries_lazy() {
+    // foo@plt is lazy and bar@plt is in .plt.got, since the address of bar is taken. PLT0 jumps
+    // to the resolver rather than through a slot with a relocation.
+    assert_eq!(plt_entries("elf64le_plt"), [
+        (0x1010, 16, 0x2140, "foo@plt".to_string()),
+        (0x1020, 8, 0x2120, "bar@plt".to_string()),
+    ]);
+}
+
+#[test]
+fn plt_entries_ibt() {
+    // The lazy .plt entries only push their index, so foo@plt is the .plt.sec stub.
+    assert_eq!(plt_entries("elf64le_ibtplt"), [
+        (0x1020, 16, 0x2120, "bar@plt".to_string()),
+        (0x1030, 16, 0x2140, "foo@plt".to_string()),
+    ]);
+}
+
+#[test]
+fn got_slots_from_packed_relocations() {
+    let data = fixture("elf64le_android");
+    let reader = Reader::new(&data).unwrap();
+    let slots = reader.got_slots().unwrap();
+    let addresses: Vec<_> = slots.iter().map(|slot| slot.address).collect();
+    assert_eq!(addresses, [0x2000, 0x2008, 0x2010]);
+
+    let relocation = |index: usize| slots[index].relocation.unwrap();
+    assert_eq!((relocation(0).relocation_type(), relocation(0).symbol.name()),
+               (R_AARCH64_GLOB_DAT, Some(&b"foo"[..])));
+    assert_eq!((relocation(1).relocation_type(), relocation(1).symbol.name()),
+               (R_AARCH64_GLOB_DAT, Some(&b"bar"[..])));
+    assert!(slots[2].relocation.is_none());
+    assert_eq!((slots[2].value, slots[2].relative), (0x1100, true));
+    assert!(slots.iter().all(|slot| slot.relro && slot.section_name == b".got"));
+}
+
+#[test]
+fn got_slots_lazy() {
+    let data = fixture("elf64le_plt");
+    let reader = Reader::new(&data).unwrap();
+    let slots: Vec<_> = reader.got_slots().unwrap().iter()
+        .map(|slot| (slot.address,
+                     slot.section_name,
+                     slot.value,
+                     slot.relocation.map(|relocation| (relocation.relocation_type(), relocation.symbol.name()))))
+        .collect();
+    // The lazily bound slot of foo points back into its PLT entry, and the first three slots of
+    // .got.plt are reserved for the dynamic linker.
+    assert_eq!(slots, [
+        (0x2120, &b".got"[..], 0, Some((R_X86_64_GLOB_DAT, Some(&b"bar"[..])))),
+        (0x2128, &b".got.plt"[..], 0x2000, None),
+        (0x2130, &b".got.plt"[..], 0, None),
+        (0x2138, &b".got.plt"[..], 0, None),
+        (0x2140, &b".got.plt"[..], 0x1016, Some((R_X86_64_JUMP_SLOT, Some(&b"foo"[..])))),
+    ]);
+}
+
+#[test]
+fn got_slots_relocated_twice() {
+    // Make the GLOB_DAT relocation of .rela.dyn apply to the slot of foo too: the JUMP_SLOT
+    // relocation of .rela.plt, which the dynamic linker applies last, is the one reported.
+    let mut data = fixture("elf64le_plt");
+    data[0x2a0..0x2a8].copy_from_slice(&0x2140u64.to_le_bytes());
+    let reader = Reader::new(&data).unwrap();
+    let slots: Vec<_> = reader.got_slots().unwrap().iter()
+        .filter_map(|slot| slot.relocation.map(|relocation| (slot.address, relocation.relocation_type())))
+        .collect();
+    assert_eq!(slots, [(0x2140, R_X86_64_JUMP_SLOT)]);
+}
+
+#[test]
+fn got_slots_relro_past_the_end_of_memory() {
+    let mut data = fixture("elf64le_android");
+    let phoff = Reader::new(&data).unwrap().elf_header().e_phoff() as usize;
+    // Extend PT_GNU_RELRO, the fourth program header, past the end of the address space.
+    let memsz = phoff + 3 * 0x38 + 0x28;
+    data[memsz..memsz + 8].copy_from_slice(&u64::MAX.to_le_bytes());
+    let reader = Reader::new(&data).unwrap();
+    match reader.got_slots() {
+        Err(Error::NotContainedInFile { what: "address", which }) => assert_eq!(which, 0x2000),
+        result => panic!("unexpected result {:?}", result.map(|slots| slots.len())),
+    }
+}
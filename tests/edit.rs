@@ -0,0 +1,49 @@
+extern crate elftk;
+
+use elftk::*;
+
+mod common;
+use common::fixture;
+
+#[test]
+fn write_unmodified() {
+    for &name in &["elf32le_rel", "elf64be_sparc64_rel", "elf64le_android", "elf64le_plt"] {
+        let data = fixture(name);
+        let file = ElfFile::new(&Reader::new(&data).unwrap()).unwrap();
+        assert!(file.write().unwrap() == data, "{} changed", name);
+    }
+}
+
+#[test]
+fn grow_and_add_sections() {
+    let data = fixture("elf64le_x86_64_rel");
+    let original = Reader::new(&data).unwrap().section_by_name(b".text").unwrap().shdr.sh_offset() as usize;
+    let mut file = ElfFile::new(&Reader::new(&data).unwrap()).unwrap();
+    let text = file.section_index(b".text").unwrap() as usize;
+    file.sections[text].data.extend_from_slice(&[0x90; 0x100]);
+    let mut comment = FileSection::new(b".comment.elftk", SHT_PROGBITS, SHF_MERGE | SHF_STRINGS, b"elftk\0".to_vec());
+    comment.header.sh_entsize = 1;
+    file.add_section(comment);
+    let out = file.write().unwrap();
+
+    // The grown section moves to the end of the file and its original bytes are kept.
+    assert_eq!(out[original..original + 0x12], data[original..original + 0x12]);
+    let reader = Reader::new(&out).unwrap();
+    let text = reader.section_by_name(b".text").unwrap();
+    assert!(text.shdr.sh_offset() >= data.len() as Elf64_Off);
+    match text.data {
+        SectionDataRef::Uninterpreted(contents) => {
+            assert_eq!(contents.len(), 0x112);
+            assert!(contents[0x12..].iter().all(|&b| b == 0x90));
+        },
+        _ => panic!("unexpected .text data"),
+    }
+    match reader.section_by_name(b".comment.elftk").unwrap().data {
+        SectionDataRef::Uninterpreted(contents) => assert_eq!(contents, b"elftk\0"),
+        _                                       => panic!("unexpected .comment.elftk data"),
+    }
+    // Relocations still apply to .text.
+    let rela = reader.section_by_name(b".rela.text").unwrap();
+    assert_eq!(reader.relocations_for(&text).count(), 1);
+    assert_eq!(rela.shdr.sh_info() as usize, reader.section_index(text.shdr).unwrap() as usize);
+}
@@ -0,0 +1,155 @@
+extern crate elftk;
+
+mod common;
+
+use common::fixture;
+use elftk::*;
+
+fn load_image() -> LoadImage {
+    let data = fixture("elf64le_exec");
+    Reader::new(&data).unwrap().load_image().unwrap()
+}
+
+// The output of `objcopy -O ihex` and `objcopy -O srec` for the executable fixture, which starts a
+// record at each section.
+const OBJCOPY_IHEX: &str = "\
+:020000040040BA
+:100200002F6C696236342F6C642D6C696E75782D95
+:0C0210007838362D36342E736F2E3200F5
+:10021C00040000001000000001000000474E5500D3
+:10022C0000000000030000000200000000000000BD
+:0310000031C0C339
+:042000002A000000B2
+:0420040007000000D1
+:0400000500401000A7
+:00000001FF
+";
+const OBJCOPY_SREC: &str = "\
+S00B0000652E737265632E3254
+S2144002002F6C696236342F6C642D6C696E75782D50
+S2104002107838362D36342E736F2E3200B0
+S21440021C040000001000000001000000474E55008E
+S21440022C0000000003000000020000000000000078
+S20740100031C0C3F4
+S2084020002A0000006D
+S208402004070000008C
+S804401000AB
+";
+
+#[test]
+fn load_image_blocks() {
+    let image = load_image();
+    let blocks: Vec<_> = image.blocks.iter().map(|block| (block.address, block.data.len())).collect();
+    // .interp and the adjacent note, .text, and .tdata followed by .data.
+    assert_eq!(blocks, [(0x400200, 0x3c), (0x401000, 3), (0x402000, 8)]);
+    assert_eq!(image.entry, 0x401000);
+}
+
+#[test]
+fn merge_overlapping_blocks() {
+    let blocks = vec![
+        LoadBlock { address: 0x10, data: vec![3; 4] },
+        LoadBlock { address: 0x00, data: vec![1; 8] },
+        LoadBlock { address: 0x06, data: vec![2; 4] },
+    ];
+    let image = LoadImage::new(blocks, 0).unwrap();
+    assert_eq!(image.blocks, [LoadBlock { address: 0, data: vec![1, 1, 1, 1, 1, 1, 2, 2, 2, 2] },
+                              LoadBlock { address: 0x10, data: vec![3; 4] }]);
+    assert_eq!(image.to_binary(0xff), [1, 1, 1, 1, 1, 1, 2, 2, 2, 2, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 3, 3, 3, 3]);
+}
+
+#[test]
+fn reject_data_past_the_end_of_memory() {
+    let mut image = LoadImage::default();
+    assert!(image.add(u64::MAX - 4, &[0; 4]).is_ok());
+    assert!(image.add(u64::MAX - 4, &[0; 5]).is_err());
+    assert!(image.add(u64::MAX, &[0]).is_err());
+    assert_eq!(image.end(), u64::MAX);
+}
+
+#[test]
+fn intel_hex() {
+    let hex = load_image().to_intel_hex().unwrap();
+    // An extended linear address record for the upper 16 bits of the addresses, and a start
+    // linear address record for the entry point.
+    let lines: Vec<_> = hex.lines().collect();
+    assert_eq!(lines[0], ":020000040040BA");
+    assert_eq!(lines[1], ":100200002F6C696236342F6C642D6C696E75782D95");
+    assert_eq!(&lines[lines.len()-2..], [":0400000500401000A7", ":00000001FF"]);
+}
+
+#[test]
+fn srec() {
+    let srec = load_image().to_srec().unwrap();
+    // S2 records with 24-bit addresses, and an S8 record for the entry point.
+    assert!(srec.lines().skip(1).take(4).all(|line| line.starts_with("S2")));
+    assert_eq!(srec.lines().nth(1), Some("S2144002002F6C696236342F6C642D6C696E75782D50"));
+    assert_eq!(srec.lines().last(), Some("S804401000AB"));
+}
+
+#[test]
+fn intel_hex_round_trip() {
+    let image = load_image();
+    let hex = image.to_intel_hex().unwrap();
+    assert_eq!(LoadImage::from_intel_hex(&hex).unwrap(), image);
+    assert_eq!(LoadImage::from_intel_hex(OBJCOPY_IHEX).unwrap(), image);
+    assert!(LoadImage::from_intel_hex(":0400000500401000A8\n").is_err());
+}
+
+#[test]
+fn srec_round_trip() {
+    let image = load_image();
+    let srec = image.to_srec().unwrap();
+    assert_eq!(LoadImage::from_srec(&srec).unwrap(), image);
+    assert_eq!(LoadImage::from_srec(OBJCOPY_SREC).unwrap(), image);
+    assert!(LoadImage::from_srec("S804401000AC\n").is_err());
+}
+
+#[test]
+fn relocatable_from_image() {
+    let image = LoadImage::from_intel_hex(OBJCOPY_IHEX).unwrap();
+    let header = FileHeader { e_machine: EM_X86_64, ..FileHeader::default() };
+    let data = image.to_relocatable(ElfFormat::Elf64LE(()), header).unwrap();
+    let reader = Reader::new(&data).unwrap();
+    assert_eq!((reader.elf_header().e_type(), reader.elf_header().e_entry()), (ET_REL, 0x401000));
+    let sections: Vec<_> = [&b".sec1"[..], b".sec2", b".sec3"].iter()
+        .map(|&name| reader.section_by_name(name).unwrap().shdr)
+        .map(|shdr| (shdr.sh_addr(), shdr.sh_size()))
+        .collect();
+    assert_eq!(sections, [(0x400200, 0x3c), (0x401000, 3), (0x402000, 8)]);
+    assert!(image.to_relocatable(ElfFormat::Elf32LE(()), header).is_ok());
+}
+
+#[test]
+fn binary_object() {
+    let mut object = BinaryObject::new(b"data/logo.png");
+    object.sh_addralign = 16;
+    let header = FileHeader { e_machine: EM_ARM, ..FileHeader::default() };
+    let data = object.build(ElfFormat::Elf32LE(()), header, b"\x89PNG").unwrap();
+    let reader = Reader::new(&data).unwrap();
+    let section = reader.section_by_name(b".data").unwrap();
+    assert_eq!((section.shdr.sh_size(), section.shdr.sh_addralign()), (4, 16));
+    let table = match reader.symtab().unwrap().unwrap().data {
+        SectionDataRef::SymbolTable(table) => table,
+        _                                  => panic!("unexpected .symtab data"),
+    };
+    let symbols: Vec<_> = (1..table.len())
+        .map(|index| table.get(index).unwrap())
+        .map(|symbol| {
+            let section = match symbol.section {
+                SectionIndex::Normal(index)   => index,
+                SectionIndex::Reserved(index) => Elf_Word::from(index),
+            };
+            (symbol.name().unwrap().to_vec(), section, symbol.value)
+        })
+        .collect();
+    let abs = Elf_Word::from(SHN_ABS);
+    assert_eq!(symbols, [
+        (b"_binary_data_logo_png_start".to_vec(), 1, 0),
+        (b"_binary_data_logo_png_end".to_vec(), 1, 4),
+        (b"_binary_data_logo_png_size".to_vec(), abs, 4),
+    ]);
+
+    object.sh_addralign = 3;
+    assert!(object.build(ElfFormat::Elf32LE(()), header, b"").is_err());
+}
@@ -0,0 +1,135 @@
+extern crate elftk;
+
+use elftk::*;
+
+mod common;
+use common::fixture;
+
+fn load_segments(reader: &Reader) -> Vec<(Elf64_Addr, Elf64_Xword)> {
+    reader.program_headers().into_iter()
+        .filter(|phdr| phdr.p_type() == PT_LOAD)
+        .map(|phdr| (phdr.p_vaddr(), phdr.p_memsz()))
+        .collect()
+}
+
+#[test]
+fn grow_dynamic_string_table() {
+    let data = fixture("elf64le_plt");
+    let mut patcher = ElfPatcher::new(&Reader::new(&data).unwrap()).unwrap();
+    patcher.add_needed(b"libbar.so");
+    patcher.set_runpath(b"$ORIGIN/../lib");
+    let patched = patcher.write().unwrap();
+
+    // Neither the strings nor the two new entries fit, so .dynstr and .dynamic move to a new
+    // PT_LOAD segment after the existing ones.
+    let reader = Reader::new(&patched).unwrap();
+    let segments = load_segments(&reader);
+    assert_eq!(segments.len(), 4);
+    let (address, size) = segments[3];
+    assert_eq!(address, 0x3000);
+    let in_new_segment = |value: Elf64_Addr| value >= address && value < address + size;
+    let dynamic = reader.dynamic_table().unwrap().unwrap();
+    let dynstr = reader.section_by_name(b".dynstr").unwrap().shdr;
+    assert!(in_new_segment(dynamic.get(DT_STRTAB).unwrap()));
+    assert_eq!(dynamic.get(DT_STRTAB), Some(dynstr.sh_addr()));
+    assert_eq!(dynamic.get(DT_STRSZ), Some(dynstr.sh_size()));
+    assert!(in_new_segment(reader.section_by_name(b".dynamic").unwrap().shdr.sh_addr()));
+
+    let patcher = ElfPatcher::new(&reader).unwrap();
+    assert_eq!(patcher.needed(), [&b"libbar.so"[..]]);
+    assert_eq!(patcher.runpath(), Some(&b"$ORIGIN/../lib"[..]));
+}
+
+#[test]
+fn edit_dynamic_table_in_place() {
+    let data = fixture("elf32be_dyn");
+    let mut patcher = ElfPatcher::new(&Reader::new(&data).unwrap()).unwrap();
+    assert!(patcher.remove_needed(b"libc.so.6"));
+    patcher.set_soname(b"libc.so.6");
+    let patched = patcher.write().unwrap();
+
+    let reader = Reader::new(&patched).unwrap();
+    assert_eq!(load_segments(&reader), load_segments(&Reader::new(&data).unwrap()));
+    assert_eq!(patched.len(), data.len());
+    let patcher = ElfPatcher::new(&reader).unwrap();
+    assert!(patcher.needed().is_empty());
+    assert_eq!(patcher.soname(), Some(&b"libc.so.6"[..]));
+}
+
+// A version need as (file, [(version name, version index)]).
+type VersionNeed = (Vec<u8>, Vec<(Vec<u8>, u16)>);
+
+// Returns the version needs of `data`.
+fn version_needs(data: &[u8]) -> Vec<VersionNeed> {
+    let reader = Reader::new(data).unwrap();
+    let dynamic = reader.dynamic_table().unwrap().unwrap();
+    let section = reader.section_by_name(b".gnu.version_r").unwrap();
+    let data = match section.data {
+        SectionDataRef::Uninterpreted(data) => data,
+        _                                   => panic!("unexpected .gnu.version_r data"),
+    };
+    let dynstr = match reader.section_by_name(b".dynstr").unwrap().data {
+        SectionDataRef::StringTable(strings) => strings,
+        _                                    => panic!("unexpected .dynstr data"),
+    };
+    let string = |offset: u32| dynstr.get_string(offset).unwrap().to_vec();
+    let half = |offset: usize| u16::from_le_bytes([data[offset], data[offset + 1]]);
+    let word = |offset: usize| u32::from_le_bytes([data[offset], data[offset + 1], data[offset + 2], data[offset + 3]]);
+
+    let mut needs = Vec::new();
+    let mut offset = 0;
+    for _ in 0..dynamic.get(DT_VERNEEDNUM).unwrap_or(0) {
+        let mut versions = Vec::new();
+        let mut aux = offset + word(offset + 8) as usize;
+        for _ in 0..half(offset + 2) {
+            versions.push((string(word(aux + 8)), half(aux + 6)));
+            aux += word(aux + 12) as usize;
+        }
+        needs.push((string(word(offset + 4)), versions));
+        offset += word(offset + 12) as usize;
+    }
+    assert_eq!(section.shdr.sh_info() as usize, needs.len());
+    needs
+}
+
+// Returns the .gnu.version entries of `data`.
+fn symbol_versions(data: &[u8]) -> Vec<u16> {
+    match Reader::new(data).unwrap().section_by_name(b".gnu.version").unwrap().data {
+        SectionDataRef::Uninterpreted(data) => data.chunks(2).map(|b| u16::from_le_bytes([b[0], b[1]])).collect(),
+        _                                   => panic!("unexpected .gnu.version data"),
+    }
+}
+
+#[test]
+fn remove_needed_version_needs() {
+    let data = fixture("elf64le_verneed");
+    assert_eq!(version_needs(&data), [
+        (b"libc.so.6".to_vec(), vec![(b"GLIBC_2.2.5".to_vec(), 2)]),
+        (b"libm.so.6".to_vec(), vec![(b"GLIBC_2.29".to_vec(), 3)]),
+    ]);
+
+    // Removing libc.so.6 unlinks its version need, and printf, which had version 2, becomes
+    // unversioned.
+    let mut patcher = ElfPatcher::new(&Reader::new(&data).unwrap()).unwrap();
+    assert!(patcher.remove_needed(b"libc.so.6"));
+    let patched = patcher.write().unwrap();
+    assert_eq!(version_needs(&patched), [(b"libm.so.6".to_vec(), vec![(b"GLIBC_2.29".to_vec(), 3)])]);
+    assert_eq!(symbol_versions(&patched), [0, 1, 3, 1]);
+
+    // Without any version needs left, DT_VERNEED and DT_VERNEEDNUM go too.
+    let mut patcher = ElfPatcher::new(&Reader::new(&patched).unwrap()).unwrap();
+    assert!(patcher.remove_needed(b"libm.so.6"));
+    let patched = patcher.write().unwrap();
+    let reader = Reader::new(&patched).unwrap();
+    let dynamic = reader.dynamic_table().unwrap().unwrap();
+    assert_eq!((dynamic.get(DT_VERNEED), dynamic.get(DT_VERNEEDNUM)), (None, None));
+    assert_eq!(symbol_versions(&patched), [0, 1, 1, 1]);
+    assert!(ElfPatcher::new(&reader).unwrap().needed().is_empty());
+}
+
+#[test]
+fn rewrite_unmodified_version_needs() {
+    let data = fixture("elf64le_verneed");
+    let patcher = ElfPatcher::new(&Reader::new(&data).unwrap()).unwrap();
+    assert!(patcher.write().unwrap() == data);
+}
@@ -0,0 +1,106 @@
+extern crate elftk;
+
+use std::process::Command;
+
+mod common;
+use common::{fixture, TempDir};
+
+// Runs readelf-lite with `args` on the fixture `name`, with its e_flags replaced by `flags` if
+// given, and returns the output.
+fn readelf(name: &str, flags: Option<u32>, args: &[&str]) -> String {
+    let mut data = fixture(name);
+    if let Some(flags) = flags {
+        let offset = if data[4] == 2 { 0x30 } else { 0x24 };
+        let bytes = if data[5] == 2 { flags.to_be_bytes() } else { flags.to_le_bytes() };
+        data[offset..offset+4].copy_from_slice(&bytes);
+    }
+    let dir = TempDir::new(&format!("readelf-{}", name));
+    let path = dir.write(name, &data);
+    let output = Command::new(env!("CARGO_BIN_EXE_readelf-lite")).args(args).arg(&path).output().unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    String::from_utf8(output.stdout).unwrap()
+}
+
+// Returns the decoded e_flags of the fixture `name` with its e_flags replaced by `flags`.
+fn header_flags(name: &str, flags: u32) -> String {
+    let output = readelf(name, Some(flags), &["-h"]);
+    let line = output.lines().find(|line| line.trim_start().starts_with("Flags")).unwrap();
+    line.trim_start()["Flags".len()..].trim().to_string()
+}
+
+// Returns the relocation types listed by `readelf-lite -r` for the fixture `name`.
+fn relocation_types(name: &str) -> Vec<String> {
+    readelf(name, None, &["-r"]).lines()
+        .filter_map(|line| line.split_whitespace().nth(2))
+        .filter(|name| name.starts_with("R_"))
+        .map(String::from)
+        .collect()
+}
+
+#[test]
+fn aarch64() {
+    assert_eq!(relocation_types("elf64le_aarch64_rel"), [
+        "R_AARCH64_ADR_PREL_PG_HI21", "R_AARCH64_ADD_ABS_LO12_NC", "R_AARCH64_LDST64_ABS_LO12_NC",
+        "R_AARCH64_CALL26", "R_AARCH64_MOVW_UABS_G1", "R_AARCH64_MOVW_UABS_G0_NC",
+        "R_AARCH64_ABS64", "R_AARCH64_PREL32",
+    ]);
+    assert_eq!(header_flags("elf64le_aarch64_rel", 0), "0x0");
+    assert_eq!(header_flags("elf64le_aarch64_rel", 1), "0x1, <unknown>");
+}
+
+#[test]
+fn arm() {
+    assert_eq!(relocation_types("elf32le_arm_rel"), ["R_ARM_THM_CALL", "R_ARM_CALL", "R_ARM_ABS32", "R_ARM_ABS32"]);
+    assert_eq!(header_flags("elf32le_arm_rel", 0x0500_0400), "0x5000400, Version5 EABI, hard-float ABI");
+    assert_eq!(header_flags("elf32le_arm_rel", 0x0480_0000), "0x4800000, Version4 EABI, BE8");
+    assert_eq!(header_flags("elf32le_arm_rel", 0x0000_0204), "0x204, GNU EABI, interworking enabled, software FP");
+    assert_eq!(header_flags("elf32le_arm_rel", 0x0700_0000), "0x7000000, <unrecognized EABI>");
+}
+
+#[test]
+fn riscv() {
+    assert_eq!(relocation_types("elf64le_riscv_rel"), [
+        "R_RISCV_PCREL_HI20", "R_RISCV_PCREL_HI20", "R_RISCV_PCREL_LO12_I", "R_RISCV_PCREL_LO12_S",
+        "R_RISCV_CALL_PLT", "R_RISCV_ADD32", "R_RISCV_SUB32", "R_RISCV_SET_ULEB128", "R_RISCV_SUB_ULEB128",
+        "R_RISCV_64",
+    ]);
+    // The %pcrel_lo relocations show the %pcrel_hi relocation holding their target.
+    let output = readelf("elf64le_riscv_rel", None, &["-r"]);
+    assert!(output.contains(".L2 + 0 (R_RISCV_PCREL_HI20 at 4: var + 8)"));
+    assert_eq!(header_flags("elf64le_riscv_rel", 0x5), "0x5, RVC, double-float ABI");
+    assert_eq!(header_flags("elf64le_riscv_rel", 0x0), "0x0, soft-float ABI");
+    assert_eq!(header_flags("elf64le_riscv_rel", 0x1a), "0x1a, single-float ABI, RVE, TSO");
+    assert_eq!(header_flags("elf64le_riscv_rel", 0x20), "0x20, soft-float ABI, <unknown>");
+}
+
+#[test]
+fn ppc64() {
+    assert_eq!(relocation_types("elf64be_ppc64_rel"), ["R_PPC64_ADDR64", "R_PPC64_TOC"]);
+    assert_eq!(relocation_types("elf64le_ppc64_rel"), ["R_PPC64_REL16_HA", "R_PPC64_REL16_LO", "R_PPC64_REL24"]);
+    assert_eq!(header_flags("elf64be_ppc64_rel", 0x1), "0x1, abiv1");
+    assert_eq!(header_flags("elf64le_ppc64_rel", 0x2), "0x2, abiv2");
+    assert_eq!(header_flags("elf64le_ppc64_rel", 0x6), "0x6, abiv2, <unknown>");
+    assert_eq!(header_flags("elf64le_ppc64_rel", 0x4), "0x4, <unknown>");
+}
+
+#[test]
+fn mips64() {
+    for name in &["elf64le_mips64_rel", "elf64be_mips64_rel"] {
+        assert_eq!(relocation_types(name), ["R_MIPS_GPREL16", "R_MIPS_64"]);
+        assert_eq!(header_flags(name, 0x8000_0007), "0x80000007, noreorder, pic, cpic, mips64r2");
+    }
+}
+
+#[test]
+fn sparc64() {
+    assert_eq!(relocation_types("elf64be_sparc64_rel"), ["R_SPARC_HI22", "R_SPARC_OLO10"]);
+}
+
+#[test]
+fn plt() {
+    assert_eq!(readelf("elf64le_plt", None, &["--plt"]),
+               "\nPLT contains 2 entries:\n\
+                Address   Size GOT slot Name\n\
+                00001010    16 00002140 foo@plt\n\
+                00001020     8 00002120 bar@plt\n");
+}
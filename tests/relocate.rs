@@ -0,0 +1,167 @@
+extern crate elftk;
+
+use elftk::*;
+
+mod common;
+use common::fixture;
+
+// Relocates the section `name` of `data` as if each section named in `sections` were loaded at
+// the given address and each undefined symbol named in `symbols` had the given address.
+fn relocate(data: &[u8],
+            name: &str,
+            sections: &[(&str, Elf64_Addr)],
+            symbols: &[(&str, Elf64_Addr)]) -> Result<Vec<u8>>
+{
+    let reader = Reader::new(data).unwrap();
+    let section_address = |index: Elf_Word| {
+        let shdr = reader.section_headers().get(index as usize).ok()?;
+        let name = reader.section_name(shdr);
+        sections.iter().find(|&&(section, _)| section.as_bytes() == name).map(|&(_, address)| address)
+    };
+    let resolve = |symbol: &SymbolRef| match symbol.section {
+        SectionIndex::Normal(0) => {
+            let name = symbol.symbol_name?;
+            symbols.iter().find(|&&(other, _)| other.as_bytes() == name).map(|&(_, address)| address)
+        },
+        SectionIndex::Normal(index) => Some(section_address(index)? + symbol.value),
+        SectionIndex::Reserved(_)   => Some(symbol.value),
+    };
+
+    let section = reader.section_by_name(name.as_bytes()).unwrap();
+    let address = section_address(reader.section_index(section.shdr).unwrap()).unwrap();
+    let relocations = reader.relocations_for(&section).next().unwrap().unwrap();
+    relocate_section(&reader, &section, relocations, address, resolve)
+}
+
+fn assert_out_of_range(result: Result<Vec<u8>>, expected: &str, expected_offset: u64) {
+    match result {
+        Err(Error::RelocationOutOfRange { relocation, offset }) => {
+            assert_eq!((relocation, offset), (expected, expected_offset));
+        },
+        result => panic!("unexpected result {:?}", result),
+    }
+}
+
+#[test]
+fn relocate_mismatched_section() {
+    let data = fixture("elf64le_x86_64_rel");
+    let reader = Reader::new(&data).unwrap();
+    let text = reader.section_by_name(b".text").unwrap();
+    let data_section = reader.section_by_name(b".data").unwrap();
+    let relocations = reader.relocations_for(&data_section).next().unwrap().unwrap();
+    match relocate_section(&reader, &text, relocations, 0x1000, |_| Some(0)) {
+        Err(Error::InvalidLinkedSection { linked }) => assert_eq!(linked, 3),
+        result => panic!("unexpected result {:?}", result),
+    }
+}
+
+#[test]
+fn relocate_x86_64() {
+    let data = fixture("elf64le_x86_64_rel");
+    let sections = [(".text", 0x1000), (".data", 0x3000)];
+    let symbols = [("func", 0x4000)];
+    // R_X86_64_PC32 var - 4, R_X86_64_PLT32 func - 4 and R_X86_64_32 var.
+    assert_eq!(relocate(&data, ".text", &sections, &symbols).unwrap(),
+               [0x48, 0x8b, 0x05, 0xf9, 0x1f, 0x00, 0x00,
+                0xe8, 0xf4, 0x2f, 0x00, 0x00,
+                0xb8, 0x00, 0x30, 0x00, 0x00,
+                0xc3]);
+    // R_X86_64_64 func + 0x10 and R_X86_64_32S var - 8.
+    assert_eq!(relocate(&data, ".data", &sections, &symbols).unwrap(),
+               [0x10, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0xf8, 0x2f, 0x00, 0x00]);
+
+    // var - 8 does not fit a sign-extended 32-bit field.
+    let sections = [(".text", 0x1000), (".data", 0x8000_0008)];
+    assert_out_of_range(relocate(&data, ".data", &sections, &symbols), "R_X86_64_32S", 8);
+}
+
+#[test]
+fn relocate_i386() {
+    let mut data = fixture("elf32le_rel");
+    let sections = [(".text", 0x1000), (".data", 0x3000)];
+    // R_386_32 g, R_386_PC32 printf with the addend -4 in place, and R_386_32 .text.
+    assert_eq!(relocate(&data, ".text", &sections, &[("printf", 0x4000)]).unwrap(),
+               [0xa1, 0x00, 0x30, 0x00, 0x00,
+                0xe8, 0xf6, 0x2f, 0x00, 0x00,
+                0x8b, 0x0d, 0x00, 0x10, 0x00, 0x00,
+                0xc3]);
+
+    match relocate(&data, ".text", &sections, &[]) {
+        Err(Error::UnresolvedSymbol { symbol }) => assert_eq!(symbol, 7),
+        result => panic!("unexpected result {:?}", result),
+    }
+
+    // Turn the first relocation into an R_386_16, which cannot hold the address of g.
+    let offset = {
+        let reader = Reader::new(&data).unwrap();
+        reader.section_by_name(b".rel.text").unwrap().shdr.sh_offset() as usize
+    };
+    data[offset + 4] = R_386_16 as u8;
+    let sections = [(".text", 0x1000), (".data", 0x12_3000)];
+    assert_out_of_range(relocate(&data, ".text", &sections, &[("printf", 0x4000)]), "R_386_16", 1);
+}
+
+#[test]
+fn relocate_aarch64() {
+    let data = fixture("elf64le_aarch64_rel");
+    let sections = [(".text", 0x1000), (".data", 0x21_2340)];
+    let symbols = [("func", 0x8000)];
+    assert_eq!(relocate(&data, ".text", &sections, &symbols).unwrap(),
+               [0x80, 0x10, 0x00, 0xb0,  // adrp x0, var
+                0x00, 0x00, 0x0d, 0x91,  // add x0, x0, :lo12:var
+                0x01, 0xa4, 0x41, 0xf9,  // ldr x1, [x0, :lo12:var+8]
+                0xfd, 0x1b, 0x00, 0x94,  // bl func
+                0x22, 0x04, 0xa0, 0xd2,  // movz x2, #:abs_g1:var
+                0x02, 0x68, 0x84, 0xf2,  // movk x2, #:abs_g0_nc:var
+                0xc0, 0x03, 0x5f, 0xd6]);
+    // R_AARCH64_ABS64 load and R_AARCH64_PREL32 func.
+    assert_eq!(relocate(&data, ".data", &sections, &symbols).unwrap(),
+               [0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0xb8, 0x5c, 0xdf, 0xff, 0x00, 0x00, 0x00, 0x00]);
+
+    // bl only reaches 128 MiB.
+    assert_out_of_range(relocate(&data, ".text", &sections, &[("func", 0x1000_0000)]),
+                        "R_AARCH64_CALL26", 0xc);
+    // movz only holds bits 16 to 31 of an unsigned 32-bit value.
+    let sections = [(".text", 0x1_0000_0000), (".data", 0x1_0021_2340)];
+    assert_out_of_range(relocate(&data, ".text", &sections, &[("func", 0x1_0000_8000)]),
+                        "R_AARCH64_MOVW_UABS_G1", 0x10);
+}
+
+#[test]
+fn relocate_riscv() {
+    let data = fixture("elf64le_riscv_rel");
+    let sections = [(".text", 0x1000), (".data", 0x3000)];
+    let symbols = [("func", 0x4000)];
+    assert_eq!(relocate(&data, ".text", &sections, &symbols).unwrap(),
+               [0x17, 0x25, 0x00, 0x00,  // auipc a0, %pcrel_hi(var)
+                0x97, 0x25, 0x00, 0x00,  // auipc a1, %pcrel_hi(var + 8)
+                0x13, 0x05, 0x85, 0x00,  // addi a0, a0, %pcrel_lo(.L1)
+                0x23, 0xb6, 0xa5, 0x00,  // sd a0, %pcrel_lo(.L2)(a1)
+                0x97, 0x30, 0x00, 0x00,  // call func
+                0xe7, 0x80, 0x00, 0xff,
+                0x82, 0x80]);
+    // R_RISCV_ADD32/SUB32 and R_RISCV_SET_ULEB128/SUB_ULEB128 .Lend - load, and R_RISCV_64 var.
+    assert_eq!(relocate(&data, ".data", &sections, &symbols).unwrap(),
+               [0x1a, 0x00, 0x00, 0x00, 0x9a, 0x00, 0x00, 0x00,
+                0x08, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+
+    // auipc only reaches 2 GiB.
+    let sections = [(".text", 0x1000), (".data", 0x1_0000_0000)];
+    assert_out_of_range(relocate(&data, ".text", &sections, &symbols), "R_RISCV_PCREL_HI20", 0);
+
+    // The call at 0x1010 reaches 0x7fff_f7ff bytes forward, as rounding the upper 20 bits of
+    // larger offsets overflows, and 0x8000_0800 bytes backward.
+    let call = |data: &[u8]| u32::from_le_bytes([data[0x10], data[0x11], data[0x12], data[0x13]]);
+    let sections = [(".text", 0x1000), (".data", 0x3000)];
+    let relocated = relocate(&data, ".text", &sections, &[("func", 0x1010 + 0x7fff_f7ff)]).unwrap();
+    assert_eq!(call(&relocated), 0x7ffff097);
+    assert_out_of_range(relocate(&data, ".text", &sections, &[("func", 0x1010 + 0x7fff_f800)]),
+                        "R_RISCV_CALL_PLT", 0x10);
+    let sections = [(".text", 0x9000_0000), (".data", 0x9000_3000)];
+    let relocated = relocate(&data, ".text", &sections, &[("func", 0x9000_0010 - 0x8000_0800)]).unwrap();
+    assert_eq!(call(&relocated), 0x80000097);
+    assert_out_of_range(relocate(&data, ".text", &sections, &[("func", 0x9000_0010 - 0x8000_0801)]),
+                        "R_RISCV_CALL_PLT", 0x10);
+}
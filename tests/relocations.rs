@@ -0,0 +1,130 @@
+extern crate elftk;
+
+use elftk::*;
+
+mod common;
+use common::fixture;
+
+// Returns the explicit relocation table of the section named `name`.
+fn rela_table<'a>(reader: &Reader<'a>, name: &[u8]) -> RelaTableRef<'a> {
+    match reader.section_by_name(name).unwrap().data {
+        SectionDataRef::ExplicitRelocationTable(table) => table,
+        _                                              => panic!("not a RELA table"),
+    }
+}
+
+#[test]
+fn riscv_pcrel_pairs() {
+    let data = fixture("elf64le_riscv_rel");
+    let reader = Reader::new(&data).unwrap();
+    // The %pcrel_lo relocations at 8 and 0xc label the auipc instructions at 0 and 4.
+    assert_eq!(rela_table(&reader, b".rela.text").riscv_pcrel_hi_indexes().unwrap(),
+               [None, None, Some(0), Some(1), None]);
+    assert_eq!(rela_table(&reader, b".rela.data").riscv_pcrel_hi_indexes().unwrap(), [None; 5]);
+
+    // Other machines have no such pairs.
+    let data = fixture("elf64le_aarch64_rel");
+    let reader = Reader::new(&data).unwrap();
+    assert_eq!(rela_table(&reader, b".rela.text").riscv_pcrel_hi_indexes().unwrap(), [None; 6]);
+}
+
+#[test]
+fn mips64_relocation_info() {
+    // MIPS64 lays out r_info the same way in both byte orders.
+    for name in &["elf64le_mips64_rel", "elf64be_mips64_rel"] {
+        let data = fixture(name);
+        let reader = Reader::new(&data).unwrap();
+        let info = rela_table(&reader, b".rela.text").info(0).unwrap();
+        assert_eq!(info.symbol_index, 1, "{}", name);
+        assert_eq!(info.special_symbol, 0, "{}", name);
+        assert_eq!(info.types, [R_MIPS_GPREL16, R_MIPS_SUB, R_MIPS_HI16], "{}", name);
+        assert_eq!(info.relocation_type(), R_MIPS_GPREL16, "{}", name);
+
+        let info = rela_table(&reader, b".rela.data").info(0).unwrap();
+        assert_eq!((info.symbol_index, info.types), (1, [R_MIPS_64, R_MIPS_NONE, R_MIPS_NONE]), "{}", name);
+    }
+}
+
+#[test]
+fn sparc64_type_data() {
+    let data = fixture("elf64be_sparc64_rel");
+    let reader = Reader::new(&data).unwrap();
+    let table = rela_table(&reader, b".rela.text");
+    let info = table.info(0).unwrap();
+    assert_eq!((info.relocation_type(), info.type_data), (R_SPARC_HI22, 0));
+    // The second addend of R_SPARC_OLO10 is stored above the type.
+    let info = table.info(1).unwrap();
+    assert_eq!((info.symbol_index, info.relocation_type(), info.type_data), (1, R_SPARC_OLO10, -4));
+}
+
+#[test]
+fn relocation_names() {
+    assert_eq!(relocation_name(EM_S390, ELFCLASS64, R_390_64), "R_390_64");
+    assert_eq!(relocation_name(EM_S390, ELFCLASS64, R_390_PC32DBL), "R_390_PC32DBL");
+    assert_eq!(relocation_name(EM_SPARCV9, ELFCLASS64, R_SPARC_OLO10), "R_SPARC_OLO10");
+    assert_eq!(relocation_name(EM_SPARC, ELFCLASS32, R_SPARC_HI22), "R_SPARC_HI22");
+    assert_eq!(relocation_name(EM_LOONGARCH, ELFCLASS64, R_LARCH_B26), "R_LARCH_B26");
+    assert_eq!(relocation_name(EM_LOONGARCH, ELFCLASS64, R_LARCH_PCALA_HI20), "R_LARCH_PCALA_HI20");
+    // x32 uses the x86-64 relocations.
+    assert_eq!(relocation_name(EM_X86_64, ELFCLASS32, R_X86_64_32), "R_X86_64_32");
+    assert_eq!(relocation_name(EM_S390, ELFCLASS64, 0xffff), "<unknown>");
+}
+
+#[test]
+fn relocation_descriptions() {
+    let describe = |machine, class, relocation_type| {
+        let description = relocation_description(machine, class, relocation_type).unwrap();
+        (description.size, description.overflow, description.formula)
+    };
+    use RelocationFormula::*;
+    use RelocationOverflow::*;
+    assert_eq!(describe(EM_386, ELFCLASS32, R_386_32), (4, Bitfield, Absolute));
+    assert_eq!(describe(EM_386, ELFCLASS32, R_386_PC32), (4, Signed, PcRelative));
+    assert_eq!(describe(EM_386, ELFCLASS32, R_386_GOT32), (4, Unchecked, GotOffset));
+    assert_eq!(describe(EM_386, ELFCLASS32, R_386_16), (2, Bitfield, Absolute));
+    assert_eq!(describe(EM_X86_64, ELFCLASS64, R_X86_64_PC32), (4, Signed, PcRelative));
+    assert_eq!(describe(EM_X86_64, ELFCLASS64, R_X86_64_32), (4, Unsigned, Absolute));
+    assert_eq!(describe(EM_X86_64, ELFCLASS64, R_X86_64_32S), (4, Signed, Absolute));
+    assert_eq!(describe(EM_AARCH64, ELFCLASS64, R_AARCH64_ABS64), (8, Unchecked, Absolute));
+    assert_eq!(describe(EM_AARCH64, ELFCLASS64, R_AARCH64_ADR_PREL_PG_HI21), (4, Signed, PagePcRelative));
+    assert_eq!(describe(EM_AARCH64, ELFCLASS64, R_AARCH64_CALL26), (4, Signed, PcRelative));
+    assert_eq!(describe(EM_ARM, ELFCLASS32, R_ARM_ABS32), (4, Unchecked, Absolute));
+    assert_eq!(describe(EM_RISCV, ELFCLASS64, R_RISCV_64), (8, Unchecked, Absolute));
+    assert_eq!(describe(EM_RISCV, ELFCLASS64, R_RISCV_BRANCH), (4, Signed, PcRelative));
+    // ULEB128 fields have no fixed size.
+    assert_eq!(describe(EM_RISCV, ELFCLASS64, R_RISCV_SET_ULEB128), (0, Unchecked, Absolute));
+    assert_eq!(describe(EM_PPC64, ELFCLASS64, R_PPC64_REL24), (4, Signed, PcRelative));
+    assert_eq!(describe(EM_MIPS, ELFCLASS32, R_MIPS_32), (4, Bitfield, Absolute));
+
+    let description = relocation_description(EM_X86_64, ELFCLASS64, R_X86_64_PLT32).unwrap();
+    assert_eq!(description.name, "R_X86_64_PLT32");
+    assert!(description.pc_relative && description.plt && !description.got && !description.dynamic);
+    assert!(relocation_description(EM_X86_64, ELFCLASS64, 0xffff).is_none());
+}
+
+#[test]
+fn relocations_for_section() {
+    let data = fixture("elf32le_rel");
+    let reader = Reader::new(&data).unwrap();
+    let text = reader.section_by_name(b".text").unwrap();
+    let tables: Vec<_> = reader.relocations_for(&text).collect::<Result<_>>().unwrap();
+    assert_eq!(tables.len(), 1);
+    let target = tables[0].target().unwrap();
+    assert_eq!(reader.section_index(target), reader.section_index(text.shdr));
+    let relocations: Vec<_> = tables[0].iter()
+        .map(|relocation| relocation.unwrap())
+        .map(|relocation| {
+            (relocation.offset, relocation.relocation_type(), relocation.symbol.name(), relocation.addend)
+        })
+        .collect();
+    assert_eq!(relocations, [
+        (1, R_386_32, Some(&b"g"[..]), None),
+        (6, R_386_PC32, Some(&b"printf"[..]), None),
+        // Section symbols are named after their section.
+        (12, R_386_32, Some(&b".text"[..]), None),
+    ]);
+
+    // .data has no relocations.
+    let data_section = reader.section_by_name(b".data").unwrap();
+    assert_eq!(reader.relocations_for(&data_section).count(), 0);
+}
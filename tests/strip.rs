@@ -0,0 +1,96 @@
+extern crate elftk;
+
+use elftk::*;
+
+mod common;
+use common::fixture;
+
+// Strips the fixture `name`, after adding a `.debug_str` section, and returns the stripped file.
+fn strip(name: &str, mode: StripMode) -> Vec<u8> {
+    let data = fixture(name);
+    let mut file = ElfFile::new(&Reader::new(&data).unwrap()).unwrap();
+    file.add_section(FileSection::new(b".debug_str", SHT_PROGBITS, SHF_MERGE | SHF_STRINGS, b"int\0".to_vec()));
+    file.strip(mode).unwrap();
+    file.write().unwrap()
+}
+
+fn section_names(reader: &Reader) -> Vec<String> {
+    reader.section_headers().into_iter()
+        .map(|shdr| String::from_utf8_lossy(reader.section_name(shdr)).into_owned())
+        .collect()
+}
+
+fn symbol_names<'a>(reader: &Reader<'a>) -> Vec<&'a [u8]> {
+    let symtab = match reader.symtab().unwrap().unwrap().data {
+        SectionDataRef::SymbolTable(symtab) => symtab,
+        _                                   => panic!("not a symbol table"),
+    };
+    (0..symtab.len()).map(|index| symtab.get(index).unwrap().name().unwrap()).collect()
+}
+
+#[test]
+fn strip_all_relocatable() {
+    let data = strip("elf32le_rel", StripMode::All);
+    let reader = Reader::new(&data).unwrap();
+    assert_eq!(section_names(&reader), ["", ".group", ".text", ".rel.text", ".text.inline", ".rel.text.inline",
+                                        ".data", ".bss", ".note.GNU-stack", ".symtab", ".strtab", ".shstrtab"]);
+    // Only the symbols used by relocations, and the group signature, are left.
+    assert_eq!(symbol_names(&reader), [&b""[..], b".text", b"counter", b"inline_fn", b"g", b"printf"]);
+    assert_eq!(reader.section_by_name(b".group").unwrap().shdr.sh_info(), 3);
+
+    // The relocations still refer to the same symbols after renumbering.
+    let text = reader.section_by_name(b".text").unwrap();
+    let relocations = reader.relocations_for(&text).next().unwrap().unwrap();
+    let symbols: Vec<_> = relocations.iter()
+        .map(|relocation| relocation.unwrap().symbol.name().unwrap())
+        .collect();
+    assert_eq!(symbols, [&b"g"[..], b"printf", b".text"]);
+}
+
+#[test]
+fn strip_all_executable() {
+    let data = strip("elf64le_exec", StripMode::All);
+    let reader = Reader::new(&data).unwrap();
+    assert_eq!(section_names(&reader), ["", ".interp", ".note.ABI-tag", ".text", ".tdata", ".tbss", ".data", ".bss",
+                                        ".comment", ".shstrtab"]);
+    assert!(reader.symtab().unwrap().is_none());
+    let original = fixture("elf64le_exec");
+    let segments = |reader: &Reader| reader.program_headers().into_iter()
+        .map(|phdr| (phdr.p_type(), phdr.p_offset(), phdr.p_vaddr(), phdr.p_filesz(), phdr.p_memsz()))
+        .collect::<Vec<_>>();
+    assert_eq!(segments(&reader), segments(&Reader::new(&original).unwrap()));
+}
+
+#[test]
+fn remove_linked_sections() {
+    // The hash table and the symbol versions go with .dynsym, but .gnu.version_r and .dynamic
+    // stay with .dynstr.
+    let data = fixture("elf64le_verneed");
+    let mut file = ElfFile::new(&Reader::new(&data).unwrap()).unwrap();
+    file.remove_sections(|_, section| section.name == b".dynsym").unwrap();
+    let names: Vec<_> = file.sections.iter().map(|section| String::from_utf8_lossy(&section.name).into_owned()).collect();
+    assert!(!names.iter().any(|name| name == ".gnu.version" || name == ".hash" || name == ".dynsym"));
+    let dynstr = file.section_index(b".dynstr").unwrap();
+    let dynamic = file.section_index(b".dynamic").unwrap();
+    assert_eq!(file.sections[dynamic as usize].header.sh_link, dynstr);
+
+    // Removing .dynstr would leave .dynsym and .dynamic linked to nothing.
+    let mut file = ElfFile::new(&Reader::new(&data).unwrap()).unwrap();
+    let count = file.sections.len();
+    let dynstr = file.section_index(b".dynstr").unwrap();
+    match file.remove_sections(|_, section| section.name == b".dynstr") {
+        Err(Error::InvalidLinkedSection { linked }) => assert_eq!(linked, dynstr),
+        result => panic!("unexpected result {:?}", result),
+    }
+    assert_eq!(file.sections.len(), count);
+
+    // SHF_LINK_ORDER sections go with the section they are ordered with.
+    let data = fixture("elf64le_x86_64_rel");
+    let mut file = ElfFile::new(&Reader::new(&data).unwrap()).unwrap();
+    let data_section = file.section_index(b".data").unwrap();
+    let mut ordered = FileSection::new(b".data.meta", SHT_PROGBITS, SHF_LINK_ORDER, vec![0; 4]);
+    ordered.header.sh_link = data_section;
+    file.add_section(ordered);
+    file.remove_sections(|_, section| section.name == b".data" || section.name == b".rela.text").unwrap();
+    assert_eq!(file.section_index(b".data.meta"), None);
+}
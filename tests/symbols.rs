@@ -0,0 +1,132 @@
+extern crate elftk;
+
+use elftk::*;
+
+mod common;
+use common::fixture;
+
+// Returns the symbol named `name` in the symbol table of `reader`.
+fn symbol<'a>(reader: &Reader<'a>, name: &[u8]) -> SymbolRef<'a> {
+    let symtab = match reader.symtab().unwrap().unwrap().data {
+        SectionDataRef::SymbolTable(symtab) => symtab,
+        _                                   => panic!("not a symbol table"),
+    };
+    (0..symtab.len())
+        .map(|index| symtab.get(index).unwrap())
+        .find(|symbol| symbol.symbol_name == Some(name))
+        .unwrap()
+}
+
+#[test]
+fn thumb_symbols() {
+    let data = fixture("elf32le_arm_rel");
+    let reader = Reader::new(&data).unwrap();
+    let thumb = |name: &[u8]| {
+        let symbol = symbol(&reader, name);
+        (symbol.is_thumb(), symbol.address())
+    };
+    assert_eq!(thumb(b"thumb_fn"), (true, 0));
+    assert_eq!(thumb(b"arm_fn"), (false, 8));
+    assert_eq!(thumb(b"legacy_fn"), (true, 0));
+    // Bit 0 only marks Thumb code for functions.
+    assert_eq!(thumb(b"odd_byte"), (false, 1));
+
+    // Other machines have no Thumb symbols.
+    let data = fixture("elf32le_rel");
+    let reader = Reader::new(&data).unwrap();
+    assert!(!symbol(&reader, b"helper").is_thumb());
+}
+
+#[test]
+fn ppc64_local_entry_offsets() {
+    let data = fixture("elf64le_ppc64_rel");
+    let reader = Reader::new(&data).unwrap();
+    let offsets: Vec<_> = ["entry0", "entry1", "entry2", "entry3", "entry6", "entry7"].iter()
+        .map(|name| symbol(&reader, name.as_bytes()).ppc64_local_entry_offset())
+        .collect();
+    // Encoding 1 means the global and local entry points coincide and 7 is reserved.
+    assert_eq!(offsets, [0, 0, 4, 8, 64, 0]);
+}
+
+#[test]
+fn ppc64_function_addresses() {
+    // ELFv1 relocatable files fill in the descriptor with a relocation.
+    let data = fixture("elf64be_ppc64_rel");
+    let reader = Reader::new(&data).unwrap();
+    assert_eq!(reader.function_address(&symbol(&reader, b"func")).unwrap(), 8);
+
+    // ELFv1 executables hold the code address in the descriptor.
+    let data = fixture("elf64be_ppc64_exec");
+    let reader = Reader::new(&data).unwrap();
+    assert_eq!(reader.function_address(&symbol(&reader, b"func")).unwrap(), 0x1000_0008);
+    assert_eq!(reader.function_address(&symbol(&reader, b"code")).unwrap(), 0x1000_0008);
+
+    // ELFv2 has no function descriptors.
+    let data = fixture("elf64le_ppc64_rel");
+    let reader = Reader::new(&data).unwrap();
+    assert_eq!(reader.function_address(&symbol(&reader, b"entry2")).unwrap(), 0);
+}
+
+#[test]
+fn symbol_index() {
+    let data = fixture("elf64le_plt");
+    let reader = Reader::new(&data).unwrap();
+    let index = reader.symbol_index().unwrap();
+    // call and bar_address are in both symbol tables, and undefined symbols have no address.
+    let entries: Vec<_> = index.entries().iter()
+        .map(|entry| (entry.address, entry.size, String::from_utf8_lossy(&entry.name).into_owned(), entry.source))
+        .collect();
+    assert_eq!(entries, [
+        (0x1010, 16, "foo@plt".to_string(), AddressSource::Plt),
+        (0x1020, 8, "bar@plt".to_string(), AddressSource::Plt),
+        (0x1030, 8, "bar_address".to_string(), AddressSource::SymbolTable),
+        (0x1040, 16, "call".to_string(), AddressSource::SymbolTable),
+        (0x2000, 0, "_DYNAMIC".to_string(), AddressSource::SymbolTable),
+    ]);
+
+    let lookup = |address| index.lookup(address).map(|entry| String::from_utf8_lossy(&entry.name).into_owned());
+    assert_eq!(lookup(0x1000), None);
+    assert_eq!(lookup(0x101b), Some("foo@plt".to_string()));
+    assert_eq!(lookup(0x1037), Some("bar_address".to_string()));
+    assert_eq!(lookup(0x1038), None);
+    assert_eq!(lookup(0x104f), Some("call".to_string()));
+    assert_eq!(lookup(0x2000), Some("_DYNAMIC".to_string()));
+    assert_eq!(lookup(0x2008), None);
+}
+
+#[test]
+fn symbol_index_labels_and_mapping_symbols() {
+    let description = ElfDescription::from_yaml("
+header: {class: ELF64, data: LE, type: EXEC, machine: ARM 64-bit architecture (AARCH64)}
+sections:
+  - name: .text
+    type: PROGBITS
+    flags: AX
+    address: 0x1000
+    alignment: 4
+    content: 1f2003d5 1f2003d5 1f2003d5 c0035fd6 00000000
+  - name: .symtab
+    type: SYMTAB
+    link: .strtab
+    alignment: 8
+    symbols:
+      - {name: $x, section: .text, value: 0x1000}
+      - {name: .Lloop, section: .text, value: 0x1008}
+      - {name: $d.1, section: .text, value: 0x1010}
+      - {name: func, type: FUNC, binding: GLOBAL, section: .text, value: 0x1000, size: 16}
+  - name: .strtab
+    type: STRTAB
+").unwrap();
+    let data = description.build().unwrap();
+    let reader = Reader::new(&data).unwrap();
+    let index = reader.symbol_index().unwrap();
+    let names: Vec<_> = index.entries().iter().map(|entry| String::from_utf8_lossy(&entry.name).into_owned()).collect();
+    assert_eq!(names, ["func", ".Lloop"]);
+
+    let lookup = |address| index.lookup(address).map(|entry| String::from_utf8_lossy(&entry.name).into_owned());
+    // The zero-size label names only its own address, and the function contains the rest.
+    assert_eq!(lookup(0x1004), Some("func".to_string()));
+    assert_eq!(lookup(0x1008), Some(".Lloop".to_string()));
+    assert_eq!(lookup(0x100c), Some("func".to_string()));
+    assert_eq!(lookup(0x1010), None);
+}
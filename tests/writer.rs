@@ -0,0 +1,125 @@
+extern crate elftk;
+
+use elftk::*;
+
+// Builds an executable with .text and .bss in one PT_LOAD segment.
+fn executable(format: ElfFormat) -> Vec<u8> {
+    let mut builder = ElfBuilder::new(format, FileHeader {
+        e_type: ET_EXEC,
+        e_machine: EM_RISCV,
+        e_entry: 0x1_0010,
+        ..FileHeader::default()
+    });
+    let mut text = OutputSection::new(b".text", SHT_PROGBITS, SHF_ALLOC | SHF_EXECINSTR, vec![0x13, 0, 0, 0]);
+    text.sh_addr = 0x1_0010;
+    text.sh_addralign = 4;
+    let text = builder.add_section(text);
+    let mut bss = OutputSection::nobits(b".bss", SHF_ALLOC | SHF_WRITE, 0x100);
+    bss.sh_addr = 0x1_0100;
+    let bss = builder.add_section(bss);
+    builder.add_segment(OutputSegment::new(PT_LOAD, PF_R | PF_W | PF_X, 0x1000, vec![text, bss]));
+    builder.build().unwrap()
+}
+
+#[test]
+fn build_all_formats() {
+    let formats = [ElfFormat::Elf32LE(()), ElfFormat::Elf32BE(()), ElfFormat::Elf64LE(()), ElfFormat::Elf64BE(())];
+    for &format in &formats {
+        let data = executable(format);
+        let reader = Reader::new(&data).unwrap();
+        assert_eq!((reader.is_64bit(), reader.format().is_little_endian()),
+                   (format.is_64bit(), format.is_little_endian()));
+        assert_eq!(reader.elf_header().e_entry(), 0x1_0010);
+
+        let text = reader.section_by_name(b".text").unwrap();
+        assert_eq!(text.shdr.sh_addr(), 0x1_0010);
+        match text.data {
+            SectionDataRef::Uninterpreted(data) => assert_eq!(data, [0x13, 0, 0, 0]),
+            _                                   => panic!("unexpected .text data"),
+        }
+
+        // The segment starts at .text, at an offset congruent to its address, and its memory
+        // image extends over .bss.
+        let phdr = reader.program_headers().into_iter().next().unwrap();
+        assert_eq!((phdr.p_type(), phdr.p_vaddr(), phdr.p_filesz(), phdr.p_memsz()),
+                   (PT_LOAD, 0x1_0010, 4, 0x1f0));
+        assert_eq!(phdr.p_offset(), text.shdr.sh_offset());
+        assert_eq!(phdr.p_offset() % 0x1000, 0x10);
+    }
+}
+
+#[test]
+fn extended_section_count() {
+    let mut builder = ElfBuilder::new(ElfFormat::Elf64BE(()), FileHeader {
+        e_type: ET_REL,
+        e_machine: EM_PPC64,
+        ..FileHeader::default()
+    });
+    for index in 0..SHN_LORESERVE {
+        let name = format!(".text.{}", index);
+        builder.add_section(OutputSection::new(name.as_bytes(), SHT_PROGBITS, SHF_ALLOC | SHF_EXECINSTR, Vec::new()));
+    }
+    let data = builder.build().unwrap();
+    let reader = Reader::new(&data).unwrap();
+    // The null section, the added sections, and .shstrtab.
+    assert_eq!((reader.elf_header().e_shnum(), reader.elf_header().e_shstrndx()), (0, SHN_XINDEX));
+    assert_eq!(reader.section_headers().len(), usize::from(SHN_LORESERVE) + 2);
+    assert!(reader.section_by_name(b".text.65279").is_some());
+}
+
+#[test]
+fn extended_symbol_section_indexes() {
+    let mut builder = SymbolTableBuilder::new();
+    builder.add(OutputSymbol::new(b"low", STB_GLOBAL, STT_FUNC, SectionIndex::Normal(3), 0x10, 4));
+    builder.add(OutputSymbol::new(b"high", STB_GLOBAL, STT_OBJECT, SectionIndex::Normal(69999), 0x20, 8));
+    builder.add(OutputSymbol::new(b"abs", STB_GLOBAL, STT_NOTYPE, SectionIndex::Reserved(SHN_ABS), 0x30, 0));
+    let table = builder.build(ElfFormat::Elf64LE(())).unwrap();
+
+    // Only symbols whose st_shndx is SHN_XINDEX have a nonzero entry.
+    let words: Vec<_> = table.section_indexes.unwrap().chunks(4)
+        .map(|word| u32::from_le_bytes([word[0], word[1], word[2], word[3]]))
+        .collect();
+    assert_eq!(words, [0, 0, 69999, 0]);
+
+    let st_shndx: Vec<_> = table.symbols.chunks(24)
+        .map(|entry| u16::from_le_bytes([entry[6], entry[7]]))
+        .collect();
+    assert_eq!(st_shndx, [SHN_UNDEF, 3, SHN_XINDEX, SHN_ABS]);
+}
+
+#[test]
+fn string_table_suffixes() {
+    let mut builder = StringTableBuilder::new();
+    for &string in &[&b".text"[..], b".rela.text", b".data", b".text", b""] {
+        builder.add(string);
+    }
+    let table = builder.build();
+    // .text is the tail of .rela.text and each string is stored once.
+    assert_eq!(table.data(), b"\0.rela.text\0.data\0");
+    assert_eq!(table.offset(b".rela.text"), Some(1));
+    assert_eq!(table.offset(b".text"), Some(6));
+    assert_eq!(table.offset(b".data"), Some(12));
+    assert_eq!(table.offset(b""), Some(0));
+    assert_eq!(table.offset(b".bss"), None);
+}
+
+#[test]
+fn local_symbols_first() {
+    let mut builder = SymbolTableBuilder::new();
+    let global = builder.add(OutputSymbol::new(b"main", STB_GLOBAL, STT_FUNC, SectionIndex::Normal(1), 0, 8));
+    let local = builder.add(OutputSymbol::new(b"helper", STB_LOCAL, STT_FUNC, SectionIndex::Normal(1), 8, 4));
+    let weak = builder.add(OutputSymbol::new(b"hook", STB_WEAK, STT_NOTYPE, SectionIndex::Normal(0), 0, 0));
+    let table = builder.build(ElfFormat::Elf32BE(())).unwrap();
+
+    assert_eq!((table.index(local), table.index(global), table.index(weak)), (Some(1), Some(2), Some(3)));
+    assert_eq!(table.first_non_local, 2);
+    assert_eq!(table.symbols.len(), 4 * 16);
+    assert!(table.section_indexes.is_none());
+    // Elf32_Sym has st_name first, in the file's byte order.
+    let st_name = |index: usize| {
+        let entry = &table.symbols[index * 16..];
+        u32::from_be_bytes([entry[0], entry[1], entry[2], entry[3]]) as usize
+    };
+    assert_eq!(&table.strings[st_name(1)..st_name(1) + 7], b"helper\0");
+    assert_eq!(&table.strings[st_name(2)..st_name(2) + 5], b"main\0");
+}